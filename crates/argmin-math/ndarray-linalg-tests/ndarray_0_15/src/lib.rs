@@ -1,9 +1,15 @@
 mod add {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/add.rs"));
 }
+mod cholesky {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/cholesky.rs"));
+}
 mod conj {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/conj.rs"));
 }
+mod diag {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/diag.rs"));
+}
 mod div {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/div.rs"));
 }
@@ -22,12 +28,21 @@ mod l1norm {
 mod l2norm {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/l2norm.rs"));
 }
+mod lu {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/lu.rs"));
+}
+mod map {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/map.rs"));
+}
 mod minmax {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/minmax.rs"));
 }
 mod mul {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/mul.rs"));
 }
+mod qr {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/qr.rs"));
+}
 mod random {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/random.rs"));
 }
@@ -37,9 +52,15 @@ mod scaledadd {
 mod scaledsub {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/scaledsub.rs"));
 }
+mod scalerows {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/scalerows.rs"));
+}
 mod signum {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/signum.rs"));
 }
+mod solve {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/solve.rs"));
+}
 mod sub {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../ndarray-tests-src/sub.rs"));
 }
@@ -0,0 +1,56 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use approx::assert_relative_eq;
+    use argmin_math::ArgminDiag;
+    use ndarray::array;
+    use ndarray::{Array1, Array2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diag_ $t>]() {
+                    let x = array![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t],
+                        [3 as $t, 6 as $t, 7 as $t]
+                    ];
+                    let y = array![1 as $t, 5 as $t, 7 as $t];
+                    let res = <Array2<$t> as ArgminDiag<Array1<$t>>>::diag(&x);
+                    assert_eq!(res.len(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(y[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_diag_not_square() {
+        let x = array![[1.0f64, 4.0, 3.0], [2.0, 5.0, 6.0]];
+        let _ = <Array2<f64> as ArgminDiag<Array1<f64>>>::diag(&x);
+    }
+}
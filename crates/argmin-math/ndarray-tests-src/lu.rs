@@ -0,0 +1,52 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use approx::assert_relative_eq;
+    use argmin_math::ArgminLU;
+    use ndarray::array;
+    use ndarray::{Array1, Array2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_solve_ $t>]() {
+                    let a = array![
+                        [4 as $t, 2 as $t, 0 as $t],
+                        [2 as $t, 5 as $t, 1 as $t],
+                        [0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = array![1 as $t, -2 as $t, 3 as $t];
+                    let b = array![0 as $t, -5 as $t, 7 as $t];
+                    let res = <Array2<$t> as ArgminLU<Array1<$t>, Array1<$t>>>::lu_solve(&a, &b).unwrap();
+                    assert_eq!(res.len(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_solve_error_ $t>]() {
+                    let a = array![[1 as $t, 2 as $t], [2 as $t, 4 as $t]];
+                    let b = array![1 as $t, 1 as $t];
+                    let res = <Array2<$t> as ArgminLU<Array1<$t>, Array1<$t>>>::lu_solve(&a, &b);
+                    assert!(res.is_err());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
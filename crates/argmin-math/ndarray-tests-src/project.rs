@@ -0,0 +1,85 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use approx::assert_relative_eq;
+    use argmin_math::ArgminProject;
+    use ndarray::array;
+    use ndarray::{Array1, Array2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_project_vec_ $t>]() {
+                    let a = array![1 as $t, 4 as $t, 8 as $t];
+                    let lower = array![2 as $t, 3 as $t, 4 as $t];
+                    let upper = array![3 as $t, 5 as $t, 6 as $t];
+                    let target = array![2 as $t, 4 as $t, 6 as $t];
+                    let res = <Array1<$t> as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_project_mat_ $t>]() {
+                    let a = array![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let lower = array![
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [1 as $t, 6 as $t, 5 as $t]
+                    ];
+                    let upper = array![
+                        [3 as $t, 5 as $t, 6 as $t],
+                        [3 as $t, 7 as $t, 7 as $t]
+                    ];
+                    let target = array![
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [2 as $t, 6 as $t, 7 as $t]
+                    ];
+                    let res = <Array2<$t> as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[(j, i)] as f64, res[(j, i)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_project_vec_panic_ $t>]() {
+                    let a = array![1 as $t, 4 as $t];
+                    let lower = array![2 as $t, 3 as $t, 4 as $t];
+                    let upper = array![3 as $t, 5 as $t, 6 as $t];
+                    <Array1<$t> as ArgminProject>::project(&a, &lower, &upper);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
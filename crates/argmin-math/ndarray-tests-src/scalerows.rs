@@ -0,0 +1,59 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use argmin_math::ArgminScaleRows;
+    use ndarray::array;
+    use ndarray::{Array1, Array2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scale_rows_ $t>]() {
+                    let x = array![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let factors = array![3 as $t, 2 as $t];
+                    let y = array![
+                        [3 as $t, 12 as $t, 24 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <Array2<$t> as ArgminScaleRows<Array1<$t>>>::scale_rows(&x, &factors);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((y[(j, i)] - res[(j, i)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_scale_rows_wrong_length() {
+        let x = array![[1.0f64, 4.0], [2.0, 5.0]];
+        let _ = x.scale_rows(&array![1.0f64]);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholesky, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct NotPositiveDefiniteError;
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite")
+    }
+}
+
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl<const N: usize> ArgminCholesky<[$t; N], [$t; N]> for [[$t; N]; N] {
+            fn cholesky_solve(&self, b: &[$t; N]) -> Result<[$t; N], Error> {
+                // Lower triangular factor `l` with `self = l * l^T`
+                let mut l = [[0.0 as $t; N]; N];
+                for j in 0..N {
+                    let mut d = self[j][j];
+                    for k in 0..j {
+                        d -= l[j][k] * l[j][k];
+                    }
+                    if d <= 0.0 || !d.is_finite() {
+                        return Err(NotPositiveDefiniteError {}.into());
+                    }
+                    let d = d.sqrt();
+                    l[j][j] = d;
+                    for i in (j + 1)..N {
+                        let mut s = self[i][j];
+                        for k in 0..j {
+                            s -= l[i][k] * l[j][k];
+                        }
+                        l[i][j] = s / d;
+                    }
+                }
+                // Forward substitution `l * y = b`
+                let mut x = *b;
+                for i in 0..N {
+                    for k in 0..i {
+                        x[i] -= l[i][k] * x[k];
+                    }
+                    x[i] /= l[i][i];
+                }
+                // Backward substitution `l^T * x = y`
+                for i in (0..N).rev() {
+                    for k in (i + 1)..N {
+                        x[i] -= l[k][i] * x[k];
+                    }
+                    x[i] /= l[i][i];
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_cholesky!(f32);
+make_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    // [[4, 2, 0], [2, 5, 1], [0, 1, 3]]
+                    let a = [
+                        [4 as $t, 2 as $t, 0 as $t],
+                        [2 as $t, 5 as $t, 1 as $t],
+                        [0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = [1 as $t, -2 as $t, 3 as $t];
+                    let b = [0 as $t, -5 as $t, 7 as $t];
+                    let res = <[[$t; 3]; 3] as ArgminCholesky<[$t; 3], [$t; 3]>>::cholesky_solve(&a, &b).unwrap();
+                                        for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_not_pd_ $t>]() {
+                    let a = [[1 as $t, 2 as $t], [2 as $t, 1 as $t]];
+                    let b = [1 as $t, 1 as $t];
+                    let res = <[[$t; 2]; 2] as ArgminCholesky<[$t; 2], [$t; 2]>>::cholesky_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
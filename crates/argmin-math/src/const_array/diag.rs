@@ -0,0 +1,82 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiag;
+use num_complex::Complex;
+
+macro_rules! make_diag {
+    ($t:ty) => {
+        impl<const N: usize> ArgminDiag<[$t; N]> for [[$t; N]; N] {
+            #[inline]
+            fn diag(&self) -> [$t; N] {
+                std::array::from_fn(|i| self[i][i])
+            }
+        }
+    };
+}
+
+make_diag!(i8);
+make_diag!(u8);
+make_diag!(i16);
+make_diag!(u16);
+make_diag!(i32);
+make_diag!(u32);
+make_diag!(i64);
+make_diag!(u64);
+make_diag!(f32);
+make_diag!(f64);
+make_diag!(Complex<i8>);
+make_diag!(Complex<u8>);
+make_diag!(Complex<i16>);
+make_diag!(Complex<u16>);
+make_diag!(Complex<i32>);
+make_diag!(Complex<u32>);
+make_diag!(Complex<i64>);
+make_diag!(Complex<u64>);
+make_diag!(Complex<f32>);
+make_diag!(Complex<f64>);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_diag);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diag_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 9 as $t]
+                    ];
+                    let target = [1 as $t, 5 as $t, 9 as $t];
+                    let res = <[[$t; 3]; 3] as ArgminDiag<[$t; 3]>>::diag(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
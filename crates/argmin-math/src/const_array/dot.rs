@@ -0,0 +1,308 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use num_complex::Complex;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl<const N: usize> ArgminDot<[$t; N], $t> for [$t; N] {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> $t {
+                self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+            }
+        }
+
+        impl<const N: usize> ArgminDot<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn dot(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] * *other)
+            }
+        }
+
+        impl<const N: usize> ArgminDot<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| other[i] * *self)
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<[$t; M], [[$t; M]; N]> for [$t; N] {
+            #[inline]
+            fn dot(&self, other: &[$t; M]) -> [[$t; M]; N] {
+                std::array::from_fn(|i| std::array::from_fn(|j| self[i] * other[j]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<[$t; N], [$t; M]> for [[$t; N]; M] {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> [$t; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminDot<[$t; N], $t>>::dot(&self[i], other))
+            }
+        }
+
+        impl<const N: usize, const M: usize, const K: usize> ArgminDot<[[$t; K]; N], [[$t; K]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn dot(&self, other: &[[$t; K]; N]) -> [[$t; K]; M] {
+                std::array::from_fn(|i| {
+                    std::array::from_fn(|j| (0..N).map(|k| self[i][k] * other[k][j]).sum())
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<$t, [[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn dot(&self, other: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminDot<$t, [$t; N]>>::dot(&self[i], other))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<[[$t; N]; M], [[$t; N]; M]> for $t {
+            #[inline]
+            fn dot(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminDot<$t, [$t; N]>>::dot(&other[i], self))
+            }
+        }
+    };
+}
+
+make_dot!(f32);
+make_dot!(f64);
+make_dot!(i8);
+make_dot!(i16);
+make_dot!(i32);
+make_dot!(i64);
+make_dot!(u8);
+make_dot!(u16);
+make_dot!(u32);
+make_dot!(u64);
+make_dot!(Complex<f32>);
+make_dot!(Complex<f64>);
+make_dot!(Complex<i8>);
+make_dot!(Complex<i16>);
+make_dot!(Complex<i32>);
+make_dot!(Complex<i64>);
+make_dot!(Complex<u8>);
+make_dot!(Complex<u16>);
+make_dot!(Complex<u32>);
+make_dot!(Complex<u64>);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_dot);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_vec_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = [4 as $t, 5 as $t, 6 as $t];
+                    let res: $t = a.dot(&b);
+                    assert_relative_eq!(32 as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_vec_complex_ $t>]() {
+                    let a = [
+                        Complex::new(2 as $t, 2 as $t),
+                        Complex::new(5 as $t, 2 as $t),
+                        Complex::new(3 as $t, 2 as $t),
+                    ];
+                    let b = [
+                        Complex::new(5 as $t, 3 as $t),
+                        Complex::new(2 as $t, 4 as $t),
+                        Complex::new(8 as $t, 4 as $t),
+                    ];
+                    let res: Complex<$t> = a.dot(&b);
+                    let target = a[0]*b[0] + a[1]*b[1] + a[2]*b[2];
+                    assert_relative_eq!(res.re as f64, target.re as f64, epsilon = f64::EPSILON);
+                    assert_relative_eq!(res.im as f64, target.im as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_scalar_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product: [$t; 3] = a.dot(&b);
+                    let res = [2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, product[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scalar_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product: [$t; 3] = b.dot(&a);
+                    let res = [2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, product[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_vec_outer_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = [4 as $t, 5 as $t];
+                    let res = [
+                        [4 as $t, 5 as $t],
+                        [8 as $t, 10 as $t],
+                        [12 as $t, 15 as $t]
+                    ];
+                    let product: [[$t; 2]; 3] = a.dot(&b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, product[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = [1 as $t, 2 as $t, 3 as $t];
+                    let res = [14 as $t, 32 as $t];
+                    let product: [$t; 2] = a.dot(&b);
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i] as f64, product[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_complex_ $t>]() {
+                    let a = [
+                        [Complex::new(2 as $t, 2 as $t), Complex::new(5 as $t, 2 as $t)],
+                        [Complex::new(2 as $t, 2 as $t), Complex::new(5 as $t, 2 as $t)],
+                    ];
+                    let b = [
+                        Complex::new(5 as $t, 1 as $t),
+                        Complex::new(2 as $t, 1 as $t),
+                    ];
+                    let res = [
+                        a[0][0] * b[0] + a[0][1] * b[1],
+                        a[1][0] * b[0] + a[1][1] * b[1],
+                    ];
+                    let product: [Complex<$t>; 2] = a.dot(&b);
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re as f64, product[i].re as f64, epsilon = f64::EPSILON);
+                        assert_relative_eq!(res[i].im as f64, product[i].im as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 1 as $t]
+                    ];
+                    let b = [
+                        [3 as $t, 2 as $t, 1 as $t],
+                        [6 as $t, 5 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 3 as $t]
+                    ];
+                    let res = [
+                        [21 as $t, 24 as $t, 18 as $t],
+                        [54 as $t, 57 as $t, 42 as $t],
+                        [23 as $t, 20 as $t, 14 as $t]
+                    ];
+                    let product: [[$t; 3]; 3] = a.dot(&b);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, product[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_non_square_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = [
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                        [5 as $t, 6 as $t]
+                    ];
+                    let res = [
+                        [22 as $t, 28 as $t],
+                        [49 as $t, 64 as $t]
+                    ];
+                    let product: [[$t; 2]; 2] = a.dot(&b);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, product[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_scalar_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let res = [
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t]
+                    ];
+                    let product: [[$t; 3]; 2] = a.dot(&b);
+                    let product2: [[$t; 3]; 2] = b.dot(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, product[i][j] as f64, epsilon = f64::EPSILON);
+                            assert_relative_eq!(res[i][j] as f64, product2[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,128 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminInv, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct InverseError;
+
+impl fmt::Display for InverseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Non-invertible matrix")
+    }
+}
+
+macro_rules! make_inv {
+    ($t:ty) => {
+        // Gauss-Jordan elimination with partial pivoting
+        impl<const N: usize> ArgminInv<[[$t; N]; N]> for [[$t; N]; N] {
+            fn inv(&self) -> Result<[[$t; N]; N], Error> {
+                let mut a = *self;
+                let mut inv: [[$t; N]; N] = std::array::from_fn(|i| {
+                    std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 })
+                });
+                for k in 0..N {
+                    let pivot = (k..N)
+                        .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                        .unwrap();
+                    if a[pivot][k] == 0.0 || !a[pivot][k].is_finite() {
+                        return Err(InverseError {}.into());
+                    }
+                    a.swap(k, pivot);
+                    inv.swap(k, pivot);
+                    let p = a[k][k];
+                    for j in 0..N {
+                        a[k][j] /= p;
+                        inv[k][j] /= p;
+                    }
+                    for i in 0..N {
+                        if i != k {
+                            let f = a[i][k];
+                            for j in 0..N {
+                                a[i][j] -= f * a[k][j];
+                                inv[i][j] -= f * inv[k][j];
+                            }
+                        }
+                    }
+                }
+                Ok(inv)
+            }
+        }
+    };
+}
+
+make_inv!(f32);
+make_inv!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let target = [
+                        [3 as $t, -5 as $t],
+                        [-1 as $t, 2 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminInv<[[$t; 2]; 2]>>::inv(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j], target[i][j], epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_pivoting_ $t>]() {
+                    let a = [
+                        [0 as $t, 1 as $t, 2 as $t],
+                        [1 as $t, 0 as $t, 3 as $t],
+                        [4 as $t, -3 as $t, 8 as $t],
+                    ];
+                    let target = [
+                        [-4.5 as $t, 7 as $t, -1.5 as $t],
+                        [-2 as $t, 4 as $t, -1 as $t],
+                        [1.5 as $t, -2 as $t, 0.5 as $t],
+                    ];
+                    let res = <[[$t; 3]; 3] as ArgminInv<[[$t; 3]; 3]>>::inv(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j], target[i][j], epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_singular_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminInv<[[$t; 2]; 2]>>::inv(&a);
+                    assert_eq!(res.unwrap_err().to_string(), "Non-invertible matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,70 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+use num_complex::Complex;
+
+macro_rules! make_len {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLen for [$t; N] {
+            #[inline]
+            fn len(&self) -> usize {
+                N
+            }
+        }
+    };
+}
+
+make_len!(i8);
+make_len!(u8);
+make_len!(i16);
+make_len!(u16);
+make_len!(i32);
+make_len!(u32);
+make_len!(i64);
+make_len!(u64);
+make_len!(f32);
+make_len!(f64);
+make_len!(Complex<i8>);
+make_len!(Complex<u8>);
+make_len!(Complex<i16>);
+make_len!(Complex<u16>);
+make_len!(Complex<i32>);
+make_len!(Complex<u32>);
+make_len!(Complex<i64>);
+make_len!(Complex<u64>);
+make_len!(Complex<f32>);
+make_len!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_len_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    assert_eq!(<[$t; 3] as ArgminLen>::len(&a), 3);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
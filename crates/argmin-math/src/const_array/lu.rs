@@ -0,0 +1,99 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLU, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SingularError;
+
+impl fmt::Display for SingularError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        // Gaussian elimination with partial pivoting
+        impl<const N: usize> ArgminLU<[$t; N], [$t; N]> for [[$t; N]; N] {
+            fn lu_solve(&self, b: &[$t; N]) -> Result<[$t; N], Error> {
+                let mut a = *self;
+                let mut x = *b;
+                for k in 0..N {
+                    let pivot = (k..N)
+                        .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                        .unwrap();
+                    if a[pivot][k] == 0.0 || !a[pivot][k].is_finite() {
+                        return Err(SingularError {}.into());
+                    }
+                    a.swap(k, pivot);
+                    x.swap(k, pivot);
+                    for i in (k + 1)..N {
+                        let f = a[i][k] / a[k][k];
+                        for j in k..N {
+                            a[i][j] -= f * a[k][j];
+                        }
+                        x[i] -= f * x[k];
+                    }
+                }
+                for k in (0..N).rev() {
+                    for j in (k + 1)..N {
+                        x[k] -= a[k][j] * x[j];
+                    }
+                    x[k] /= a[k][k];
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_solve_ $t>]() {
+                    // [[4, 2, 0], [2, 5, 1], [0, 1, 3]]
+                    let a = [
+                        [4 as $t, 2 as $t, 0 as $t],
+                        [2 as $t, 5 as $t, 1 as $t],
+                        [0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = [1 as $t, -2 as $t, 3 as $t];
+                    let b = [0 as $t, -5 as $t, 7 as $t];
+                    let res = <[[$t; 3]; 3] as ArgminLU<[$t; 3], [$t; 3]>>::lu_solve(&a, &b).unwrap();
+                                        for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_solve_singular_ $t>]() {
+                    let a = [[1 as $t, 2 as $t], [2 as $t, 4 as $t]];
+                    let b = [1 as $t, 1 as $t];
+                    let res = <[[$t; 2]; 2] as ArgminLU<[$t; 2], [$t; 2]>>::lu_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,105 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMap;
+use num_complex::Complex;
+
+macro_rules! make_map {
+    ($t:ty) => {
+        impl<const N: usize> ArgminMap<$t> for [$t; N] {
+            #[inline]
+            fn map<G: Fn($t) -> $t>(&self, f: G) -> [$t; N] {
+                std::array::from_fn(|i| f(self[i]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMap<$t> for [[$t; N]; M] {
+            #[inline]
+            fn map<G: Fn($t) -> $t>(&self, f: G) -> [[$t; N]; M] {
+                std::array::from_fn(|i| std::array::from_fn(|j| f(self[i][j])))
+            }
+        }
+    };
+}
+
+make_map!(i8);
+make_map!(u8);
+make_map!(i16);
+make_map!(u16);
+make_map!(i32);
+make_map!(u32);
+make_map!(i64);
+make_map!(u64);
+make_map!(f32);
+make_map!(f64);
+make_map!(Complex<i8>);
+make_map!(Complex<u8>);
+make_map!(Complex<i16>);
+make_map!(Complex<u16>);
+make_map!(Complex<i32>);
+make_map!(Complex<u32>);
+make_map!(Complex<i64>);
+make_map!(Complex<u64>);
+make_map!(Complex<f32>);
+make_map!(Complex<f64>);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_map);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_map_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let target = [2 as $t, 5 as $t, 9 as $t];
+                    let res = <[$t; 3] as ArgminMap<$t>>::map(&a, |x| x + 1 as $t);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_map_vec_vec_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let target = [
+                        [2 as $t, 8 as $t, 16 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminMap<$t>>::map(&a, |x| 2 as $t * x);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
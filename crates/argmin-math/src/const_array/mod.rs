@@ -0,0 +1,65 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// Vectors are represented as `[T; N]` and matrices as `[[T; N]; M]`, i.e. `M` rows of `N` columns
+// each (row-major, analogous to `Vec<Vec<T>>`).
+
+#![allow(unused_imports)]
+
+mod add;
+mod cholesky;
+mod conj;
+mod diag;
+mod div;
+mod dot;
+mod eye;
+mod inv;
+mod l1norm;
+mod l2norm;
+mod len;
+mod lu;
+mod map;
+mod minmax;
+mod mul;
+mod project;
+mod qr;
+mod random;
+mod scaledadd;
+mod scaledsub;
+mod scalerows;
+mod signum;
+mod solve;
+mod sub;
+mod transpose;
+mod zero;
+
+pub use add::*;
+pub use cholesky::*;
+pub use conj::*;
+pub use diag::*;
+pub use div::*;
+pub use dot::*;
+pub use eye::*;
+pub use inv::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use len::*;
+pub use lu::*;
+pub use map::*;
+pub use minmax::*;
+pub use mul::*;
+pub use project::*;
+pub use qr::*;
+pub use random::*;
+pub use scaledadd::*;
+pub use scaledsub::*;
+pub use scalerows::*;
+pub use signum::*;
+pub use solve::*;
+pub use sub::*;
+pub use transpose::*;
+pub use zero::*;
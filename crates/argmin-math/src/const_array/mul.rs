@@ -0,0 +1,210 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use num_complex::Complex;
+
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl<const N: usize> ArgminMul<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn mul(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] * *other)
+            }
+        }
+
+        impl<const N: usize> ArgminMul<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn mul(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| other[i] * *self)
+            }
+        }
+
+        impl<const N: usize> ArgminMul<[$t; N], [$t; N]> for [$t; N] {
+            #[inline]
+            fn mul(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self[i] * other[i])
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMul<[[$t; N]; M], [[$t; N]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn mul(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| {
+                    <[$t; N] as ArgminMul<[$t; N], [$t; N]>>::mul(&self[i], &other[i])
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMul<$t, [[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn mul(&self, other: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminMul<$t, [$t; N]>>::mul(&self[i], other))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMul<[[$t; N]; M], [[$t; N]; M]> for $t {
+            #[inline]
+            fn mul(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminMul<$t, [$t; N]>>::mul(&other[i], self))
+            }
+        }
+    };
+}
+
+make_mul!(i8);
+make_mul!(i16);
+make_mul!(i32);
+make_mul!(i64);
+make_mul!(u8);
+make_mul!(u16);
+make_mul!(u32);
+make_mul!(u64);
+make_mul!(f32);
+make_mul!(f64);
+make_mul!(Complex<i8>);
+make_mul!(Complex<i16>);
+make_mul!(Complex<i32>);
+make_mul!(Complex<i64>);
+make_mul!(Complex<u8>);
+make_mul!(Complex<u16>);
+make_mul!(Complex<u32>);
+make_mul!(Complex<u64>);
+make_mul!(Complex<f32>);
+make_mul!(Complex<f64>);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_mul);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_vec_scalar_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = [2 as $t, 8 as $t, 16 as $t];
+                    let res = <[$t; 3] as ArgminMul<$t, [$t; 3]>>::mul(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON)
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = [2 as $t, 8 as $t, 16 as $t];
+                    let res = <$t as ArgminMul<[$t; 3], [$t; 3]>>::mul(&b, &a);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON)
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_vec_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = [2 as $t, 3 as $t, 4 as $t];
+                    let target = [2 as $t, 12 as $t, 32 as $t];
+                    let res = <[$t; 3] as ArgminMul<[$t; 3], [$t; 3]>>::mul(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_mat_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = [
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [3 as $t, 4 as $t, 5 as $t]
+                    ];
+                    let target = [
+                        [2 as $t, 12 as $t, 32 as $t],
+                        [6 as $t, 20 as $t, 45 as $t]
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminMul<[[$t; 3]; 2], [[$t; 3]; 2]>>::mul(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_mat_scalar_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = [
+                        [2 as $t, 8 as $t, 16 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminMul<$t, [[$t; 3]; 2]>>::mul(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = [
+                        [2 as $t, 8 as $t, 16 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <$t as ArgminMul<[[$t; 3]; 2], [[$t; 3]; 2]>>::mul(&b, &a);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,106 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminProject;
+
+macro_rules! make_project {
+    ($t:ty) => {
+        impl<const N: usize> ArgminProject for [$t; N] {
+            fn project(&self, lower: &Self, upper: &Self) -> Self {
+                std::array::from_fn(|i| {
+                    <$t as ArgminProject>::project(&self[i], &lower[i], &upper[i])
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminProject for [[$t; N]; M] {
+            fn project(&self, lower: &Self, upper: &Self) -> Self {
+                std::array::from_fn(|i| {
+                    <[$t; N] as ArgminProject>::project(&self[i], &lower[i], &upper[i])
+                })
+            }
+        }
+    };
+}
+
+make_project!(i8);
+make_project!(u8);
+make_project!(i16);
+make_project!(u16);
+make_project!(i32);
+make_project!(u32);
+make_project!(i64);
+make_project!(u64);
+make_project!(f32);
+make_project!(f64);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_project);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_project_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let lower = [2 as $t, 3 as $t, 4 as $t];
+                    let upper = [3 as $t, 5 as $t, 6 as $t];
+                    let target = [2 as $t, 4 as $t, 6 as $t];
+                    let res = <[$t; 3] as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_project_vec_vec_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let lower = [
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [1 as $t, 6 as $t, 5 as $t]
+                    ];
+                    let upper = [
+                        [3 as $t, 5 as $t, 6 as $t],
+                        [3 as $t, 7 as $t, 7 as $t]
+                    ];
+                    let target = [
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [2 as $t, 6 as $t, 7 as $t]
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,128 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct RankDeficientError;
+
+impl fmt::Display for RankDeficientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rank-deficient matrix")
+    }
+}
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        // Householder QR decomposition
+        impl<const N: usize, const M: usize> ArgminQR<[$t; M], [$t; N]> for [[$t; N]; M] {
+            fn qr_solve(&self, b: &[$t; M]) -> Result<[$t; N], Error> {
+                assert!(M >= N, "QR solve requires at least as many rows as columns");
+                let mut a = *self;
+                let mut y = *b;
+                // Householder vector `v = a[k.., k] - alpha * e_1`, stored in `v[k..]`
+                let mut v = [0.0 as $t; M];
+                for k in 0..N {
+                    let norm = (k..M).map(|i| a[i][k] * a[i][k]).sum::<$t>().sqrt();
+                    if norm == 0.0 || !norm.is_finite() {
+                        return Err(RankDeficientError {}.into());
+                    }
+                    let alpha = if a[k][k] > 0.0 { -norm } else { norm };
+                    for i in k..M {
+                        v[i] = a[i][k];
+                    }
+                    v[k] -= alpha;
+                    let vtv: $t = (k..M).map(|i| v[i] * v[i]).sum();
+                    if vtv > 0.0 {
+                        for j in k..N {
+                            let s = 2.0 * (k..M).map(|i| v[i] * a[i][j]).sum::<$t>() / vtv;
+                            for i in k..M {
+                                a[i][j] -= s * v[i];
+                            }
+                        }
+                        let s = 2.0 * (k..M).map(|i| v[i] * y[i]).sum::<$t>() / vtv;
+                        for i in k..M {
+                            y[i] -= s * v[i];
+                        }
+                    }
+                }
+                // Backward substitution `r * x = q^T * b`
+                let mut x: [$t; N] = std::array::from_fn(|i| y[i]);
+                for k in (0..N).rev() {
+                    for j in (k + 1)..N {
+                        x[k] -= a[k][j] * x[j];
+                    }
+                    x[k] /= a[k][k];
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_solve_ $t>]() {
+                    // [[4, 2, 0], [2, 5, 1], [0, 1, 3]]
+                    let a = [
+                        [4 as $t, 2 as $t, 0 as $t],
+                        [2 as $t, 5 as $t, 1 as $t],
+                        [0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = [1 as $t, -2 as $t, 3 as $t];
+                    let b = [0 as $t, -5 as $t, 7 as $t];
+                    let res = <[[$t; 3]; 3] as ArgminQR<[$t; 3], [$t; 3]>>::qr_solve(&a, &b).unwrap();
+                                        for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_least_squares_ $t>]() {
+                    // Fit of a line `y = 1 + 2 * t` through four points
+                    let a = [
+                        [1 as $t, 0 as $t],
+                        [1 as $t, 1 as $t],
+                        [1 as $t, 2 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let b = [1.5 as $t, 2.5 as $t, 5.5 as $t, 6.5 as $t];
+                    let res = <[[$t; 2]; 4] as ArgminQR<[$t; 4], [$t; 2]>>::qr_solve(&a, &b).unwrap();
+                                        assert_relative_eq!(res[0], 1.3 as $t, epsilon = <$t>::EPSILON.sqrt());
+                    assert_relative_eq!(res[1], 1.8 as $t, epsilon = <$t>::EPSILON.sqrt());
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_rank_deficient_ $t>]() {
+                    let a = [[1 as $t, 0 as $t], [2 as $t, 0 as $t], [3 as $t, 0 as $t]];
+                    let b = [1 as $t, 1 as $t, 1 as $t];
+                    let res = <[[$t; 2]; 3] as ArgminQR<[$t; 3], [$t; 2]>>::qr_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Rank-deficient matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,107 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRandom;
+use rand::Rng;
+
+macro_rules! make_random {
+    ($t:ty) => {
+        impl<const N: usize> ArgminRandom for [$t; N] {
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> [$t; N] {
+                std::array::from_fn(|i| {
+                    let (a, b) = (min[i], max[i]);
+                    // Do not require a < b:
+
+                    // We do want to know if a and b are *exactly* the same.
+                    #[allow(clippy::float_cmp)]
+                    if a == b {
+                        a
+                    } else if a < b {
+                        rng.gen_range(a..b)
+                    } else {
+                        rng.gen_range(b..a)
+                    }
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminRandom for [[$t; N]; M] {
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N]>::rand_from_range(&min[i], &max[i], rng))
+            }
+        }
+    };
+}
+
+make_random!(f32);
+make_random!(f64);
+make_random!(i8);
+make_random!(i16);
+make_random!(i32);
+make_random!(i64);
+make_random!(u8);
+make_random!(u16);
+make_random!(u32);
+make_random!(u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use rand::SeedableRng;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_random_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 4 as $t];
+                    let b = [2 as $t, 3 as $t, 5 as $t];
+                    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+                    let random = <[$t; 3]>::rand_from_range(&a, &b, &mut rng);
+                    for i in 0..3usize {
+                        assert!(random[i] >= a[i]);
+                        assert!(random[i] <= b[i]);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_random_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 3 as $t, 5 as $t]
+                    ];
+                    let b = [
+                        [2 as $t, 3 as $t, 5 as $t],
+                        [3 as $t, 4 as $t, 6 as $t]
+                    ];
+                    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+                    let random = <[[$t; 3]; 2]>::rand_from_range(&a, &b, &mut rng);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(random[j][i] >= a[j][i]);
+                            assert!(random[j][i] <= b[j][i]);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
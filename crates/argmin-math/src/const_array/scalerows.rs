@@ -0,0 +1,87 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaleRows;
+use num_complex::Complex;
+
+macro_rules! make_scale_rows {
+    ($t:ty) => {
+        impl<const N: usize, const M: usize> ArgminScaleRows<[$t; M]> for [[$t; N]; M] {
+            #[inline]
+            fn scale_rows(&self, factors: &[$t; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| std::array::from_fn(|j| self[i][j] * factors[i]))
+            }
+        }
+    };
+}
+
+make_scale_rows!(i8);
+make_scale_rows!(u8);
+make_scale_rows!(i16);
+make_scale_rows!(u16);
+make_scale_rows!(i32);
+make_scale_rows!(u32);
+make_scale_rows!(i64);
+make_scale_rows!(u64);
+make_scale_rows!(f32);
+make_scale_rows!(f64);
+make_scale_rows!(Complex<i8>);
+make_scale_rows!(Complex<u8>);
+make_scale_rows!(Complex<i16>);
+make_scale_rows!(Complex<u16>);
+make_scale_rows!(Complex<i32>);
+make_scale_rows!(Complex<u32>);
+make_scale_rows!(Complex<i64>);
+make_scale_rows!(Complex<u64>);
+make_scale_rows!(Complex<f32>);
+make_scale_rows!(Complex<f64>);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_scale_rows);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scale_rows_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let factors = [3 as $t, 2 as $t];
+                    let target = [
+                        [3 as $t, 12 as $t, 24 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminScaleRows<[$t; 2]>>::scale_rows(&a, &factors);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,64 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLU, ArgminSolve, Error};
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSolve<[$t; N], [$t; N]> for [[$t; N]; N] {
+            #[inline]
+            fn solve(&self, b: &[$t; N]) -> Result<[$t; N], Error> {
+                <Self as ArgminLU<[$t; N], [$t; N]>>::lu_solve(self, b)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    // [[4, 2, 0], [2, 5, 1], [0, 1, 3]]
+                    let a = [
+                        [4 as $t, 2 as $t, 0 as $t],
+                        [2 as $t, 5 as $t, 1 as $t],
+                        [0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = [1 as $t, -2 as $t, 3 as $t];
+                    let b = [0 as $t, -5 as $t, 7 as $t];
+                    let res = <[[$t; 3]; 3] as ArgminSolve<[$t; 3], [$t; 3]>>::solve(&a, &b).unwrap();
+                                        for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a = [[1 as $t, 2 as $t], [2 as $t, 4 as $t]];
+                    let b = [1 as $t, 1 as $t];
+                    let res = <[[$t; 2]; 2] as ArgminSolve<[$t; 2], [$t; 2]>>::solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
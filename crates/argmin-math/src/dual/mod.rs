@@ -0,0 +1,984 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Dual and hyper-dual numbers for forward-mode automatic differentiation.
+//!
+//! A [`Dual`] number `re + eps ε` (with `ε² = 0`) carries the first derivative of a computation
+//! along with its value, a [`HyperDual`] number `re + e1 ε₁ + e2 ε₂ + e12 ε₁ε₂` (with
+//! `ε₁² = ε₂² = 0`) additionally carries a second (mixed) derivative. Both implement the
+//! `num_traits` float traits and can therefore be passed to any function which is generic over
+//! `T: Float`. Comparisons only consider the real part.
+//!
+//! # Example
+//!
+//! ```
+//! use argmin_math::dual::{Dual, HyperDual};
+//! use num_traits::Float;
+//!
+//! fn f<T: Float>(x: T) -> T {
+//!     x.powi(3) + x.sin()
+//! }
+//!
+//! // First derivative at `x = 2`
+//! let y = f(Dual::variable(2.0f64));
+//! assert!((y.eps - (12.0 + 2.0f64.cos())).abs() < 1e-12);
+//!
+//! // Second derivative at `x = 2`
+//! let y = f(HyperDual::new(2.0f64, 1.0, 1.0, 0.0));
+//! assert!((y.e12 - (12.0 - 2.0f64.sin())).abs() < 1e-12);
+//! ```
+
+#[cfg(feature = "vec")]
+use crate::ArgminEye;
+use crate::{ArgminRandom, ArgminZero, ArgminZeroLike};
+use num_traits::{Float, FloatConst, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero};
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Invokes the macro `$m` for all dual number types supported by the math backends.
+macro_rules! for_dual_types {
+    ($m:ident) => {
+        $m!($crate::dual::Dual<f32>);
+        $m!($crate::dual::Dual<f64>);
+        $m!($crate::dual::HyperDual<f32>);
+        $m!($crate::dual::HyperDual<f64>);
+    };
+}
+
+/// Dual number `re + eps ε` with `ε² = 0`
+///
+/// Evaluating a function `f` at `Dual::new(x, 1)` yields `f(x)` in `re` and `f'(x)` in `eps`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<T> {
+    /// Real part
+    pub re: T,
+    /// Dual part (first derivative)
+    pub eps: T,
+}
+
+impl<T: Float> Dual<T> {
+    /// Constructs a new dual number `re + eps ε`.
+    pub fn new(re: T, eps: T) -> Self {
+        Dual { re, eps }
+    }
+
+    /// Constructs the dual number `re + 1 ε` of an independent variable.
+    pub fn variable(re: T) -> Self {
+        Dual { re, eps: T::one() }
+    }
+
+    /// Multiplication of dual numbers
+    fn mul_dual(self, other: Self) -> Self {
+        Dual {
+            re: self.re * other.re,
+            eps: self.re * other.eps + self.eps * other.re,
+        }
+    }
+
+    /// Applies a function with value `f0` and first derivative `f1` at `self.re` to `self`.
+    fn chain(self, f0: T, f1: T, _f2: T) -> Self {
+        Dual {
+            re: f0,
+            eps: f1 * self.eps,
+        }
+    }
+}
+
+/// Hyper-dual number `re + e1 ε₁ + e2 ε₂ + e12 ε₁ε₂` with `ε₁² = ε₂² = 0`
+///
+/// Evaluating a function `f` of several variables at a point where variable `i` is seeded with
+/// `e1 = 1` and variable `j` with `e2 = 1` yields the partial derivatives `∂f/∂xᵢ` in `e1`,
+/// `∂f/∂xⱼ` in `e2` and the second derivative `∂²f/∂xᵢ∂xⱼ` in `e12`. Unlike finite differences,
+/// this is exact up to floating point precision.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HyperDual<T> {
+    /// Real part
+    pub re: T,
+    /// First dual part
+    pub e1: T,
+    /// Second dual part
+    pub e2: T,
+    /// Mixed dual part
+    pub e12: T,
+}
+
+impl<T: Float> HyperDual<T> {
+    /// Constructs a new hyper-dual number `re + e1 ε₁ + e2 ε₂ + e12 ε₁ε₂`.
+    pub fn new(re: T, e1: T, e2: T, e12: T) -> Self {
+        HyperDual { re, e1, e2, e12 }
+    }
+
+    /// Multiplication of hyper-dual numbers
+    fn mul_dual(self, other: Self) -> Self {
+        HyperDual {
+            re: self.re * other.re,
+            e1: self.re * other.e1 + self.e1 * other.re,
+            e2: self.re * other.e2 + self.e2 * other.re,
+            e12: self.re * other.e12
+                + self.e1 * other.e2
+                + self.e2 * other.e1
+                + self.e12 * other.re,
+        }
+    }
+
+    /// Applies a function with value `f0`, first derivative `f1` and second derivative `f2` at
+    /// `self.re` to `self`.
+    fn chain(self, f0: T, f1: T, f2: T) -> Self {
+        HyperDual {
+            re: f0,
+            e1: f1 * self.e1,
+            e2: f1 * self.e2,
+            e12: f1 * self.e12 + f2 * self.e1 * self.e2,
+        }
+    }
+}
+
+/// Converts an `f64` constant into `T`.
+fn c<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Implements everything which is shared by `Dual` and `HyperDual` in terms of their fields and
+/// their `mul_dual` and `chain` methods.
+macro_rules! impl_dual {
+    ($d:ident, $($part:ident),+) => {
+        impl<T: Float> $d<T> {
+            /// Constructs a constant (all dual parts are zero).
+            pub fn constant(re: T) -> Self {
+                $d { re, $($part: T::zero()),+ }
+            }
+
+            /// Applies `f` to all parts.
+            fn map(self, f: impl Fn(T) -> T) -> Self {
+                $d { re: f(self.re), $($part: f(self.$part)),+ }
+            }
+
+            /// Applies `f` to all pairs of parts of `self` and `other`.
+            fn zip(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+                $d { re: f(self.re, other.re), $($part: f(self.$part, other.$part)),+ }
+            }
+
+            /// Returns `true` if all dual parts are zero.
+            fn is_constant(&self) -> bool {
+                $(self.$part == T::zero())&&+
+            }
+
+            /// `self` raised to a constant power `n`
+            fn powc(self, n: T) -> Self {
+                if n == T::zero() {
+                    Self::one()
+                } else if n == T::one() {
+                    self
+                } else {
+                    let a = self.re;
+                    self.chain(
+                        a.powf(n),
+                        n * a.powf(n - T::one()),
+                        n * (n - T::one()) * a.powf(n - c(2.0)),
+                    )
+                }
+            }
+        }
+
+        impl<T: Float> PartialEq for $d<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.re == other.re
+            }
+        }
+
+        impl<T: Float> PartialOrd for $d<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.re.partial_cmp(&other.re)
+            }
+        }
+
+        impl<T: Float + fmt::Display> fmt::Display for $d<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.re)?;
+                $(write!(f, " + {}{}", self.$part, stringify!($part))?;)+
+                Ok(())
+            }
+        }
+
+        impl<T: Float> Neg for $d<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.map(|x| -x)
+            }
+        }
+
+        impl<T: Float> Add for $d<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                self.zip(other, |a, b| a + b)
+            }
+        }
+
+        impl<T: Float> Sub for $d<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.zip(other, |a, b| a - b)
+            }
+        }
+
+        impl<T: Float> Mul for $d<T> {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                self.mul_dual(other)
+            }
+        }
+
+        impl<T: Float> Div for $d<T> {
+            type Output = Self;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, other: Self) -> Self {
+                self.mul_dual(other.recip())
+            }
+        }
+
+        impl<T: Float> Rem for $d<T> {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                self - other * $d::constant((self.re / other.re).trunc())
+            }
+        }
+
+        impl_dual!(@ref_ops $d, Add, add, AddAssign, add_assign);
+        impl_dual!(@ref_ops $d, Sub, sub, SubAssign, sub_assign);
+        impl_dual!(@ref_ops $d, Mul, mul, MulAssign, mul_assign);
+        impl_dual!(@ref_ops $d, Div, div, DivAssign, div_assign);
+        impl_dual!(@ref_ops $d, Rem, rem, RemAssign, rem_assign);
+
+        impl<T: Float> Sum for $d<T> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(<Self as Zero>::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<'a, T: Float> Sum<&'a $d<T>> for $d<T> {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(<Self as Zero>::zero(), |acc, x| acc + *x)
+            }
+        }
+
+        impl<T: Float> Product for $d<T> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl<'a, T: Float> Product<&'a $d<T>> for $d<T> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * *x)
+            }
+        }
+
+        impl<T: Float> Zero for $d<T> {
+            fn zero() -> Self {
+                Self::constant(T::zero())
+            }
+
+            fn is_zero(&self) -> bool {
+                self.re.is_zero()
+            }
+        }
+
+        impl<T: Float> One for $d<T> {
+            fn one() -> Self {
+                Self::constant(T::one())
+            }
+        }
+
+        impl<T: Float> Num for $d<T> {
+            type FromStrRadixErr = T::FromStrRadixErr;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                T::from_str_radix(str, radix).map(Self::constant)
+            }
+        }
+
+        impl<T: Float> ToPrimitive for $d<T> {
+            fn to_i64(&self) -> Option<i64> {
+                self.re.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.re.to_u64()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                self.re.to_f32()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.re.to_f64()
+            }
+        }
+
+        impl<T: Float> NumCast for $d<T> {
+            fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                T::from(n).map(Self::constant)
+            }
+        }
+
+        impl<T: Float + FromPrimitive> FromPrimitive for $d<T> {
+            fn from_i64(n: i64) -> Option<Self> {
+                T::from_i64(n).map(Self::constant)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                T::from_u64(n).map(Self::constant)
+            }
+
+            fn from_f32(n: f32) -> Option<Self> {
+                T::from_f32(n).map(Self::constant)
+            }
+
+            fn from_f64(n: f64) -> Option<Self> {
+                T::from_f64(n).map(Self::constant)
+            }
+        }
+
+        impl<T: Float + FloatConst> FloatConst for $d<T> {
+            impl_dual!(@consts E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2,
+                FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI,
+                SQRT_2, TAU, LOG10_2, LOG2_10);
+        }
+
+        impl<T: Float> Float for $d<T> {
+            impl_dual!(@consts nan, infinity, neg_infinity, neg_zero, min_value,
+                min_positive_value, epsilon, max_value);
+
+            fn is_nan(self) -> bool {
+                self.re.is_nan()
+            }
+
+            fn is_infinite(self) -> bool {
+                self.re.is_infinite()
+            }
+
+            fn is_finite(self) -> bool {
+                self.re.is_finite()
+            }
+
+            fn is_normal(self) -> bool {
+                self.re.is_normal()
+            }
+
+            fn classify(self) -> FpCategory {
+                self.re.classify()
+            }
+
+            fn floor(self) -> Self {
+                Self::constant(self.re.floor())
+            }
+
+            fn ceil(self) -> Self {
+                Self::constant(self.re.ceil())
+            }
+
+            fn round(self) -> Self {
+                Self::constant(self.re.round())
+            }
+
+            fn trunc(self) -> Self {
+                Self::constant(self.re.trunc())
+            }
+
+            fn fract(self) -> Self {
+                self - self.trunc()
+            }
+
+            fn abs(self) -> Self {
+                if self.re < T::zero() {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            fn signum(self) -> Self {
+                Self::constant(self.re.signum())
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.re.is_sign_positive()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.re.is_sign_negative()
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
+            fn recip(self) -> Self {
+                let r = self.re.recip();
+                self.chain(r, -r * r, c::<T>(2.0) * r * r * r)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                self.powc(T::from(n).unwrap())
+            }
+
+            fn powf(self, n: Self) -> Self {
+                if n.is_constant() {
+                    self.powc(n.re)
+                } else {
+                    (n * self.ln()).exp()
+                }
+            }
+
+            fn sqrt(self) -> Self {
+                let s = self.re.sqrt();
+                self.chain(s, (c::<T>(2.0) * s).recip(), -(c::<T>(4.0) * s * s * s).recip())
+            }
+
+            fn exp(self) -> Self {
+                let e = self.re.exp();
+                self.chain(e, e, e)
+            }
+
+            fn exp2(self) -> Self {
+                let p = self.re.exp2();
+                let ln2 = c::<T>(2.0).ln();
+                self.chain(p, p * ln2, p * ln2 * ln2)
+            }
+
+            fn ln(self) -> Self {
+                let r = self.re.recip();
+                self.chain(self.re.ln(), r, -r * r)
+            }
+
+            fn log(self, base: Self) -> Self {
+                self.ln() / base.ln()
+            }
+
+            fn log2(self) -> Self {
+                self.ln() / Self::constant(c::<T>(2.0).ln())
+            }
+
+            fn log10(self) -> Self {
+                self.ln() / Self::constant(c::<T>(10.0).ln())
+            }
+
+            fn max(self, other: Self) -> Self {
+                if self.re.is_nan() || other.re > self.re {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            fn min(self, other: Self) -> Self {
+                if self.re.is_nan() || other.re < self.re {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                if self.re > other.re {
+                    self - other
+                } else {
+                    <Self as Zero>::zero()
+                }
+            }
+
+            fn cbrt(self) -> Self {
+                let r = self.re.cbrt();
+                let r2 = r * r;
+                self.chain(
+                    r,
+                    (c::<T>(3.0) * r2).recip(),
+                    -c::<T>(2.0) / (c::<T>(9.0) * r2 * r2 * r),
+                )
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                let mut h = (self * self + other * other).sqrt();
+                h.re = self.re.hypot(other.re);
+                h
+            }
+
+            fn sin(self) -> Self {
+                let (s, co) = self.re.sin_cos();
+                self.chain(s, co, -s)
+            }
+
+            fn cos(self) -> Self {
+                let (s, co) = self.re.sin_cos();
+                self.chain(co, -s, -co)
+            }
+
+            fn tan(self) -> Self {
+                let t = self.re.tan();
+                let sec2 = T::one() + t * t;
+                self.chain(t, sec2, c::<T>(2.0) * t * sec2)
+            }
+
+            fn asin(self) -> Self {
+                let d = T::one() - self.re * self.re;
+                let s = d.sqrt();
+                self.chain(self.re.asin(), s.recip(), self.re / (d * s))
+            }
+
+            fn acos(self) -> Self {
+                let d = T::one() - self.re * self.re;
+                let s = d.sqrt();
+                self.chain(self.re.acos(), -s.recip(), -self.re / (d * s))
+            }
+
+            fn atan(self) -> Self {
+                let d = T::one() + self.re * self.re;
+                self.chain(self.re.atan(), d.recip(), -c::<T>(2.0) * self.re / (d * d))
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                // atan(y/x) and -atan(x/y) differ from atan2(y, x) only by a constant
+                let mut t = if other.re.abs() >= self.re.abs() {
+                    (self / other).atan()
+                } else {
+                    -(other / self).atan()
+                };
+                t.re = self.re.atan2(other.re);
+                t
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+
+            fn exp_m1(self) -> Self {
+                let e = self.re.exp();
+                self.chain(self.re.exp_m1(), e, e)
+            }
+
+            fn ln_1p(self) -> Self {
+                let r = (T::one() + self.re).recip();
+                self.chain(self.re.ln_1p(), r, -r * r)
+            }
+
+            fn sinh(self) -> Self {
+                let (s, co) = (self.re.sinh(), self.re.cosh());
+                self.chain(s, co, s)
+            }
+
+            fn cosh(self) -> Self {
+                let (s, co) = (self.re.sinh(), self.re.cosh());
+                self.chain(co, s, co)
+            }
+
+            fn tanh(self) -> Self {
+                let t = self.re.tanh();
+                let sech2 = T::one() - t * t;
+                self.chain(t, sech2, -c::<T>(2.0) * t * sech2)
+            }
+
+            fn asinh(self) -> Self {
+                let d = self.re * self.re + T::one();
+                let s = d.sqrt();
+                self.chain(self.re.asinh(), s.recip(), -self.re / (d * s))
+            }
+
+            fn acosh(self) -> Self {
+                let d = self.re * self.re - T::one();
+                let s = d.sqrt();
+                self.chain(self.re.acosh(), s.recip(), -self.re / (d * s))
+            }
+
+            fn atanh(self) -> Self {
+                let d = T::one() - self.re * self.re;
+                self.chain(self.re.atanh(), d.recip(), c::<T>(2.0) * self.re / (d * d))
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                self.re.integer_decode()
+            }
+        }
+    };
+    (@ref_ops $d:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<'a, T: Float> $op<&'a $d<T>> for $d<T> {
+            type Output = $d<T>;
+
+            fn $method(self, other: &'a $d<T>) -> $d<T> {
+                $op::$method(self, *other)
+            }
+        }
+
+        impl<'a, T: Float> $op<$d<T>> for &'a $d<T> {
+            type Output = $d<T>;
+
+            fn $method(self, other: $d<T>) -> $d<T> {
+                $op::$method(*self, other)
+            }
+        }
+
+        impl<'a, 'b, T: Float> $op<&'b $d<T>> for &'a $d<T> {
+            type Output = $d<T>;
+
+            fn $method(self, other: &'b $d<T>) -> $d<T> {
+                $op::$method(*self, *other)
+            }
+        }
+
+        impl<T: Float> $op_assign for $d<T> {
+            fn $method_assign(&mut self, other: $d<T>) {
+                *self = $op::$method(*self, other);
+            }
+        }
+    };
+    (@consts $($name:ident),+) => {
+        $(
+            #[allow(non_snake_case)]
+            fn $name() -> Self {
+                Self::constant(T::$name())
+            }
+        )+
+    };
+}
+
+impl_dual!(Dual, eps);
+impl_dual!(HyperDual, e1, e2, e12);
+
+/// Implements the scalar `argmin-math` traits which cannot be generated by the backend macros
+/// because these rely on primitive casts or on `rand` sampling the type directly.
+macro_rules! impl_argmin_dual {
+    ($d:ident) => {
+        impl<T: Float> ArgminZero for $d<T> {
+            #[inline]
+            fn zero() -> $d<T> {
+                Zero::zero()
+            }
+        }
+
+        impl<T: Float> ArgminZeroLike for $d<T> {
+            #[inline]
+            fn zero_like(&self) -> $d<T> {
+                Zero::zero()
+            }
+        }
+
+        impl<T: Float + SampleUniform> ArgminRandom for $d<T> {
+            /// Samples the real part, all dual parts are zero.
+            #[inline]
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> $d<T> {
+                $d::constant(rng.gen_range(min.re..max.re))
+            }
+        }
+
+        #[cfg(feature = "vec")]
+        impl<T: Float> ArgminEye for Vec<Vec<$d<T>>> {
+            #[inline]
+            fn eye_like(&self) -> Vec<Vec<$d<T>>> {
+                <Self as ArgminEye>::eye(self.len())
+            }
+
+            #[inline]
+            fn eye(n: usize) -> Vec<Vec<$d<T>>> {
+                let mut out = vec![vec![<$d<T> as Zero>::zero(); n]; n];
+                for (i, row) in out.iter_mut().enumerate() {
+                    row[i] = $d::one();
+                }
+                out
+            }
+        }
+
+        #[cfg(feature = "vec")]
+        impl<T: Float + SampleUniform> ArgminRandom for Vec<$d<T>> {
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Vec<$d<T>> {
+                assert!(!min.is_empty());
+                assert_eq!(min.len(), max.len());
+
+                min.iter()
+                    .zip(max.iter())
+                    .map(|(a, b)| {
+                        // Do not require a < b
+                        if a.re == b.re {
+                            *a
+                        } else if a.re < b.re {
+                            $d::rand_from_range(a, b, rng)
+                        } else {
+                            $d::rand_from_range(b, a, rng)
+                        }
+                    })
+                    .collect()
+            }
+        }
+
+        #[cfg(feature = "vec")]
+        impl<T: Float + SampleUniform> ArgminRandom for Vec<Vec<$d<T>>> {
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Vec<Vec<$d<T>>> {
+                assert!(!min.is_empty());
+                assert_eq!(min.len(), max.len());
+                min.iter()
+                    .zip(max.iter())
+                    .map(|(a, b)| Vec::<$d<T>>::rand_from_range(a, b, rng))
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_argmin_dual!(Dual);
+impl_argmin_dual!(HyperDual);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// Checks first and second derivatives of `f` at `x` against `df` and `d2f`.
+    fn check(
+        f: impl Fn(HyperDual<f64>) -> HyperDual<f64>,
+        g: impl Fn(Dual<f64>) -> Dual<f64>,
+        x: f64,
+        df: f64,
+        d2f: f64,
+    ) {
+        let y = g(Dual::variable(x));
+        assert_relative_eq!(y.eps, df, epsilon = 1e-10, max_relative = 1e-10);
+        let y = f(HyperDual::new(x, 1.0, 1.0, 0.0));
+        assert_relative_eq!(y.e1, df, epsilon = 1e-10, max_relative = 1e-10);
+        assert_relative_eq!(y.e2, df, epsilon = 1e-10, max_relative = 1e-10);
+        assert_relative_eq!(y.e12, d2f, epsilon = 1e-10, max_relative = 1e-10);
+    }
+
+    macro_rules! make_test {
+        ($name:ident, $f:expr, $x:expr, $df:expr, $d2f:expr) => {
+            #[test]
+            fn $name() {
+                let x: f64 = $x;
+                check($f, $f, x, $df(x), $d2f(x));
+            }
+        };
+    }
+
+    make_test!(
+        test_sqrt,
+        |x| x.sqrt(),
+        2.0,
+        |x: f64| 0.5 / x.sqrt(),
+        |x: f64| -0.25 * x.powf(-1.5)
+    );
+    make_test!(
+        test_cbrt,
+        |x| x.cbrt(),
+        2.0,
+        |x: f64| x.powf(-2.0 / 3.0) / 3.0,
+        |x: f64| { -2.0 / 9.0 * x.powf(-5.0 / 3.0) }
+    );
+    make_test!(test_exp, |x| x.exp(), 0.7, |x: f64| x.exp(), |x: f64| x
+        .exp());
+    make_test!(
+        test_exp2,
+        |x| x.exp2(),
+        0.7,
+        |x: f64| x.exp2() * 2f64.ln(),
+        |x: f64| x.exp2() * 2f64.ln().powi(2)
+    );
+    make_test!(test_ln, |x| x.ln(), 0.7, |x: f64| 1.0 / x, |x: f64| -1.0
+        / (x * x));
+    make_test!(
+        test_log10,
+        |x| x.log10(),
+        0.7,
+        |x: f64| 1.0 / (x * 10f64.ln()),
+        |x: f64| -1.0 / (x * x * 10f64.ln())
+    );
+    make_test!(
+        test_powi,
+        |x| x.powi(3),
+        -1.5,
+        |x: f64| 3.0 * x * x,
+        |x: f64| 6.0 * x
+    );
+    make_test!(
+        test_powf,
+        |x| x.powf(Float::sqrt(c(2.0))),
+        1.5,
+        |x: f64| 2f64.sqrt() * x.powf(2f64.sqrt() - 1.0),
+        |x: f64| 2f64.sqrt() * (2f64.sqrt() - 1.0) * x.powf(2f64.sqrt() - 2.0)
+    );
+    make_test!(
+        test_recip,
+        |x| x.recip(),
+        -1.5,
+        |x: f64| -1.0 / (x * x),
+        |x: f64| 2.0 / (x * x * x)
+    );
+    make_test!(test_sin, |x| x.sin(), 0.3, |x: f64| x.cos(), |x: f64| -x
+        .sin());
+    make_test!(test_cos, |x| x.cos(), 0.3, |x: f64| -x.sin(), |x: f64| -x
+        .cos());
+    make_test!(
+        test_tan,
+        |x| x.tan(),
+        0.3,
+        |x: f64| 1.0 / x.cos().powi(2),
+        |x: f64| 2.0 * x.tan() / x.cos().powi(2)
+    );
+    make_test!(
+        test_asin,
+        |x| x.asin(),
+        0.3,
+        |x: f64| 1.0 / (1.0 - x * x).sqrt(),
+        |x: f64| x / (1.0 - x * x).powf(1.5)
+    );
+    make_test!(
+        test_acos,
+        |x| x.acos(),
+        0.3,
+        |x: f64| -1.0 / (1.0 - x * x).sqrt(),
+        |x: f64| -x / (1.0 - x * x).powf(1.5)
+    );
+    make_test!(
+        test_atan,
+        |x| x.atan(),
+        0.3,
+        |x: f64| 1.0 / (1.0 + x * x),
+        |x: f64| -2.0 * x / (1.0 + x * x).powi(2)
+    );
+    make_test!(test_sinh, |x| x.sinh(), 0.3, |x: f64| x.cosh(), |x: f64| x
+        .sinh());
+    make_test!(test_cosh, |x| x.cosh(), 0.3, |x: f64| x.sinh(), |x: f64| x
+        .cosh());
+    make_test!(
+        test_tanh,
+        |x| x.tanh(),
+        0.3,
+        |x: f64| 1.0 - x.tanh().powi(2),
+        |x: f64| -2.0 * x.tanh() * (1.0 - x.tanh().powi(2))
+    );
+    make_test!(
+        test_asinh,
+        |x| x.asinh(),
+        0.3,
+        |x: f64| 1.0 / (x * x + 1.0).sqrt(),
+        |x: f64| -x / (x * x + 1.0).powf(1.5)
+    );
+    make_test!(
+        test_acosh,
+        |x| x.acosh(),
+        1.3,
+        |x: f64| 1.0 / (x * x - 1.0).sqrt(),
+        |x: f64| -x / (x * x - 1.0).powf(1.5)
+    );
+    make_test!(
+        test_atanh,
+        |x| x.atanh(),
+        0.3,
+        |x: f64| 1.0 / (1.0 - x * x),
+        |x: f64| 2.0 * x / (1.0 - x * x).powi(2)
+    );
+    make_test!(
+        test_div,
+        |x| (x * x + x.constant_like(1.0)) / x,
+        0.7,
+        |x: f64| 1.0 - 1.0 / (x * x),
+        |x: f64| 2.0 / (x * x * x)
+    );
+    make_test!(test_abs, |x| x.abs(), -0.7, |_x: f64| -1.0, |_x: f64| 0.0);
+
+    trait ConstantLike: Float {
+        fn constant_like(&self, x: f64) -> Self {
+            Self::from(x).unwrap()
+        }
+    }
+
+    impl<T: Float> ConstantLike for T {}
+
+    #[test]
+    fn test_atan2() {
+        // d/dy atan2(y, x) = x / (x² + y²) and d/dx atan2(y, x) = -y / (x² + y²)
+        for (y, x) in [(0.3, 1.2), (1.2, 0.3), (-1.2, -0.3), (0.5, -2.0)] {
+            let r2 = x * x + y * y;
+            let res = Dual::variable(y).atan2(Dual::constant(x));
+            assert_relative_eq!(res.re, f64::atan2(y, x), epsilon = 1e-12);
+            assert_relative_eq!(res.eps, x / r2, epsilon = 1e-12);
+            let res = Dual::constant(y).atan2(Dual::variable(x));
+            assert_relative_eq!(res.eps, -y / r2, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_mixed_derivative() {
+        // f(x, y) = x² y³ + sin(x y)
+        let f = |x: HyperDual<f64>, y: HyperDual<f64>| x.powi(2) * y.powi(3) + (x * y).sin();
+        let (x, y) = (0.7, -1.3);
+        let res = f(
+            HyperDual::new(x, 1.0, 0.0, 0.0),
+            HyperDual::new(y, 0.0, 1.0, 0.0),
+        );
+        assert_relative_eq!(
+            res.e1,
+            2.0 * x * y.powi(3) + y * (x * y).cos(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            res.e2,
+            3.0 * x * x * y * y + x * (x * y).cos(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            res.e12,
+            6.0 * x * y * y + (x * y).cos() - x * y * (x * y).sin(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_comparison() {
+        let a = Dual::new(1.0f64, 2.0);
+        let b = Dual::new(1.0f64, 3.0);
+        assert!(a == b);
+        assert!(a < Dual::new(2.0, 0.0));
+        assert_relative_eq!(Float::max(a, Dual::new(0.5, 0.0)).eps, 2.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Dual::new(1.0f64, 2.0)), "1 + 2eps");
+        assert_eq!(
+            format!("{}", HyperDual::new(1.0f64, 2.0, 3.0, 4.0)),
+            "1 + 2e1 + 3e2 + 4e12"
+        );
+    }
+
+    #[cfg(feature = "vec")]
+    #[test]
+    fn test_vec_backend() {
+        use crate::{ArgminDot, ArgminEye, ArgminL2Norm, ArgminScaledAdd};
+
+        // d/dt |x + t d|² at t = 0 is 2 x·d
+        let x = vec![Dual::constant(1.0f64), Dual::constant(-2.0)];
+        let d = vec![Dual::constant(0.5f64), Dual::constant(3.0)];
+        let y = x.scaled_add(&Dual::variable(0.0), &d);
+        let norm = y.l2_norm();
+        assert_relative_eq!(norm.re, 5f64.sqrt());
+        assert_relative_eq!((norm * norm).eps, 2.0 * (0.5 - 6.0));
+        let eye = Vec::<Vec<HyperDual<f64>>>::eye(2);
+        let z: Vec<HyperDual<f64>> = eye.dot(&vec![HyperDual::new(2.0, 1.0, 1.0, 0.0); 2]);
+        let zz: HyperDual<f64> = z.dot(&z);
+        assert_relative_eq!(zz.e12, 4.0);
+    }
+}
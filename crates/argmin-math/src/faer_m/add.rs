@@ -2,7 +2,7 @@ use crate::ArgminAdd;
 use faer::{
     mat::{AsMatMut, AsMatRef},
     reborrow::{IntoConst, Reborrow, ReborrowMut},
-    unzipped, zipped, zipped_rw, ComplexField, Conjugate, Entity, Mat, MatMut, MatRef,
+    unzipped, zipped, zipped_rw, Col, ComplexField, Conjugate, Entity, Mat, MatMut, MatRef,
     SimpleEntity,
 };
 use std::ops::{Add, AddAssign};
@@ -99,6 +99,37 @@ impl<E: Entity + ComplexField> ArgminAdd<Mat<E>, Mat<E>> for Mat<E> {
     }
 }
 
+/// Col + Scalar -> Col
+impl<E> ArgminAdd<E, Col<E>> for Col<E>
+where
+    E: Entity + Add<E, Output = E>,
+{
+    #[inline]
+    fn add(&self, other: &E) -> Col<E> {
+        zipped_rw!(self).map(|unzipped!(this)| this.read() + *other)
+    }
+}
+
+/// Scalar + Col -> Col
+impl<E> ArgminAdd<Col<E>, Col<E>> for E
+where
+    E: Entity + Add<E, Output = E>,
+{
+    #[inline]
+    fn add(&self, other: &Col<E>) -> Col<E> {
+        // commutative with Col + Scalar so we can fall back on that case
+        <_ as ArgminAdd<_, _>>::add(other, self)
+    }
+}
+
+/// Col + Col -> Col
+impl<E: Entity + Add<E, Output = E>> ArgminAdd<Col<E>, Col<E>> for Col<E> {
+    #[inline]
+    fn add(&self, other: &Col<E>) -> Col<E> {
+        zipped_rw!(self, other).map(|unzipped!(this, other)| this.read() + other.read())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -270,6 +301,58 @@ mod tests {
                     <_ as ArgminAdd<_, _>>::add(&a, &b);
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_add_col_scalar_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![3 as $t, 6 as $t, 10 as $t];
+                    let res = <_ as ArgminAdd<$t, _>>::add(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_scalar_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![3 as $t, 6 as $t, 10 as $t];
+                    let res = <$t as ArgminAdd<_, _>>::add(&b, &a);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    let target = faer::col![3 as $t, 6 as $t, 12 as $t];
+                    let res = <_ as ArgminAdd<_, _>>::add(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_col_col_panic_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    <_ as ArgminAdd<_, _>>::add(&a, &b);
+                }
+            }
         };
     }
 
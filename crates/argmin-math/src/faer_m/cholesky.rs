@@ -0,0 +1,116 @@
+use crate::ArgminCholesky;
+use faer::{mat::AsMatRef, prelude::SpSolver, Col, ComplexField, Mat, MatRef, Side, SimpleEntity};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct NotPositiveDefiniteError;
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite")
+    }
+}
+
+/// solve the linear system via Cholesky decomposition
+impl<E: SimpleEntity + ComplexField> ArgminCholesky<Mat<E>, Mat<E>> for MatRef<'_, E> {
+    #[inline]
+    fn cholesky_solve(&self, b: &Mat<E>) -> Result<Mat<E>, anyhow::Error> {
+        assert_eq!(
+            self.nrows(),
+            self.ncols(),
+            "Cholesky solve requires a square matrix"
+        );
+        assert_eq!(self.nrows(), b.nrows());
+        match self.cholesky(Side::Lower) {
+            Ok(chol) => Ok(chol.solve(b)),
+            Err(_) => Err(NotPositiveDefiniteError {}.into()),
+        }
+    }
+}
+
+/// solve the linear system via Cholesky decomposition
+impl<E: SimpleEntity + ComplexField> ArgminCholesky<Mat<E>, Mat<E>> for Mat<E> {
+    #[inline]
+    fn cholesky_solve(&self, b: &Mat<E>) -> Result<Mat<E>, anyhow::Error> {
+        <_ as ArgminCholesky<_, _>>::cholesky_solve(&self.as_mat_ref(), b)
+    }
+}
+
+/// solve the linear system via Cholesky decomposition
+impl<E: SimpleEntity + ComplexField> ArgminCholesky<Col<E>, Col<E>> for Mat<E> {
+    #[inline]
+    fn cholesky_solve(&self, b: &Col<E>) -> Result<Col<E>, anyhow::Error> {
+        let x: Mat<E> =
+            <_ as ArgminCholesky<_, _>>::cholesky_solve(&self.as_mat_ref(), &b.as_2d().to_owned())?;
+        Ok(x.col(0).to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::mat::AsMatRef;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    let a = matrix3_new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = vector3_new(1 as $t, -2 as $t, 3 as $t);
+                    let b = vector3_new(0 as $t, -5 as $t, 7 as $t);
+                    let res = <_ as ArgminCholesky<_, _>>::cholesky_solve(&a, &b).unwrap();
+                    let res1 = <_ as ArgminCholesky<_, _>>::cholesky_solve(&a.as_mat_ref(), &b).unwrap();
+                    assert_eq!(res, res1);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[(i, 0)], x[(i, 0)], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_error_ $t>]() {
+                    let a = matrix2_new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let b = vector2_new(1 as $t, 1 as $t);
+                    let err = <_ as ArgminCholesky<_, _>>::cholesky_solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<NotPositiveDefiniteError>()
+                        .unwrap();
+                    assert_eq!(err, NotPositiveDefiniteError {});
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_col_ $t>]() {
+                    let a = matrix3_new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = faer::col![1 as $t, -2 as $t, 3 as $t];
+                    let b = faer::col![0 as $t, -5 as $t, 7 as $t];
+                    let res: Col<$t> = <_ as ArgminCholesky<_, _>>::cholesky_solve(&a, &b).unwrap();
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -1,6 +1,6 @@
 use crate::ArgminConj;
 use faer::{
-    mat::AsMatRef, reborrow::ReborrowMut, unzipped, Conjugate, Entity, Mat, MatMut, MatRef,
+    mat::AsMatRef, reborrow::ReborrowMut, unzipped, Col, Conjugate, Entity, Mat, MatMut, MatRef,
     SimpleEntity,
 };
 use num_complex::ComplexFloat;
@@ -15,6 +15,13 @@ impl<E: Entity + num_complex::ComplexFloat> ArgminConj for Mat<E> {
     }
 }
 
+impl<E: Entity + num_complex::ComplexFloat> ArgminConj for Col<E> {
+    #[inline]
+    fn conj(&self) -> Self {
+        faer::zipped_rw!(self).map(|unzipped!(this)| ComplexFloat::conj(this.read()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -60,6 +67,28 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_conj_complex_faer_col_ $t>]() {
+                    let a = faer::col![
+                        Complex::new(1 as $t, 2 as $t),
+                        Complex::new(4 as $t, -3 as $t),
+                        Complex::new(8 as $t, 0 as $t)
+                    ];
+                    let b = faer::col![
+                        Complex::new(1 as $t, -2 as $t),
+                        Complex::new(4 as $t, 3 as $t),
+                        Complex::new(8 as $t, 0 as $t)
+                    ];
+                    let res: Col<_> = <Col<Complex<$t>> as ArgminConj>::conj(&a);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(b.read(i).re(), res.read(i).re(), epsilon = $t::EPSILON);
+                        assert_relative_eq!(b.read(i).im(), res.read(i).im(), epsilon = $t::EPSILON);
+                    }
+                }
+            }
         };
     }
 
@@ -0,0 +1,66 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiag;
+use faer::{Entity, Mat};
+
+/// Mat (square matrix) -> Mat (column vector)
+impl<E: Entity> ArgminDiag<Mat<E>> for Mat<E> {
+    #[inline]
+    fn diag(&self) -> Mat<E> {
+        assert_eq!(self.nrows(), self.ncols());
+        Mat::<E>::from_fn(self.nrows(), 1, |i, _| self.read(i, i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diag_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t],
+                        [3 as $t, 6 as $t, 7 as $t]
+                    ];
+                    let target = vector3_new(1 as $t, 5 as $t, 7 as $t);
+                    let res = <Mat<$t> as ArgminDiag<Mat<$t>>>::diag(&a);
+                    assert_eq!(res.ncols(), 1);
+                    for i in 0..3 {
+                        assert_relative_eq!(target.read(i, 0) as f64, res.read(i, 0) as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_diag_not_square() {
+        let a = mat![[1.0f64, 4.0, 3.0], [2.0, 5.0, 6.0]];
+        let _ = a.diag();
+    }
+}
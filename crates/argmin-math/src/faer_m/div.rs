@@ -2,7 +2,7 @@ use crate::ArgminDiv;
 use faer::{
     mat::AsMatRef,
     reborrow::{IntoConst, Reborrow, ReborrowMut},
-    unzipped, zipped_rw, Conjugate, Entity, Mat, MatMut, MatRef, SimpleEntity,
+    unzipped, zipped_rw, Col, Conjugate, Entity, Mat, MatMut, MatRef, SimpleEntity,
 };
 use std::ops::{Div, DivAssign};
 
@@ -90,6 +90,36 @@ impl<E: Entity + Div<E, Output = E>> ArgminDiv<Mat<E>, Mat<E>> for Mat<E> {
     }
 }
 
+/// Col / Scalar -> Col
+impl<E> ArgminDiv<E, Col<E>> for Col<E>
+where
+    E: Entity + Div<E, Output = E>,
+{
+    #[inline]
+    fn div(&self, other: &E) -> Col<E> {
+        zipped_rw!(self).map(|unzipped!(this)| this.read() / *other)
+    }
+}
+
+/// Scalar / Col -> Col
+impl<E> ArgminDiv<Col<E>, Col<E>> for E
+where
+    E: Entity + Div<E, Output = E>,
+{
+    #[inline]
+    fn div(&self, other: &Col<E>) -> Col<E> {
+        zipped_rw!(other).map(|unzipped!(other_elem)| *self / other_elem.read())
+    }
+}
+
+/// Col / Col -> Col (pointwise division)
+impl<E: Entity + Div<E, Output = E>> ArgminDiv<Col<E>, Col<E>> for Col<E> {
+    #[inline]
+    fn div(&self, other: &Col<E>) -> Col<E> {
+        zipped_rw!(self, other).map(|unzipped!(this, other)| this.read() / other.read())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -242,6 +272,58 @@ mod tests {
                     <_ as ArgminDiv<_, _>>::div(&a, &b);
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_div_col_scalar_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![0.5 as $t, 2 as $t, 4 as $t];
+                    let res = <_ as ArgminDiv<$t, _>>::div(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![2 as $t, 0.5 as $t, 0.25 as $t];
+                    let res = <$t as ArgminDiv<_, _>>::div(&b, &a);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    let target = faer::col![0.5 as $t, 2 as $t, 2 as $t];
+                    let res = <_ as ArgminDiv<_, _>>::div(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_col_col_panic_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    <_ as ArgminDiv<_, _>>::div(&a, &b);
+                }
+            }
         };
     }
 
@@ -156,12 +156,69 @@ mod multiply_matrix_with_scalar {
     }
 }
 
+/// ArgminDot implementations involving column vectors (`Col`).
+/// Contrary to the `Mat` case, the scalar product and the outer product of two column vectors
+/// can be told apart by the requested output type.
+mod column_vector {
+    use super::*;
+    use crate::ArgminMul;
+    use faer::{Col, Conjugate, Entity};
+
+    /// Col . Col -> Scalar (scalar product v^H . u)
+    impl<E: SimpleEntity + ComplexField + Conjugate<Conj = E>> ArgminDot<Col<E>, E> for Col<E> {
+        #[inline]
+        fn dot(&self, other: &Col<E>) -> E {
+            <_ as ArgminDot<_, E>>::dot(&self.as_2d(), &other.as_2d())
+        }
+    }
+
+    /// Col . Col -> Mat (outer product v . u^T)
+    impl<E: SimpleEntity + ComplexField> ArgminDot<Col<E>, Mat<E>> for Col<E> {
+        #[inline]
+        fn dot(&self, other: &Col<E>) -> Mat<E> {
+            <_ as ArgminDot<_, Mat<E>>>::dot(&self.as_2d(), &other.as_2d().transpose())
+        }
+    }
+
+    /// MatRef . Col -> Col (matrix-vector product)
+    impl<E: SimpleEntity + ComplexField> ArgminDot<Col<E>, Col<E>> for MatRef<'_, E> {
+        #[inline]
+        fn dot(&self, other: &Col<E>) -> Col<E> {
+            self * other
+        }
+    }
+
+    /// Mat . Col -> Col (matrix-vector product)
+    impl<E: SimpleEntity + ComplexField> ArgminDot<Col<E>, Col<E>> for Mat<E> {
+        #[inline]
+        fn dot(&self, other: &Col<E>) -> Col<E> {
+            <_ as ArgminDot<_, _>>::dot(&self.as_mat_ref(), other)
+        }
+    }
+
+    /// Col . Scalar -> Col
+    impl<E: Entity + Mul<E, Output = E>> ArgminDot<E, Col<E>> for Col<E> {
+        #[inline]
+        fn dot(&self, other: &E) -> Col<E> {
+            <Self as ArgminMul<E, _>>::mul(self, other)
+        }
+    }
+
+    /// Scalar . Col -> Col
+    impl<E: Entity + Mul<E, Output = E>> ArgminDot<Col<E>, Col<E>> for E {
+        #[inline]
+        fn dot(&self, other: &Col<E>) -> Col<E> {
+            <E as ArgminMul<Col<E>, _>>::mul(self, other)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
     use super::*;
     use approx::assert_relative_eq;
-    use faer::mat::AsMatRef;
+    use faer::{mat::AsMatRef, Col};
     use paste::item;
 
     macro_rules! make_test {
@@ -369,6 +426,72 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    let res: $t = <_ as ArgminDot<_, _>>::dot(&a, &b);
+                    assert_relative_eq!(res as f64, 32 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_col_col_outer_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    let res = matrix3_new(
+                        4 as $t, 5 as $t, 6 as $t,
+                        8 as $t, 10 as $t, 12 as $t,
+                        12 as $t, 15 as $t, 18 as $t
+                    );
+                    let product: Mat<$t> = <_ as ArgminDot<_, _>>::dot(&a, &b);
+                    assert_eq!(product.nrows(), 3);
+                    assert_eq!(product.ncols(), 3);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, product[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_col_ $t>]() {
+                    let a = matrix3_new(
+                        1 as $t, 2 as $t, 3 as $t,
+                        4 as $t, 5 as $t, 6 as $t,
+                        7 as $t, 8 as $t, 9 as $t
+                    );
+                    let b = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let res = faer::col![14 as $t, 32 as $t, 50 as $t];
+                    let product1: Col<$t> = <_ as ArgminDot<_, _>>::dot(&a, &b);
+                    let product2: Col<$t> = <_ as ArgminDot<_, _>>::dot(&a.as_mat_ref(), &b);
+                    assert_eq!(product1, product2);
+                    assert_eq!(product1.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, product1[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_col_scalar_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product1: Col<$t> = <_ as ArgminDot<$t, _>>::dot(&a, &b);
+                    let product2: Col<$t> = <$t as ArgminDot<_, _>>::dot(&b, &a);
+                    let res = faer::col![2 as $t, 4 as $t, 6 as $t];
+                    assert_eq!(product1, product2);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, product1[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
         };
     }
 
@@ -18,8 +18,7 @@ where
     fn eye_like(&self) -> Self {
         let nr = self.nrows();
         let nc = self.ncols();
-        //@note(geo-ant) in the nalgebra implementation we also enforce
-        // that the matrix is square, which we don't enforce here
+        assert_eq!(nr.unbound(), nc.unbound());
         Mat::identity(nr, nc)
     }
 }
@@ -71,6 +70,19 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                #[should_panic]
+                #[allow(unused)]
+                fn [<test_eye_like_panic_ $t>]() {
+                    let a = faer::mat![
+                        [0 as $t, 2 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 7 as $t],
+                    ];
+                    let e: Mat<$t> = a.eye_like();
+                }
+            }
         };
     }
 
@@ -1,5 +1,5 @@
 use crate::ArgminL2Norm;
-use faer::{ComplexField, Entity, Mat, MatRef, SimpleEntity};
+use faer::{Col, ComplexField, Entity, Mat, MatRef, SimpleEntity};
 
 impl<E: Entity + ComplexField> ArgminL2Norm<E::Real> for MatRef<'_, E> {
     fn l2_norm(&self) -> E::Real {
@@ -13,6 +13,12 @@ impl<E: Entity + ComplexField> ArgminL2Norm<E::Real> for Mat<E> {
     }
 }
 
+impl<E: Entity + ComplexField> ArgminL2Norm<E::Real> for Col<E> {
+    fn l2_norm(&self) -> E::Real {
+        self.norm_l2()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -31,6 +37,16 @@ mod tests {
                     assert_relative_eq!(target as $t, res as $t, epsilon = $t::EPSILON);
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_norm_col_ $t>]() {
+                    let a = faer::col![4 as $t, -3 as $t];
+                    let res = <_ as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = 5 as $t;
+                    assert_relative_eq!(target as $t, res as $t, epsilon = $t::EPSILON);
+                }
+            }
         };
     }
 
@@ -0,0 +1,76 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+use faer::{Col, Entity, Mat};
+
+/// Mat (column vector): number of rows
+impl<E: Entity> ArgminLen for Mat<E> {
+    #[inline]
+    fn len(&self) -> usize {
+        assert_eq!(self.ncols(), 1);
+        self.nrows()
+    }
+}
+
+impl<E: Entity> ArgminLen for Col<E> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.nrows()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_len_vec_ $t>]() {
+                    let a = vector3_new(1 as $t, 4 as $t, 8 as $t);
+                    assert_eq!(<Mat<$t> as ArgminLen>::len(&a), 3);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_len_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    assert_eq!(<Col<$t> as ArgminLen>::len(&a), 3);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_len_mat_panic_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t],
+                        [2 as $t, 5 as $t]
+                    ];
+                    <Mat<$t> as ArgminLen>::len(&a);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,91 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMap;
+use faer::{mat::AsMatRef, unzipped, zipped_rw, Col, Entity, Mat};
+
+impl<E: Entity> ArgminMap<E> for Mat<E> {
+    #[inline]
+    fn map<G: Fn(E) -> E>(&self, f: G) -> Mat<E> {
+        zipped_rw!(self.as_mat_ref()).map(|unzipped!(elem)| f(elem.read()))
+    }
+}
+
+impl<E: Entity> ArgminMap<E> for Col<E> {
+    #[inline]
+    fn map<G: Fn(E) -> E>(&self, f: G) -> Col<E> {
+        zipped_rw!(self).map(|unzipped!(elem)| f(elem.read()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_map_vec_ $t>]() {
+                    let a = vector3_new(1 as $t, 4 as $t, 8 as $t);
+                    let target = vector3_new(2 as $t, 5 as $t, 9 as $t);
+                    let res = <Mat<$t> as ArgminMap<$t>>::map(&a, |x| x + 1 as $t);
+                    for i in 0..3 {
+                        assert_relative_eq!(target.read(i, 0) as f64, res.read(i, 0) as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_map_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let target = mat![
+                        [2 as $t, 8 as $t, 16 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminMap<$t>>::map(&a, |x| 2 as $t * x);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target.read(j, i) as f64, res.read(j, i) as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_map_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let target = faer::col![2 as $t, 5 as $t, 9 as $t];
+                    let res = <Col<$t> as ArgminMap<$t>>::map(&a, |x| x + 1 as $t);
+                    for i in 0..3 {
+                        assert_relative_eq!(target.read(i) as f64, res.read(i) as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -1,4 +1,4 @@
-use faer::{mat, unzipped, zipped, Entity, Mat, MatRef, SimpleEntity};
+use faer::{mat, unzipped, zipped, Col, Entity, Mat, MatRef, SimpleEntity};
 
 use crate::ArgminMinMax;
 
@@ -34,6 +34,18 @@ impl<E: SimpleEntity + PartialOrd> ArgminMinMax for Mat<E> {
     }
 }
 
+impl<E: SimpleEntity + PartialOrd> ArgminMinMax for Col<E> {
+    #[inline]
+    fn max(a: &Self, b: &Self) -> Self {
+        faer::zipped!(a, b).map(|faer::unzipped!(a, b)| if *a > *b { *a } else { *b })
+    }
+
+    #[inline]
+    fn min(a: &Self, b: &Self) -> Self {
+        faer::zipped!(a, b).map(|faer::unzipped!(a, b)| if *a < *b { *a } else { *b })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -88,6 +100,22 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_minmax_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = faer::col![2 as $t, 3 as $t, 4 as $t];
+                    let target_max = faer::col![2 as $t, 4 as $t, 8 as $t];
+                    let target_min = faer::col![1 as $t, 3 as $t, 4 as $t];
+                    let res_max = <_ as ArgminMinMax>::max(&a, &b);
+                    let res_min = <_ as ArgminMinMax>::min(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(target_max[i] as f64, res_max[i] as f64, epsilon = f64::EPSILON);
+                        assert_relative_eq!(target_min[i] as f64, res_min[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
         };
     }
 
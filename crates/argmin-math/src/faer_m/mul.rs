@@ -2,7 +2,7 @@ use crate::ArgminMul;
 use faer::{
     mat::{AsMatMut, AsMatRef},
     reborrow::{IntoConst, Reborrow, ReborrowMut},
-    unzipped, zipped, zipped_rw, ComplexField, Conjugate, Entity, Mat, MatMut, MatRef,
+    unzipped, zipped, zipped_rw, Col, ComplexField, Conjugate, Entity, Mat, MatMut, MatRef,
     SimpleEntity,
 };
 use std::ops::Mul;
@@ -88,6 +88,37 @@ impl<E: SimpleEntity + ComplexField> ArgminMul<Mat<E>, Mat<E>> for Mat<E> {
     }
 }
 
+/// Col * Scalar -> Col
+impl<E> ArgminMul<E, Col<E>> for Col<E>
+where
+    E: Entity + Mul<E, Output = E>,
+{
+    #[inline]
+    fn mul(&self, other: &E) -> Col<E> {
+        zipped_rw!(self).map(|unzipped!(this)| this.read() * *other)
+    }
+}
+
+/// Scalar * Col -> Col
+impl<E> ArgminMul<Col<E>, Col<E>> for E
+where
+    E: Entity + Mul<E, Output = E>,
+{
+    #[inline]
+    fn mul(&self, other: &Col<E>) -> Col<E> {
+        // commutative with Col * Scalar so we can fall back on that case
+        <_ as ArgminMul<_, _>>::mul(other, self)
+    }
+}
+
+/// Col * Col -> Col (pointwise multiplication)
+impl<E: Entity + Mul<E, Output = E>> ArgminMul<Col<E>, Col<E>> for Col<E> {
+    #[inline]
+    fn mul(&self, other: &Col<E>) -> Col<E> {
+        zipped_rw!(self, other).map(|unzipped!(this, other)| this.read() * other.read())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -188,6 +219,64 @@ mod tests {
                 }
             }
 
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_vec_vec_panic_ $t>]() {
+                    let a = column_vector_from_vec(vec![1 as $t, 4 as $t]);
+                    let b = column_vector_from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    <_ as ArgminMul<_, _>>::mul(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_vec_vec_panic_2_ $t>]() {
+                    let a = column_vector_from_vec(vec![]);
+                    let b = column_vector_from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    <_ as ArgminMul<_, _>>::mul(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_vec_vec_panic_3_ $t>]() {
+                    let a = column_vector_from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    let b = column_vector_from_vec(vec![]);
+                    <_ as ArgminMul<_, _>>::mul(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_mat_mat_panic_2_ $t>]() {
+                    let a = faer::mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = faer::mat![
+                        [41 as $t, 38 as $t]
+                    ];
+                    <_ as ArgminMul<_, _>>::mul(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_mat_mat_panic_3_ $t>]() {
+                    let a = faer::mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = faer::Mat::new();
+                    <_ as ArgminMul<_, _>>::mul(&a, &b);
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_mul_scalar_mat_1_ $t>]() {
@@ -237,6 +326,58 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_mul_col_scalar_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![2 as $t, 8 as $t, 16 as $t];
+                    let res = <_ as ArgminMul<$t, _>>::mul(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![2 as $t, 8 as $t, 16 as $t];
+                    let res = <$t as ArgminMul<_, _>>::mul(&b, &a);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    let target = faer::col![2 as $t, 8 as $t, 32 as $t];
+                    let res = <_ as ArgminMul<_, _>>::mul(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_col_col_panic_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    <_ as ArgminMul<_, _>>::mul(&a, &b);
+                }
+            }
         };
     }
 
@@ -0,0 +1,105 @@
+use crate::ArgminProject;
+use faer::{unzipped, zipped, Col, Mat, SimpleEntity};
+
+impl<E: SimpleEntity + PartialOrd> ArgminProject for Mat<E> {
+    #[inline]
+    fn project(&self, lower: &Self, upper: &Self) -> Self {
+        zipped!(self, lower, upper).map(|unzipped!(x, l, u)| {
+            let (x, l, u) = (*x, *l, *u);
+            if x < l {
+                l
+            } else if x > u {
+                u
+            } else {
+                x
+            }
+        })
+    }
+}
+
+impl<E: SimpleEntity + PartialOrd> ArgminProject for Col<E> {
+    #[inline]
+    fn project(&self, lower: &Self, upper: &Self) -> Self {
+        zipped!(self, lower, upper).map(|unzipped!(x, l, u)| {
+            let (x, l, u) = (*x, *l, *u);
+            if x < l {
+                l
+            } else if x > u {
+                u
+            } else {
+                x
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_project_vec_ $t>]() {
+                    let a = vector3_new(1 as $t, 4 as $t, 8 as $t);
+                    let lower = vector3_new(2 as $t, 3 as $t, 4 as $t);
+                    let upper = vector3_new(3 as $t, 5 as $t, 6 as $t);
+                    let target = vector3_new(2 as $t, 4 as $t, 6 as $t);
+                    let res = <_ as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[(i, 0)] as f64, res[(i, 0)] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_project_mat_ $t>]() {
+                    let a = matrix2x3_new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t
+                    );
+                    let lower = matrix2x3_new(
+                        2 as $t, 3 as $t, 4 as $t,
+                        1 as $t, 6 as $t, 5 as $t
+                    );
+                    let upper = matrix2x3_new(
+                        3 as $t, 5 as $t, 6 as $t,
+                        3 as $t, 7 as $t, 7 as $t
+                    );
+                    let target = matrix2x3_new(
+                        2 as $t, 4 as $t, 6 as $t,
+                        2 as $t, 6 as $t, 7 as $t
+                    );
+                    let res = <_ as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[(j, i)] as f64, res[(j, i)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_project_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let lower = faer::col![2 as $t, 3 as $t, 4 as $t];
+                    let upper = faer::col![3 as $t, 5 as $t, 6 as $t];
+                    let target = faer::col![2 as $t, 4 as $t, 6 as $t];
+                    let res = <_ as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,140 @@
+use crate::ArgminQR;
+use faer::{
+    mat::AsMatRef, prelude::SpSolverLstsq, Col, ComplexField, Mat, MatRef, RealField, SimpleEntity,
+};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct RankDeficientError;
+
+impl fmt::Display for RankDeficientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rank-deficient matrix")
+    }
+}
+
+/// solve the linear least squares problem via QR decomposition
+impl<E: SimpleEntity + ComplexField> ArgminQR<Mat<E>, Mat<E>> for MatRef<'_, E> {
+    #[inline]
+    fn qr_solve(&self, b: &Mat<E>) -> Result<Mat<E>, anyhow::Error> {
+        assert!(
+            self.nrows() >= self.ncols(),
+            "QR solve requires at least as many rows as columns"
+        );
+        assert_eq!(self.nrows(), b.nrows());
+        let qr_decomp = self.qr();
+        let rmat = qr_decomp.compute_thin_r();
+        let is_rank_deficient = rmat.diagonal().column_vector().iter().any(|elem: &E| {
+            !elem.faer_is_finite() || (elem.faer_abs() <= E::Real::faer_zero_threshold())
+        });
+        if is_rank_deficient {
+            return Err(RankDeficientError {}.into());
+        }
+        Ok(qr_decomp.solve_lstsq(b))
+    }
+}
+
+/// solve the linear least squares problem via QR decomposition
+impl<E: SimpleEntity + ComplexField> ArgminQR<Mat<E>, Mat<E>> for Mat<E> {
+    #[inline]
+    fn qr_solve(&self, b: &Mat<E>) -> Result<Mat<E>, anyhow::Error> {
+        <_ as ArgminQR<_, _>>::qr_solve(&self.as_mat_ref(), b)
+    }
+}
+
+/// solve the linear least squares problem via QR decomposition
+impl<E: SimpleEntity + ComplexField> ArgminQR<Col<E>, Col<E>> for Mat<E> {
+    #[inline]
+    fn qr_solve(&self, b: &Col<E>) -> Result<Col<E>, anyhow::Error> {
+        let x: Mat<E> = <_ as ArgminQR<_, _>>::qr_solve(&self.as_mat_ref(), &b.as_2d().to_owned())?;
+        Ok(x.col(0).to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::mat::AsMatRef;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_solve_ $t>]() {
+                    let a = matrix3_new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = vector3_new(1 as $t, -2 as $t, 3 as $t);
+                    let b = vector3_new(0 as $t, -5 as $t, 7 as $t);
+                    let res = <_ as ArgminQR<_, _>>::qr_solve(&a, &b).unwrap();
+                    let res1 = <_ as ArgminQR<_, _>>::qr_solve(&a.as_mat_ref(), &b).unwrap();
+                    assert_eq!(res, res1);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[(i, 0)], x[(i, 0)], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_least_squares_ $t>]() {
+                    // Fit of a line `y = 1 + 2 * t` through four points
+                    let a = faer::mat![
+                        [1 as $t, 0 as $t],
+                        [1 as $t, 1 as $t],
+                        [1 as $t, 2 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let b = column_vector_from_vec(vec![1.5 as $t, 2.5 as $t, 5.5 as $t, 6.5 as $t]);
+                    let res = <_ as ArgminQR<_, _>>::qr_solve(&a, &b).unwrap();
+                    assert_eq!(res.nrows(), 2);
+                    assert_eq!(res.ncols(), 1);
+                    assert_relative_eq!(res[(0, 0)], 1.3 as $t, epsilon = $t::EPSILON.sqrt());
+                    assert_relative_eq!(res[(1, 0)], 1.8 as $t, epsilon = $t::EPSILON.sqrt());
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_rank_deficient_ $t>]() {
+                    let a = matrix2_new(
+                        1 as $t, 0 as $t,
+                        2 as $t, 0 as $t,
+                    );
+                    let b = vector2_new(1 as $t, 1 as $t);
+                    let err = <_ as ArgminQR<_, _>>::qr_solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<RankDeficientError>()
+                        .unwrap();
+                    assert_eq!(err, RankDeficientError {});
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_col_ $t>]() {
+                    let a = matrix3_new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = faer::col![1 as $t, -2 as $t, 3 as $t];
+                    let b = faer::col![0 as $t, -5 as $t, 7 as $t];
+                    let res: Col<$t> = <_ as ArgminQR<_, _>>::qr_solve(&a, &b).unwrap();
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
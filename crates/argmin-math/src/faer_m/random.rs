@@ -1,4 +1,4 @@
-use faer::{unzipped, Entity, Mat};
+use faer::{unzipped, Col, Entity, Mat};
 use rand::distributions::uniform::SampleUniform;
 
 use crate::ArgminRandom;
@@ -34,6 +34,30 @@ impl<E: Entity + PartialOrd + SampleUniform> ArgminRandom for Mat<E> {
     }
 }
 
+impl<E: Entity + PartialOrd + SampleUniform> ArgminRandom for Col<E> {
+    fn rand_from_range<R: rand::Rng>(min: &Self, max: &Self, rng: &mut R) -> Self {
+        assert!(min.nrows() != 0, "internal error: empty vector unexpected");
+        assert_eq!(
+            min.nrows(),
+            max.nrows(),
+            "internal error: vectors of same length expected"
+        );
+
+        faer::zipped_rw!(min, max).map(|unzipped!(min, max)| {
+            let a = min.read();
+            let b = max.read();
+            #[allow(clippy::float_cmp)]
+            if a == b {
+                a
+            } else if a < b {
+                rng.gen_range(a..b)
+            } else {
+                rng.gen_range(b..a)
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -108,6 +132,20 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_random_col_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = faer::col![2 as $t, 3 as $t, 4 as $t];
+                    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+                    let random = <_ as ArgminRandom>::rand_from_range(&a, &b, &mut rng);
+                    for i in 0..3 {
+                        assert!(random[i] >= a[i]);
+                        assert!(random[i] <= b[i]);
+                    }
+                }
+            }
         };
     }
 
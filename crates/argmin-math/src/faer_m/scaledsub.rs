@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use crate::ArgminScaledSub;
+    use approx::assert_relative_eq;
+    use faer::{Col, Mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledsub_col_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let c = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    let res = <Col<$t> as ArgminScaledSub<Col<$t>, $t, Col<$t>>>::scaled_sub(&a, &b, &c);
+                    let target = faer::col![-7 as $t, -8 as $t, -9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsub_col_panic_1_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let c = faer::col![4 as $t, 5 as $t];
+                    <Col<$t> as ArgminScaledSub<Col<$t>, $t, Col<$t>>>::scaled_sub(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsub_col_panic_2_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t];
+                    let b = 2 as $t;
+                    let c = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSub<Col<$t>, $t, Col<$t>>>::scaled_sub(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsub_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = faer::col![3 as $t, 2 as $t, 1 as $t];
+                    let c = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    let res = <Col<$t> as ArgminScaledSub<Col<$t>, Col<$t>, Col<$t>>>::scaled_sub(&a, &b, &c);
+                    let target = faer::col![-11 as $t, -8 as $t, -3 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsub_col_col_panic_1_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t];
+                    let b = faer::col![3 as $t, 2 as $t, 1 as $t];
+                    let c = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSub<Col<$t>, Col<$t>, Col<$t>>>::scaled_sub(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsub_col_col_panic_2_ $t>]() {
+                    let a = faer::col![1 as $t, 2 as $t, 3 as $t];
+                    let b = faer::col![3 as $t, 2 as $t];
+                    let c = faer::col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSub<Col<$t>, Col<$t>, Col<$t>>>::scaled_sub(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsub_mat_vec_ $t>]() {
+                    let a = vector3_new(1 as $t, 2 as $t, 3 as $t);
+                    let b = 2 as $t;
+                    let c = vector3_new(4 as $t, 5 as $t, 6 as $t);
+                    let res = <Mat<$t> as ArgminScaledSub<Mat<$t>, $t, Mat<$t>>>::scaled_sub(&a, &b, &c);
+                    let target = vector3_new(-7 as $t, -8 as $t, -9 as $t);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[(i, 0)] as f64, target[(i, 0)] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsub_mat_mat_ $t>]() {
+                    let a = matrix2_new(
+                        1 as $t, 2 as $t,
+                        3 as $t, 4 as $t,
+                    );
+                    let b = matrix2_new(
+                        4 as $t, 3 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let c = matrix2_new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let res = <Mat<$t> as ArgminScaledSub<Mat<$t>, Mat<$t>, Mat<$t>>>::scaled_sub(&a, &b, &c);
+                    let target = matrix2_new(
+                        -3 as $t, -4 as $t,
+                        -1 as $t, 3 as $t,
+                    );
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsub_mat_scalar_ $t>]() {
+                    let a = matrix2_new(
+                        1 as $t, 2 as $t,
+                        3 as $t, 4 as $t,
+                    );
+                    let b = 2 as $t;
+                    let c = matrix2_new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let res = <Mat<$t> as ArgminScaledSub<Mat<$t>, $t, Mat<$t>>>::scaled_sub(&a, &b, &c);
+                    let target = matrix2_new(
+                        -1 as $t, -2 as $t,
+                        -1 as $t, 2 as $t,
+                    );
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
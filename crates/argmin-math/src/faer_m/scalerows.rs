@@ -0,0 +1,77 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaleRows;
+use faer::{Entity, Mat};
+use std::ops::Mul;
+
+/// Mat (matrix) scaled row-wise by Mat (column vector) -> Mat
+impl<E> ArgminScaleRows<Mat<E>> for Mat<E>
+where
+    E: Entity + Mul<E, Output = E>,
+{
+    #[inline]
+    fn scale_rows(&self, factors: &Mat<E>) -> Mat<E> {
+        assert_eq!(factors.ncols(), 1);
+        assert_eq!(self.nrows(), factors.nrows());
+        Mat::<E>::from_fn(self.nrows(), self.ncols(), |i, j| {
+            self.read(i, j) * factors.read(i, 0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scale_rows_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let factors = vector2_new(3 as $t, 2 as $t);
+                    let target = mat![
+                        [3 as $t, 12 as $t, 24 as $t],
+                        [4 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminScaleRows<Mat<$t>>>::scale_rows(&a, &factors);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target.read(j, i) as f64, res.read(j, i) as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_scale_rows_wrong_length() {
+        let a = mat![[1.0f64, 4.0], [2.0, 5.0]];
+        let _ = a.scale_rows(&column_vector_from_vec(vec![1.0f64]));
+    }
+}
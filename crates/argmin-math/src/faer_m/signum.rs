@@ -1,5 +1,5 @@
 use crate::ArgminSignum;
-use faer::{unzipped, zipped_rw, Entity, Mat, MatRef, Shape};
+use faer::{unzipped, zipped_rw, Col, Entity, Mat, MatRef, Shape};
 use num_complex::Complex;
 
 /// helper trait that indicates the signum of a numeric value can
@@ -58,6 +58,17 @@ where
     }
 }
 
+impl<E, R> ArgminSignum for Col<E, R>
+where
+    E: Entity + SignumInternal,
+    R: Shape,
+{
+    #[inline]
+    fn signum(self) -> Self {
+        zipped_rw!(self).map(|unzipped!(elem)| elem.read().signum_internal())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -100,6 +111,18 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_signum_col_ $t>]() {
+                    let a = faer::col![3 as $t, -4 as $t, -8 as $t];
+                    let b = faer::col![1 as $t, -1 as $t, -1 as $t];
+                    let res = <_ as ArgminSignum>::signum(a);
+                    for i in 0..3 {
+                        assert_relative_eq!(b[i], res[i], epsilon = $t::EPSILON);
+                    }
+                }
+            }
         };
     }
 
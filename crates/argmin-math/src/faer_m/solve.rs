@@ -0,0 +1,93 @@
+use crate::{ArgminLU, ArgminSolve};
+use faer::{mat::AsMatRef, Col, ComplexField, Mat, MatRef, SimpleEntity};
+
+/// solve the linear system via LU decomposition with partial pivoting
+impl<E: SimpleEntity + ComplexField> ArgminSolve<Mat<E>, Mat<E>> for MatRef<'_, E> {
+    #[inline]
+    fn solve(&self, b: &Mat<E>) -> Result<Mat<E>, anyhow::Error> {
+        <_ as ArgminLU<_, _>>::lu_solve(self, b)
+    }
+}
+
+/// solve the linear system via LU decomposition with partial pivoting
+impl<E: SimpleEntity + ComplexField> ArgminSolve<Mat<E>, Mat<E>> for Mat<E> {
+    #[inline]
+    fn solve(&self, b: &Mat<E>) -> Result<Mat<E>, anyhow::Error> {
+        <_ as ArgminLU<_, _>>::lu_solve(&self.as_mat_ref(), b)
+    }
+}
+
+/// solve the linear system via LU decomposition with partial pivoting
+impl<E: SimpleEntity + ComplexField> ArgminSolve<Col<E>, Col<E>> for Mat<E> {
+    #[inline]
+    fn solve(&self, b: &Col<E>) -> Result<Col<E>, anyhow::Error> {
+        let x: Mat<E> = <_ as ArgminSolve<_, _>>::solve(&self.as_mat_ref(), &b.as_2d().to_owned())?;
+        Ok(x.col(0).to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helper::*;
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::mat::AsMatRef;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = matrix3_new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = vector3_new(1 as $t, -2 as $t, 3 as $t);
+                    let b = vector3_new(0 as $t, -5 as $t, 7 as $t);
+                    let res = <_ as ArgminSolve<_, _>>::solve(&a, &b).unwrap();
+                    let res1 = <_ as ArgminSolve<_, _>>::solve(&a.as_mat_ref(), &b).unwrap();
+                    assert_eq!(res, res1);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[(i, 0)], x[(i, 0)], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a = matrix2_new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 4 as $t,
+                    );
+                    let b = vector2_new(1 as $t, 1 as $t);
+                    let res = <_ as ArgminSolve<_, _>>::solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_col_ $t>]() {
+                    let a = matrix3_new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = faer::col![1 as $t, -2 as $t, 3 as $t];
+                    let b = faer::col![0 as $t, -5 as $t, 7 as $t];
+                    let res: Col<$t> = <_ as ArgminSolve<_, _>>::solve(&a, &b).unwrap();
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = $t::EPSILON.sqrt());
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -2,7 +2,7 @@ use crate::ArgminSub;
 use faer::{
     mat::AsMatRef,
     reborrow::{IntoConst, Reborrow, ReborrowMut},
-    unzipped, zipped_rw, Conjugate, Entity, Mat, MatMut, MatRef, SimpleEntity,
+    unzipped, zipped_rw, Col, Conjugate, Entity, Mat, MatMut, MatRef, SimpleEntity,
 };
 use std::ops::{Sub, SubAssign};
 
@@ -90,6 +90,36 @@ impl<E: Entity + Sub<E, Output = E>> ArgminSub<Mat<E>, Mat<E>> for Mat<E> {
     }
 }
 
+/// Col - Scalar -> Col
+impl<E> ArgminSub<E, Col<E>> for Col<E>
+where
+    E: Entity + Sub<E, Output = E>,
+{
+    #[inline]
+    fn sub(&self, other: &E) -> Col<E> {
+        zipped_rw!(self).map(|unzipped!(this)| this.read() - *other)
+    }
+}
+
+/// Scalar - Col -> Col
+impl<E> ArgminSub<Col<E>, Col<E>> for E
+where
+    E: Entity + Sub<E, Output = E>,
+{
+    #[inline]
+    fn sub(&self, other: &Col<E>) -> Col<E> {
+        zipped_rw!(other).map(|unzipped!(other_elem)| *self - other_elem.read())
+    }
+}
+
+/// Col - Col -> Col
+impl<E: Entity + Sub<E, Output = E>> ArgminSub<Col<E>, Col<E>> for Col<E> {
+    #[inline]
+    fn sub(&self, other: &Col<E>) -> Col<E> {
+        zipped_rw!(self, other).map(|unzipped!(this, other)| this.read() - other.read())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::test_helper::*;
@@ -192,6 +222,64 @@ mod test {
                 }
             }
 
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_ $t>]() {
+                    let a = column_vector_from_vec(vec![1 as $t, 4 as $t]);
+                    let b = column_vector_from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    <_ as ArgminSub<_, _>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_2_ $t>]() {
+                    let a = column_vector_from_vec(vec![]);
+                    let b = column_vector_from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    <_ as ArgminSub<_, _>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_3_ $t>]() {
+                    let a = column_vector_from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    let b = column_vector_from_vec(vec![]);
+                    <_ as ArgminSub<_, _>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_mat_mat_panic_2_ $t>]() {
+                    let a = faer::mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = faer::mat![
+                        [41 as $t, 38 as $t]
+                    ];
+                    <_ as ArgminSub<_, _>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_mat_mat_panic_3_ $t>]() {
+                    let a = faer::mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = faer::Mat::new();
+                    <_ as ArgminSub<_, _>>::sub(&a, &b);
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_sub_mat_scalar_ $t>]() {
@@ -217,6 +305,58 @@ mod test {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_sub_col_scalar_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![-1 as $t, 2 as $t, 6 as $t];
+                    let res = <_ as ArgminSub<$t, _>>::sub(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_scalar_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = faer::col![1 as $t, -2 as $t, -6 as $t];
+                    let res = <$t as ArgminSub<_, _>>::sub(&b, &a);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_col_col_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t, 8 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    let target = faer::col![-1 as $t, 2 as $t, 4 as $t];
+                    let res = <_ as ArgminSub<_, _>>::sub(&a, &b);
+                    assert_eq!(res.nrows(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_col_col_panic_ $t>]() {
+                    let a = faer::col![1 as $t, 4 as $t];
+                    let b = faer::col![2 as $t, 2 as $t, 4 as $t];
+                    <_ as ArgminSub<_, _>>::sub(&a, &b);
+                }
+            }
         };
     }
 
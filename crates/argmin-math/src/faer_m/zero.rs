@@ -1,5 +1,5 @@
 use crate::ArgminZeroLike;
-use faer::{Entity, Mat, Shape};
+use faer::{Col, Entity, Mat, Shape};
 
 impl<E, R, C> ArgminZeroLike for Mat<E, R, C>
 where
@@ -12,6 +12,16 @@ where
     }
 }
 
+impl<E, R> ArgminZeroLike for Col<E, R>
+where
+    E: Entity,
+    R: Shape,
+{
+    fn zero_like(&self) -> Self {
+        Self::zeros(self.nrows())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helper::*;
@@ -66,6 +76,17 @@ mod tests {
                     }
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_zero_like_col_ $t>]() {
+                    let a = faer::col![42 as $t, 42 as $t, 42 as $t, 42 as $t].zero_like();
+                    assert_eq!(a.nrows(), 4);
+                    for i in 0..4 {
+                        assert_relative_eq!(0 as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
         };
     }
 
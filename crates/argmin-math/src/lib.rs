@@ -6,9 +6,10 @@
 // copied, modified, or distributed except according to those terms.
 
 //! argmin-math provides mathematics related abstractions needed in argmin. It supports
-//! implementations of these abstractions for basic `Vec`s and for the `ndarray`, `nalgebra`,
-//! and `faer` linear algebra libraries. The traits can of course also be implemented
-//! for your own types to make them compatible with argmin.
+//! implementations of these abstractions for basic `Vec`s, fixed-size arrays and for the `ndarray`,
+//! `nalgebra`, and `faer` linear algebra libraries, as well as for sparse matrices from `sprs`. The
+//! traits can of course also be implemented for your own types to make them compatible with
+//! argmin.
 //!
 //! For an introduction on how to use argmin, please also have a look at the
 //! [book](https://www.argmin-rs.org/book/).
@@ -37,6 +38,27 @@
 //! | `primitives`           | yes     | basic integer and floating point types                |
 //! | `vec`                  | yes     | `Vec`s (basic functionality)                          |
 //!
+//! ### Dual numbers
+//!
+//! | Feature                | Default | Comment                                               |
+//! |------------------------|---------|-------------------------------------------------------|
+//! | `dual`                 | no      | dual and hyper-dual numbers (see `dual` module)       |
+//!
+//! The `dual` feature adds the `dual::Dual` and `dual::HyperDual` number types for
+//! forward-mode automatic differentiation. They implement the `num_traits` float traits and are
+//! supported by the `primitives`, `vec` and `const_array` backends.
+//!
+//! ### Fixed-size arrays
+//!
+//! | Feature                | Default | Comment                                               |
+//! |------------------------|---------|-------------------------------------------------------|
+//! | `const_array`          | no      | fixed-size arrays `[T; N]` and `[[T; N]; M]`          |
+//!
+//! Vectors are represented as `[T; N]` and matrices as `[[T; N]; M]` (`M` rows with `N` columns
+//! each). Since the sizes are known at compile time, small problems can be solved without any
+//! heap allocations in the math operations. Matrix inversion and the linear solve traits are
+//! implemented for `f32` and `f64`.
+//!
 //! ### `ndarray`
 //!
 //! | Feature                         | Default | Comment                                                            |
@@ -50,10 +72,11 @@
 //!
 //! Note that the `*-nolinalg*` features do NOT pull in `ndarray-linalg` as a dependency. This
 //! avoids linking against a BLAS library. This will however disable the implementation of
-//! `ArgminInv`, meaning that any solver which requires the matrix inverse will not work with the
-//! `ndarray` backend. It is recommended to use the `*-nolinalg*` options if the matrix inverse is
-//! not needed in order to keep the compilation times low and avoid problems when linking against a
-//! BLAS library.
+//! `ArgminInv` as well as of the linear solve traits `ArgminSolve`, `ArgminCholesky`, `ArgminLU`
+//! and `ArgminQR`, meaning that any solver which requires the matrix inverse or solves linear
+//! systems will not work with the `ndarray` backend. It is recommended to use the `*-nolinalg*`
+//! options if these are not needed in order to keep the compilation times low and avoid problems
+//! when linking against a BLAS library.
 //!
 //! Using the `ndarray_*` features with `ndarray-linalg` support may require to explicitly choose
 //! the `ndarray-linalg` BLAS backend in your `Cargo.toml` (see the [`ndarray-linalg` documentation
@@ -63,6 +86,8 @@
 //! ndarray-linalg = { version = "<appropriate_version>", features = ["<linalg_backend>"] }
 //! ```
 //!
+//! The selected version of `ndarray` is re-exported as `argmin_math::ndarray`.
+//!
 //! ### `nalgebra`
 //!
 //! | Feature                | Default | Comment                                  |
@@ -74,13 +99,32 @@
 //! | `nalgebra_v0_30`       | no      | version 0.30                             |
 //! | `nalgebra_v0_29`       | no      | version 0.29                             |
 //!
+//! The selected version of `nalgebra` is re-exported as `argmin_math::nalgebra`.
+//!
 //! ### `faer`
 //!
+//! Parameter vectors can be represented either as `Col` or as `n x 1` matrices (`Mat`). Matrices
+//! such as Hessians and Jacobians are represented as `Mat`.
+//!
 //! | Feature                | Default | Comment                                  |
 //! |------------------------|---------|------------------------------------------|
 //! | `faer_latest`          | no      | latest supported version                 |
 //! | `faer_v0_20`           | no      | version 0.20                             |
 //!
+//! ### `sprs`
+//!
+//! Sparse matrices (`CsMat`, both CSR and CSC) are combined with dense `ndarray` vectors
+//! (`Array1`). Activating a `sprs` feature therefore also activates the corresponding
+//! `ndarray` backend without `ndarray-linalg`. The inverse of a sparse matrix is in general dense,
+//! therefore `ArgminInv` is not implemented. Linear systems and least squares problems
+//! (`ArgminSolve`, `ArgminQR`) are solved iteratively via CGLS, which only requires products of
+//! the sparse matrix with vectors; `ArgminLU` is not implemented.
+//!
+//! | Feature                | Default | Comment                                  |
+//! |------------------------|---------|------------------------------------------|
+//! | `sprs_latest`          | no      | latest supported version                 |
+//! | `sprs_v0_11`           | no      | version 0.11                             |
+//!
 //! ## Choosing a backend
 //!
 //! It is not possible to activate two versions of the same backend.
@@ -139,7 +183,7 @@
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "nalgebra_0_33")] {
-        extern crate nalgebra_0_33 as nalgebra;
+        pub extern crate nalgebra_0_33 as nalgebra;
         trait Allocator<T, R, C = nalgebra::U1>: nalgebra::allocator::Allocator<R, C>
         where
             R: nalgebra::Dim,
@@ -175,19 +219,19 @@ cfg_if::cfg_if! {
             ClosedMulAssign as ClosedMul,
         };
     } else if #[cfg(feature = "nalgebra_0_32")] {
-        extern crate nalgebra_0_32 as nalgebra;
+        pub extern crate nalgebra_0_32 as nalgebra;
         use nalgebra::allocator::{Allocator, SameShapeAllocator};
         use nalgebra::{ClosedAdd, ClosedSub, ClosedDiv, ClosedMul};
     } else if #[cfg(feature = "nalgebra_0_31")] {
-        extern crate nalgebra_0_31 as nalgebra;
+        pub extern crate nalgebra_0_31 as nalgebra;
         use nalgebra::allocator::{Allocator, SameShapeAllocator};
         use nalgebra::{ClosedAdd, ClosedSub, ClosedDiv, ClosedMul};
     } else if #[cfg(feature = "nalgebra_0_30")] {
-        extern crate nalgebra_0_30 as nalgebra;
+        pub extern crate nalgebra_0_30 as nalgebra;
         use nalgebra::allocator::{Allocator, SameShapeAllocator};
         use nalgebra::{ClosedAdd, ClosedSub, ClosedDiv, ClosedMul};
     } else if #[cfg(feature = "nalgebra_0_29")] {
-        extern crate nalgebra_0_29 as nalgebra;
+        pub extern crate nalgebra_0_29 as nalgebra;
         use nalgebra::allocator::{Allocator, SameShapeAllocator};
         use nalgebra::{ClosedAdd, ClosedSub, ClosedDiv, ClosedMul};
     }
@@ -195,11 +239,11 @@ cfg_if::cfg_if! {
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "ndarray_0_15")] {
-        extern crate ndarray_0_15 as ndarray;
+        pub extern crate ndarray_0_15 as ndarray;
     } else if #[cfg(feature = "ndarray_0_14")]  {
-        extern crate ndarray_0_14 as ndarray;
+        pub extern crate ndarray_0_14 as ndarray;
     } else if #[cfg(feature = "ndarray_0_13")]  {
-        extern crate ndarray_0_13 as ndarray;
+        pub extern crate ndarray_0_13 as ndarray;
     }
 }
 
@@ -225,6 +269,16 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "sprs_v0_11")] {
+        extern crate sprs_0_11 as sprs;
+    }
+}
+
+#[cfg(feature = "dual")]
+#[macro_use]
+pub mod dual;
+
 #[cfg(feature = "primitives")]
 mod primitives;
 #[cfg(feature = "primitives")]
@@ -249,12 +303,24 @@ mod vec;
 #[allow(unused_imports)]
 pub use crate::vec::*;
 
+#[cfg(feature = "const_array")]
+mod const_array;
+#[cfg(feature = "const_array")]
+#[allow(unused_imports)]
+pub use crate::const_array::*;
+
 #[cfg(feature = "faer_all")]
 mod faer_m;
 #[cfg(feature = "faer_all")]
 #[allow(unused_imports)]
 pub use crate::faer_m::*;
 
+#[cfg(feature = "sprs_all")]
+mod sprs_m;
+#[cfg(feature = "sprs_all")]
+#[allow(unused_imports)]
+pub use crate::sprs_m::*;
+
 // Re-export of types appearing in the api as recommended here: https://www.lurklurk.org/effective-rust/re-export.html
 pub use anyhow::Error;
 pub use rand::Rng;
@@ -361,6 +427,40 @@ pub trait ArgminInv<T> {
     fn inv(&self) -> Result<T, Error>;
 }
 
+/// Solve the linear system `self * x = b` for `x` (`X`) given the right-hand side `b` (`B`).
+///
+/// This should be preferred over computing the inverse via [`ArgminInv`], because it is both
+/// cheaper and numerically more stable. Implementations choose an appropriate factorization of
+/// `self`, typically an LU decomposition with partial pivoting.
+pub trait ArgminSolve<B, X> {
+    /// Solve `self * x = b`
+    fn solve(&self, b: &B) -> Result<X, Error>;
+}
+
+/// Solve the linear system `self * x = b` for `x` (`X`) via a Cholesky decomposition of `self`.
+///
+/// `self` must be symmetric (Hermitian) and positive definite, otherwise an error is returned.
+pub trait ArgminCholesky<B, X> {
+    /// Solve `self * x = b` via a Cholesky decomposition of `self`
+    fn cholesky_solve(&self, b: &B) -> Result<X, Error>;
+}
+
+/// Solve the linear system `self * x = b` for `x` (`X`) via an LU decomposition of `self`.
+pub trait ArgminLU<B, X> {
+    /// Solve `self * x = b` via an LU decomposition of `self`
+    fn lu_solve(&self, b: &B) -> Result<X, Error>;
+}
+
+/// Solve the linear least squares problem `min_x ||self * x - b||` for `x` (`X`) via a QR
+/// decomposition of `self`.
+///
+/// `self` must have at least as many rows as columns and full column rank. For square matrices
+/// this is the solution of `self * x = b`.
+pub trait ArgminQR<B, X> {
+    /// Solve `min_x ||self * x - b||` via a QR decomposition of `self`
+    fn qr_solve(&self, b: &B) -> Result<X, Error>;
+}
+
 /// Create a random number
 pub trait ArgminRandom {
     /// Get a random element between min and max,
@@ -375,8 +475,46 @@ pub trait ArgminMinMax {
     fn max(x: &Self, y: &Self) -> Self;
 }
 
+/// Projection onto a box defined by lower and upper bounds
+pub trait ArgminProject {
+    /// Project `self` onto the box `[lower, upper]` by (piecewise) clamping each element to its
+    /// respective bounds.
+    fn project(&self, lower: &Self, upper: &Self) -> Self;
+}
+
 /// Returns a number that represents the sign of `self`.
 pub trait ArgminSignum {
     /// Returns a number that represents the sign of `self`.
     fn signum(self) -> Self;
 }
+
+/// Applies a function to each element of `self`
+pub trait ArgminMap<T> {
+    /// Returns a copy of `self` where each element `x` is replaced by `f(x)`.
+    #[must_use]
+    fn map<G: Fn(T) -> T>(&self, f: G) -> Self;
+}
+
+/// Scales each row of a matrix by the corresponding element of a vector
+pub trait ArgminScaleRows<V> {
+    /// Returns `diag(factors) * self`, i.e. row `i` of `self` multiplied by `factors[i]`.
+    #[must_use]
+    fn scale_rows(&self, factors: &V) -> Self;
+}
+
+/// Returns the diagonal of a square matrix
+pub trait ArgminDiag<V> {
+    /// Returns the diagonal elements of `self` as a vector.
+    fn diag(&self) -> V;
+}
+
+/// Returns the number of elements of a vector
+pub trait ArgminLen {
+    /// Returns the number of elements of `self`.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if `self` has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Allocator, ArgminCholesky, Error};
+use nalgebra::{ComplexField, DefaultAllocator, Dim, OMatrix, OVector};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct NotPositiveDefiniteError;
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite")
+    }
+}
+
+impl<N, D> ArgminCholesky<OVector<N, D>, OVector<N, D>> for OMatrix<N, D, D>
+where
+    N: ComplexField,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    #[inline]
+    fn cholesky_solve(&self, b: &OVector<N, D>) -> Result<OVector<N, D>, Error> {
+        match self.clone().cholesky() {
+            Some(chol) => Ok(chol.solve(b)),
+            None => Err(NotPositiveDefiniteError {}.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2, Matrix3, Vector2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    let a = Matrix3::new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = Vector3::new(1 as $t, -2 as $t, 3 as $t);
+                    let b = Vector3::new(0 as $t, -5 as $t, 7 as $t);
+                    let res = <Matrix3<$t> as ArgminCholesky<Vector3<$t>, Vector3<$t>>>::cholesky_solve(&a, &b).unwrap();
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_singular_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 1 as $t);
+                    let res = <Matrix2<$t> as ArgminCholesky<Vector2<$t>, Vector2<$t>>>::cholesky_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,69 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Allocator, ArgminDiag};
+
+use nalgebra::{base::dimension::Dim, DefaultAllocator, OMatrix, OVector, Scalar};
+
+impl<N, D> ArgminDiag<OVector<N, D>> for OMatrix<N, D, D>
+where
+    N: Scalar,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    #[inline]
+    fn diag(&self) -> OVector<N, D> {
+        assert!(self.is_square());
+        self.diagonal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DMatrix, Matrix3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diag_ $t>]() {
+                    let a = Matrix3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t,
+                        3 as $t, 6 as $t, 7 as $t
+                    );
+                    let target = Vector3::new(1 as $t, 5 as $t, 7 as $t);
+                    let res = <Matrix3<$t> as ArgminDiag<Vector3<$t>>>::diag(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_diag_not_square() {
+        let a = DMatrix::from_element(2, 3, 1.0f64);
+        let _ = a.diag();
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Allocator, ArgminLen};
+
+use nalgebra::{base::dimension::Dim, DefaultAllocator, OVector, Scalar};
+
+impl<N, D> ArgminLen for OVector<N, D>
+where
+    N: Scalar,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        OVector::<N, D>::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DVector, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_len_ $t>]() {
+                    let a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    assert_eq!(<Vector3<$t> as ArgminLen>::len(&a), 3);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_len_dyn_ $t>]() {
+                    let a = DVector::from_vec(vec![1 as $t, 4 as $t, 8 as $t, 2 as $t]);
+                    assert_eq!(<DVector<$t> as ArgminLen>::len(&a), 4);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -8,37 +8,55 @@
 #![allow(unused_imports)]
 
 mod add;
+mod cholesky;
 mod conj;
+mod diag;
 mod div;
 mod dot;
 mod eye;
 mod inv;
 mod l1norm;
 mod l2norm;
+mod len;
+mod lu;
+mod map;
 mod minmax;
 mod mul;
+mod project;
+mod qr;
 mod random;
 mod scaledadd;
 mod scaledsub;
+mod scalerows;
 mod signum;
+mod solve;
 mod sub;
 mod transpose;
 mod zero;
 
 pub use add::*;
+pub use cholesky::*;
 pub use conj::*;
+pub use diag::*;
 pub use div::*;
 pub use dot::*;
 pub use eye::*;
 pub use inv::*;
 pub use l1norm::*;
 pub use l2norm::*;
+pub use len::*;
+pub use lu::*;
+pub use map::*;
 pub use minmax::*;
 pub use mul::*;
+pub use project::*;
+pub use qr::*;
 pub use random::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
+pub use scalerows::*;
 pub use signum::*;
+pub use solve::*;
 pub use sub::*;
 pub use transpose::*;
 pub use zero::*;
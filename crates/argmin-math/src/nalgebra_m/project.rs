@@ -0,0 +1,99 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Allocator, ArgminProject};
+
+use nalgebra::{
+    base::{dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+
+impl<N, R, C> ArgminProject for OMatrix<N, R, C>
+where
+    N: Scalar + Copy + PartialOrd,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn project(&self, lower: &OMatrix<N, R, C>, upper: &OMatrix<N, R, C>) -> OMatrix<N, R, C> {
+        self.zip_zip_map(lower, upper, |x, l, u| {
+            if x < l {
+                l
+            } else if x > u {
+                u
+            } else {
+                x
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_project_vec_ $t>]() {
+                    let a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    let lower = Vector3::new(2 as $t, 3 as $t, 4 as $t);
+                    let upper = Vector3::new(3 as $t, 5 as $t, 6 as $t);
+                    let target = Vector3::new(2 as $t, 4 as $t, 6 as $t);
+                    let res = <Vector3<$t> as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_project_mat_ $t>]() {
+                    let a = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t
+                    );
+                    let lower = Matrix2x3::new(
+                        2 as $t, 3 as $t, 4 as $t,
+                        1 as $t, 6 as $t, 5 as $t
+                    );
+                    let upper = Matrix2x3::new(
+                        3 as $t, 5 as $t, 6 as $t,
+                        3 as $t, 7 as $t, 7 as $t
+                    );
+                    let target = Matrix2x3::new(
+                        2 as $t, 4 as $t, 6 as $t,
+                        2 as $t, 6 as $t, 7 as $t
+                    );
+                    let res = <Matrix2x3<$t> as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[(j, i)] as f64, res[(j, i)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,108 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Allocator, ArgminQR, Error};
+use nalgebra::{ComplexField, DefaultAllocator, Dim, DimMin, OMatrix, OVector};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct RankDeficientError;
+
+impl fmt::Display for RankDeficientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rank-deficient matrix")
+    }
+}
+
+impl<N, R, C> ArgminQR<OVector<N, R>, OVector<N, C>> for OMatrix<N, R, C>
+where
+    N: ComplexField,
+    R: DimMin<C, Output = C>,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, R> + Allocator<N, C> + Allocator<N, C, C>,
+{
+    #[inline]
+    fn qr_solve(&self, b: &OVector<N, R>) -> Result<OVector<N, C>, Error> {
+        assert!(
+            self.nrows() >= self.ncols(),
+            "QR solve requires at least as many rows as columns"
+        );
+        let qr = self.clone().qr();
+        let r = qr.r();
+        if r.diagonal().iter().any(|d| d.is_zero() || !d.is_finite()) {
+            return Err(RankDeficientError {}.into());
+        }
+        let qtb = qr.q().ad_mul(b);
+        match r.solve_upper_triangular(&qtb) {
+            Some(x) => Ok(x),
+            None => Err(RankDeficientError {}.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DMatrix, DVector, Matrix2, Matrix3, Vector2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_solve_ $t>]() {
+                    let a = Matrix3::new(
+                        4 as $t, 2 as $t, 0 as $t,
+                        2 as $t, 5 as $t, 1 as $t,
+                        0 as $t, 1 as $t, 3 as $t,
+                    );
+                    let x = Vector3::new(1 as $t, -2 as $t, 3 as $t);
+                    let b = Vector3::new(0 as $t, -5 as $t, 7 as $t);
+                    let res = <Matrix3<$t> as ArgminQR<Vector3<$t>, Vector3<$t>>>::qr_solve(&a, &b).unwrap();
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_least_squares_ $t>]() {
+                    // Fit of a line `y = 1 + 2 * t` through four points
+                    let a = DMatrix::from_row_slice(4, 2, &[
+                        1 as $t, 0 as $t,
+                        1 as $t, 1 as $t,
+                        1 as $t, 2 as $t,
+                        1 as $t, 3 as $t,
+                    ]);
+                    let b = DVector::from_vec(vec![1.5 as $t, 2.5 as $t, 5.5 as $t, 6.5 as $t]);
+                    let res = <DMatrix<$t> as ArgminQR<DVector<$t>, DVector<$t>>>::qr_solve(&a, &b).unwrap();
+                    assert_eq!(res.len(), 2);
+                    assert_relative_eq!(res[0], 1.3 as $t, epsilon = <$t>::EPSILON.sqrt());
+                    assert_relative_eq!(res[1], 1.8 as $t, epsilon = <$t>::EPSILON.sqrt());
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_singular_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 0 as $t,
+                        2 as $t, 0 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 1 as $t);
+                    let res = <Matrix2<$t> as ArgminQR<Vector2<$t>, Vector2<$t>>>::qr_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Rank-deficient matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
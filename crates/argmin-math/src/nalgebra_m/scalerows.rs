@@ -0,0 +1,82 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Allocator, ArgminScaleRows};
+
+use crate::ClosedMul;
+use nalgebra::{base::dimension::Dim, DefaultAllocator, OMatrix, OVector, Scalar};
+
+impl<N, R, C> ArgminScaleRows<OVector<N, R>> for OMatrix<N, R, C>
+where
+    N: Scalar + Copy + ClosedMul,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, R>,
+{
+    #[inline]
+    fn scale_rows(&self, factors: &OVector<N, R>) -> OMatrix<N, R, C> {
+        assert_eq!(self.nrows(), factors.len());
+        let mut out = self.clone();
+        for j in 0..out.ncols() {
+            for (i, f) in factors.iter().enumerate() {
+                out[(i, j)] *= *f;
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DMatrix, DVector, Matrix2x3, Vector2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scale_rows_ $t>]() {
+                    let a = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t
+                    );
+                    let factors = Vector2::new(3 as $t, 2 as $t);
+                    let target = Matrix2x3::new(
+                        3 as $t, 12 as $t, 24 as $t,
+                        4 as $t, 10 as $t, 18 as $t
+                    );
+                    let res = <Matrix2x3<$t> as ArgminScaleRows<Vector2<$t>>>::scale_rows(&a, &factors);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[(j, i)] as f64, res[(j, i)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_scale_rows_wrong_length() {
+        let a = DMatrix::from_element(2, 2, 1.0f64);
+        let _ = a.scale_rows(&DVector::from_element(1, 1.0f64));
+    }
+}
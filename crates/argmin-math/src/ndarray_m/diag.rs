@@ -0,0 +1,53 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiag;
+use ndarray::{Array1, Array2};
+use num_complex::Complex;
+
+macro_rules! make_diag {
+    ($t:ty) => {
+        impl ArgminDiag<Array1<$t>> for Array2<$t> {
+            #[inline]
+            fn diag(&self) -> Array1<$t> {
+                assert!(self.is_square());
+                Array2::<$t>::diag(self).to_owned()
+            }
+        }
+    };
+}
+
+make_diag!(i8);
+make_diag!(u8);
+make_diag!(i16);
+make_diag!(u16);
+make_diag!(i32);
+make_diag!(u32);
+make_diag!(i64);
+make_diag!(u64);
+make_diag!(f32);
+make_diag!(f64);
+make_diag!(Complex<i8>);
+make_diag!(Complex<u8>);
+make_diag!(Complex<i16>);
+make_diag!(Complex<u16>);
+make_diag!(Complex<i32>);
+make_diag!(Complex<u32>);
+make_diag!(Complex<i64>);
+make_diag!(Complex<u64>);
+make_diag!(Complex<f32>);
+make_diag!(Complex<f64>);
+
+// All code that does not depend on a linked ndarray-linalg backend can still be tested as normal.
+// To avoid dublicating tests and to allow convenient testing of functionality that does not need ndarray-linalg the tests are still included here.
+// The tests expect the name for the crate containing the tested functions to be argmin_math
+#[cfg(test)]
+use crate as argmin_math;
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/ndarray-tests-src/diag.rs"
+));
@@ -0,0 +1,38 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLU, Error};
+use ndarray::{Array1, Array2};
+use ndarray_linalg::Solve;
+use num_complex::Complex;
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        // `ndarray_linalg::Solve` uses an LU decomposition with partial pivoting (`*getrf`)
+        impl ArgminLU<Array1<$t>, Array1<$t>> for Array2<$t> {
+            #[inline]
+            fn lu_solve(&self, b: &Array1<$t>) -> Result<Array1<$t>, Error> {
+                Ok(<Self as Solve<$t>>::solve(self, b)?)
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+make_lu!(Complex<f32>);
+make_lu!(Complex<f64>);
+
+// All code that does not depend on a linked ndarray-linalg backend can still be tested as normal.
+// To avoid dublicating tests and to allow convenient testing of functionality that does not need ndarray-linalg the tests are still included here.
+// The tests expect the name for the crate containing the tested functions to be argmin_math
+#[cfg(test)]
+use crate as argmin_math;
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/ndarray-tests-src/lu.rs"
+));
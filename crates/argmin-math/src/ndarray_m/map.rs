@@ -0,0 +1,59 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMap;
+use ndarray::{Array1, Array2};
+use num_complex::Complex;
+
+macro_rules! make_map {
+    ($t:ty) => {
+        impl ArgminMap<$t> for Array1<$t> {
+            #[inline]
+            fn map<G: Fn($t) -> $t>(&self, f: G) -> Array1<$t> {
+                self.mapv(f)
+            }
+        }
+
+        impl ArgminMap<$t> for Array2<$t> {
+            #[inline]
+            fn map<G: Fn($t) -> $t>(&self, f: G) -> Array2<$t> {
+                self.mapv(f)
+            }
+        }
+    };
+}
+
+make_map!(i8);
+make_map!(u8);
+make_map!(i16);
+make_map!(u16);
+make_map!(i32);
+make_map!(u32);
+make_map!(i64);
+make_map!(u64);
+make_map!(f32);
+make_map!(f64);
+make_map!(Complex<i8>);
+make_map!(Complex<u8>);
+make_map!(Complex<i16>);
+make_map!(Complex<u16>);
+make_map!(Complex<i32>);
+make_map!(Complex<u32>);
+make_map!(Complex<i64>);
+make_map!(Complex<u64>);
+make_map!(Complex<f32>);
+make_map!(Complex<f64>);
+
+// All code that does not depend on a linked ndarray-linalg backend can still be tested as normal.
+// To avoid dublicating tests and to allow convenient testing of functionality that does not need ndarray-linalg the tests are still included here.
+// The tests expect the name for the crate containing the tested functions to be argmin_math
+#[cfg(test)]
+use crate as argmin_math;
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/ndarray-tests-src/map.rs"
+));
@@ -8,7 +8,10 @@
 #![allow(unused_imports)]
 
 mod add;
+#[cfg(feature = "ndarray-linalg_0_16")]
+mod cholesky;
 mod conj;
+mod diag;
 mod div;
 mod dot;
 mod eye;
@@ -16,18 +19,31 @@ mod eye;
 mod inv;
 mod l1norm;
 mod l2norm;
+mod len;
+#[cfg(feature = "ndarray-linalg_0_16")]
+mod lu;
+mod map;
 mod minmax;
 mod mul;
+mod project;
+#[cfg(feature = "ndarray-linalg_0_16")]
+mod qr;
 mod random;
 mod scaledadd;
 mod scaledsub;
+mod scalerows;
 mod signum;
+#[cfg(feature = "ndarray-linalg_0_16")]
+mod solve;
 mod sub;
 mod transpose;
 mod zero;
 
 pub use add::*;
+#[cfg(feature = "ndarray-linalg_0_16")]
+pub use cholesky::*;
 pub use conj::*;
+pub use diag::*;
 pub use div::*;
 pub use dot::*;
 pub use eye::*;
@@ -35,11 +51,21 @@ pub use eye::*;
 pub use inv::*;
 pub use l1norm::*;
 pub use l2norm::*;
+pub use len::*;
+#[cfg(feature = "ndarray-linalg_0_16")]
+pub use lu::*;
+pub use map::*;
 pub use minmax::*;
 pub use mul::*;
+pub use project::*;
+#[cfg(feature = "ndarray-linalg_0_16")]
+pub use qr::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
+pub use scalerows::*;
 pub use signum::*;
+#[cfg(feature = "ndarray-linalg_0_16")]
+pub use solve::*;
 pub use sub::*;
 pub use transpose::*;
 pub use zero::*;
@@ -0,0 +1,56 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminProject;
+use ndarray::{Array1, Array2};
+
+macro_rules! make_project {
+    ($t:ty) => {
+        impl ArgminProject for Array1<$t> {
+            #[inline]
+            fn project(&self, lower: &Self, upper: &Self) -> Array1<$t> {
+                assert_eq!(self.shape(), lower.shape());
+                assert_eq!(self.shape(), upper.shape());
+                Array1::from_shape_fn(self.raw_dim(), |i| {
+                    <$t as ArgminProject>::project(&self[i], &lower[i], &upper[i])
+                })
+            }
+        }
+
+        impl ArgminProject for Array2<$t> {
+            #[inline]
+            fn project(&self, lower: &Self, upper: &Self) -> Array2<$t> {
+                assert_eq!(self.shape(), lower.shape());
+                assert_eq!(self.shape(), upper.shape());
+                Array2::from_shape_fn(self.raw_dim(), |idx| {
+                    <$t as ArgminProject>::project(&self[idx], &lower[idx], &upper[idx])
+                })
+            }
+        }
+    };
+}
+
+make_project!(i8);
+make_project!(u8);
+make_project!(i16);
+make_project!(u16);
+make_project!(i32);
+make_project!(u32);
+make_project!(i64);
+make_project!(u64);
+make_project!(f32);
+make_project!(f64);
+
+// All code that does not depend on a linked ndarray-linalg backend can still be tested as normal.
+// To avoid dublicating tests and to allow convenient testing of functionality that does not need ndarray-linalg the tests are still included here.
+// The tests expect the name for the crate containing the tested functions to be argmin_math
+#[cfg(test)]
+use crate as argmin_math;
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/ndarray-tests-src/project.rs"
+));
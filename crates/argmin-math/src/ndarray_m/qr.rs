@@ -0,0 +1,55 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use ndarray::{Array1, Array2};
+use ndarray_linalg::{Diag, Scalar, SolveTriangular, QR, UPLO};
+use num_complex::Complex;
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct RankDeficientError;
+
+impl fmt::Display for RankDeficientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rank-deficient matrix")
+    }
+}
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        impl ArgminQR<Array1<$t>, Array1<$t>> for Array2<$t> {
+            fn qr_solve(&self, b: &Array1<$t>) -> Result<Array1<$t>, Error> {
+                assert!(
+                    self.nrows() >= self.ncols(),
+                    "QR solve requires at least as many rows as columns"
+                );
+                let (q, r) = <Self as QR>::qr(self)?;
+                if r.diag().iter().any(|d| d.abs() == 0.0 || !d.is_finite()) {
+                    return Err(RankDeficientError {}.into());
+                }
+                let qtb = q.t().mapv(|x| x.conj()).dot(b);
+                Ok(r.solve_triangular(UPLO::Upper, Diag::NonUnit, &qtb)?)
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+make_qr!(Complex<f32>);
+make_qr!(Complex<f64>);
+
+// All code that does not depend on a linked ndarray-linalg backend can still be tested as normal.
+// To avoid dublicating tests and to allow convenient testing of functionality that does not need ndarray-linalg the tests are still included here.
+// The tests expect the name for the crate containing the tested functions to be argmin_math
+#[cfg(test)]
+use crate as argmin_math;
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/ndarray-tests-src/qr.rs"
+));
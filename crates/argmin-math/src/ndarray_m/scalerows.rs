@@ -0,0 +1,57 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaleRows;
+use ndarray::{Array1, Array2};
+use num_complex::Complex;
+
+macro_rules! make_scale_rows {
+    ($t:ty) => {
+        impl ArgminScaleRows<Array1<$t>> for Array2<$t> {
+            #[inline]
+            fn scale_rows(&self, factors: &Array1<$t>) -> Array2<$t> {
+                assert_eq!(self.nrows(), factors.len());
+                let mut out = self.clone();
+                for (mut row, f) in out.outer_iter_mut().zip(factors.iter()) {
+                    row.mapv_inplace(|a| a * *f);
+                }
+                out
+            }
+        }
+    };
+}
+
+make_scale_rows!(i8);
+make_scale_rows!(u8);
+make_scale_rows!(i16);
+make_scale_rows!(u16);
+make_scale_rows!(i32);
+make_scale_rows!(u32);
+make_scale_rows!(i64);
+make_scale_rows!(u64);
+make_scale_rows!(f32);
+make_scale_rows!(f64);
+make_scale_rows!(Complex<i8>);
+make_scale_rows!(Complex<u8>);
+make_scale_rows!(Complex<i16>);
+make_scale_rows!(Complex<u16>);
+make_scale_rows!(Complex<i32>);
+make_scale_rows!(Complex<u32>);
+make_scale_rows!(Complex<i64>);
+make_scale_rows!(Complex<u64>);
+make_scale_rows!(Complex<f32>);
+make_scale_rows!(Complex<f64>);
+
+// All code that does not depend on a linked ndarray-linalg backend can still be tested as normal.
+// To avoid dublicating tests and to allow convenient testing of functionality that does not need ndarray-linalg the tests are still included here.
+// The tests expect the name for the crate containing the tested functions to be argmin_math
+#[cfg(test)]
+use crate as argmin_math;
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/ndarray-tests-src/scalerows.rs"
+));
@@ -40,6 +40,9 @@ make_add!(Complex<u64>);
 make_add!(Complex<f32>);
 make_add!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_add);
+
 #[cfg(test)]
 mod tests {
     use super::*;
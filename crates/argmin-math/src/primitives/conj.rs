@@ -47,6 +47,9 @@ make_complex_conj!(Complex<i64>);
 make_complex_conj!(Complex<f32>);
 make_complex_conj!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_conj);
+
 #[cfg(test)]
 mod tests {
     use super::*;
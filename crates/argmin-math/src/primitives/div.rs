@@ -40,6 +40,9 @@ make_div!(Complex<u64>);
 make_div!(Complex<f32>);
 make_div!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_div);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,6 +40,9 @@ make_dot_vec!(Complex<u16>);
 make_dot_vec!(Complex<u32>);
 make_dot_vec!(Complex<u64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_dot_vec);
+
 #[cfg(test)]
 mod tests {
     use super::*;
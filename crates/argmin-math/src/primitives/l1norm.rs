@@ -7,6 +7,8 @@
 
 use crate::ArgminL1Norm;
 use num_complex::Complex;
+#[cfg(feature = "dual")]
+use num_traits::Float;
 
 macro_rules! make_l1norm_unsigned {
     ($t:ty) => {
@@ -73,6 +75,9 @@ make_l1norm_complex_unsigned!(u16);
 make_l1norm_complex_unsigned!(u32);
 make_l1norm_complex_unsigned!(u64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_l1norm);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,8 @@
 
 use crate::ArgminL2Norm;
 use num_complex::Complex;
+#[cfg(feature = "dual")]
+use num_traits::Float;
 
 macro_rules! make_norm_unsigned {
     ($t:ty) => {
@@ -55,6 +57,9 @@ make_norm!(f64);
 make_norm_complex!(f32);
 make_norm_complex!(f64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_norm);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,6 +41,9 @@ make_minmax!(u16);
 make_minmax!(u32);
 make_minmax!(u64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_minmax);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,9 +15,11 @@ mod l1norm;
 mod l2norm;
 mod minmax;
 mod mul;
+mod project;
 mod random;
 mod scaledadd;
 mod scaledsub;
+mod solve;
 mod sub;
 mod transpose;
 mod weighteddot;
@@ -31,9 +33,11 @@ pub use l1norm::*;
 pub use l2norm::*;
 pub use minmax::*;
 pub use mul::*;
+pub use project::*;
 pub use random::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
+pub use solve::*;
 pub use sub::*;
 pub use transpose::*;
 pub use weighteddot::*;
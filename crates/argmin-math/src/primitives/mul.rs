@@ -40,6 +40,9 @@ make_mul!(Complex<u64>);
 make_mul!(Complex<f32>);
 make_mul!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_mul);
+
 #[cfg(test)]
 mod tests {
     use super::*;
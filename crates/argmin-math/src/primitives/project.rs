@@ -0,0 +1,71 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminProject;
+
+macro_rules! make_project {
+    ($t:ty) => {
+        impl ArgminProject for $t {
+            #[inline]
+            fn project(&self, lower: &Self, upper: &Self) -> $t {
+                if self < lower {
+                    *lower
+                } else if self > upper {
+                    *upper
+                } else {
+                    *self
+                }
+            }
+        }
+    };
+}
+
+make_project!(f32);
+make_project!(f64);
+make_project!(i8);
+make_project!(i16);
+make_project!(i32);
+make_project!(i64);
+make_project!(u8);
+make_project!(u16);
+make_project!(u32);
+make_project!(u64);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_project);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_project_ $t>]() {
+                    let lower = 5 as $t;
+                    let upper = 10 as $t;
+                    assert_eq!((2 as $t).project(&lower, &upper).to_ne_bytes(), lower.to_ne_bytes());
+                    assert_eq!((7 as $t).project(&lower, &upper).to_ne_bytes(), (7 as $t).to_ne_bytes());
+                    assert_eq!((12 as $t).project(&lower, &upper).to_ne_bytes(), upper.to_ne_bytes());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+}
@@ -0,0 +1,109 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholesky, ArgminLU, ArgminQR, ArgminSolve, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SingularError;
+
+impl fmt::Display for SingularError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct NotPositiveDefiniteError;
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite")
+    }
+}
+
+// Linear "systems" for scalars (1d solvers)
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<$t, $t> for $t {
+            #[inline]
+            fn solve(&self, b: &$t) -> Result<$t, Error> {
+                <$t as ArgminLU<$t, $t>>::lu_solve(self, b)
+            }
+        }
+
+        impl ArgminLU<$t, $t> for $t {
+            #[inline]
+            fn lu_solve(&self, b: &$t) -> Result<$t, Error> {
+                if *self == 0.0 || !self.is_finite() {
+                    return Err(SingularError {}.into());
+                }
+                Ok(b / self)
+            }
+        }
+
+        impl ArgminQR<$t, $t> for $t {
+            #[inline]
+            fn qr_solve(&self, b: &$t) -> Result<$t, Error> {
+                <$t as ArgminLU<$t, $t>>::lu_solve(self, b)
+            }
+        }
+
+        impl ArgminCholesky<$t, $t> for $t {
+            #[inline]
+            fn cholesky_solve(&self, b: &$t) -> Result<$t, Error> {
+                if *self <= 0.0 || !self.is_finite() {
+                    return Err(NotPositiveDefiniteError {}.into());
+                }
+                Ok(b / self)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = 4 as $t;
+                    let b = 2 as $t;
+                    let res = <$t as ArgminSolve<$t, $t>>::solve(&a, &b).unwrap();
+                    assert_relative_eq!(res as f64, 0.5, epsilon = f64::EPSILON);
+                    let res = <$t as ArgminLU<$t, $t>>::lu_solve(&a, &b).unwrap();
+                    assert_relative_eq!(res as f64, 0.5, epsilon = f64::EPSILON);
+                    let res = <$t as ArgminQR<$t, $t>>::qr_solve(&a, &b).unwrap();
+                    assert_relative_eq!(res as f64, 0.5, epsilon = f64::EPSILON);
+                    let res = <$t as ArgminCholesky<$t, $t>>::cholesky_solve(&a, &b).unwrap();
+                    assert_relative_eq!(res as f64, 0.5, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let res = <$t as ArgminSolve<$t, $t>>::solve(&(0 as $t), &(1 as $t));
+                    assert_eq!(res.unwrap_err().to_string(), "Singular matrix");
+                    let res = <$t as ArgminCholesky<$t, $t>>::cholesky_solve(&(-1 as $t), &(1 as $t));
+                    assert_eq!(res.unwrap_err().to_string(), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
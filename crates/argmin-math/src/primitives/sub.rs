@@ -40,6 +40,9 @@ make_sub!(Complex<u64>);
 make_sub!(Complex<f32>);
 make_sub!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_sub);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,6 +40,9 @@ make_transpose!(Complex<u64>);
 make_transpose!(Complex<f32>);
 make_transpose!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_transpose);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,103 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_add {
+    ($t:ty) => {
+        impl ArgminAdd<CsMat<$t>, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn add(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                self + other
+            }
+        }
+    };
+}
+
+make_add!(i8);
+make_add!(u8);
+make_add!(i16);
+make_add!(u16);
+make_add!(i32);
+make_add!(u32);
+make_add!(i64);
+make_add!(u64);
+make_add!(f32);
+make_add!(f64);
+make_add!(Complex<i8>);
+make_add!(Complex<u8>);
+make_add!(Complex<i16>);
+make_add!(Complex<u16>);
+make_add!(Complex<i32>);
+make_add!(Complex<u32>);
+make_add!(Complex<i64>);
+make_add!(Complex<u64>);
+make_add!(Complex<f32>);
+make_add!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = CsMat::new((2, 3), vec![0, 1, 3], vec![0, 1, 2], vec![4 as $t, 5 as $t, 6 as $t]);
+                    let target = array![[5 as $t, 0 as $t, 2 as $t], [0 as $t, 8 as $t, 6 as $t]];
+                    let res = <CsMat<$t> as ArgminAdd<CsMat<$t>, CsMat<$t>>>::add(&a, &b).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mixed_storage_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = CsMat::new((2, 3), vec![0, 1, 3], vec![0, 1, 2], vec![4 as $t, 5 as $t, 6 as $t]).to_csc();
+                    let target = array![[5 as $t, 0 as $t, 2 as $t], [0 as $t, 8 as $t, 6 as $t]];
+                    let res = <CsMat<$t> as ArgminAdd<CsMat<$t>, CsMat<$t>>>::add(&a, &b).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_add_wrong_shape() {
+        let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1.0f64, 2.0, 3.0]);
+        let b = CsMat::<f64>::eye(2);
+        let _ = <CsMat<f64> as ArgminAdd<CsMat<f64>, CsMat<f64>>>::add(&a, &b);
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiag;
+use ndarray::Array1;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_diag {
+    ($t:ty) => {
+        impl ArgminDiag<Array1<$t>> for CsMat<$t> {
+            #[inline]
+            fn diag(&self) -> Array1<$t> {
+                assert_eq!(self.rows(), self.cols());
+                Array1::from_shape_fn(self.rows(), |i| self.get(i, i).copied().unwrap_or_default())
+            }
+        }
+    };
+}
+
+make_diag!(i8);
+make_diag!(u8);
+make_diag!(i16);
+make_diag!(u16);
+make_diag!(i32);
+make_diag!(u32);
+make_diag!(i64);
+make_diag!(u64);
+make_diag!(f32);
+make_diag!(f64);
+make_diag!(Complex<i8>);
+make_diag!(Complex<u8>);
+make_diag!(Complex<i16>);
+make_diag!(Complex<u16>);
+make_diag!(Complex<i32>);
+make_diag!(Complex<u32>);
+make_diag!(Complex<i64>);
+make_diag!(Complex<u64>);
+make_diag!(Complex<f32>);
+make_diag!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diag_ $t>]() {
+                    // [[1, 0, 2], [0, 0, 0], [4, 0, 3]]
+                    let a = CsMat::new((3, 3), vec![0, 2, 2, 4], vec![0, 2, 0, 2], vec![1 as $t, 2 as $t, 4 as $t, 3 as $t]);
+                    let target = array![1 as $t, 0 as $t, 3 as $t];
+                    let res = <CsMat<$t> as ArgminDiag<Array1<$t>>>::diag(&a);
+                    let res_csc = <CsMat<$t> as ArgminDiag<Array1<$t>>>::diag(&a.to_csc());
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                        assert_relative_eq!(target[i] as f64, res_csc[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_diag_not_square() {
+        let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1.0f64, 2.0, 3.0]);
+        let _ = <CsMat<f64> as ArgminDiag<Array1<f64>>>::diag(&a);
+    }
+}
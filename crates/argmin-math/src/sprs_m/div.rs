@@ -0,0 +1,79 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl ArgminDiv<$t, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn div(&self, other: &$t) -> CsMat<$t> {
+                self.map(|x| *x / *other)
+            }
+        }
+    };
+}
+
+make_div!(i8);
+make_div!(u8);
+make_div!(i16);
+make_div!(u16);
+make_div!(i32);
+make_div!(u32);
+make_div!(i64);
+make_div!(u64);
+make_div!(f32);
+make_div!(f64);
+make_div!(Complex<i8>);
+make_div!(Complex<u8>);
+make_div!(Complex<i16>);
+make_div!(Complex<u16>);
+make_div!(Complex<i32>);
+make_div!(Complex<u32>);
+make_div!(Complex<i64>);
+make_div!(Complex<u64>);
+make_div!(Complex<f32>);
+make_div!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_scalar_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![2 as $t, 4 as $t, 6 as $t]);
+                    let target = array![[1 as $t, 0 as $t, 2 as $t], [0 as $t, 3 as $t, 0 as $t]];
+                    let res = <CsMat<$t> as ArgminDiv<$t, CsMat<$t>>>::div(&a, &(2 as $t)).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
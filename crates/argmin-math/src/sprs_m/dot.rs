@@ -0,0 +1,144 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use ndarray::Array1;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Array1<$t>, Array1<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &Array1<$t>) -> Array1<$t> {
+                self * other
+            }
+        }
+
+        impl ArgminDot<CsMat<$t>, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                self * other
+            }
+        }
+
+        impl ArgminDot<$t, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> CsMat<$t> {
+                self.map(|x| *x * *other)
+            }
+        }
+
+        impl ArgminDot<CsMat<$t>, CsMat<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                other.map(|x| *self * *x)
+            }
+        }
+    };
+}
+
+make_dot!(i8);
+make_dot!(u8);
+make_dot!(i16);
+make_dot!(u16);
+make_dot!(i32);
+make_dot!(u32);
+make_dot!(i64);
+make_dot!(u64);
+make_dot!(f32);
+make_dot!(f64);
+make_dot!(Complex<i8>);
+make_dot!(Complex<u8>);
+make_dot!(Complex<i16>);
+make_dot!(Complex<u16>);
+make_dot!(Complex<i32>);
+make_dot!(Complex<u32>);
+make_dot!(Complex<i64>);
+make_dot!(Complex<u64>);
+make_dot!(Complex<f32>);
+make_dot!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = array![1 as $t, 2 as $t, 3 as $t];
+                    let target = array![7 as $t, 6 as $t];
+                    let res = <CsMat<$t> as ArgminDot<Array1<$t>, Array1<$t>>>::dot(&a, &b);
+                    let res_csc = <CsMat<$t> as ArgminDot<Array1<$t>, Array1<$t>>>::dot(&a.to_csc(), &b);
+                    assert_eq!(res.len(), 2);
+                    for i in 0..2 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                        assert_relative_eq!(target[i] as f64, res_csc[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = CsMat::new((3, 2), vec![0, 1, 2, 3], vec![0, 1, 0], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let target = array![[7 as $t, 0 as $t], [0 as $t, 6 as $t]];
+                    let res = <CsMat<$t> as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&a, &b).to_dense();
+                    let res_mixed = <CsMat<$t> as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&a, &b.to_csc()).to_dense();
+                    assert_eq!(res.shape(), &[2, 2]);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                            assert_relative_eq!(target[[i, j]] as f64, res_mixed[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_scalar_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let target = array![[2 as $t, 0 as $t, 4 as $t], [0 as $t, 6 as $t, 0 as $t]];
+                    let res = <CsMat<$t> as ArgminDot<$t, CsMat<$t>>>::dot(&a, &(2 as $t)).to_dense();
+                    let res2 = <$t as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&(2 as $t), &a).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                            assert_relative_eq!(target[[i, j]] as f64, res2[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_mat_vec_wrong_length() {
+        let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1.0f64, 2.0, 3.0]);
+        let _ = <CsMat<f64> as ArgminDot<Array1<f64>, Array1<f64>>>::dot(&a, &array![1.0, 2.0]);
+    }
+}
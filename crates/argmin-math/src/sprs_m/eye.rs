@@ -0,0 +1,111 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminEye;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_eye {
+    ($t:ty) => {
+        impl ArgminEye for CsMat<$t> {
+            #[inline]
+            fn eye_like(&self) -> CsMat<$t> {
+                assert_eq!(self.rows(), self.cols());
+                if self.is_csr() {
+                    CsMat::eye(self.rows())
+                } else {
+                    CsMat::eye_csc(self.rows())
+                }
+            }
+
+            #[inline]
+            fn eye(n: usize) -> CsMat<$t> {
+                CsMat::eye(n)
+            }
+        }
+    };
+}
+
+make_eye!(i8);
+make_eye!(u8);
+make_eye!(i16);
+make_eye!(u16);
+make_eye!(i32);
+make_eye!(u32);
+make_eye!(i64);
+make_eye!(u64);
+make_eye!(f32);
+make_eye!(f64);
+make_eye!(Complex<i8>);
+make_eye!(Complex<u8>);
+make_eye!(Complex<i16>);
+make_eye!(Complex<u16>);
+make_eye!(Complex<i32>);
+make_eye!(Complex<u32>);
+make_eye!(Complex<i64>);
+make_eye!(Complex<u64>);
+make_eye!(Complex<f32>);
+make_eye!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eye_ $t>]() {
+                    let e = <CsMat<$t> as ArgminEye>::eye(3).to_dense();
+                    assert_eq!(e.shape(), &[3, 3]);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            let target = if i == j { 1.0 } else { 0.0 };
+                            assert_relative_eq!(target, e[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_eye_like_ $t>]() {
+                    let a = CsMat::new_csc((2, 2), vec![0, 1, 2], vec![1, 0], vec![4 as $t, 5 as $t]);
+                    let e = <CsMat<$t> as ArgminEye>::eye_like(&a);
+                    assert!(!e.is_csr());
+                    let e = e.to_dense();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let target = if i == j { 1.0 } else { 0.0 };
+                            assert_relative_eq!(target, e[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_eye_like_not_square() {
+        let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1.0f64, 2.0, 3.0]);
+        let _ = <CsMat<f64> as ArgminEye>::eye_like(&a);
+    }
+}
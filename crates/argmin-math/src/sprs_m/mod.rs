@@ -0,0 +1,34 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![allow(unused_imports)]
+
+mod add;
+mod diag;
+mod div;
+mod dot;
+mod eye;
+mod mul;
+mod qr;
+mod scalerows;
+mod solve;
+mod sub;
+mod transpose;
+mod zero;
+
+pub use add::*;
+pub use diag::*;
+pub use div::*;
+pub use dot::*;
+pub use eye::*;
+pub use mul::*;
+pub use qr::*;
+pub use scalerows::*;
+pub use solve::*;
+pub use sub::*;
+pub use transpose::*;
+pub use zero::*;
@@ -0,0 +1,116 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use num_complex::Complex;
+use sprs::{binop::csmat_binop, CsMat};
+
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl ArgminMul<$t, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> CsMat<$t> {
+                self.map(|x| *x * *other)
+            }
+        }
+
+        impl ArgminMul<CsMat<$t>, CsMat<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                other.map(|x| *self * *x)
+            }
+        }
+
+        /// Element-wise multiplication
+        impl ArgminMul<CsMat<$t>, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn mul(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                if self.storage() == other.storage() {
+                    csmat_binop(self.view(), other.view(), |x, y| *x * *y)
+                } else {
+                    let other = other.to_other_storage();
+                    csmat_binop(self.view(), other.view(), |x, y| *x * *y)
+                }
+            }
+        }
+    };
+}
+
+make_mul!(i8);
+make_mul!(u8);
+make_mul!(i16);
+make_mul!(u16);
+make_mul!(i32);
+make_mul!(u32);
+make_mul!(i64);
+make_mul!(u64);
+make_mul!(f32);
+make_mul!(f64);
+make_mul!(Complex<i8>);
+make_mul!(Complex<u8>);
+make_mul!(Complex<i16>);
+make_mul!(Complex<u16>);
+make_mul!(Complex<i32>);
+make_mul!(Complex<u32>);
+make_mul!(Complex<i64>);
+make_mul!(Complex<u64>);
+make_mul!(Complex<f32>);
+make_mul!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_scalar_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let target = array![[2 as $t, 0 as $t, 4 as $t], [0 as $t, 6 as $t, 0 as $t]];
+                    let res = <CsMat<$t> as ArgminMul<$t, CsMat<$t>>>::mul(&a, &(2 as $t)).to_dense();
+                    let res2 = <$t as ArgminMul<CsMat<$t>, CsMat<$t>>>::mul(&(2 as $t), &a).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                            assert_relative_eq!(target[[i, j]] as f64, res2[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_elementwise_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = CsMat::new((2, 3), vec![0, 1, 3], vec![0, 1, 2], vec![4 as $t, 5 as $t, 6 as $t]).to_csc();
+                    let target = array![[4 as $t, 0 as $t, 0 as $t], [0 as $t, 15 as $t, 0 as $t]];
+                    let res = <CsMat<$t> as ArgminMul<CsMat<$t>, CsMat<$t>>>::mul(&a, &b).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
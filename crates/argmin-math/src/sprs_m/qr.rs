@@ -0,0 +1,203 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use ndarray::Array1;
+use sprs::CsMat;
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct RankDeficientError;
+
+impl fmt::Display for RankDeficientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rank-deficient matrix")
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct NotConvergedError;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub(super) struct DimensionMismatchError;
+
+impl fmt::Display for DimensionMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Dimension mismatch")
+    }
+}
+
+impl fmt::Display for NotConvergedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Least squares solver did not converge")
+    }
+}
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        /// The factor `Q` of a sparse matrix is in general dense. Therefore the least squares
+        /// problem is not solved via a QR decomposition but via the conjugate gradient method
+        /// applied to the normal equations (CGLS), which only requires products of the sparse
+        /// matrix and its transpose with vectors.
+        ///
+        /// The iteration stops once `|r| <= sqrt(eps) * |b|` (the system is solved) or
+        /// `|A^T r| <= sqrt(eps) * |A| * |r|` (the normal equations are satisfied relative to the
+        /// size of the residual `r`), where `|A|` is the Frobenius norm and `eps` the machine
+        /// epsilon. An error is returned if the method does not converge within `10 * (n + 10)`
+        /// iterations, where `n` is the number of columns, or if the dimensions of the matrix and
+        /// `b` do not match or the matrix has fewer rows than columns.
+        ///
+        /// Only structural rank deficiency (a column without nonzero entries) is detected. If
+        /// the matrix is rank-deficient otherwise, the minimum norm solution is returned.
+        impl ArgminQR<Array1<$t>, Array1<$t>> for CsMat<$t> {
+            fn qr_solve(&self, b: &Array1<$t>) -> Result<Array1<$t>, Error> {
+                let (m, n) = (self.rows(), self.cols());
+                if m < n || m != b.len() {
+                    return Err(DimensionMismatchError {}.into());
+                }
+                let mut nonzero_cols = vec![false; n];
+                for (v, (_, j)) in self.iter() {
+                    nonzero_cols[j] |= *v != 0.0;
+                }
+                if nonzero_cols.contains(&false) {
+                    return Err(RankDeficientError {}.into());
+                }
+                let tol = <$t>::EPSILON.sqrt();
+                let a_t = self.transpose_view();
+                let a_norm = self.data().iter().map(|v| v * v).sum::<$t>().sqrt();
+                let b_norm = b.dot(b).sqrt();
+                let mut x = Array1::<$t>::zeros(n);
+                let mut r = b.clone();
+                let mut s = &a_t * &r;
+                let mut p = s.clone();
+                let mut gamma = s.dot(&s);
+                for _ in 0..10 * (n + 10) {
+                    let r_norm = r.dot(&r).sqrt();
+                    // Stop if either the system is solved or the solution satisfies the normal
+                    // equations
+                    if r_norm <= tol * b_norm || gamma.sqrt() <= tol * a_norm * r_norm {
+                        return Ok(x);
+                    }
+                    let q = self * &p;
+                    let alpha = gamma / q.dot(&q);
+                    x.scaled_add(alpha, &p);
+                    r.scaled_add(-alpha, &q);
+                    s = &a_t * &r;
+                    let gamma_new = s.dot(&s);
+                    p = &s + &(p * (gamma_new / gamma));
+                    gamma = gamma_new;
+                }
+                Err(NotConvergedError {}.into())
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_solve_ $t>]() {
+                    // [[0, 1, 2], [1, 0, 3], [4, -3, 8]]
+                    let a = CsMat::new(
+                        (3, 3),
+                        vec![0, 2, 4, 7],
+                        vec![1, 2, 0, 2, 0, 1, 2],
+                        vec![1 as $t, 2 as $t, 1 as $t, 3 as $t, 4 as $t, -3 as $t, 8 as $t],
+                    );
+                    let x = array![1 as $t, -2 as $t, 3 as $t];
+                    let b = array![4 as $t, 10 as $t, 34 as $t];
+                    let res = <CsMat<$t> as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(&a, &b).unwrap();
+                    let res_csc = <CsMat<$t> as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(&a.to_csc(), &b).unwrap();
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = 1e-4);
+                        assert_relative_eq!(res_csc[i], x[i], epsilon = 1e-4);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_least_squares_ $t>]() {
+                    // Fit of a line through four points
+                    let a = CsMat::new(
+                        (4, 2),
+                        vec![0, 1, 3, 5, 7],
+                        vec![0, 0, 1, 0, 1, 0, 1],
+                        vec![1 as $t, 1 as $t, 1 as $t, 1 as $t, 2 as $t, 1 as $t, 3 as $t],
+                    );
+                    let b = array![1.5 as $t, 2.5 as $t, 5.5 as $t, 6.5 as $t];
+                    let res = <CsMat<$t> as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(&a, &b).unwrap();
+                    assert_eq!(res.len(), 2);
+                    assert_relative_eq!(res[0], 1.3 as $t, epsilon = 1e-4);
+                    assert_relative_eq!(res[1], 1.8 as $t, epsilon = 1e-4);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_rank_deficient_ $t>]() {
+                    let a = CsMat::new((3, 2), vec![0, 1, 2, 3], vec![0, 0, 0], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = array![1 as $t, 1 as $t, 1 as $t];
+                    let res = <CsMat<$t> as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Rank-deficient matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_dimension_mismatch_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 1, 2], vec![0, 1], vec![1 as $t, 2 as $t]);
+                    let b = array![1 as $t, 1 as $t];
+                    let res = <CsMat<$t> as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Dimension mismatch");
+                    let a = CsMat::new((2, 1), vec![0, 1, 2], vec![0, 0], vec![1 as $t, 2 as $t]);
+                    let b = array![1 as $t, 1 as $t, 1 as $t];
+                    let res = <CsMat<$t> as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Dimension mismatch");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_qr_solve_ill_conditioned() {
+        // Least squares fit of a polynomial of degree 11 to perturbed data on [0, 1]. The monomial
+        // columns are nearly linearly dependent and the residual does not vanish, therefore the
+        // normal equations cannot be satisfied to machine precision.
+        let m = 20;
+        let mut a = sprs::TriMat::new((m, 12));
+        let mut b = Array1::zeros(m);
+        for i in 0..m {
+            let t = i as f64 / (m - 1) as f64;
+            for j in 0..12 {
+                a.add_triplet(i, j, t.powi(j as i32));
+            }
+            b[i] = 1.0 - 2.0 * t + 3.0 * t.powi(2) + 0.01 * (-1.0f64).powi(i as i32);
+        }
+        let a: CsMat<f64> = a.to_csr();
+        let x = <CsMat<f64> as ArgminQR<Array1<f64>, Array1<f64>>>::qr_solve(&a, &b).unwrap();
+        // The solution satisfies the normal equations
+        let r = &b - &(&a * &x);
+        let a_norm = a.data().iter().map(|v| v * v).sum::<f64>().sqrt();
+        let s = &a.transpose_view() * &r;
+        assert!(s.dot(&s).sqrt() <= 1e-6 * a_norm * r.dot(&r).sqrt());
+    }
+}
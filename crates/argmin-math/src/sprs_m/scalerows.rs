@@ -0,0 +1,99 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaleRows;
+use ndarray::Array1;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_scale_rows {
+    ($t:ty) => {
+        impl ArgminScaleRows<Array1<$t>> for CsMat<$t> {
+            #[inline]
+            fn scale_rows(&self, factors: &Array1<$t>) -> CsMat<$t> {
+                assert_eq!(self.rows(), factors.len());
+                let is_csr = self.is_csr();
+                let mut out = self.clone();
+                for (outer, mut vec) in out.outer_iterator_mut().enumerate() {
+                    for (inner, val) in vec.iter_mut() {
+                        let row = if is_csr { outer } else { inner };
+                        *val *= factors[row];
+                    }
+                }
+                out
+            }
+        }
+    };
+}
+
+make_scale_rows!(i8);
+make_scale_rows!(u8);
+make_scale_rows!(i16);
+make_scale_rows!(u16);
+make_scale_rows!(i32);
+make_scale_rows!(u32);
+make_scale_rows!(i64);
+make_scale_rows!(u64);
+make_scale_rows!(f32);
+make_scale_rows!(f64);
+make_scale_rows!(Complex<i8>);
+make_scale_rows!(Complex<u8>);
+make_scale_rows!(Complex<i16>);
+make_scale_rows!(Complex<u16>);
+make_scale_rows!(Complex<i32>);
+make_scale_rows!(Complex<u32>);
+make_scale_rows!(Complex<i64>);
+make_scale_rows!(Complex<u64>);
+make_scale_rows!(Complex<f32>);
+make_scale_rows!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scale_rows_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let factors = array![3 as $t, 2 as $t];
+                    let target = array![[3 as $t, 0 as $t, 6 as $t], [0 as $t, 6 as $t, 0 as $t]];
+                    let res = <CsMat<$t> as ArgminScaleRows<Array1<$t>>>::scale_rows(&a, &factors).to_dense();
+                    let res_csc = <CsMat<$t> as ArgminScaleRows<Array1<$t>>>::scale_rows(&a.to_csc(), &factors).to_dense();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                            assert_relative_eq!(target[[i, j]] as f64, res_csc[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_scale_rows_wrong_length() {
+        let a = CsMat::<f64>::eye(2);
+        let _ = a.scale_rows(&array![1.0f64]);
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::qr::DimensionMismatchError;
+use crate::{ArgminQR, ArgminSolve, Error};
+use ndarray::Array1;
+use sprs::CsMat;
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        /// Solved iteratively via CGLS, see the implementation of `ArgminQR`.
+        impl ArgminSolve<Array1<$t>, Array1<$t>> for CsMat<$t> {
+            #[inline]
+            fn solve(&self, b: &Array1<$t>) -> Result<Array1<$t>, Error> {
+                if self.rows() != self.cols() {
+                    return Err(DimensionMismatchError {}.into());
+                }
+                <Self as ArgminQR<Array1<$t>, Array1<$t>>>::qr_solve(self, b)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    // [[0, 1, 2], [1, 0, 3], [4, -3, 8]]
+                    let a = CsMat::new(
+                        (3, 3),
+                        vec![0, 2, 4, 7],
+                        vec![1, 2, 0, 2, 0, 1, 2],
+                        vec![1 as $t, 2 as $t, 1 as $t, 3 as $t, 4 as $t, -3 as $t, 8 as $t],
+                    );
+                    let x = array![1 as $t, -2 as $t, 3 as $t];
+                    let b = array![4 as $t, 10 as $t, 34 as $t];
+                    let res = <CsMat<$t> as ArgminSolve<Array1<$t>, Array1<$t>>>::solve(&a, &b).unwrap();
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = 1e-4);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_not_square_ $t>]() {
+                    let a = CsMat::new((3, 2), vec![0, 1, 2, 3], vec![0, 1, 0], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let b = array![1 as $t, 1 as $t, 1 as $t];
+                    let res = <CsMat<$t> as ArgminSolve<Array1<$t>, Array1<$t>>>::solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Dimension mismatch");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,81 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminTranspose;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_transpose {
+    ($t:ty) => {
+        /// Transposition is free, but turns a CSR matrix into a CSC matrix and vice versa.
+        impl ArgminTranspose<CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn t(self) -> CsMat<$t> {
+                self.transpose_into()
+            }
+        }
+    };
+}
+
+make_transpose!(i8);
+make_transpose!(u8);
+make_transpose!(i16);
+make_transpose!(u16);
+make_transpose!(i32);
+make_transpose!(u32);
+make_transpose!(i64);
+make_transpose!(u64);
+make_transpose!(f32);
+make_transpose!(f64);
+make_transpose!(Complex<i8>);
+make_transpose!(Complex<u8>);
+make_transpose!(Complex<i16>);
+make_transpose!(Complex<u16>);
+make_transpose!(Complex<i32>);
+make_transpose!(Complex<u32>);
+make_transpose!(Complex<i64>);
+make_transpose!(Complex<u64>);
+make_transpose!(Complex<f32>);
+make_transpose!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_transpose_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let target = array![[1 as $t, 0 as $t], [0 as $t, 3 as $t], [2 as $t, 0 as $t]];
+                    let res = <CsMat<$t> as ArgminTranspose<CsMat<$t>>>::t(a).to_dense();
+                    assert_eq!(res.shape(), &[3, 2]);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[[i, j]] as f64, res[[i, j]] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,81 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminZeroLike;
+use num_complex::Complex;
+use sprs::CsMat;
+
+macro_rules! make_zero {
+    ($t:ty) => {
+        impl ArgminZeroLike for CsMat<$t> {
+            #[inline]
+            fn zero_like(&self) -> CsMat<$t> {
+                let zero = CsMat::zero(self.shape());
+                if self.is_csr() {
+                    zero
+                } else {
+                    zero.to_csc()
+                }
+            }
+        }
+    };
+}
+
+make_zero!(i8);
+make_zero!(u8);
+make_zero!(i16);
+make_zero!(u16);
+make_zero!(i32);
+make_zero!(u32);
+make_zero!(i64);
+make_zero!(u64);
+make_zero!(f32);
+make_zero!(f64);
+make_zero!(Complex<i8>);
+make_zero!(Complex<u8>);
+make_zero!(Complex<i16>);
+make_zero!(Complex<u16>);
+make_zero!(Complex<i32>);
+make_zero!(Complex<u32>);
+make_zero!(Complex<i64>);
+make_zero!(Complex<u64>);
+make_zero!(Complex<f32>);
+make_zero!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_like_ $t>]() {
+                    let a = CsMat::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1 as $t, 2 as $t, 3 as $t]);
+                    let z = <CsMat<$t> as ArgminZeroLike>::zero_like(&a);
+                    assert_eq!(z.shape(), (2, 3));
+                    assert_eq!(z.nnz(), 0);
+                    assert!(z.is_csr());
+                    let z = <CsMat<$t> as ArgminZeroLike>::zero_like(&a.to_csc());
+                    assert!(!z.is_csr());
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
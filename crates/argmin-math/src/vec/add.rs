@@ -83,6 +83,9 @@ make_add!(u64);
 make_add!(f32);
 make_add!(f64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_add);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,6 +41,9 @@ make_conj!(Complex<i64>);
 make_conj!(Complex<f32>);
 make_conj!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_conj);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,93 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiag;
+use num_complex::Complex;
+
+macro_rules! make_diag {
+    ($t:ty) => {
+        impl ArgminDiag<Vec<$t>> for Vec<Vec<$t>> {
+            #[inline]
+            fn diag(&self) -> Vec<$t> {
+                self.iter()
+                    .enumerate()
+                    .map(|(i, row)| {
+                        assert_eq!(row.len(), self.len());
+                        row[i]
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+make_diag!(i8);
+make_diag!(u8);
+make_diag!(i16);
+make_diag!(u16);
+make_diag!(i32);
+make_diag!(u32);
+make_diag!(i64);
+make_diag!(u64);
+make_diag!(f32);
+make_diag!(f64);
+make_diag!(Complex<i8>);
+make_diag!(Complex<u8>);
+make_diag!(Complex<i16>);
+make_diag!(Complex<u16>);
+make_diag!(Complex<i32>);
+make_diag!(Complex<u32>);
+make_diag!(Complex<i64>);
+make_diag!(Complex<u64>);
+make_diag!(Complex<f32>);
+make_diag!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diag_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 4 as $t, 8 as $t],
+                        vec![2 as $t, 5 as $t, 9 as $t],
+                        vec![3 as $t, 6 as $t, 7 as $t]
+                    ];
+                    let target = vec![1 as $t, 5 as $t, 7 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminDiag<Vec<$t>>>::diag(&a);
+                    assert_eq!(res.len(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    #[should_panic]
+    fn test_diag_not_square() {
+        let a = vec![vec![1.0f64, 4.0, 3.0], vec![2.0, 5.0, 6.0]];
+        let _ = a.diag();
+    }
+}
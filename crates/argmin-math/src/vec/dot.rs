@@ -52,6 +52,7 @@ macro_rules! make_dot_vec {
             #[inline]
             fn dot(&self, other: &Vec<Vec<$t>>) -> Vec<Vec<$t>> {
                 // Would be more efficient if this wasn't necessary!
+                // Rows of `other` are the columns of the original `other`.
                 let other = other.clone().t();
                 let sr = self.len();
                 assert!(sr > 0);
@@ -60,13 +61,12 @@ macro_rules! make_dot_vec {
                 let or = other.len();
                 assert!(or > 0);
                 let oc = other[0].len();
-                assert_eq!(sc, or);
-                assert!(oc > 0);
-                let v = vec![<$t>::default(); oc];
+                assert_eq!(sc, oc);
+                let v = vec![<$t>::default(); or];
                 let mut out = vec![v; sr];
                 for i in 0..sr {
                     assert_eq!(self[i].len(), sc);
-                    for j in 0..oc {
+                    for j in 0..or {
                         out[i][j] = self[i].dot(&other[j]);
                     }
                 }
@@ -115,6 +115,9 @@ make_dot_vec!(Complex<u16>);
 make_dot_vec!(Complex<u32>);
 make_dot_vec!(Complex<u64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_dot_vec);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,6 +329,33 @@ mod tests {
                 }
             }
 
+            item! {
+                #[test]
+                fn [<test_mat_mat_non_square_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = vec![
+                        vec![3 as $t, 2 as $t],
+                        vec![6 as $t, 5 as $t],
+                        vec![3 as $t, 2 as $t]
+                    ];
+                    let res = vec![
+                        vec![24 as $t, 18 as $t],
+                        vec![60 as $t, 45 as $t]
+                    ];
+                    let product = a.dot(&b);
+                    assert_eq!(product.len(), 2);
+                    for i in 0..2 {
+                        assert_eq!(product[i].len(), 2);
+                        for j in 0..2 {
+                            assert!((((res[i][j] - product[i][j]) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_mat_mat_complex $t>]() {
@@ -407,15 +437,15 @@ mod tests {
                 #[test]
                 #[should_panic]
                 fn [<test_mat_mat_panic_4_ $t>]() {
+                    // Inner dimensions do not match: (3 x 3) times (2 x 3)
                     let a = vec![
                         vec![1 as $t, 2 as $t, 3 as $t],
                         vec![4 as $t, 5 as $t, 6 as $t],
                         vec![3 as $t, 2 as $t, 1 as $t]
                     ];
                     let b = vec![
-                        vec![3 as $t, 2 as $t],
-                        vec![6 as $t, 5 as $t],
-                        vec![3 as $t, 2 as $t]
+                        vec![3 as $t, 2 as $t, 1 as $t],
+                        vec![6 as $t, 5 as $t, 4 as $t]
                     ];
                     a.dot(&b);
                 }
@@ -0,0 +1,132 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminInv, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct InverseError;
+
+impl fmt::Display for InverseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Non-invertible matrix")
+    }
+}
+
+macro_rules! make_inv {
+    ($t:ty) => {
+        // Gauss-Jordan elimination with partial pivoting
+        impl ArgminInv<Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            fn inv(&self) -> Result<Vec<Vec<$t>>, Error> {
+                let n = self.len();
+                let mut a = self.clone();
+                let mut inv: Vec<Vec<$t>> = (0..n)
+                    .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                    .collect();
+                for row in a.iter() {
+                    assert_eq!(row.len(), n);
+                }
+                for k in 0..n {
+                    let pivot = (k..n)
+                        .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                        .unwrap();
+                    if a[pivot][k] == 0.0 || !a[pivot][k].is_finite() {
+                        return Err(InverseError {}.into());
+                    }
+                    a.swap(k, pivot);
+                    inv.swap(k, pivot);
+                    let p = a[k][k];
+                    for j in 0..n {
+                        a[k][j] /= p;
+                        inv[k][j] /= p;
+                    }
+                    for i in 0..n {
+                        if i != k {
+                            let f = a[i][k];
+                            for j in 0..n {
+                                a[i][j] -= f * a[k][j];
+                                inv[i][j] -= f * inv[k][j];
+                            }
+                        }
+                    }
+                }
+                Ok(inv)
+            }
+        }
+    };
+}
+
+make_inv!(f32);
+make_inv!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = vec![
+                        vec![2 as $t, 5 as $t],
+                        vec![1 as $t, 3 as $t],
+                    ];
+                    let target = vec![
+                        vec![3 as $t, -5 as $t],
+                        vec![-1 as $t, 2 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j], target[i][j], epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_pivoting_ $t>]() {
+                    let a = vec![
+                        vec![0 as $t, 1 as $t, 2 as $t],
+                        vec![1 as $t, 0 as $t, 3 as $t],
+                        vec![4 as $t, -3 as $t, 8 as $t],
+                    ];
+                    let target = vec![
+                        vec![-4.5 as $t, 7 as $t, -1.5 as $t],
+                        vec![-2 as $t, 4 as $t, -1 as $t],
+                        vec![1.5 as $t, -2 as $t, 0.5 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j], target[i][j], epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_singular_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![2 as $t, 4 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a);
+                    assert_eq!(res.unwrap_err().to_string(), "Non-invertible matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
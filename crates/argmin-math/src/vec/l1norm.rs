@@ -7,6 +7,8 @@
 
 use crate::ArgminL1Norm;
 use num_complex::Complex;
+#[cfg(feature = "dual")]
+use num_traits::Float;
 
 macro_rules! make_l1norm {
     ($t:ty) => {
@@ -62,6 +64,9 @@ make_l1norm_complex!(Complex<u64>, u64);
 make_l1norm_complex!(Complex<f32>, f32);
 make_l1norm_complex!(Complex<f64>, f64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_l1norm);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,8 @@
 use crate::ArgminL2Norm;
 use num_complex::Complex;
 use num_integer::Roots;
+#[cfg(feature = "dual")]
+use num_traits::Float;
 
 macro_rules! make_norm_float {
     ($t:ty) => {
@@ -74,6 +76,9 @@ make_norm_complex!(Complex<u64>, u64);
 make_norm_complex!(Complex<f32>, f32);
 make_norm_complex!(Complex<f64>, f64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_norm_float);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,105 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLU, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SingularError;
+
+impl fmt::Display for SingularError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        // Gaussian elimination with partial pivoting
+        impl ArgminLU<Vec<$t>, Vec<$t>> for Vec<Vec<$t>> {
+            fn lu_solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                let n = self.len();
+                for row in self.iter() {
+                    assert_eq!(row.len(), n);
+                }
+                assert_eq!(b.len(), n);
+                let mut a = self.clone();
+                let mut x = b.clone();
+                for k in 0..n {
+                    let pivot = (k..n)
+                        .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                        .unwrap();
+                    if a[pivot][k] == 0.0 || !a[pivot][k].is_finite() {
+                        return Err(SingularError {}.into());
+                    }
+                    a.swap(k, pivot);
+                    x.swap(k, pivot);
+                    for i in (k + 1)..n {
+                        let f = a[i][k] / a[k][k];
+                        for j in k..n {
+                            a[i][j] -= f * a[k][j];
+                        }
+                        x[i] -= f * x[k];
+                    }
+                }
+                for k in (0..n).rev() {
+                    for j in (k + 1)..n {
+                        x[k] -= a[k][j] * x[j];
+                    }
+                    x[k] /= a[k][k];
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_solve_ $t>]() {
+                    // [[4, 2, 0], [2, 5, 1], [0, 1, 3]]
+                    let a = vec![
+                        vec![4 as $t, 2 as $t, 0 as $t],
+                        vec![2 as $t, 5 as $t, 1 as $t],
+                        vec![0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = vec![1 as $t, -2 as $t, 3 as $t];
+                    let b = vec![0 as $t, -5 as $t, 7 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminLU<Vec<$t>, Vec<$t>>>::lu_solve(&a, &b).unwrap();
+                    assert_eq!(res.len(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_solve_singular_ $t>]() {
+                    let a = vec![vec![1 as $t, 2 as $t], vec![2 as $t, 4 as $t]];
+                    let b = vec![1 as $t, 1 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminLU<Vec<$t>, Vec<$t>>>::lu_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
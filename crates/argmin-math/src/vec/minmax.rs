@@ -66,6 +66,9 @@ make_minmax!(u64);
 make_minmax!(f32);
 make_minmax!(f64);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_minmax);
+
 #[cfg(test)]
 mod tests {
     use super::*;
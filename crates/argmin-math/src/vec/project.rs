@@ -0,0 +1,125 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminProject;
+
+macro_rules! make_project {
+    ($t:ty) => {
+        impl ArgminProject for Vec<$t> {
+            fn project(&self, lower: &Self, upper: &Self) -> Self {
+                assert_eq!(self.len(), lower.len());
+                assert_eq!(self.len(), upper.len());
+
+                self.iter()
+                    .zip(lower.iter().zip(upper.iter()))
+                    .map(|(x, (l, u))| <$t as ArgminProject>::project(x, l, u))
+                    .collect()
+            }
+        }
+
+        impl ArgminProject for Vec<Vec<$t>> {
+            fn project(&self, lower: &Self, upper: &Self) -> Self {
+                assert_eq!(self.len(), lower.len());
+                assert_eq!(self.len(), upper.len());
+
+                self.iter()
+                    .zip(lower.iter().zip(upper.iter()))
+                    .map(|(x, (l, u))| <Vec<$t> as ArgminProject>::project(x, l, u))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_project!(i8);
+make_project!(u8);
+make_project!(i16);
+make_project!(u16);
+make_project!(i32);
+make_project!(u32);
+make_project!(i64);
+make_project!(u64);
+make_project!(f32);
+make_project!(f64);
+
+#[cfg(feature = "dual")]
+for_dual_types!(make_project);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_project_vec_ $t>]() {
+                    let a = vec![1 as $t, 4 as $t, 8 as $t];
+                    let lower = vec![2 as $t, 3 as $t, 4 as $t];
+                    let upper = vec![3 as $t, 5 as $t, 6 as $t];
+                    let target = vec![2 as $t, 4 as $t, 6 as $t];
+                    let res = <Vec<$t> as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_project_vec_vec_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 4 as $t, 8 as $t],
+                        vec![2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let lower = vec![
+                        vec![2 as $t, 3 as $t, 4 as $t],
+                        vec![1 as $t, 6 as $t, 5 as $t]
+                    ];
+                    let upper = vec![
+                        vec![3 as $t, 5 as $t, 6 as $t],
+                        vec![3 as $t, 7 as $t, 7 as $t]
+                    ];
+                    let target = vec![
+                        vec![2 as $t, 4 as $t, 6 as $t],
+                        vec![2 as $t, 6 as $t, 7 as $t]
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminProject>::project(&a, &lower, &upper);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, res[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_project_vec_panic_ $t>]() {
+                    let a = vec![1 as $t, 4 as $t];
+                    let lower = vec![2 as $t, 3 as $t, 4 as $t];
+                    let upper = vec![3 as $t, 5 as $t, 6 as $t];
+                    <Vec<$t> as ArgminProject>::project(&a, &lower, &upper);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
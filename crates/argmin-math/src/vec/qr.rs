@@ -0,0 +1,133 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct RankDeficientError;
+
+impl fmt::Display for RankDeficientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rank-deficient matrix")
+    }
+}
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        // Householder QR decomposition
+        impl ArgminQR<Vec<$t>, Vec<$t>> for Vec<Vec<$t>> {
+            fn qr_solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                let m = self.len();
+                let n = if m > 0 { self[0].len() } else { 0 };
+                for row in self.iter() {
+                    assert_eq!(row.len(), n);
+                }
+                assert!(m >= n, "QR solve requires at least as many rows as columns");
+                assert_eq!(b.len(), m);
+                let mut a = self.clone();
+                let mut x = b.clone();
+                for k in 0..n {
+                    let norm = (k..m).map(|i| a[i][k] * a[i][k]).sum::<$t>().sqrt();
+                    if norm == 0.0 || !norm.is_finite() {
+                        return Err(RankDeficientError {}.into());
+                    }
+                    let alpha = if a[k][k] > 0.0 { -norm } else { norm };
+                    // Householder vector `v = a[k.., k] - alpha * e_1`
+                    let mut v: Vec<$t> = (k..m).map(|i| a[i][k]).collect();
+                    v[0] -= alpha;
+                    let vtv: $t = v.iter().map(|vi| vi * vi).sum();
+                    if vtv > 0.0 {
+                        for j in k..n {
+                            let s = 2.0 * (k..m).map(|i| v[i - k] * a[i][j]).sum::<$t>() / vtv;
+                            for i in k..m {
+                                a[i][j] -= s * v[i - k];
+                            }
+                        }
+                        let s = 2.0 * (k..m).map(|i| v[i - k] * x[i]).sum::<$t>() / vtv;
+                        for i in k..m {
+                            x[i] -= s * v[i - k];
+                        }
+                    }
+                }
+                // Backward substitution `r * x = q^T * b`
+                x.truncate(n);
+                for k in (0..n).rev() {
+                    for j in (k + 1)..n {
+                        x[k] -= a[k][j] * x[j];
+                    }
+                    x[k] /= a[k][k];
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_solve_ $t>]() {
+                    // [[4, 2, 0], [2, 5, 1], [0, 1, 3]]
+                    let a = vec![
+                        vec![4 as $t, 2 as $t, 0 as $t],
+                        vec![2 as $t, 5 as $t, 1 as $t],
+                        vec![0 as $t, 1 as $t, 3 as $t],
+                    ];
+                    let x = vec![1 as $t, -2 as $t, 3 as $t];
+                    let b = vec![0 as $t, -5 as $t, 7 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminQR<Vec<$t>, Vec<$t>>>::qr_solve(&a, &b).unwrap();
+                    assert_eq!(res.len(), 3);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i], x[i], epsilon = <$t>::EPSILON.sqrt());
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_least_squares_ $t>]() {
+                    // Fit of a line `y = 1 + 2 * t` through four points
+                    let a = vec![
+                        vec![1 as $t, 0 as $t],
+                        vec![1 as $t, 1 as $t],
+                        vec![1 as $t, 2 as $t],
+                        vec![1 as $t, 3 as $t],
+                    ];
+                    let b = vec![1.5 as $t, 2.5 as $t, 5.5 as $t, 6.5 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminQR<Vec<$t>, Vec<$t>>>::qr_solve(&a, &b).unwrap();
+                    assert_eq!(res.len(), 2);
+                    assert_relative_eq!(res[0], 1.3 as $t, epsilon = <$t>::EPSILON.sqrt());
+                    assert_relative_eq!(res[1], 1.8 as $t, epsilon = <$t>::EPSILON.sqrt());
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_rank_deficient_ $t>]() {
+                    let a = vec![vec![1 as $t, 0 as $t], vec![2 as $t, 0 as $t], vec![3 as $t, 0 as $t]];
+                    let b = vec![1 as $t, 1 as $t, 1 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminQR<Vec<$t>, Vec<$t>>>::qr_solve(&a, &b);
+                    assert_eq!(res.unwrap_err().to_string(), "Rank-deficient matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
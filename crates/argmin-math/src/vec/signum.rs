@@ -7,6 +7,8 @@
 
 use crate::ArgminSignum;
 use num_complex::Complex;
+#[cfg(feature = "dual")]
+use num_traits::Float;
 
 macro_rules! make_signum {
     ($t:ty) => {
@@ -48,6 +50,9 @@ make_signum_complex!(Complex<i64>);
 make_signum_complex!(Complex<f32>);
 make_signum_complex!(Complex<f64>);
 
+#[cfg(feature = "dual")]
+for_dual_types!(make_signum);
+
 #[cfg(test)]
 mod tests {
     use super::*;
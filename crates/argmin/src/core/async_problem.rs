@@ -0,0 +1,849 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{CostFunction, Error, Gradient, SendAlias, SyncAlias};
+use futures::future::{FutureExt, LocalBoxFuture};
+use std::any::Any;
+use std::borrow::Borrow;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+/// Defines the asynchronous computation of a cost function value
+///
+/// Async counterpart of [`CostFunction`]. In order to be used with a solver, the problem needs to
+/// be wrapped in an [`AsyncProblem`], which implements [`CostFunction`], and solved with
+/// [`Executor::run_async`](`crate::core::Executor::run_async`).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{AsyncCostFunction, Error};
+/// use argmin_testfunctions::rosenbrock;
+///
+/// struct Simulation {}
+///
+/// impl AsyncCostFunction for Simulation {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     /// Compute cost function, for instance by querying a simulation service
+///     async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(rosenbrock(param))
+///     }
+/// }
+/// ```
+pub trait AsyncCostFunction {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the return value of the cost function
+    type Output;
+
+    /// Compute cost function
+    fn cost(&self, param: &Self::Param) -> impl Future<Output = Result<Self::Output, Error>>;
+
+    /// Compute cost function for multiple parameter vectors concurrently.
+    ///
+    /// By default, all evaluations are started at once and awaited jointly. This method can be
+    /// overwritten, for instance in order to send all parameter vectors in a single request or to
+    /// limit the number of concurrent evaluations.
+    fn bulk_cost<'a, P>(
+        &'a self,
+        params: &'a [P],
+    ) -> impl Future<Output = Result<Vec<Self::Output>, Error>>
+    where
+        P: Borrow<Self::Param>,
+        Self::Param: 'a,
+    {
+        futures::future::try_join_all(params.iter().map(|p| self.cost(p.borrow())))
+    }
+}
+
+/// Defines the asynchronous computation of the gradient
+///
+/// Async counterpart of [`Gradient`]. In order to be used with a solver, the problem needs to be
+/// wrapped in an [`AsyncProblem`], which implements [`Gradient`], and solved with
+/// [`Executor::run_async`](`crate::core::Executor::run_async`).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{AsyncGradient, Error};
+/// use argmin_testfunctions::rosenbrock_derivative;
+///
+/// struct Simulation {}
+///
+/// impl AsyncGradient for Simulation {
+///     type Param = Vec<f64>;
+///     type Gradient = Vec<f64>;
+///
+///     /// Compute gradient, for instance by querying a simulation service
+///     async fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+///         Ok(rosenbrock_derivative(param))
+///     }
+/// }
+/// ```
+pub trait AsyncGradient {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the gradient
+    type Gradient;
+
+    /// Compute gradient
+    fn gradient(&self, param: &Self::Param) -> impl Future<Output = Result<Self::Gradient, Error>>;
+
+    /// Compute gradient for multiple parameter vectors concurrently.
+    ///
+    /// By default, all evaluations are started at once and awaited jointly. This method can be
+    /// overwritten.
+    fn bulk_gradient<'a, P>(
+        &'a self,
+        params: &'a [P],
+    ) -> impl Future<Output = Result<Vec<Self::Gradient>, Error>>
+    where
+        P: Borrow<Self::Param>,
+        Self::Param: 'a,
+    {
+        futures::future::try_join_all(params.iter().map(|p| self.gradient(p.borrow())))
+    }
+}
+
+/// Wrapper which makes problems implementing [`AsyncCostFunction`] and/or [`AsyncGradient`]
+/// usable by solvers.
+///
+/// Implements [`CostFunction`] and [`Gradient`] such that the problem can be solved with
+/// [`Executor::run_async`](`crate::core::Executor::run_async`). Since solvers are synchronous,
+/// the futures returned by the async methods cannot be awaited while the solver computes an
+/// iteration. Instead, the solver is interrupted as soon as it requests an evaluation whose
+/// result is not known yet. `run_async` then awaits the evaluation and repeats the iteration
+/// from the beginning, this time answering the requests which have been awaited so far with the
+/// stored results. Therefore the solver (including its random number generator, if any) is
+/// cloned before each attempt. Stored results are matched to requests by the kind of the
+/// evaluation and the parameter vector (which therefore needs to implement `PartialEq`), such
+/// that the order of the requests does not matter. Each stored result is handed to the solver
+/// at most once per attempt, hence repeated evaluations at the same parameter vector are
+/// awaited (and counted) as often as the solver requests them.
+///
+/// Repeating an iteration means that an iteration with `k` evaluations is computed `k + 1`
+/// times. This is negligible if the evaluations are expensive, which is the intended use case.
+///
+/// The interruption is signalled to the solver by an error, which the solver must pass on
+/// unchanged. This is the case for solvers which run other solvers internally via an
+/// [`Executor`](`crate::core::Executor`) (for instance the line searches of gradient based
+/// solvers). Solvers which catch, retry or wrap errors of the problem cannot be used with
+/// `run_async`; `run_async` returns an error if a solver does not pass on the interruption. The
+/// same applies to solvers which evaluate clones of the problem, such as
+/// [`MultiStart`](`crate::solver::multistart::MultiStart`), because clones of an `AsyncProblem`
+/// are independent of the executor.
+///
+/// Bulk evaluations (as used by population based solvers such as
+/// [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`),
+/// [`DifferentialEvolution`](`crate::solver::differentialevolution::DifferentialEvolution`) or
+/// [`CMAES`](`crate::solver::cmaes::CMAES`)) are forwarded to
+/// [`AsyncCostFunction::bulk_cost`] and [`AsyncGradient::bulk_gradient`] and therefore run
+/// concurrently.
+///
+/// Evaluating the problem outside of `run_async` results in an error.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{AsyncCostFunction, AsyncProblem, Error, Executor};
+/// # use argmin::solver::neldermead::NelderMead;
+/// # use argmin_testfunctions::rosenbrock;
+/// #
+/// struct Simulation {}
+///
+/// impl AsyncCostFunction for Simulation {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(rosenbrock(param))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let solver = NelderMead::new(vec![vec![-1.0, 3.0], vec![2.0, 1.5], vec![2.0, -1.0]]);
+///
+/// let res = Executor::new(AsyncProblem::new(Simulation {}), solver)
+///     .configure(|state| state.max_iters(100))
+///     .run_async()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncProblem<O> {
+    /// Problem defined by user
+    problem: Arc<O>,
+    /// Evaluations of the iteration which is currently computed by `Executor::run_async`
+    log: Arc<Mutex<EvaluationLog<O>>>,
+}
+
+/// Future of an evaluation requested by the solver which has not been awaited yet
+type PendingFuture<O> =
+    Box<dyn for<'a> FnOnce(&'a O) -> LocalBoxFuture<'a, Result<Box<dyn Any + Send>, Error>> + Send>;
+
+/// Kind of an evaluation requested by the solver
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Request {
+    Cost,
+    BulkCost,
+    Gradient,
+    BulkGradient,
+}
+
+/// Evaluation requested by the solver which has not been awaited yet
+struct PendingEvaluation<O> {
+    /// Kind of the evaluation
+    request: Request,
+    /// Parameter vector(s)
+    params: Box<dyn Any + Send>,
+    /// Future computing the result
+    future: PendingFuture<O>,
+}
+
+/// Evaluation which has been awaited during the current iteration
+struct Evaluation {
+    /// Kind of the evaluation
+    request: Request,
+    /// Parameter vector(s)
+    params: Box<dyn Any + Send>,
+    /// Result of the evaluation
+    result: Box<dyn Any + Send>,
+    /// Set once the result has been handed to the solver during the current attempt
+    used: bool,
+}
+
+/// Results of the evaluations requested by the solver during the current iteration
+struct EvaluationLog<O> {
+    /// Set while the problem is solved by `Executor::run_async`
+    active: bool,
+    /// Awaited evaluations of the current iteration
+    evaluations: Vec<Evaluation>,
+    /// Evaluation which needs to be awaited before the iteration can be repeated
+    pending: Option<PendingEvaluation<O>>,
+}
+
+/// Returned to the solver if it requests an evaluation which has not been awaited yet.
+///
+/// Detected by `Executor::run_async`, which awaits the evaluation and repeats the iteration.
+#[derive(Debug)]
+pub(crate) struct EvaluationPending;
+
+impl std::fmt::Display for EvaluationPending {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Evaluation of `AsyncProblem` pending")
+    }
+}
+
+impl std::error::Error for EvaluationPending {}
+
+impl<O> AsyncProblem<O> {
+    /// Wraps a problem implementing [`AsyncCostFunction`] and/or [`AsyncGradient`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::AsyncProblem;
+    /// # struct Simulation {}
+    /// let problem = AsyncProblem::new(Simulation {});
+    /// ```
+    pub fn new(problem: O) -> Self {
+        AsyncProblem {
+            problem: Arc::new(problem),
+            log: Arc::new(Mutex::new(EvaluationLog {
+                active: false,
+                evaluations: vec![],
+                pending: None,
+            })),
+        }
+    }
+
+    /// Returns a reference to the wrapped problem.
+    pub fn problem(&self) -> &O {
+        &self.problem
+    }
+
+    /// Returns a handle which shares the wrapped problem and the stored results with `self`.
+    ///
+    /// Used by `Executor::run_async` to restore the problem if a solver did not put it back
+    /// after being interrupted.
+    pub(crate) fn share(&self) -> Self {
+        AsyncProblem {
+            problem: Arc::clone(&self.problem),
+            log: Arc::clone(&self.log),
+        }
+    }
+
+    /// Enables (or disables) the evaluation of the problem and discards all stored results.
+    pub(crate) fn activate(&self, active: bool) {
+        let mut log = self.log.lock().unwrap();
+        log.active = active;
+        log.evaluations.clear();
+        log.pending = None;
+    }
+
+    /// Discards the stored results once an iteration is completed.
+    pub(crate) fn clear(&self) {
+        self.activate(true);
+    }
+
+    /// Returns `true` if the solver requested an evaluation which has not been awaited yet.
+    pub(crate) fn is_pending(&self) -> bool {
+        self.log.lock().unwrap().pending.is_some()
+    }
+
+    /// Awaits the pending evaluation (if any) and prepares the repetition of the iteration.
+    pub(crate) async fn await_pending(&self) -> Result<(), Error> {
+        let pending = {
+            let mut log = self.log.lock().unwrap();
+            log.evaluations
+                .iter_mut()
+                .for_each(|evaluation| evaluation.used = false);
+            log.pending.take()
+        };
+        if let Some(PendingEvaluation {
+            request,
+            params,
+            future,
+        }) = pending
+        {
+            let result = future(&self.problem).await?;
+            self.log.lock().unwrap().evaluations.push(Evaluation {
+                request,
+                params,
+                result,
+                used: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a stored result of the evaluation of kind `request` at `params` which has not been
+    /// handed to the solver during the current attempt yet. If there is none, the evaluation is
+    /// stored as pending evaluation (unless another one is pending already) and
+    /// [`EvaluationPending`] is returned.
+    fn evaluate<K, T, R>(&self, request: Request, params: &K, future: R) -> Result<T, Error>
+    where
+        K: Clone + PartialEq + Send + 'static,
+        T: Clone + 'static,
+        R: FnOnce(K) -> PendingFuture<O>,
+    {
+        let mut log = self.log.lock().unwrap();
+        if !log.active {
+            return Err(argmin_error!(
+                NotInitialized,
+                "`AsyncProblem` can only be evaluated by `Executor::run_async`."
+            ));
+        }
+        if let Some(evaluation) = log.evaluations.iter_mut().find(|evaluation| {
+            !evaluation.used
+                && evaluation.request == request
+                && evaluation.params.downcast_ref::<K>() == Some(params)
+        }) {
+            evaluation.used = true;
+            return evaluation.result.downcast_ref::<T>().cloned().ok_or_else(
+                argmin_error_closure!(
+                    PotentialBug,
+                    "`AsyncProblem`: stored result has an unexpected type."
+                ),
+            );
+        }
+        if log.pending.is_none() {
+            log.pending = Some(PendingEvaluation {
+                request,
+                params: Box::new(params.clone()),
+                future: future(params.clone()),
+            });
+        }
+        Err(EvaluationPending.into())
+    }
+}
+
+impl<O: Clone> Clone for AsyncProblem<O> {
+    fn clone(&self) -> Self {
+        AsyncProblem::new(O::clone(&self.problem))
+    }
+}
+
+impl<O: Default> Default for AsyncProblem<O> {
+    fn default() -> Self {
+        AsyncProblem::new(O::default())
+    }
+}
+
+impl<O: std::fmt::Debug> std::fmt::Debug for AsyncProblem<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncProblem")
+            .field("problem", &self.problem)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<O> CostFunction for AsyncProblem<O>
+where
+    O: AsyncCostFunction,
+    O::Param: Clone + PartialEq + Send + 'static,
+    O::Output: Clone + Send + 'static,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.evaluate(Request::Cost, param, |param| {
+            Box::new(move |problem: &O| {
+                async move {
+                    let output = AsyncCostFunction::cost(problem, &param).await?;
+                    Ok(Box::new(output) as Box<dyn Any + Send>)
+                }
+                .boxed_local()
+            })
+        })
+    }
+
+    fn bulk_cost<P>(&self, params: &[P]) -> Result<Vec<Self::Output>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Output: SendAlias,
+        Self: SyncAlias,
+    {
+        let params: Vec<O::Param> = params.iter().map(|p| p.borrow().clone()).collect();
+        self.evaluate(Request::BulkCost, &params, |params| {
+            Box::new(move |problem: &O| {
+                async move {
+                    let outputs = AsyncCostFunction::bulk_cost(problem, &params).await?;
+                    Ok(Box::new(outputs) as Box<dyn Any + Send>)
+                }
+                .boxed_local()
+            })
+        })
+    }
+}
+
+impl<O> Gradient for AsyncProblem<O>
+where
+    O: AsyncGradient,
+    O::Param: Clone + PartialEq + Send + 'static,
+    O::Gradient: Clone + Send + 'static,
+{
+    type Param = O::Param;
+    type Gradient = O::Gradient;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.evaluate(Request::Gradient, param, |param| {
+            Box::new(move |problem: &O| {
+                async move {
+                    let gradient = AsyncGradient::gradient(problem, &param).await?;
+                    Ok(Box::new(gradient) as Box<dyn Any + Send>)
+                }
+                .boxed_local()
+            })
+        })
+    }
+
+    fn bulk_gradient<P>(&self, params: &[P]) -> Result<Vec<Self::Gradient>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Gradient: SendAlias,
+        Self: SyncAlias,
+    {
+        let params: Vec<O::Param> = params.iter().map(|p| p.borrow().clone()).collect();
+        self.evaluate(Request::BulkGradient, &params, |params| {
+            Box::new(move |problem: &O| {
+                async move {
+                    let gradients = AsyncGradient::bulk_gradient(problem, &params).await?;
+                    Ok(Box::new(gradients) as Box<dyn Any + Send>)
+                }
+                .boxed_local()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        ArgminError, Executor, FuncEvalBudget, IterState, Problem, Solver, State,
+        TerminationReason, KV,
+    };
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::particleswarm::ParticleSwarm;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[derive(Clone, Default)]
+    struct Simulation {
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+        cost_evals: Arc<AtomicUsize>,
+        gradient_evals: Arc<AtomicUsize>,
+    }
+
+    impl AsyncCostFunction for Simulation {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+            self.cost_evals.fetch_add(1, Ordering::SeqCst);
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(param.iter().map(|x| x.powi(2)).sum())
+        }
+    }
+
+    impl AsyncGradient for Simulation {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        async fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+            self.gradient_evals.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok(param.iter().map(|x| 2.0 * x).collect())
+        }
+    }
+
+    #[test]
+    fn test_not_activated() {
+        let problem = AsyncProblem::new(Simulation::default());
+        let res = problem.cost(&vec![1.0, 2.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Not initialized: \"`AsyncProblem` can only be evaluated by `Executor::run_async`.\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replay() {
+        let problem = AsyncProblem::new(Simulation::default());
+        problem.activate(true);
+
+        // The first request is pending until it is awaited
+        let res = problem.cost(&vec![1.0, 2.0]);
+        assert!(res.unwrap_err().is::<EvaluationPending>());
+        problem.await_pending().await.unwrap();
+
+        // Afterwards it is answered from the stored results and the next request is pending
+        assert_eq!(
+            problem.cost(&vec![1.0, 2.0]).unwrap().to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        let res = problem.gradient(&vec![1.0, 2.0]);
+        assert!(res.unwrap_err().is::<EvaluationPending>());
+        problem.await_pending().await.unwrap();
+
+        assert_eq!(
+            problem.cost(&vec![1.0, 2.0]).unwrap().to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        assert_eq!(problem.gradient(&vec![1.0, 2.0]).unwrap(), vec![2.0, 4.0]);
+        assert_eq!(problem.problem().cost_evals.load(Ordering::SeqCst), 1);
+        assert_eq!(problem.problem().gradient_evals.load(Ordering::SeqCst), 1);
+
+        // Stored results are matched by parameter vector, not by the order of the requests
+        problem.await_pending().await.unwrap();
+        assert_eq!(problem.gradient(&vec![1.0, 2.0]).unwrap(), vec![2.0, 4.0]);
+        let res = problem.cost(&vec![3.0, 4.0]);
+        assert!(res.unwrap_err().is::<EvaluationPending>());
+        assert_eq!(
+            problem.cost(&vec![1.0, 2.0]).unwrap().to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+
+        problem.await_pending().await.unwrap();
+
+        // Each stored result is handed to the solver only once per attempt
+        assert_eq!(
+            problem.cost(&vec![1.0, 2.0]).unwrap().to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        let res = problem.cost(&vec![1.0, 2.0]);
+        assert!(res.unwrap_err().is::<EvaluationPending>());
+        problem.await_pending().await.unwrap();
+        assert_eq!(
+            problem.cost(&vec![1.0, 2.0]).unwrap().to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        assert_eq!(
+            problem.cost(&vec![1.0, 2.0]).unwrap().to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        assert_eq!(
+            problem.cost(&vec![3.0, 4.0]).unwrap().to_ne_bytes(),
+            25.0f64.to_ne_bytes()
+        );
+        assert_eq!(problem.problem().cost_evals.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_cost_concurrent() {
+        let simulation = Simulation::default();
+        let max_running = simulation.max_running.clone();
+        let problem = AsyncProblem::new(simulation);
+        problem.activate(true);
+        let params = vec![vec![1.0], vec![2.0], vec![3.0], vec![4.0]];
+
+        assert!(problem
+            .bulk_cost(&params)
+            .unwrap_err()
+            .is::<EvaluationPending>());
+        problem.await_pending().await.unwrap();
+        assert_eq!(
+            problem.bulk_cost(&params).unwrap(),
+            vec![1.0, 4.0, 9.0, 16.0]
+        );
+        assert!(problem
+            .bulk_gradient(&params)
+            .unwrap_err()
+            .is::<EvaluationPending>());
+        problem.await_pending().await.unwrap();
+
+        assert_eq!(
+            problem.bulk_cost(&params).unwrap(),
+            vec![1.0, 4.0, 9.0, 16.0]
+        );
+        assert_eq!(
+            problem.bulk_gradient(&params).unwrap(),
+            vec![vec![2.0], vec![4.0], vec![6.0], vec![8.0]]
+        );
+        assert_eq!(max_running.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_run_async_population() {
+        let simulation = Simulation::default();
+        let max_running = simulation.max_running.clone();
+        let cost_evals = simulation.cost_evals.clone();
+        let solver = ParticleSwarm::new((vec![-2.0, -2.0], vec![2.0, 2.0]), 8);
+        let res = Executor::new(AsyncProblem::new(simulation), solver)
+            .configure(|state| state.max_iters(5).counting(true))
+            .run_async()
+            .await
+            .unwrap();
+        assert_eq!(res.state().get_iter(), 5);
+        assert_eq!(res.state().get_func_counts()["cost_count"], 6 * 8);
+        assert_eq!(cost_evals.load(Ordering::SeqCst), 6 * 8);
+        // All particles are evaluated concurrently
+        assert_eq!(max_running.load(Ordering::SeqCst), 8);
+    }
+
+    #[tokio::test]
+    async fn test_run_async_gradient() {
+        let simulation = Simulation::default();
+        let cost_evals = simulation.cost_evals.clone();
+        let gradient_evals = simulation.gradient_evals.clone();
+        let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let res = Executor::new(AsyncProblem::new(simulation), solver)
+            .configure(|state| state.param(vec![1.0, -2.0]).max_iters(1).counting(true))
+            .run_async()
+            .await
+            .unwrap();
+        // The minimum of the quadratic is found in a single iteration
+        assert!(res.state().get_best_cost() < 1e-10);
+        // Repeated iterations do not count the awaited evaluations again
+        let counts = res.state().get_func_counts();
+        assert!(counts["cost_count"] > 1);
+        assert_eq!(
+            counts["cost_count"],
+            cost_evals.load(Ordering::SeqCst) as u64
+        );
+        assert_eq!(
+            counts["gradient_count"],
+            gradient_evals.load(Ordering::SeqCst) as u64
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_async_budget() {
+        let simulation = Simulation::default();
+        let cost_evals = simulation.cost_evals.clone();
+        let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let res = Executor::new(AsyncProblem::new(simulation), solver)
+            .configure(|state| state.param(vec![1.0, -2.0]).max_iters(10).counting(true))
+            .func_eval_budget(FuncEvalBudget::new().with_limit("cost_count", 2))
+            .run_async()
+            .await
+            .unwrap();
+        assert_eq!(
+            res.state().get_termination_reason(),
+            Some(&TerminationReason::MaxFuncEvalsReached)
+        );
+        assert_eq!(cost_evals.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            res.problem()
+                .func_eval_budget()
+                .unwrap()
+                .spent("cost_count"),
+            2
+        );
+    }
+
+    /// Holds state which cannot be shared between threads
+    struct LocalSimulation {
+        evals: Rc<Cell<usize>>,
+    }
+
+    impl AsyncCostFunction for LocalSimulation {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+            let evals = self.evals.clone();
+            tokio::task::yield_now().await;
+            evals.set(evals.get() + 1);
+            Ok(param.iter().map(|x| x.powi(2)).sum())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_async_not_send() {
+        use crate::solver::neldermead::NelderMead;
+
+        let evals = Rc::new(Cell::new(0));
+        let problem = LocalSimulation {
+            evals: evals.clone(),
+        };
+        let solver = NelderMead::new(vec![vec![-1.0, 3.0], vec![2.0, 1.5], vec![2.0, -1.0]]);
+        let res = Executor::new(AsyncProblem::new(problem), solver)
+            .configure(|state| state.max_iters(20).counting(true))
+            .run_async()
+            .await
+            .unwrap();
+        assert_eq!(res.state().get_iter(), 20);
+        assert_eq!(
+            res.state().get_func_counts()["cost_count"],
+            evals.get() as u64
+        );
+    }
+
+    #[derive(Clone, Default)]
+    struct Rosenbrock {
+        evals: Arc<AtomicUsize>,
+    }
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(argmin_testfunctions::rosenbrock(param))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(argmin_testfunctions::rosenbrock_derivative(param))
+        }
+    }
+
+    impl AsyncCostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+            self.evals.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            CostFunction::cost(self, param)
+        }
+    }
+
+    impl AsyncGradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        async fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+            self.evals.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Gradient::gradient(self, param)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_async_nested_executor() {
+        use crate::solver::quasinewton::LBFGS;
+
+        // The line search of L-BFGS is run by an `Executor` inside of `next_iter`
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let problem = Rosenbrock::default();
+        let evals = problem.evals.clone();
+        let res = Executor::new(AsyncProblem::new(problem), solver.clone())
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(20).counting(true))
+            .run_async()
+            .await
+            .unwrap();
+        let expected = Executor::new(Rosenbrock::default(), solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(20).counting(true))
+            .run()
+            .unwrap();
+
+        assert_eq!(res.state().get_iter(), expected.state().get_iter());
+        assert_eq!(
+            res.state().get_best_param(),
+            expected.state().get_best_param()
+        );
+        assert_eq!(
+            res.state().get_func_counts(),
+            expected.state().get_func_counts()
+        );
+        // Every evaluation is awaited exactly once
+        let counts = res.state().get_func_counts();
+        assert_eq!(
+            counts["cost_count"] + counts["gradient_count"],
+            evals.load(Ordering::SeqCst) as u64
+        );
+    }
+
+    /// Solver which replaces failed evaluations by infinity
+    #[derive(Clone)]
+    struct Swallowing {}
+
+    impl<O> Solver<O, IterState<Vec<f64>, (), (), (), (), f64>> for Swallowing
+    where
+        O: CostFunction<Param = Vec<f64>, Output = f64>,
+    {
+        fn name(&self) -> &str {
+            "Swallowing"
+        }
+
+        fn next_iter(
+            &mut self,
+            problem: &mut Problem<O>,
+            state: IterState<Vec<f64>, (), (), (), (), f64>,
+        ) -> Result<(IterState<Vec<f64>, (), (), (), (), f64>, Option<KV>), Error> {
+            let cost = problem
+                .cost(state.get_param().unwrap())
+                .unwrap_or(f64::INFINITY);
+            Ok((state.cost(cost), None))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_async_swallowed_interruption() {
+        let res = Executor::new(AsyncProblem::new(Simulation::default()), Swallowing {})
+            .configure(|state| state.param(vec![1.0, 2.0]).max_iters(2))
+            .run_async()
+            .await;
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`Executor::run_async`: solver `Swallowing` did not pass ",
+                "on the interruption by a pending evaluation of `AsyncProblem`.\""
+            )
+        );
+    }
+}
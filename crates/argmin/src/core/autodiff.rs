@@ -0,0 +1,358 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{count_evaluations, ArgminFloat, CostFunction, Error, Gradient, Hessian};
+use argmin_math::dual::{Dual, HyperDual};
+use std::iter::{Product, Sum};
+use std::marker::PhantomData;
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+/// Float types a [`GenericCostFunction`] can be evaluated with
+///
+/// In addition to [`ArgminFloat`] this requires the traits needed by the generic functions of
+/// `argmin-testfunctions`. Implemented for `f32`, `f64` and the dual numbers
+/// [`Dual`](argmin_math::dual::Dual) and [`HyperDual`](argmin_math::dual::HyperDual) of those.
+pub trait AutoDiffFloat:
+    ArgminFloat + Sum + for<'a> Sum<&'a Self> + Product + AddAssign + SubAssign + MulAssign + DivAssign
+{
+}
+
+/// `AutoDiffFloat` is automatically implemented for all types which fulfill the trait bounds.
+impl<I> AutoDiffFloat for I where
+    I: ArgminFloat
+        + Sum
+        + for<'a> Sum<&'a Self>
+        + Product
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + DivAssign
+{
+}
+
+/// Cost function which is written once for any float type
+///
+/// Wrapped in a [`ForwardDiff`], the cost function is evaluated with dual numbers in order to
+/// compute exact gradients and Hessians via forward-mode automatic differentiation.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{AutoDiffFloat, Error, GenericCostFunction};
+///
+/// struct Rosenbrock {}
+///
+/// impl GenericCostFunction for Rosenbrock {
+///     fn cost<T: AutoDiffFloat>(&self, p: &[T]) -> Result<T, Error> {
+///         Ok(argmin_testfunctions::rosenbrock(p))
+///     }
+/// }
+/// ```
+pub trait GenericCostFunction {
+    /// Compute cost function
+    fn cost<T: AutoDiffFloat>(&self, param: &[T]) -> Result<T, Error>;
+}
+
+/// Derives [`Gradient`] and [`Hessian`] of a [`GenericCostFunction`] via forward-mode automatic
+/// differentiation
+///
+/// The wrapped problem implements [`CostFunction`], [`Gradient`] and [`Hessian`] for parameter
+/// vectors of type `Vec<F>`. Unlike finite differences, the derivatives are exact up to floating
+/// point precision. A gradient requires `n` evaluations of the cost function with [`Dual`]
+/// numbers, a Hessian `n*(n+1)/2` evaluations with [`HyperDual`] numbers (for parameter vectors of
+/// dimension `n`). These evaluations are reported as cost function evaluations to
+/// [`Problem`](`crate::core::Problem`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{AutoDiffFloat, Error, Executor, ForwardDiff, GenericCostFunction};
+/// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+/// # use argmin::solver::newton::NewtonCG;
+/// #
+/// struct Rosenbrock {}
+///
+/// impl GenericCostFunction for Rosenbrock {
+///     fn cost<T: AutoDiffFloat>(&self, p: &[T]) -> Result<T, Error> {
+///         Ok(argmin_testfunctions::rosenbrock(p))
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let problem: ForwardDiff<_, f64> = ForwardDiff::new(Rosenbrock {});
+///
+/// let solver = NewtonCG::new(MoreThuenteLineSearch::new());
+/// let res = Executor::new(problem, solver)
+///     .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(50))
+///     .run()?;
+///
+/// assert!(res.state().best_cost < 1.0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ForwardDiff<O, F = f64> {
+    /// Wrapped problem
+    problem: O,
+    _float: PhantomData<fn() -> F>,
+}
+
+impl<O, F> ForwardDiff<O, F> {
+    /// Wraps `problem`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::ForwardDiff;
+    /// # struct UserDefinedProblem {}
+    /// let problem: ForwardDiff<_, f64> = ForwardDiff::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(problem: O) -> Self {
+        ForwardDiff {
+            problem,
+            _float: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped problem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::ForwardDiff;
+    /// # struct UserDefinedProblem {}
+    /// let problem: ForwardDiff<_, f64> = ForwardDiff::new(UserDefinedProblem {});
+    /// let inner: &UserDefinedProblem = problem.inner();
+    /// ```
+    pub fn inner(&self) -> &O {
+        &self.problem
+    }
+}
+
+impl<O, F> CostFunction for ForwardDiff<O, F>
+where
+    O: GenericCostFunction,
+    F: AutoDiffFloat,
+    Dual<F>: AutoDiffFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(param)
+    }
+
+    fn cost_and_gradient(
+        &self,
+        param: &<Self as CostFunction>::Param,
+    ) -> Result<(<Self as CostFunction>::Output, <Self as Gradient>::Gradient), Error> {
+        // The real parts of the dual passes already contain the cost.
+        let mut dual: Vec<Dual<F>> = param.iter().map(|&x| Dual::constant(x)).collect();
+        let mut cost = None;
+        let mut gradient = Vec::with_capacity(param.len());
+        for i in 0..param.len() {
+            dual[i].eps = F::one();
+            let res = self.problem.cost(&dual)?;
+            dual[i].eps = F::zero();
+            cost = Some(res.re);
+            gradient.push(res.eps);
+        }
+        count_evaluations("cost_count", param.len() as u64);
+        let cost = match cost {
+            Some(cost) => cost,
+            None => self.problem.cost(param)?,
+        };
+        Ok((cost, gradient))
+    }
+}
+
+impl<O, F> Gradient for ForwardDiff<O, F>
+where
+    O: GenericCostFunction,
+    F: AutoDiffFloat,
+    Dual<F>: AutoDiffFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let mut dual: Vec<Dual<F>> = param.iter().map(|&x| Dual::constant(x)).collect();
+        let mut gradient = Vec::with_capacity(param.len());
+        for i in 0..param.len() {
+            dual[i].eps = F::one();
+            gradient.push(self.problem.cost(&dual)?.eps);
+            dual[i].eps = F::zero();
+        }
+        count_evaluations("cost_count", param.len() as u64);
+        Ok(gradient)
+    }
+}
+
+impl<O, F> Hessian for ForwardDiff<O, F>
+where
+    O: GenericCostFunction,
+    F: AutoDiffFloat,
+    HyperDual<F>: AutoDiffFloat,
+{
+    type Param = Vec<F>;
+    type Hessian = Vec<Vec<F>>;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let n = param.len();
+        let mut dual: Vec<HyperDual<F>> = param.iter().map(|&x| HyperDual::constant(x)).collect();
+        let mut hessian = vec![vec![F::zero(); n]; n];
+        for i in 0..n {
+            dual[i].e1 = F::one();
+            for j in i..n {
+                dual[j].e2 = F::one();
+                let h = self.problem.cost(&dual)?.e12;
+                dual[j].e2 = F::zero();
+                hessian[i][j] = h;
+                hessian[j][i] = h;
+            }
+            dual[i].e1 = F::zero();
+        }
+        count_evaluations("cost_count", (n * (n + 1) / 2) as u64);
+        Ok(hessian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Executor, Problem};
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::newton::NewtonCG;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{
+        rosenbrock, rosenbrock_derivative, rosenbrock_hessian, styblinski_tang,
+        styblinski_tang_derivative, styblinski_tang_hessian,
+    };
+
+    send_sync_test!(forward_diff, ForwardDiff<()>);
+
+    struct Rosenbrock {}
+
+    impl GenericCostFunction for Rosenbrock {
+        fn cost<T: AutoDiffFloat>(&self, p: &[T]) -> Result<T, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    struct StyblinskiTang {}
+
+    impl GenericCostFunction for StyblinskiTang {
+        fn cost<T: AutoDiffFloat>(&self, p: &[T]) -> Result<T, Error> {
+            Ok(styblinski_tang(p))
+        }
+    }
+
+    #[test]
+    fn test_rosenbrock() {
+        let param = vec![-1.2, 1.0, 0.5];
+        let mut problem = Problem::new(ForwardDiff::new(Rosenbrock {}));
+
+        let cost = problem.cost(&param).unwrap();
+        assert_relative_eq!(cost, rosenbrock(&param), epsilon = f64::EPSILON);
+
+        let gradient = problem.gradient(&param).unwrap();
+        for (g, e) in gradient.iter().zip(rosenbrock_derivative(&param).iter()) {
+            assert_relative_eq!(g, e, epsilon = 1e-12);
+        }
+
+        let hessian = problem.hessian(&param).unwrap();
+        for (h, e) in hessian.iter().zip(rosenbrock_hessian(&param).iter()) {
+            for (h, e) in h.iter().zip(e.iter()) {
+                assert_relative_eq!(h, e, epsilon = 1e-12);
+            }
+        }
+
+        assert_eq!(problem.counts["gradient_count"], 1);
+        assert_eq!(problem.counts["hessian_count"], 1);
+        // 1 + 3 (gradient) + 6 (Hessian)
+        assert_eq!(problem.counts["cost_count"], 10);
+    }
+
+    #[test]
+    fn test_styblinski_tang_f32() {
+        let param = vec![-1.2f32, 1.0, 0.5];
+        let mut problem = Problem::new(ForwardDiff::new(StyblinskiTang {}));
+
+        let (cost, gradient) = problem.cost_and_gradient(&param).unwrap();
+        assert_relative_eq!(cost, styblinski_tang(&param), epsilon = 1e-5);
+        for (g, e) in gradient
+            .iter()
+            .zip(styblinski_tang_derivative(&param).iter())
+        {
+            assert_relative_eq!(g, e, epsilon = 1e-5);
+        }
+
+        let hessian = problem.hessian(&param).unwrap();
+        for (h, e) in hessian.iter().zip(styblinski_tang_hessian(&param).iter()) {
+            for (h, e) in h.iter().zip(e.iter()) {
+                assert_relative_eq!(h, e, epsilon = 1e-5);
+            }
+        }
+
+        assert_eq!(problem.counts["gradient_count"], 1);
+        // 1 + 3 (cost and gradient) + 6 (Hessian)
+        assert_eq!(problem.counts["cost_count"], 10);
+    }
+
+    #[test]
+    fn test_newton_cg() {
+        // Must follow the same path as with hand-written derivatives
+        struct RosenbrockAnalytic {}
+
+        impl CostFunction for RosenbrockAnalytic {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock(p))
+            }
+        }
+
+        impl Gradient for RosenbrockAnalytic {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_derivative(p))
+            }
+        }
+
+        impl Hessian for RosenbrockAnalytic {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+
+            fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(rosenbrock_hessian(p))
+            }
+        }
+
+        let problem: ForwardDiff<_, f64> = ForwardDiff::new(Rosenbrock {});
+        let res = Executor::new(problem, NewtonCG::new(MoreThuenteLineSearch::new()))
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(50))
+            .run()
+            .unwrap();
+        let expected = Executor::new(
+            RosenbrockAnalytic {},
+            NewtonCG::new(MoreThuenteLineSearch::new()),
+        )
+        .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(50))
+        .run()
+        .unwrap();
+
+        let best = res.state().best_param.as_ref().unwrap();
+        let expected_best = expected.state().best_param.as_ref().unwrap();
+        assert!(res.state().best_cost < 1.0);
+        for (b, e) in best.iter().zip(expected_best.iter()) {
+            assert_relative_eq!(b, e, epsilon = 1e-8);
+        }
+    }
+}
@@ -0,0 +1,395 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{Error, Executor, OptimizationResult, SendAlias, Solver, State, SyncAlias};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "ctrlc")]
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+/// Type of the default setup function of [`BatchExecutor`], which leaves the `Executor` unchanged
+pub type DefaultSetup<O, S, I> = fn(usize, Executor<O, S, I>) -> Executor<O, S, I>;
+
+/// Solves many independent optimization problems
+///
+/// Takes `(problem, solver, init)` triples, where `init` configures the initial state of the run
+/// in the same way as [`Executor::configure`] does. Each triple is solved by its own
+/// [`Executor`]. If the `rayon` feature is enabled, the runs are executed in parallel, either on
+/// the global rayon thread pool or on a dedicated thread pool shared by all runs (see
+/// [`num_threads`](`BatchExecutor::num_threads`)). Parallelized solvers (such as
+/// [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`)) also use this thread pool.
+///
+/// The individual `Executor`s can be customized via a setup function (see
+/// [`with_setup`](`BatchExecutor::with_setup`)) which is called with the index of the run. This
+/// is where observers, checkpoints, timeouts and the like are added to the runs. Since the setup
+/// function is called on the thread which executes the run, observers are not required to be
+/// `Send`. The index of the run can be used to give each run a distinct name, for instance
+/// with the `SpectatorBuilder::with_name` method of the `argmin-observer-spectator` crate.
+///
+/// CTRL-C handling of the individual runs is disabled, instead a single CTRL-C handler interrupts
+/// all runs (if enabled, see [`ctrlc`](`BatchExecutor::ctrlc`)).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{BatchExecutor, Error, Executor, State, observers::ObserverMode};
+/// # use argmin::core::test_utils::{TestSolver, TestProblem};
+/// # use argmin_observer_slog::SlogLogger;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let runs = (0..4).map(|i| {
+///     let init_param = vec![i as f64, 0.0];
+///     (
+///         TestProblem::new(),
+///         TestSolver::new(),
+///         move |state: argmin::core::IterState<_, _, _, _, _, _>| {
+///             state.param(init_param).max_iters(10)
+///         },
+///     )
+/// });
+///
+/// let results = BatchExecutor::new(runs)
+///     // Add an observer to each run
+///     .with_setup(|index, executor| {
+///         executor.add_observer(SlogLogger::term_noblock(), ObserverMode::Never)
+///     })
+///     .run()?;
+///
+/// for result in results {
+///     // Each run either returns an `OptimizationResult` or an error
+///     let result = result?;
+///     # assert_eq!(result.state().get_iter(), 10);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct BatchExecutor<O, S, I, C, F = DefaultSetup<O, S, I>> {
+    /// Problems, solvers and initialization of the states
+    runs: Vec<(O, S, C)>,
+    /// Customizes the `Executor` of each run
+    setup: F,
+    /// Number of threads of the dedicated thread pool
+    num_threads: Option<usize>,
+    /// Indicates whether Ctrl-C functionality should be active or not
+    ctrlc: bool,
+    /// Phantom state
+    state: std::marker::PhantomData<I>,
+}
+
+impl<O, S, I, C> BatchExecutor<O, S, I, C>
+where
+    S: Solver<O, I>,
+    I: State,
+    C: FnOnce(I) -> I,
+{
+    /// Constructs a `BatchExecutor` from an iterator of `(problem, solver, init)` triples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{BatchExecutor, IterState};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// let runs = (0..4).map(|i| {
+    ///     (
+    ///         TestProblem::new(),
+    ///         TestSolver::new(),
+    ///         move |state: IterState<_, _, _, _, _, _>| state.param(vec![i as f64, 0.0]),
+    ///     )
+    /// });
+    /// let batch = BatchExecutor::new(runs);
+    /// ```
+    pub fn new<T: IntoIterator<Item = (O, S, C)>>(runs: T) -> Self {
+        BatchExecutor {
+            runs: runs.into_iter().collect(),
+            setup: |_, executor| executor,
+            num_threads: None,
+            ctrlc: true,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<O, S, I, C, F> BatchExecutor<O, S, I, C, F>
+where
+    S: Solver<O, I>,
+    I: State,
+    C: FnOnce(I) -> I,
+    F: Fn(usize, Executor<O, S, I>) -> Executor<O, S, I>,
+{
+    /// Sets a function which customizes the [`Executor`] of each run.
+    ///
+    /// The function is called with the index of the run (its position in the iterator passed to
+    /// [`new`](`BatchExecutor::new`)) and the `Executor` of the run, which is already configured
+    /// with the `init` closure of the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{BatchExecutor, IterState, observers::ObserverMode};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// # use argmin_observer_slog::SlogLogger;
+    /// # let runs = (0..4).map(|i| {
+    /// #     (
+    /// #         TestProblem::new(),
+    /// #         TestSolver::new(),
+    /// #         move |state: IterState<_, _, _, _, _, _>| state.param(vec![i as f64, 0.0]),
+    /// #     )
+    /// # });
+    /// let batch = BatchExecutor::new(runs).with_setup(|index, executor| {
+    ///     executor
+    ///         .add_observer(SlogLogger::term(), ObserverMode::NewBest)
+    ///         .timeout(std::time::Duration::from_secs(10))
+    /// });
+    /// ```
+    pub fn with_setup<F2>(self, setup: F2) -> BatchExecutor<O, S, I, C, F2>
+    where
+        F2: Fn(usize, Executor<O, S, I>) -> Executor<O, S, I>,
+    {
+        BatchExecutor {
+            runs: self.runs,
+            setup,
+            num_threads: self.num_threads,
+            ctrlc: self.ctrlc,
+            state: std::marker::PhantomData,
+        }
+    }
+
+    /// Runs the optimizations on a dedicated thread pool with `num_threads` threads instead of
+    /// the global rayon thread pool. Only has an effect if the `rayon` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{BatchExecutor, IterState};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// # let runs = (0..4).map(|i| {
+    /// #     (
+    /// #         TestProblem::new(),
+    /// #         TestSolver::new(),
+    /// #         move |state: IterState<_, _, _, _, _, _>| state.param(vec![i as f64, 0.0]),
+    /// #     )
+    /// # });
+    /// let batch = BatchExecutor::new(runs).num_threads(4);
+    /// ```
+    #[must_use]
+    pub fn num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Enables or disables CTRL-C handling (default: enabled). If enabled, a single CTRL-C handler
+    /// gracefully stops all runs. Requires the optional `ctrlc` feature to be set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{BatchExecutor, IterState};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// # let runs = (0..4).map(|i| {
+    /// #     (
+    /// #         TestProblem::new(),
+    /// #         TestSolver::new(),
+    /// #         move |state: IterState<_, _, _, _, _, _>| state.param(vec![i as f64, 0.0]),
+    /// #     )
+    /// # });
+    /// let batch = BatchExecutor::new(runs).ctrlc(false);
+    /// ```
+    #[must_use]
+    pub fn ctrlc(mut self, ctrlc: bool) -> Self {
+        self.ctrlc = ctrlc;
+        self
+    }
+
+    /// Runs all optimizations.
+    ///
+    /// Returns the result of each run in the order of the runs passed to
+    /// [`new`](`BatchExecutor::new`). A failing run does not affect the other runs. An error is
+    /// only returned if the CTRL-C handler or the thread pool cannot be set up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{BatchExecutor, Error, IterState, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// # fn main() -> Result<(), Error> {
+    /// # let runs = (0..4).map(|i| {
+    /// #     (
+    /// #         TestProblem::new(),
+    /// #         TestSolver::new(),
+    /// #         move |state: IterState<_, _, _, _, _, _>| {
+    /// #             state.param(vec![i as f64, 0.0]).max_iters(10)
+    /// #         },
+    /// #     )
+    /// # });
+    /// let results = BatchExecutor::new(runs).run()?;
+    ///
+    /// let num_failed = results.iter().filter(|result| result.is_err()).count();
+    /// # assert_eq!(num_failed, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn run(self) -> Result<Vec<Result<OptimizationResult<O, S, I>, Error>>, Error>
+    where
+        O: SendAlias,
+        S: SendAlias,
+        I: SendAlias,
+        C: SendAlias,
+        F: SyncAlias,
+    {
+        let interrupt = Arc::new(AtomicBool::new(false));
+        if self.ctrlc {
+            #[cfg(feature = "ctrlc")]
+            {
+                let interp = interrupt.clone();
+                let handler = move || {
+                    interp.store(true, Ordering::SeqCst);
+                };
+                // See `Executor` for why `MultipleHandlers` is ignored.
+                match ctrlc::set_handler(handler) {
+                    Err(ctrlc::Error::MultipleHandlers) => Ok(()),
+                    interp => interp,
+                }?;
+            }
+        }
+
+        let setup = &self.setup;
+        let run_one = |(index, (problem, solver, init)): (usize, (O, S, C))| {
+            setup(index, Executor::new(problem, solver).configure(init))
+                .share_interrupt(interrupt.clone())
+                .run()
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            let runs = self.runs;
+            let run_all = || runs.into_par_iter().enumerate().map(run_one).collect();
+            match self.num_threads {
+                Some(num_threads) => Ok(rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()?
+                    .install(run_all)),
+                None => Ok(run_all()),
+            }
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            let _ = self.num_threads;
+            Ok(self.runs.into_iter().enumerate().map(run_one).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "rayon")]
+    use crate::core::test_utils::{TestProblem, TestSolver};
+    use crate::core::{IterState, TerminationReason};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl crate::core::CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(argmin_testfunctions::rosenbrock(p))
+        }
+    }
+
+    impl crate::core::Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(argmin_testfunctions::rosenbrock_derivative(p))
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let runs = (0..20).map(|i| {
+            let init_param = vec![-1.2 + 0.1 * i as f64, 1.0];
+            (
+                Rosenbrock {},
+                SteepestDescent::new(MoreThuenteLineSearch::new()),
+                move |state: IterState<_, _, _, _, _, _>| state.param(init_param).max_iters(10 + i),
+            )
+        });
+        let results = BatchExecutor::new(runs)
+            .with_setup(|index, executor| executor.configure(|state| state.counting(index > 9)))
+            .run()
+            .unwrap();
+
+        assert_eq!(results.len(), 20);
+        for (i, result) in results.into_iter().enumerate() {
+            let result = result.unwrap();
+            // Results are returned in order
+            assert_eq!(result.state().get_iter(), 10 + i as u64);
+            assert_eq!(
+                result.state().get_termination_reason(),
+                Some(&TerminationReason::MaxItersReached)
+            );
+            assert_eq!(result.state().get_func_counts().is_empty(), i <= 9);
+            let init_cost = argmin_testfunctions::rosenbrock(&[-1.2 + 0.1 * i as f64, 1.0]);
+            assert!(result.state().get_best_cost() < init_cost);
+        }
+    }
+
+    #[test]
+    fn test_errors_per_run() {
+        // Even runs lack an initial parameter vector and fail
+        let runs = (0..6).map(|i| {
+            (
+                Rosenbrock {},
+                SteepestDescent::new(MoreThuenteLineSearch::new()),
+                move |state: IterState<_, _, _, _, _, _>| {
+                    let state = state.max_iters(3);
+                    if i % 2 == 0 {
+                        state
+                    } else {
+                        state.param(vec![1.0, 2.0])
+                    }
+                },
+            )
+        });
+        let results = BatchExecutor::new(runs).ctrlc(false).run().unwrap();
+
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.is_err(), i % 2 == 0);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_num_threads() {
+        let runs = (0..8).map(|_| {
+            (
+                TestProblem::new(),
+                TestSolver::new(),
+                |state: IterState<Vec<f64>, (), (), (), (), f64>| {
+                    state.param(vec![1.0, 2.0]).max_iters(3)
+                },
+            )
+        });
+        let results = BatchExecutor::new(runs)
+            .num_threads(2)
+            .with_setup(|_, executor| {
+                assert_eq!(rayon::current_num_threads(), 2);
+                executor
+            })
+            .run()
+            .unwrap();
+        assert!(results.iter().all(|result| result.is_ok()));
+    }
+}
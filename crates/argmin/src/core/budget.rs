@@ -0,0 +1,372 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::Error;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Budget of function evaluations
+///
+/// Limits the number of evaluations per counter (such as `"cost_count"` or `"gradient_count"`)
+/// and/or a weighted total of all evaluations. The weight of a counter defaults to `1.0`; for
+/// instance, a gradient which is about as expensive as three cost function evaluations can be
+/// accounted for with a weight of `3.0`.
+///
+/// The budget is attached to an [`Executor`](`crate::core::Executor`) via
+/// [`Executor::func_eval_budget`](`crate::core::Executor::func_eval_budget`). It is checked
+/// before every evaluation of the problem, therefore evaluations performed in nested executors
+/// (such as line searches) which inherit the budget via
+/// [`Executor::inherit_func_eval_budget`](`crate::core::Executor::inherit_func_eval_budget`)
+/// are accounted for as well. An evaluation which would exceed the budget is not performed. Once
+/// this happens, the budget is considered exhausted and the `Executor` terminates with
+/// [`TerminationReason::MaxFuncEvalsReached`](`crate::core::TerminationReason::MaxFuncEvalsReached`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, FuncEvalBudget};
+/// # fn main() -> Result<(), Error> {
+/// let budget = FuncEvalBudget::new()
+///     // At most 100 gradient evaluations
+///     .with_limit("gradient_count", 100)
+///     // A gradient evaluation costs as much as three cost function evaluations
+///     .with_weight("gradient_count", 3.0)?
+///     // At most the equivalent of 500 cost function evaluations in total
+///     .with_total(500.0)?;
+/// # assert!(!budget.is_exhausted());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct FuncEvalBudget {
+    /// Maximum number of evaluations per counter
+    limits: HashMap<&'static str, u64>,
+    /// Weights of the counters in the total
+    weights: HashMap<&'static str, f64>,
+    /// Maximum weighted total of evaluations
+    max_total: Option<f64>,
+    /// Evaluations performed so far
+    spent: Mutex<HashMap<&'static str, u64>>,
+    /// Set once an evaluation was refused
+    exhausted: AtomicBool,
+}
+
+impl FuncEvalBudget {
+    /// Constructs a new `FuncEvalBudget` without any limits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::FuncEvalBudget;
+    /// let budget = FuncEvalBudget::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of evaluations of the counter `counter` (for instance
+    /// `"cost_count"`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::FuncEvalBudget;
+    /// let budget = FuncEvalBudget::new().with_limit("cost_count", 1000);
+    /// ```
+    #[must_use]
+    pub fn with_limit(mut self, counter: &'static str, max: u64) -> Self {
+        self.limits.insert(counter, max);
+        self
+    }
+
+    /// Sets the weight of the counter `counter` in the weighted total (default: `1.0`).
+    ///
+    /// The weight must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, FuncEvalBudget};
+    /// # fn main() -> Result<(), Error> {
+    /// let budget = FuncEvalBudget::new().with_weight("gradient_count", 3.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_weight(mut self, counter: &'static str, weight: f64) -> Result<Self, Error> {
+        if weight.is_nan() || weight < 0.0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FuncEvalBudget`: weight must be >= 0."
+            ));
+        }
+        self.weights.insert(counter, weight);
+        Ok(self)
+    }
+
+    /// Sets the maximum weighted total of evaluations.
+    ///
+    /// The total must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, FuncEvalBudget};
+    /// # fn main() -> Result<(), Error> {
+    /// let budget = FuncEvalBudget::new().with_total(500.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_total(mut self, max_total: f64) -> Result<Self, Error> {
+        if max_total.is_nan() || max_total < 0.0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FuncEvalBudget`: total must be >= 0."
+            ));
+        }
+        self.max_total = Some(max_total);
+        Ok(self)
+    }
+
+    /// Returns the weighted total of the evaluations performed so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, FuncEvalBudget};
+    /// # fn main() -> Result<(), Error> {
+    /// let budget = FuncEvalBudget::new();
+    /// assert_eq!(budget.total(), 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total(&self) -> f64 {
+        self.weighted_total(&self.spent.lock().unwrap())
+    }
+
+    /// Returns the number of evaluations of the counter `counter` performed so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::FuncEvalBudget;
+    /// let budget = FuncEvalBudget::new();
+    /// assert_eq!(budget.spent("cost_count"), 0);
+    /// ```
+    pub fn spent(&self, counter: &'static str) -> u64 {
+        self.spent
+            .lock()
+            .unwrap()
+            .get(counter)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if an evaluation was refused or if any of the limits is reached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, FuncEvalBudget};
+    /// # fn main() -> Result<(), Error> {
+    /// let budget = FuncEvalBudget::new().with_limit("cost_count", 1);
+    /// assert!(!budget.is_exhausted());
+    ///
+    /// budget.consume("cost_count", 1)?;
+    /// assert!(budget.is_exhausted());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_exhausted(&self) -> bool {
+        if self.exhausted.load(Ordering::SeqCst) {
+            return true;
+        }
+        let spent = self.spent.lock().unwrap();
+        self.limits
+            .iter()
+            .any(|(k, &max)| spent.get(k).copied().unwrap_or(0) >= max)
+            || self
+                .max_total
+                .map(|max_total| self.weighted_total(&spent) >= max_total)
+                .unwrap_or(false)
+    }
+
+    /// Accounts for `num` evaluations of the counter `counter`.
+    ///
+    /// Returns an error if the evaluations would exceed the budget or if the budget has already
+    /// been exhausted before. In this case, nothing is accounted for and the budget is considered
+    /// exhausted from then on.
+    ///
+    /// This is called by [`Problem`](`crate::core::Problem`) before evaluating the problem and
+    /// usually does not need to be called manually.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, FuncEvalBudget};
+    /// # fn main() -> Result<(), Error> {
+    /// let budget = FuncEvalBudget::new().with_limit("cost_count", 2);
+    ///
+    /// budget.consume("cost_count", 2)?;
+    /// assert!(budget.consume("cost_count", 1).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn consume(&self, counter: &'static str, num: u64) -> Result<(), Error> {
+        self.consume_all(&[(counter, num)])
+    }
+
+    /// Accounts for the evaluations of several counters at once.
+    ///
+    /// Either all evaluations are accounted for or, if any of them would exceed the budget, none
+    /// of them (see [`consume`](`FuncEvalBudget::consume`)).
+    pub(crate) fn consume_all(&self, evaluations: &[(&'static str, u64)]) -> Result<(), Error> {
+        let mut spent = self.spent.lock().unwrap();
+        let exceeded = evaluations.iter().find(|&&(counter, num)| {
+            self.limits
+                .get(counter)
+                .map(|&max| spent.get(counter).copied().unwrap_or(0) + num > max)
+                .unwrap_or(false)
+        });
+        let additional_total: f64 = evaluations
+            .iter()
+            .map(|&(counter, num)| self.weight(counter) * num as f64)
+            .sum();
+        let exceeds_total = self
+            .max_total
+            .map(|max_total| self.weighted_total(&spent) + additional_total > max_total)
+            .unwrap_or(false);
+        if self.exhausted.load(Ordering::SeqCst) || exceeded.is_some() || exceeds_total {
+            self.exhausted.store(true, Ordering::SeqCst);
+            let refused = exceeded
+                .or(evaluations.first())
+                .map(|(counter, num)| format!("{num} `{counter}`"))
+                .unwrap_or_default();
+            return Err(argmin_error!(
+                ConditionViolated,
+                format!("`FuncEvalBudget`: budget exhausted, refused {refused}")
+            ));
+        }
+        for &(counter, num) in evaluations {
+            *spent.entry(counter).or_insert(0) += num;
+        }
+        Ok(())
+    }
+
+    /// Accounts for `num` evaluations of the counter `counter` which have already been performed.
+    ///
+    /// In contrast to [`consume`](`FuncEvalBudget::consume`), the evaluations are never refused.
+    /// If they exceed the budget, the budget is exhausted from then on.
+    pub(crate) fn charge(&self, counter: &'static str, num: u64) {
+        *self.spent.lock().unwrap().entry(counter).or_insert(0) += num;
+    }
+
+    /// Gives back `num` evaluations of the counter `counter` which have been accounted for but
+    /// were not performed (for instance because they were served from a cache).
+    pub(crate) fn refund(&self, counter: &'static str, num: u64) {
+        if let Some(spent) = self.spent.lock().unwrap().get_mut(counter) {
+            *spent = spent.saturating_sub(num);
+        }
+    }
+
+    /// Returns the evaluations performed so far for all counters.
+    #[cfg(feature = "async")]
+    pub(crate) fn snapshot(&self) -> HashMap<&'static str, u64> {
+        self.spent.lock().unwrap().clone()
+    }
+
+    /// Resets the evaluations performed so far to `spent` (as returned by
+    /// [`snapshot`](`FuncEvalBudget::snapshot`)).
+    #[cfg(feature = "async")]
+    pub(crate) fn restore(&self, spent: HashMap<&'static str, u64>) {
+        *self.spent.lock().unwrap() = spent;
+    }
+
+    /// Weight of the counter `counter`
+    fn weight(&self, counter: &str) -> f64 {
+        self.weights.get(counter).copied().unwrap_or(1.0)
+    }
+
+    /// Weighted total of `spent`
+    fn weighted_total(&self, spent: &HashMap<&'static str, u64>) -> f64 {
+        spent.iter().map(|(k, &v)| self.weight(k) * v as f64).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    send_sync_test!(func_eval_budget, FuncEvalBudget);
+
+    #[test]
+    fn test_limit() {
+        let budget = FuncEvalBudget::new().with_limit("cost_count", 3);
+        budget.consume("cost_count", 2).unwrap();
+        budget.consume("gradient_count", 10).unwrap();
+        assert!(!budget.is_exhausted());
+        assert_error!(
+            budget.consume("cost_count", 2),
+            ArgminError,
+            "Condition violated: \"`FuncEvalBudget`: budget exhausted, refused 2 `cost_count`\""
+        );
+        assert_eq!(budget.spent("cost_count"), 2);
+        assert_eq!(budget.spent("gradient_count"), 10);
+        assert!(budget.is_exhausted());
+        // Once exhausted, everything is refused
+        assert!(budget.consume("gradient_count", 1).is_err());
+    }
+
+    #[test]
+    fn test_weighted_total() {
+        let budget = FuncEvalBudget::new()
+            .with_weight("gradient_count", 3.0)
+            .unwrap()
+            .with_total(10.0)
+            .unwrap();
+        budget.consume("cost_count", 1).unwrap();
+        budget.consume("gradient_count", 2).unwrap();
+        assert_relative_eq!(budget.total(), 7.0);
+        assert!(!budget.is_exhausted());
+        assert!(budget.consume("gradient_count", 1).is_ok());
+        assert!(budget.is_exhausted());
+        assert!(budget.consume("cost_count", 1).is_err());
+        assert_relative_eq!(budget.total(), 10.0);
+    }
+
+    #[test]
+    fn test_consume_all() {
+        let budget = FuncEvalBudget::new().with_limit("gradient_count", 1);
+        budget
+            .consume_all(&[("cost_count", 1), ("gradient_count", 1)])
+            .unwrap();
+        assert_error!(
+            budget.consume_all(&[("cost_count", 1), ("gradient_count", 1)]),
+            ArgminError,
+            "Condition violated: \"`FuncEvalBudget`: budget exhausted, refused 1 `gradient_count`\""
+        );
+        // Nothing is accounted for if any of the evaluations is refused
+        assert_eq!(budget.spent("cost_count"), 1);
+        assert_eq!(budget.spent("gradient_count"), 1);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_error!(
+            FuncEvalBudget::new().with_weight("cost_count", -1.0),
+            ArgminError,
+            "Invalid parameter: \"`FuncEvalBudget`: weight must be >= 0.\""
+        );
+        assert_error!(
+            FuncEvalBudget::new().with_total(f64::NAN),
+            ArgminError,
+            "Invalid parameter: \"`FuncEvalBudget`: total must be >= 0.\""
+        );
+    }
+}
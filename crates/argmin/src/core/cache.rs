@@ -0,0 +1,882 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::problem::count_cache_hits;
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, Hessian, Jacobian, Operator, SendAlias, SyncAlias,
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+/// Strategy for deriving cache keys from parameter vectors
+///
+/// Two parameter vectors are considered equal by [`CachedProblem`] if their keys are equal.
+/// Implemented by [`ExactKey`], [`RoundedKey`] and by closures `Fn(&P) -> K`.
+pub trait CacheKey<P> {
+    /// Type of the key
+    type Key: Hash + Eq + Clone + Send + 'static;
+
+    /// Derives the key of `param`
+    fn key(&self, param: &P) -> Self::Key;
+}
+
+/// Parameter vectors are equal if all their elements are bitwise identical.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ExactKey;
+
+impl<F: ArgminFloat> CacheKey<Vec<F>> for ExactKey {
+    type Key = Vec<u64>;
+
+    fn key(&self, param: &Vec<F>) -> Vec<u64> {
+        param
+            .iter()
+            .map(|x| x.to_f64().unwrap().to_bits())
+            .collect()
+    }
+}
+
+impl<F: ArgminFloat, const N: usize> CacheKey<[F; N]> for ExactKey {
+    type Key = Vec<u64>;
+
+    fn key(&self, param: &[F; N]) -> Vec<u64> {
+        param
+            .iter()
+            .map(|x| x.to_f64().unwrap().to_bits())
+            .collect()
+    }
+}
+
+/// Parameter vectors are equal if all their elements are equal after rounding to multiples of a
+/// given tolerance.
+///
+/// Note that two values closer than the tolerance may still be rounded to different multiples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundedKey {
+    /// Tolerance
+    tolerance: f64,
+}
+
+impl RoundedKey {
+    /// Constructs a new `RoundedKey` with tolerance `tolerance`, which must be larger than 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, RoundedKey};
+    /// # fn main() -> Result<(), Error> {
+    /// let key = RoundedKey::new(1e-12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(tolerance: f64) -> Result<Self, Error> {
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RoundedKey`: tolerance must be > 0."
+            ));
+        }
+        Ok(RoundedKey { tolerance })
+    }
+
+    /// Rounds `x` to a multiple of the tolerance
+    fn round<F: ArgminFloat>(&self, x: &F) -> i64 {
+        (x.to_f64().unwrap() / self.tolerance).round() as i64
+    }
+}
+
+impl<F: ArgminFloat> CacheKey<Vec<F>> for RoundedKey {
+    type Key = Vec<i64>;
+
+    fn key(&self, param: &Vec<F>) -> Vec<i64> {
+        param.iter().map(|x| self.round(x)).collect()
+    }
+}
+
+impl<F: ArgminFloat, const N: usize> CacheKey<[F; N]> for RoundedKey {
+    type Key = Vec<i64>;
+
+    fn key(&self, param: &[F; N]) -> Vec<i64> {
+        param.iter().map(|x| self.round(x)).collect()
+    }
+}
+
+impl<P, K, G> CacheKey<P> for G
+where
+    G: Fn(&P) -> K,
+    K: Hash + Eq + Clone + Send + 'static,
+{
+    type Key = K;
+
+    fn key(&self, param: &P) -> K {
+        (self)(param)
+    }
+}
+
+/// Least recently used entries of one method of the problem
+struct Entries<K, V> {
+    /// Values and the time of their last use
+    values: HashMap<K, (V, u64)>,
+    /// Incremented on every access
+    clock: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Entries<K, V> {
+    /// Returns the value stored for `key` (if any) and marks it as used.
+    fn get(&mut self, key: &K) -> Option<V> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.values.get_mut(key).map(|(value, last_used)| {
+            *last_used = clock;
+            value.clone()
+        })
+    }
+
+    /// Stores `value` for `key`, evicting the least recently used entry if `capacity` is reached.
+    fn insert(&mut self, key: K, value: V, capacity: usize) {
+        if self.values.len() >= capacity && !self.values.contains_key(&key) {
+            let oldest = self
+                .values
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                self.values.remove(&oldest);
+            }
+        }
+        self.values.insert(key, (value, self.clock));
+    }
+}
+
+/// Storage shared by all clones of a [`CachedProblem`]
+#[derive(Default)]
+struct Storage {
+    /// Entries per method, type-erased since the parameter and output types differ between the
+    /// problem traits
+    entries: HashMap<&'static str, Box<dyn Any + Send>>,
+    /// Number of cache hits per method
+    hits: HashMap<&'static str, u64>,
+    /// Number of actual evaluations per method
+    evaluations: HashMap<&'static str, u64>,
+}
+
+/// Caches evaluations of an optimization problem
+///
+/// Wraps an optimization problem and stores the results of
+/// [`Operator::apply`](`crate::core::Operator::apply`), [`CostFunction::cost`],
+/// [`Gradient::gradient`], [`Hessian::hessian`] and [`Jacobian::jacobian`] (as far as these are
+/// implemented by the wrapped problem). Repeated evaluations at the same parameter vector, for
+/// instance when a solver recomputes the cost at the point accepted by a line search, are served
+/// from the cache instead of calling the wrapped problem.
+///
+/// Whether two parameter vectors are the same is decided by comparing their keys, which are
+/// derived by a [`CacheKey`] strategy (default: [`ExactKey`]). For each method, at most
+/// `capacity` values are stored (default: `100`); once the limit is reached, the least recently
+/// used value is evicted.
+///
+/// The cache keeps track of the number of cache hits and actual evaluations for each method,
+/// labeled in the same way as the function evaluation counts of
+/// [`Problem`](`crate::core::Problem`) (`"cost_count"`, `"gradient_count"`, ...). The counts of
+/// `Problem` only contain the actual evaluations; cache hits are counted separately as
+/// `"operator_cache_hits"`, `"cost_cache_hits"`, `"gradient_cache_hits"`, `"hessian_cache_hits"`
+/// and `"jacobian_cache_hits"` and do not use up the
+/// [`FuncEvalBudget`](`crate::core::FuncEvalBudget`), if any.
+///
+/// Clones of a `CachedProblem` share the cache.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CachedProblem, CostFunction, Error, Executor};
+/// # use argmin::solver::gradientdescent::SteepestDescent;
+/// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+/// # use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+/// #
+/// # struct Rosenbrock {}
+/// #
+/// # impl CostFunction for Rosenbrock {
+/// #     type Param = Vec<f64>;
+/// #     type Output = f64;
+/// #
+/// #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+/// #         Ok(rosenbrock(p))
+/// #     }
+/// # }
+/// #
+/// # impl argmin::core::Gradient for Rosenbrock {
+/// #     type Param = Vec<f64>;
+/// #     type Gradient = Vec<f64>;
+/// #
+/// #     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+/// #         Ok(rosenbrock_derivative(p))
+/// #     }
+/// # }
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let problem = CachedProblem::new(Rosenbrock {}).with_capacity(10)?;
+///
+/// let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+/// let res = Executor::new(problem, solver)
+///     .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+///     .run()?;
+///
+/// let cache = res.problem().problem.as_ref().unwrap();
+/// println!(
+///     "cost: {} evaluations, {} cache hits",
+///     cache.evaluations("cost_count"),
+///     cache.hits("cost_count")
+/// );
+/// # assert!(cache.hits("cost_count") > 0);
+/// # Ok(())
+/// # }
+/// ```
+pub struct CachedProblem<O, K = ExactKey> {
+    /// Wrapped problem
+    problem: O,
+    /// Strategy for deriving keys
+    key: K,
+    /// Maximum number of values per method
+    capacity: usize,
+    /// Cached values and counts
+    storage: Arc<Mutex<Storage>>,
+}
+
+impl<O> CachedProblem<O> {
+    /// Wraps `problem` into a cache with the [`ExactKey`] strategy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::CachedProblem;
+    /// # struct UserDefinedProblem {}
+    /// let problem = CachedProblem::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(problem: O) -> Self {
+        CachedProblem {
+            problem,
+            key: ExactKey,
+            capacity: 100,
+            storage: Arc::new(Mutex::new(Storage::default())),
+        }
+    }
+}
+
+impl<O, K> CachedProblem<O, K> {
+    /// Sets the strategy for deriving keys from parameter vectors (default: [`ExactKey`]).
+    ///
+    /// This clears the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{CachedProblem, Error, RoundedKey};
+    /// # struct UserDefinedProblem {}
+    /// # fn main() -> Result<(), Error> {
+    /// // Parameter vectors are equal if they agree up to `1e-12`
+    /// let problem = CachedProblem::new(UserDefinedProblem {}).with_key(RoundedKey::new(1e-12)?);
+    ///
+    /// // Custom strategy
+    /// let problem = CachedProblem::new(UserDefinedProblem {})
+    ///     .with_key(|p: &Vec<f64>| p.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_key<K2>(self, key: K2) -> CachedProblem<O, K2> {
+        CachedProblem {
+            problem: self.problem,
+            key,
+            capacity: self.capacity,
+            storage: Arc::new(Mutex::new(Storage::default())),
+        }
+    }
+
+    /// Sets the maximum number of values stored per method (default: `100`). Must be larger
+    /// than 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{CachedProblem, Error};
+    /// # struct UserDefinedProblem {}
+    /// # fn main() -> Result<(), Error> {
+    /// let problem = CachedProblem::new(UserDefinedProblem {}).with_capacity(10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_capacity(mut self, capacity: usize) -> Result<Self, Error> {
+        if capacity == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CachedProblem`: capacity must be > 0."
+            ));
+        }
+        self.capacity = capacity;
+        Ok(self)
+    }
+
+    /// Returns the number of cache hits of the method labeled `counter` (for instance
+    /// `"cost_count"`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::CachedProblem;
+    /// # struct UserDefinedProblem {}
+    /// let problem = CachedProblem::new(UserDefinedProblem {});
+    /// assert_eq!(problem.hits("cost_count"), 0);
+    /// ```
+    pub fn hits(&self, counter: &str) -> u64 {
+        let storage = self.storage.lock().unwrap();
+        storage.hits.get(counter).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of actual evaluations of the method labeled `counter` (for instance
+    /// `"cost_count"`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::CachedProblem;
+    /// # struct UserDefinedProblem {}
+    /// let problem = CachedProblem::new(UserDefinedProblem {});
+    /// assert_eq!(problem.evaluations("cost_count"), 0);
+    /// ```
+    pub fn evaluations(&self, counter: &str) -> u64 {
+        let storage = self.storage.lock().unwrap();
+        storage.evaluations.get(counter).copied().unwrap_or(0)
+    }
+
+    /// Removes all cached values. The counts are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::CachedProblem;
+    /// # struct UserDefinedProblem {}
+    /// let problem = CachedProblem::new(UserDefinedProblem {});
+    /// problem.clear();
+    /// ```
+    pub fn clear(&self) {
+        self.storage.lock().unwrap().entries.clear();
+    }
+
+    /// Returns a reference to the wrapped problem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::CachedProblem;
+    /// # struct UserDefinedProblem {}
+    /// let problem = CachedProblem::new(UserDefinedProblem {});
+    /// let inner: &UserDefinedProblem = problem.inner();
+    /// ```
+    pub fn inner(&self) -> &O {
+        &self.problem
+    }
+
+    /// Looks up the value of the method labeled `counter` at `param` and calls `func` in case of a
+    /// cache miss. Cache hits are reported to the calling `Problem` as `hits_counter`.
+    fn cached<P, V, F>(
+        &self,
+        counter: &'static str,
+        hits_counter: &'static str,
+        param: &P,
+        func: F,
+    ) -> Result<V, Error>
+    where
+        K: CacheKey<P>,
+        V: Clone + Send + 'static,
+        F: FnOnce(&O, &P) -> Result<V, Error>,
+    {
+        let (value, hit) = self.lookup(counter, param, func);
+        if hit {
+            count_cache_hits(counter, hits_counter, 1);
+        }
+        value
+    }
+
+    /// Like [`cached`](`CachedProblem::cached`) for all `params`, which are processed in parallel
+    /// if `parallelize` is set and the `rayon` feature is enabled. The cache hits are reported on
+    /// the calling thread, since reports from `rayon` worker threads are not visible to `Problem`.
+    fn bulk_cached<P, Q, V, F>(
+        &self,
+        counter: &'static str,
+        hits_counter: &'static str,
+        params: &[Q],
+        parallelize: bool,
+        func: F,
+    ) -> Result<Vec<V>, Error>
+    where
+        K: CacheKey<P>,
+        Q: Borrow<P> + SyncAlias,
+        V: Clone + Send + 'static,
+        F: Fn(&O, &P) -> Result<V, Error> + SyncAlias,
+        Self: SyncAlias,
+    {
+        let lookup = |param: &Q| self.lookup(counter, param.borrow(), &func);
+        #[cfg(feature = "rayon")]
+        let results: Vec<(Result<V, Error>, bool)> = if parallelize {
+            params.par_iter().map(lookup).collect()
+        } else {
+            params.iter().map(lookup).collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let results: Vec<(Result<V, Error>, bool)> = {
+            let _ = parallelize;
+            params.iter().map(lookup).collect()
+        };
+        let hits = results.iter().filter(|(_, hit)| *hit).count() as u64;
+        if hits > 0 {
+            count_cache_hits(counter, hits_counter, hits);
+        }
+        results.into_iter().map(|(value, _)| value).collect()
+    }
+
+    /// Looks up the value of the method labeled `counter` at `param` and calls `func` in case of a
+    /// cache miss. Returns the value and whether it was a cache hit.
+    fn lookup<P, V, F>(&self, counter: &'static str, param: &P, func: F) -> (Result<V, Error>, bool)
+    where
+        K: CacheKey<P>,
+        V: Clone + Send + 'static,
+        F: FnOnce(&O, &P) -> Result<V, Error>,
+    {
+        let key = self.key.key(param);
+        {
+            let mut storage = self.storage.lock().unwrap();
+            let value = storage
+                .entries
+                .get_mut(counter)
+                .and_then(|entries| entries.downcast_mut::<Entries<K::Key, V>>())
+                .and_then(|entries| entries.get(&key));
+            if let Some(value) = value {
+                *storage.hits.entry(counter).or_insert(0) += 1;
+                return (Ok(value), true);
+            }
+        }
+        // The lock is not held during the evaluation, which allows for parallel evaluations.
+        let value = match func(&self.problem, param) {
+            Ok(value) => value,
+            Err(e) => return (Err(e), false),
+        };
+        let mut storage = self.storage.lock().unwrap();
+        *storage.evaluations.entry(counter).or_insert(0) += 1;
+        let entries = storage.entries.entry(counter).or_insert_with(|| {
+            Box::new(Entries::<K::Key, V> {
+                values: HashMap::new(),
+                clock: 0,
+            })
+        });
+        if let Some(entries) = entries.downcast_mut::<Entries<K::Key, V>>() {
+            entries.insert(key, value.clone(), self.capacity);
+        }
+        (Ok(value), false)
+    }
+}
+
+impl<O: Clone, K: Clone> Clone for CachedProblem<O, K> {
+    fn clone(&self) -> Self {
+        CachedProblem {
+            problem: self.problem.clone(),
+            key: self.key.clone(),
+            capacity: self.capacity,
+            storage: Arc::clone(&self.storage),
+        }
+    }
+}
+
+impl<O, K> Operator for CachedProblem<O, K>
+where
+    O: Operator,
+    O::Output: Clone + Send + 'static,
+    K: CacheKey<O::Param>,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.cached(
+            "operator_count",
+            "operator_cache_hits",
+            param,
+            |problem, param| problem.apply(param),
+        )
+    }
+
+    fn bulk_apply<P>(&self, params: &[P]) -> Result<Vec<Self::Output>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Output: SendAlias,
+        Self: SyncAlias,
+    {
+        self.bulk_cached(
+            "operator_count",
+            "operator_cache_hits",
+            params,
+            self.problem.parallelize(),
+            |problem, param| problem.apply(param),
+        )
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O, K> CostFunction for CachedProblem<O, K>
+where
+    O: CostFunction,
+    O::Output: Clone + Send + 'static,
+    K: CacheKey<O::Param>,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.cached("cost_count", "cost_cache_hits", param, |problem, param| {
+            problem.cost(param)
+        })
+    }
+
+    fn bulk_cost<P>(&self, params: &[P]) -> Result<Vec<Self::Output>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Output: SendAlias,
+        Self: SyncAlias,
+    {
+        self.bulk_cached(
+            "cost_count",
+            "cost_cache_hits",
+            params,
+            self.problem.parallelize(),
+            |problem, param| problem.cost(param),
+        )
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O, K> Gradient for CachedProblem<O, K>
+where
+    O: Gradient,
+    O::Gradient: Clone + Send + 'static,
+    K: CacheKey<O::Param>,
+{
+    type Param = O::Param;
+    type Gradient = O::Gradient;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.cached(
+            "gradient_count",
+            "gradient_cache_hits",
+            param,
+            |problem, param| problem.gradient(param),
+        )
+    }
+
+    fn bulk_gradient<P>(&self, params: &[P]) -> Result<Vec<Self::Gradient>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Gradient: SendAlias,
+        Self: SyncAlias,
+    {
+        self.bulk_cached(
+            "gradient_count",
+            "gradient_cache_hits",
+            params,
+            self.problem.parallelize(),
+            |problem, param| problem.gradient(param),
+        )
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O, K> Hessian for CachedProblem<O, K>
+where
+    O: Hessian,
+    O::Hessian: Clone + Send + 'static,
+    K: CacheKey<O::Param>,
+{
+    type Param = O::Param;
+    type Hessian = O::Hessian;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        self.cached(
+            "hessian_count",
+            "hessian_cache_hits",
+            param,
+            |problem, param| problem.hessian(param),
+        )
+    }
+
+    fn bulk_hessian<P>(&self, params: &[P]) -> Result<Vec<Self::Hessian>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Hessian: SendAlias,
+        Self: SyncAlias,
+    {
+        self.bulk_cached(
+            "hessian_count",
+            "hessian_cache_hits",
+            params,
+            self.problem.parallelize(),
+            |problem, param| problem.hessian(param),
+        )
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O, K> Jacobian for CachedProblem<O, K>
+where
+    O: Jacobian,
+    O::Jacobian: Clone + Send + 'static,
+    K: CacheKey<O::Param>,
+{
+    type Param = O::Param;
+    type Jacobian = O::Jacobian;
+
+    fn jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        self.cached(
+            "jacobian_count",
+            "jacobian_cache_hits",
+            param,
+            |problem, param| problem.jacobian(param),
+        )
+    }
+
+    fn bulk_jacobian<P>(&self, params: &[P]) -> Result<Vec<Self::Jacobian>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Jacobian: SendAlias,
+        Self: SyncAlias,
+    {
+        self.bulk_cached(
+            "jacobian_count",
+            "jacobian_cache_hits",
+            params,
+            self.problem.parallelize(),
+            |problem, param| problem.jacobian(param),
+        )
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::{ArgminError, Executor, FuncEvalBudget, Problem, State};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+
+    send_sync_test!(cached_problem, CachedProblem<TestProblem, ExactKey>);
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_derivative(p))
+        }
+    }
+
+    #[test]
+    fn test_cache() {
+        let cached = CachedProblem::new(Rosenbrock {});
+        assert_relative_eq!(cached.cost(&vec![1.0, 2.0]).unwrap(), 100.0);
+        assert_relative_eq!(cached.cost(&vec![1.0, 2.0]).unwrap(), 100.0);
+        // Slightly different parameter vectors are evaluated
+        cached.cost(&vec![1.0, 2.0 + 1e-15]).unwrap();
+        assert_eq!(
+            cached.gradient(&vec![1.0, 2.0]).unwrap(),
+            vec![-400.0, 200.0]
+        );
+        assert_eq!(cached.hits("cost_count"), 1);
+        assert_eq!(cached.evaluations("cost_count"), 2);
+        assert_eq!(cached.hits("gradient_count"), 0);
+        assert_eq!(cached.evaluations("gradient_count"), 1);
+
+        // Clones share the cache
+        let clone = cached.clone();
+        clone.cost(&vec![1.0, 2.0]).unwrap();
+        assert_eq!(cached.hits("cost_count"), 2);
+
+        cached.clear();
+        cached.cost(&vec![1.0, 2.0]).unwrap();
+        assert_eq!(cached.evaluations("cost_count"), 3);
+    }
+
+    #[test]
+    fn test_rounded_key() {
+        let cached = CachedProblem::new(Rosenbrock {}).with_key(RoundedKey::new(1e-10).unwrap());
+        cached.cost(&vec![1.0, 2.0]).unwrap();
+        cached.cost(&vec![1.0, 2.0 + 1e-15]).unwrap();
+        assert_eq!(cached.hits("cost_count"), 1);
+        assert_eq!(cached.evaluations("cost_count"), 1);
+
+        assert_error!(
+            RoundedKey::new(0.0),
+            ArgminError,
+            "Invalid parameter: \"`RoundedKey`: tolerance must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_closure_key() {
+        // Only the first element matters
+        let cached = CachedProblem::new(Rosenbrock {}).with_key(|p: &Vec<f64>| p[0].to_bits());
+        cached.cost(&vec![1.0, 2.0]).unwrap();
+        assert_relative_eq!(cached.cost(&vec![1.0, 3.0]).unwrap(), 100.0);
+        assert_eq!(cached.hits("cost_count"), 1);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let cached = CachedProblem::new(Rosenbrock {}).with_capacity(2).unwrap();
+        cached.cost(&vec![1.0, 1.0]).unwrap();
+        cached.cost(&vec![2.0, 2.0]).unwrap();
+        // Use the first value such that the second one is the least recently used
+        cached.cost(&vec![1.0, 1.0]).unwrap();
+        cached.cost(&vec![3.0, 3.0]).unwrap();
+        assert_eq!(cached.evaluations("cost_count"), 3);
+        cached.cost(&vec![1.0, 1.0]).unwrap();
+        assert_eq!(cached.evaluations("cost_count"), 3);
+        cached.cost(&vec![2.0, 2.0]).unwrap();
+        assert_eq!(cached.evaluations("cost_count"), 4);
+
+        assert_error!(
+            CachedProblem::new(Rosenbrock {}).with_capacity(0),
+            ArgminError,
+            "Invalid parameter: \"`CachedProblem`: capacity must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_problem_counts() {
+        let mut problem = Problem::new(CachedProblem::new(Rosenbrock {}));
+        problem.cost(&vec![1.0, 2.0]).unwrap();
+        problem.cost(&vec![1.0, 2.0]).unwrap();
+        // Cache hits are counted separately
+        assert_eq!(problem.counts["cost_count"], 1);
+        assert_eq!(problem.counts["cost_cache_hits"], 1);
+        let cached = problem.problem.as_ref().unwrap();
+        assert_eq!(cached.hits("cost_count"), 1);
+        assert_eq!(cached.evaluations("cost_count"), 1);
+    }
+
+    #[test]
+    fn test_bulk_problem_counts() {
+        let mut problem = Problem::new(CachedProblem::new(Rosenbrock {}));
+        problem.cost(&vec![1.0, 2.0]).unwrap();
+        problem.budget = Some(Arc::new(FuncEvalBudget::new()));
+        // With the `rayon` feature, the parameter vectors are evaluated on worker threads.
+        let params = vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![1.0, 2.0],
+            vec![5.0, 6.0],
+        ];
+        let costs = problem.bulk_cost(&params).unwrap();
+        for (cost, param) in costs.iter().zip(params.iter()) {
+            assert_relative_eq!(*cost, rosenbrock(param));
+        }
+        // Cache hits are counted separately and are not charged to the budget
+        assert_eq!(problem.counts["cost_count"], 3);
+        assert_eq!(problem.counts["cost_cache_hits"], 2);
+        assert_eq!(problem.func_eval_budget().unwrap().spent("cost_count"), 2);
+        let cached = problem.problem.as_ref().unwrap();
+        assert_eq!(cached.hits("cost_count"), 2);
+        assert_eq!(cached.evaluations("cost_count"), 3);
+    }
+
+    #[test]
+    fn test_parallelize() {
+        struct Sequential {}
+
+        impl CostFunction for Sequential {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock(p))
+            }
+
+            fn parallelize(&self) -> bool {
+                false
+            }
+        }
+
+        assert!(CostFunction::parallelize(&CachedProblem::new(
+            Rosenbrock {}
+        )));
+        assert!(!CostFunction::parallelize(&CachedProblem::new(
+            Sequential {}
+        )));
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let res = Executor::new(CachedProblem::new(Rosenbrock {}), solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10).counting(true))
+            .run()
+            .unwrap();
+
+        let cached = res.problem().problem.as_ref().unwrap();
+        // The outer solver recomputes the cost at the point accepted by the line search.
+        assert!(cached.hits("cost_count") > 0);
+        let counts = res.state().get_func_counts();
+        assert_eq!(counts["cost_count"], cached.evaluations("cost_count"));
+        assert_eq!(counts["cost_cache_hits"], cached.hits("cost_count"));
+        assert!(res.state().get_best_cost() < rosenbrock(&[-1.2, 1.0]));
+    }
+
+    #[test]
+    fn test_func_eval_budget() {
+        let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let res = Executor::new(CachedProblem::new(Rosenbrock {}), solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000))
+            .func_eval_budget(FuncEvalBudget::new().with_limit("cost_count", 20))
+            .run()
+            .unwrap();
+
+        // Only actual evaluations use up the budget
+        let cached = res.problem().problem.as_ref().unwrap();
+        assert!(cached.hits("cost_count") > 0);
+        let budget = res.problem().func_eval_budget().unwrap();
+        assert_eq!(budget.spent("cost_count"), cached.evaluations("cost_count"));
+        assert_eq!(budget.spent("cost_count"), 20);
+    }
+}
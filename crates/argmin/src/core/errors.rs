@@ -63,6 +63,20 @@ pub enum ArgminError {
     },
 }
 
+/// Returns `true` if `error` interrupts an iteration which is run by
+/// [`Executor::run_async`](`crate::core::Executor::run_async`) because an evaluation of an
+/// `AsyncProblem` is pending.
+///
+/// Solvers which catch errors of the problem (or of an internal `Executor`) must pass these errors
+/// on unchanged.
+#[allow(unused_variables)]
+pub(crate) fn is_interruption(error: &anyhow::Error) -> bool {
+    #[cfg(feature = "async")]
+    return error.is::<crate::core::async_problem::EvaluationPending>();
+    #[cfg(not(feature = "async"))]
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
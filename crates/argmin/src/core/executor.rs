@@ -7,8 +7,12 @@
 
 use crate::core::checkpointing::Checkpoint;
 use crate::core::observers::{Observe, ObserverMode, Observers};
+use crate::core::termination_criteria::TerminationCriterion;
+#[cfg(feature = "async")]
+use crate::core::{async_problem::EvaluationPending, AsyncProblem};
 use crate::core::{
-    Error, OptimizationResult, Problem, Solver, State, TerminationReason, TerminationStatus, KV,
+    is_interruption, Error, FuncEvalBudget, OptimizationResult, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
 };
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -26,12 +30,23 @@ pub struct Executor<O, S, I> {
     observers: Observers<I>,
     /// Checkpoint
     checkpoint: Option<Box<dyn Checkpoint<S, I>>>,
+    /// Additional termination criteria
+    termination_criteria: Vec<Box<dyn TerminationCriterion<I>>>,
     /// Timeout
     timeout: Option<std::time::Duration>,
     /// Indicates whether Ctrl-C functionality should be active or not
     ctrlc: bool,
     /// Indicates whether to time execution or not
     timer: bool,
+    /// Indicates whether the executor has been initialized (see [`Executor::step`])
+    initialized: bool,
+    /// Set by the Ctrl-C handler
+    interrupt: Arc<AtomicBool>,
+    /// Start of the time measurement of the entire run
+    total_time: Option<Instant>,
+    /// Copies the state before each iteration (only set if a budget of function evaluations is
+    /// used)
+    snapshot: Option<fn(&I) -> I>,
 }
 
 impl<O, S, I> Executor<O, S, I>
@@ -68,9 +83,14 @@ where
             state,
             observers: Observers::new(),
             checkpoint: None,
+            termination_criteria: vec![],
             timeout: None,
             ctrlc: true,
             timer: false,
+            initialized: false,
+            interrupt: Arc::new(AtomicBool::new(false)),
+            total_time: None,
+            snapshot: None,
         }
     }
 
@@ -107,6 +127,9 @@ where
 
     /// Runs the executor by applying the solver to the optimization problem.
     ///
+    /// This is equivalent to calling [`step`](`Executor::step`) until the solver terminates,
+    /// followed by [`finalize`](`Executor::finalize`).
+    ///
     /// # Example
     ///
     /// ```
@@ -130,6 +153,239 @@ where
     /// # }
     /// ```
     pub fn run(mut self) -> Result<OptimizationResult<O, S, I>, Error> {
+        while !self.iterate()?.terminated() {}
+        self.finalize()
+    }
+
+    /// Performs a single iteration of the solver.
+    ///
+    /// The first call initializes the executor: a checkpoint is loaded (if configured), Ctrl-C
+    /// handling is set up and the solver is initialized. Each call then evaluates the termination
+    /// criteria and, unless the solver has terminated, performs one iteration including the
+    /// handling of observers, timer, timeout and checkpoints in the same way as
+    /// [`run`](`Executor::run`) does. Since the termination criteria are evaluated at the
+    /// beginning of each step, the state can be inspected and modified between steps via
+    /// [`state`](`Executor::state`) and [`modify_state`](`Executor::modify_state`).
+    ///
+    /// Returns the termination status of the state after the step. Once terminated, further
+    /// calls do not perform any iterations. Call [`finalize`](`Executor::finalize`) to obtain the
+    /// [`OptimizationResult`].
+    ///
+    /// If the step fails (for instance because the solver or an observer returns an error), the
+    /// error is returned and the state is reset to the state before the step. The executor
+    /// therefore remains usable: the state can be inspected or modified and the step can be
+    /// retried. Note that the solver itself may have changed its internal state during the failed
+    /// step. In order to reset the state, a copy of it is made in every step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// #
+    /// let mut executor = Executor::new(problem, solver)
+    ///     .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(10));
+    ///
+    /// while !executor.step()?.terminated() {
+    ///     // Inspect the state between iterations
+    ///     let iter = executor.state().get_iter();
+    ///     # assert!(iter <= 10);
+    /// }
+    ///
+    /// let result = executor.finalize()?;
+    /// # assert_eq!(result.state().get_iter(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn step(&mut self) -> Result<TerminationStatus, Error>
+    where
+        I: Clone,
+    {
+        let backup = self.state.clone();
+        let initialized = self.initialized;
+        let status = self.iterate();
+        if status.is_err() {
+            self.state = backup;
+            self.initialized = initialized;
+        }
+        status
+    }
+
+    /// Performs a single iteration of the solver (see [`step`](`Executor::step`)). In case of an
+    /// error, the state is lost.
+    fn iterate(&mut self) -> Result<TerminationStatus, Error> {
+        if !self.initialized {
+            self.initialize()?;
+        }
+
+        let state = self.state.take().unwrap();
+        let state = self.check_termination(state);
+        if state.terminated() {
+            let status = state.get_termination_status().clone();
+            self.state = Some(state);
+            return Ok(status);
+        }
+
+        // Start time measurement
+        let start = if self.timer {
+            Some(Instant::now())
+        } else {
+            None
+        };
+
+        let backup = self.snapshot.map(|snapshot| snapshot(&state));
+        let (state, kv) = match self.solver.next_iter(&mut self.problem, state) {
+            Ok(res) => res,
+            Err(e) => {
+                let state = self.recover(backup, e)?;
+                let status = state.get_termination_status().clone();
+                self.state = Some(state);
+                return Ok(status);
+            }
+        };
+
+        // End time measurement
+        let duration = start.map(|start| start.elapsed());
+
+        let state = self.finish_iter(state, kv, duration)?;
+        let status = state.get_termination_status().clone();
+        self.state = Some(state);
+        Ok(status)
+    }
+
+    /// Finalizes the execution and returns the [`OptimizationResult`].
+    ///
+    /// Calls the final observers. The executor is initialized first if no step has been
+    /// performed yet. Finalizing before the solver terminated is allowed; in that case the
+    /// termination status of the returned state is `NotTerminated`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// #
+    /// let mut executor = Executor::new(problem, solver)
+    ///     .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(10));
+    ///
+    /// // Perform only 3 iterations
+    /// for _ in 0..3 {
+    ///     executor.step()?;
+    /// }
+    ///
+    /// let result = executor.finalize()?;
+    /// # assert_eq!(result.state().get_iter(), 3);
+    /// # assert!(!result.state().terminated());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finalize(mut self) -> Result<OptimizationResult<O, S, I>, Error> {
+        if !self.initialized {
+            self.initialize()?;
+        }
+
+        let mut state = self.state.take().unwrap();
+
+        if self.interrupt.load(Ordering::SeqCst) {
+            // Solver execution has been interrupted manually
+            state = state.terminate_with(TerminationReason::Interrupt);
+        }
+
+        if !self.observers.is_empty() {
+            self.observers.observe_final(&state)?;
+        }
+
+        Ok(OptimizationResult::new(self.problem, self.solver, state))
+    }
+
+    /// Returns a reference to the current state.
+    ///
+    /// Before the first [`step`](`Executor::step`), this is the state as set up via
+    /// [`configure`](`Executor::configure`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Executor, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// #
+    /// let executor = Executor::new(problem, solver)
+    ///     .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(10));
+    ///
+    /// assert_eq!(executor.state().get_max_iters(), 10);
+    /// ```
+    pub fn state(&self) -> &I {
+        self.state.as_ref().unwrap()
+    }
+
+    /// Modifies the current state, for instance in between two calls to
+    /// [`step`](`Executor::step`).
+    ///
+    /// Works like [`configure`](`Executor::configure`), but does not consume the executor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// #
+    /// let mut executor = Executor::new(problem, solver)
+    ///     .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(10));
+    ///
+    /// executor.step()?;
+    ///
+    /// // Move the current parameter vector and allow for more iterations
+    /// executor.modify_state(|state| state.param(vec![0.5, 0.5]).max_iters(20));
+    /// # assert_eq!(executor.state().get_max_iters(), 20);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn modify_state<F: FnOnce(I) -> I>(&mut self, modify: F) {
+        let state = self.state.take().unwrap();
+        self.state = Some(modify(state));
+    }
+
+    /// Loads the checkpoint (if any), sets up Ctrl-C handling and initializes the solver.
+    fn initialize(&mut self) -> Result<(), Error> {
+        let state = self.prepare()?;
+
+        // Only call `init` of `solver` if the current iteration number is 0. This avoids that
+        // `init` is called when starting from a checkpoint (because `init` could change the state
+        // of the `solver`, which would overwrite the state restored from the checkpoint).
+        let state = if state.get_iter() == 0 {
+            let backup = self.snapshot.map(|snapshot| snapshot(&state));
+            match self.solver.init(&mut self.problem, state) {
+                Ok((state, kv)) => self.finish_init(state, kv)?,
+                Err(e) => self.recover(backup, e)?,
+            }
+        } else {
+            state
+        };
+        self.state = Some(state);
+        Ok(())
+    }
+
+    /// Loads the checkpoint (if any), starts the timer and sets up Ctrl-C handling.
+    ///
+    /// Returns the state, which is taken out of the executor.
+    fn prepare(&mut self) -> Result<I, Error> {
+        self.initialized = true;
+
         // First, load checkpoint if given.
         if let Some(checkpoint) = self.checkpoint.as_ref() {
             if let Some((solver, state)) = checkpoint.load()? {
@@ -137,21 +393,17 @@ where
                 self.solver = solver;
             }
         }
-        let total_time = if self.timer {
+        self.total_time = if self.timer {
             Some(Instant::now())
         } else {
             None
         };
 
-        let state = self.state.take().unwrap();
-
-        let interrupt = Arc::new(AtomicBool::new(false));
-
         if self.ctrlc {
             #[cfg(feature = "ctrlc")]
             {
                 // Set up the Ctrl-C handler
-                let interp = interrupt.clone();
+                let interp = self.interrupt.clone();
                 // This is currently a hack to allow checkpoints to be run again within the
                 // same program (usually not really a use case anyway). Unfortunately, this
                 // means that any subsequent run started afterwards will not have Ctrl-C
@@ -169,118 +421,127 @@ where
             }
         }
 
-        // Only call `init` of `solver` if the current iteration number is 0. This avoids that
-        // `init` is called when starting from a checkpoint (because `init` could change the state
-        // of the `solver`, which would overwrite the state restored from the checkpoint).
-        let mut state = if state.get_iter() == 0 {
-            let (mut state, kv) = self.solver.init(&mut self.problem, state)?;
-            state.update();
-
-            if !self.observers.is_empty() {
-                let kv = kv.unwrap_or(kv![]);
-
-                // Observe after init
-                self.observers
-                    .observe_init(self.solver.name(), &state, &kv)?;
-            }
-
-            state.func_counts(&self.problem);
-            state
-        } else {
-            state
-        };
-
-        while !interrupt.load(Ordering::SeqCst) {
-            // check first if it has already terminated
-            // This should probably be solved better.
-            // First, check if it isn't already terminated. If it isn't, evaluate the
-            // stopping criteria. If `self.terminate()` is called without the checking
-            // whether it has terminated already, then it may overwrite a termination set
-            // within `next_iter()`!
-            state = if !state.terminated() {
-                let term = self.solver.terminate_internal(&state);
-                if let TerminationStatus::Terminated(reason) = term {
-                    state.terminate_with(reason)
-                } else {
-                    state
-                }
-            } else {
-                state
-            };
-            // Now check once more if the algorithm has terminated. If yes, then break.
-            if state.terminated() {
-                break;
-            }
+        Ok(self.state.take().unwrap())
+    }
 
-            // Start time measurement
-            let start = if self.timer {
-                Some(Instant::now())
-            } else {
-                None
-            };
+    /// Processes the state returned by the `init` method of the solver.
+    fn finish_init(&mut self, mut state: I, kv: Option<KV>) -> Result<I, Error> {
+        state.update();
 
-            let (state_t, kv) = self.solver.next_iter(&mut self.problem, state)?;
-            state = state_t;
+        if !self.observers.is_empty() {
+            let kv = kv.unwrap_or(kv![]);
 
-            state.func_counts(&self.problem);
+            // Observe after init
+            self.observers
+                .observe_init(self.solver.name(), &state, &kv)?;
+        }
 
-            // End time measurement
-            let duration = if self.timer {
-                Some(start.unwrap().elapsed())
-            } else {
-                None
-            };
+        state.func_counts(&self.problem);
+        Ok(state)
+    }
 
-            state.update();
+    /// Evaluates the termination criteria unless the state has already terminated.
+    fn check_termination(&mut self, mut state: I) -> I {
+        if self.interrupt.load(Ordering::SeqCst) {
+            // Solver execution has been interrupted manually
+            state = state.terminate_with(TerminationReason::Interrupt);
+        }
 
-            if !self.observers.is_empty() {
-                let mut log = if let Some(kv) = kv { kv } else { KV::new() };
+        // Stop if the budget of function evaluations is exhausted
+        if !state.terminated()
+            && self
+                .problem
+                .func_eval_budget()
+                .map(|budget| budget.is_exhausted())
+                .unwrap_or(false)
+        {
+            state = state.terminate_with(TerminationReason::MaxFuncEvalsReached);
+        }
 
-                if self.timer {
-                    let duration = duration.unwrap();
-                    let tmp = kv!(
-                        "time" => duration.as_secs_f64();
-                    );
-                    log = log.merge(tmp);
+        // First, check if it isn't already terminated. If it isn't, evaluate the stopping
+        // criteria. If `self.terminate()` is called without the checking whether it has
+        // terminated already, then it may overwrite a termination set within `next_iter()`!
+        if !state.terminated() {
+            if let TerminationStatus::Terminated(reason) = self.solver.terminate_internal(&state) {
+                state = state.terminate_with(reason);
+            }
+        }
+        // Finally, evaluate the additional termination criteria
+        if !state.terminated() {
+            for criterion in self.termination_criteria.iter_mut() {
+                if let TerminationStatus::Terminated(reason) = criterion.check(&state) {
+                    state = state.terminate_with(reason);
+                    break;
                 }
-                self.observers.observe_iter(&state, &log)?;
             }
+        }
+        state
+    }
 
-            // increment iteration number
-            state.increment_iter();
-
-            if let Some(checkpoint) = self.checkpoint.as_ref() {
-                checkpoint.save_cond(&self.solver, &state, state.get_iter())?;
+    /// Handles an error returned by the solver.
+    ///
+    /// If the error was caused by an exhausted budget of function evaluations, the state before
+    /// the failed call (`backup`) is terminated with `MaxFuncEvalsReached` and returned.
+    /// Otherwise the error is passed on. Requests of evaluations of an `AsyncProblem` which have
+    /// not been awaited yet are always passed on, even if they used up the budget.
+    fn recover(&self, backup: Option<I>, error: Error) -> Result<I, Error> {
+        if is_interruption(&error) {
+            return Err(error);
+        }
+        match (backup, self.problem.func_eval_budget()) {
+            (Some(mut state), Some(budget)) if budget.is_exhausted() => {
+                state.func_counts(&self.problem);
+                Ok(state.terminate_with(TerminationReason::MaxFuncEvalsReached))
             }
+            _ => Err(error),
+        }
+    }
 
-            if self.timer {
-                // Increase accumulated total_time
-                total_time.map(|total_time| state.time(Some(total_time.elapsed())));
+    /// Processes the state returned by the `next_iter` method of the solver: updates function
+    /// counts and best parameters, calls observers, increments the iteration number, saves
+    /// checkpoints and checks for timeouts.
+    fn finish_iter(
+        &mut self,
+        mut state: I,
+        kv: Option<KV>,
+        duration: Option<std::time::Duration>,
+    ) -> Result<I, Error> {
+        state.func_counts(&self.problem);
 
-                // If a timeout is set, check if timeout is reached
-                if let (Some(timeout), Some(total_time)) = (self.timeout, total_time) {
-                    if total_time.elapsed() > timeout {
-                        state = state.terminate_with(TerminationReason::Timeout);
-                    }
-                }
-            }
+        state.update();
 
-            // Check if termination occurred in the meantime
-            if state.terminated() {
-                break;
+        if !self.observers.is_empty() {
+            let mut log = if let Some(kv) = kv { kv } else { KV::new() };
+
+            if let Some(duration) = duration {
+                let tmp = kv!(
+                    "time" => duration.as_secs_f64();
+                );
+                log = log.merge(tmp);
             }
+            self.observers.observe_iter(&state, &log)?;
         }
 
-        if interrupt.load(Ordering::SeqCst) {
-            // Solver execution has been interrupted manually
-            state = state.terminate_with(TerminationReason::Interrupt);
-        }
+        // increment iteration number
+        state.increment_iter();
 
-        if !self.observers.is_empty() {
-            self.observers.observe_final(&state)?;
+        if let Some(checkpoint) = self.checkpoint.as_ref() {
+            checkpoint.save_cond(&self.solver, &state, state.get_iter())?;
         }
 
-        Ok(OptimizationResult::new(self.problem, self.solver, state))
+        if self.timer {
+            // Increase accumulated total_time
+            self.total_time
+                .map(|total_time| state.time(Some(total_time.elapsed())));
+
+            // If a timeout is set, check if timeout is reached
+            if let (Some(timeout), Some(total_time)) = (self.timeout, self.total_time) {
+                if total_time.elapsed() > timeout {
+                    state = state.terminate_with(TerminationReason::Timeout);
+                }
+            }
+        }
+        Ok(state)
     }
 
     /// Adds an observer to the executor. Observers are required to implement the
@@ -317,6 +578,112 @@ where
         self
     }
 
+    /// Adds a termination criterion to the executor. Termination criteria are required to
+    /// implement the [`TerminationCriterion`] trait. See
+    /// [`termination_criteria`](`crate::core::termination_criteria`) for the available criteria
+    /// and how to combine them.
+    ///
+    /// The criteria are checked before each iteration after the termination criteria of the
+    /// solver. It is possible to add multiple criteria, in which case the optimization terminates
+    /// as soon as one of them is met.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// use argmin::core::termination_criteria::{CostChange, Stall};
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// #
+    /// // Create instance of `Executor` with `problem` and `solver`
+    /// let executor = Executor::new(problem, solver)
+    ///     .add_termination_criterion(CostChange::relative(1e-8)?.or(Stall::new(20)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn add_termination_criterion<C: TerminationCriterion<I> + 'static>(
+        mut self,
+        criterion: C,
+    ) -> Self {
+        self.termination_criteria.push(Box::new(criterion));
+        self
+    }
+
+    /// Sets a budget of function evaluations (see [`FuncEvalBudget`]).
+    ///
+    /// The budget is checked before every evaluation of the problem. Once it is exhausted, the
+    /// optimization terminates with [`TerminationReason::MaxFuncEvalsReached`]. If an evaluation
+    /// is refused in the middle of an iteration, the state from before that iteration is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, FuncEvalBudget};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// #
+    /// // Create instance of `Executor` with `problem` and `solver`
+    /// let executor = Executor::new(problem, solver)
+    ///     .func_eval_budget(
+    ///         FuncEvalBudget::new()
+    ///             .with_weight("gradient_count", 3.0)?
+    ///             .with_total(1000.0)?
+    ///     );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn func_eval_budget(mut self, budget: FuncEvalBudget) -> Self
+    where
+        I: Clone,
+    {
+        self.problem.budget = Some(Arc::new(budget));
+        self.snapshot = Some(I::clone);
+        self
+    }
+
+    /// Shares the budget of function evaluations of `problem` (if any) with this executor.
+    ///
+    /// This is intended for solvers which run another solver internally (for instance a line
+    /// search): evaluations of the nested executor count towards the budget of the outer one and
+    /// the nested executor stops once the budget is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, FuncEvalBudget, Problem};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// // `problem` is the `Problem` passed to `next_iter` of the outer solver
+    /// # let mut problem = Problem::new(TestProblem::new());
+    /// let executor = Executor::new(problem.take_problem().unwrap(), solver)
+    ///     .inherit_func_eval_budget(&problem)
+    ///     .ctrlc(false);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn inherit_func_eval_budget<O2>(mut self, problem: &Problem<O2>) -> Self
+    where
+        I: Clone,
+    {
+        if let Some(budget) = problem.budget.as_ref() {
+            self.problem.budget = Some(Arc::clone(budget));
+            self.snapshot = Some(I::clone);
+        }
+        self
+    }
+
     /// Configures checkpointing
     ///
     /// # Example
@@ -439,6 +806,198 @@ where
         self.timeout = Some(timeout);
         self
     }
+
+    /// Replaces the interrupt flag with `interrupt` and disables CTRL-C handling.
+    ///
+    /// Used by [`BatchExecutor`](`crate::core::BatchExecutor`) to interrupt all runs via a single
+    /// CTRL-C handler.
+    #[must_use]
+    pub(crate) fn share_interrupt(mut self, interrupt: Arc<AtomicBool>) -> Self {
+        self.ctrlc = false;
+        self.interrupt = interrupt;
+        self
+    }
+}
+
+#[cfg(feature = "async")]
+impl<O, S, I> Executor<AsyncProblem<O>, S, I>
+where
+    S: Solver<AsyncProblem<O>, I> + Clone,
+    I: State + Clone,
+{
+    /// Runs the executor on a problem wrapped in an [`AsyncProblem`] and awaits the evaluations
+    /// of the problem.
+    ///
+    /// Behaves like [`run`](`Executor::run`), but runs on the calling task and awaits the
+    /// futures returned by the [`AsyncCostFunction`](`crate::core::AsyncCostFunction`) and
+    /// [`AsyncGradient`](`crate::core::AsyncGradient`) implementations of the problem. No
+    /// threads are blocked while waiting for evaluations and no particular async runtime is
+    /// required. The futures do not need to be `Send`.
+    ///
+    /// Since the solvers are synchronous, an iteration is interrupted whenever the solver
+    /// requests an evaluation which has not been awaited yet. After awaiting the evaluation, the
+    /// iteration is repeated from the beginning with a copy of the solver and the state as they
+    /// were before the iteration; evaluations which have already been awaited are answered from
+    /// the stored results (matched by parameter vector) and do not count as additional function
+    /// evaluations. Therefore the solver and the state need to implement `Clone`. Bulk
+    /// evaluations are awaited as a whole; they are run concurrently by default. The computations
+    /// of the solver itself run on the calling task.
+    ///
+    /// The interruption is passed to the solver as an error. If the solver does not pass it on
+    /// unchanged (for instance because it catches or wraps errors of the problem), an error is
+    /// returned. See [`AsyncProblem`] for details.
+    ///
+    /// Requires the `async` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{AsyncCostFunction, AsyncGradient, AsyncProblem, Error, Executor};
+    /// # use argmin::solver::gradientdescent::SteepestDescent;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+    /// #
+    /// # struct Simulation {}
+    /// #
+    /// # impl AsyncCostFunction for Simulation {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = f64;
+    /// #
+    /// #     async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(rosenbrock(param))
+    /// #     }
+    /// # }
+    /// #
+    /// # impl AsyncGradient for Simulation {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #
+    /// #     async fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+    /// #         Ok(rosenbrock_derivative(param))
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+    ///
+    /// let result = Executor::new(AsyncProblem::new(Simulation {}), solver)
+    ///     .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10))
+    ///     .run_async()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_async(mut self) -> Result<OptimizationResult<AsyncProblem<O>, S, I>, Error> {
+        let mut state = self.prepare()?;
+        self.async_problem().activate(true);
+
+        // Only call `init` of `solver` if the current iteration number is 0 (see `initialize`).
+        if state.get_iter() == 0 {
+            let backup = self.snapshot.map(|snapshot| snapshot(&state));
+            state = match self
+                .repeat_until_evaluated(state, |solver, problem, state| solver.init(problem, state))
+                .await
+            {
+                Ok((new_state, kv)) => self.finish_init(new_state, kv)?,
+                Err(e) => self.recover(backup, e)?,
+            };
+        }
+
+        loop {
+            state = self.check_termination(state);
+            if state.terminated() {
+                break;
+            }
+
+            // Start time measurement
+            let start = if self.timer {
+                Some(Instant::now())
+            } else {
+                None
+            };
+
+            let backup = self.snapshot.map(|snapshot| snapshot(&state));
+            let (new_state, kv) = match self
+                .repeat_until_evaluated(state, |solver, problem, state| {
+                    solver.next_iter(problem, state)
+                })
+                .await
+            {
+                Ok(res) => res,
+                Err(e) => {
+                    state = self.recover(backup, e)?;
+                    continue;
+                }
+            };
+
+            // End time measurement
+            let duration = start.map(|start| start.elapsed());
+
+            state = self.finish_iter(new_state, kv, duration)?;
+        }
+
+        self.async_problem().activate(false);
+        self.state = Some(state);
+        self.finalize()
+    }
+
+    /// Calls `func` on a copy of the solver and `state` until it no longer requests evaluations
+    /// which have not been awaited yet. In between, the requested evaluation is awaited and the
+    /// function evaluation counts and the budget are reset to their values before the first
+    /// call. Returns an error if the solver does not pass on the interruption.
+    async fn repeat_until_evaluated<F>(
+        &mut self,
+        state: I,
+        func: F,
+    ) -> Result<(I, Option<KV>), Error>
+    where
+        F: Fn(&mut S, &mut Problem<AsyncProblem<O>>, I) -> Result<(I, Option<KV>), Error>,
+    {
+        let counts = self.problem.counts.clone();
+        let spent = self.problem.budget.as_ref().map(|budget| budget.snapshot());
+        let async_problem = self.async_problem().share();
+        loop {
+            let mut solver = self.solver.clone();
+            let res = func(&mut solver, &mut self.problem, state.clone());
+            // Solvers which temporarily take the problem out of `self.problem` (for instance in
+            // order to pass it to a line search) do not put it back if they are interrupted.
+            if self.problem.problem.is_none() {
+                self.problem.problem = Some(async_problem.share());
+            }
+            match res {
+                Err(e) if e.is::<EvaluationPending>() => {
+                    self.problem.counts = counts.clone();
+                    if let (Some(budget), Some(spent)) = (self.problem.budget.as_ref(), &spent) {
+                        budget.restore(spent.clone());
+                    }
+                    self.async_problem().await_pending().await?;
+                }
+                _ if self.async_problem().is_pending() => {
+                    return Err(argmin_error!(
+                        ConditionViolated,
+                        format!(
+                            concat!(
+                                "`Executor::run_async`: solver `{}` did not pass on the ",
+                                "interruption by a pending evaluation of `AsyncProblem`."
+                            ),
+                            solver.name()
+                        )
+                    ));
+                }
+                res => {
+                    self.async_problem().clear();
+                    self.solver = solver;
+                    return res;
+                }
+            }
+        }
+    }
+
+    /// Returns the wrapped problem.
+    fn async_problem(&self) -> &AsyncProblem<O> {
+        self.problem.problem.as_ref().unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -763,6 +1322,356 @@ mod tests {
         let _ = std::fs::remove_file(".checkpoints/init_test.arg");
     }
 
+    #[test]
+    fn test_step() {
+        let problem = TestProblem::new();
+        let solver = TestSolver::new();
+
+        let mut executor = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(5));
+        assert!(!executor.initialized);
+
+        let mut steps = 0;
+        while !executor.step().unwrap().terminated() {
+            steps += 1;
+            assert_eq!(executor.state().get_iter(), steps);
+        }
+        assert!(executor.initialized);
+        assert_eq!(steps, 5);
+        assert_eq!(
+            executor.state().get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+
+        // Further steps do not perform any iterations
+        let status = executor.step().unwrap();
+        assert_eq!(
+            status,
+            TerminationStatus::Terminated(TerminationReason::MaxItersReached)
+        );
+        assert_eq!(executor.state().get_iter(), 5);
+
+        let result = executor.finalize().unwrap();
+        let expected = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(5))
+            .run()
+            .unwrap();
+        assert_eq!(result.state().get_iter(), expected.state().get_iter());
+        assert_eq!(
+            result.state().get_termination_status(),
+            expected.state().get_termination_status()
+        );
+        assert_eq!(result.state().get_param(), expected.state().get_param());
+    }
+
+    #[test]
+    fn test_step_modify_state() {
+        let problem = TestProblem::new();
+        let solver = TestSolver::new();
+
+        let mut executor = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(2));
+
+        assert!(!executor.step().unwrap().terminated());
+        assert!(!executor.step().unwrap().terminated());
+
+        // Modifications are taken into account by the next step
+        executor.modify_state(|state| state.param(vec![2.0, 3.0]).max_iters(3));
+        assert!(!executor.step().unwrap().terminated());
+        assert!(executor.step().unwrap().terminated());
+
+        let result = executor.finalize().unwrap();
+        assert_eq!(result.state().get_iter(), 3);
+        assert_eq!(result.state().get_param(), Some(&vec![2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_step_after_error() {
+        use crate::core::observers::{Observe, ObserverMode};
+        use crate::core::ArgminError;
+
+        /// Fails if the first element of the parameter vector is negative
+        #[derive(Clone)]
+        struct NonNegative {}
+
+        impl<O> Solver<O, IterState<Vec<f64>, (), (), (), (), f64>> for NonNegative {
+            fn name(&self) -> &str {
+                "NonNegative"
+            }
+
+            fn next_iter(
+                &mut self,
+                _problem: &mut Problem<O>,
+                state: IterState<Vec<f64>, (), (), (), (), f64>,
+            ) -> Result<(IterState<Vec<f64>, (), (), (), (), f64>, Option<KV>), Error> {
+                if state.get_param().unwrap()[0] < 0.0 {
+                    return Err(argmin_error!(InvalidParameter, "Negative parameter"));
+                }
+                Ok((state, None))
+            }
+        }
+
+        /// Fails once when observing the iteration with number `iter`
+        struct FailOnce {
+            iter: u64,
+            failed: bool,
+        }
+
+        impl<I: State> Observe<I> for FailOnce {
+            fn observe_iter(&mut self, state: &I, _kv: &KV) -> Result<(), Error> {
+                if state.get_iter() == self.iter && !self.failed {
+                    self.failed = true;
+                    return Err(argmin_error!(PotentialBug, "Observer failed"));
+                }
+                Ok(())
+            }
+        }
+
+        let mut executor = Executor::new(TestProblem::new(), NonNegative {})
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(10))
+            .add_observer(
+                FailOnce {
+                    iter: 1,
+                    failed: false,
+                },
+                ObserverMode::Always,
+            );
+
+        executor.step().unwrap();
+        assert_eq!(executor.state().get_iter(), 1);
+
+        // Failing observer: the state is reset to the state before the step
+        assert_error!(
+            executor.step(),
+            ArgminError,
+            "Potential bug: \"Observer failed\". This is potentially a bug. Please file a report on https://github.com/argmin-rs/argmin/issues"
+        );
+        assert_eq!(executor.state().get_iter(), 1);
+        executor.step().unwrap();
+        assert_eq!(executor.state().get_iter(), 2);
+
+        // Failing solver
+        executor.modify_state(|state| state.param(vec![-1.0, 0.0]));
+        assert_error!(
+            executor.step(),
+            ArgminError,
+            "Invalid parameter: \"Negative parameter\""
+        );
+        assert_eq!(executor.state().get_iter(), 2);
+        assert_eq!(executor.state().get_param(), Some(&vec![-1.0, 0.0]));
+
+        executor.modify_state(|state| state.param(vec![2.0, 0.0]));
+        executor.step().unwrap();
+        let result = executor.finalize().unwrap();
+        assert_eq!(result.state().get_iter(), 3);
+        assert_eq!(result.state().get_param(), Some(&vec![2.0, 0.0]));
+    }
+
+    #[test]
+    fn test_finalize_without_step() {
+        let problem = TestProblem::new();
+        let solver = TestSolver::new();
+
+        let result = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(5))
+            .finalize()
+            .unwrap();
+        assert_eq!(result.state().get_iter(), 0);
+        assert!(!result.state().terminated());
+        // `update` was called during initialization
+        assert_eq!(result.state().get_best_param(), Some(&vec![1.0, 0.0]));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_run_async() {
+        let problem = TestProblem::new();
+        let solver = TestSolver::new();
+
+        let result = Executor::new(AsyncProblem::new(problem), solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(5))
+            .timer(true)
+            .run_async()
+            .await
+            .unwrap();
+        let expected = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(5))
+            .run()
+            .unwrap();
+        assert_eq!(result.state().get_iter(), expected.state().get_iter());
+        assert_eq!(
+            result.state().get_termination_status(),
+            expected.state().get_termination_status()
+        );
+        assert_eq!(result.state().get_param(), expected.state().get_param());
+        assert!(result.state().get_time().is_some());
+    }
+
+    #[test]
+    fn test_termination_criteria() {
+        use crate::core::termination_criteria::{MaxFuncEvals, ParamChange, Stall};
+
+        let problem = TestProblem::new();
+        let solver = TestSolver::new();
+
+        // `TestSolver` does not change the parameter vector
+        let result = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(10))
+            .add_termination_criterion(ParamChange::new(0.0).unwrap())
+            .run()
+            .unwrap();
+        assert_eq!(result.state().get_iter(), 1);
+        assert_eq!(
+            result.state().get_termination_reason(),
+            Some(&TerminationReason::ParamChangeToleranceReached)
+        );
+
+        let result = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).cost(1.0).max_iters(10))
+            .add_termination_criterion(MaxFuncEvals::new(1))
+            .add_termination_criterion(Stall::new(4).and(Stall::new(3)))
+            .run()
+            .unwrap();
+        assert_eq!(result.state().get_iter(), 4);
+        assert_eq!(
+            result.state().get_termination_reason(),
+            Some(&TerminationReason::Stalled)
+        );
+
+        // Solver termination criteria take precedence
+        let result = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(2))
+            .add_termination_criterion(Stall::new(2))
+            .run()
+            .unwrap();
+        assert_eq!(
+            result.state().get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+    }
+
+    /// Rosenbrock function used for the tests of the budget of function evaluations
+    struct Rosenbrock {}
+
+    impl crate::core::CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(argmin_testfunctions::rosenbrock(p))
+        }
+    }
+
+    impl crate::core::Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(argmin_testfunctions::rosenbrock_derivative(p))
+        }
+    }
+
+    #[test]
+    fn test_func_eval_budget_limit() {
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let result = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100).counting(true))
+            .func_eval_budget(FuncEvalBudget::new().with_limit("gradient_count", 10))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            result.state().get_termination_reason(),
+            Some(&TerminationReason::MaxFuncEvalsReached)
+        );
+        assert!(result.state().get_iter() < 100);
+        assert!(result.state().get_best_cost() < 24.2);
+
+        // Evaluations of the nested line searches are accounted for
+        let budget = result.problem().func_eval_budget().unwrap();
+        assert_eq!(budget.spent("gradient_count"), 10);
+        assert_eq!(
+            budget.spent("gradient_count"),
+            result.problem().counts["gradient_count"]
+        );
+        assert_eq!(
+            budget.spent("cost_count"),
+            result.problem().counts["cost_count"]
+        );
+        assert_eq!(result.state().get_func_counts()["gradient_count"], 10);
+    }
+
+    #[test]
+    fn test_func_eval_budget_weighted_total() {
+        use crate::solver::gradientdescent::SteepestDescent;
+        use crate::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+
+        let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap());
+        let solver = SteepestDescent::new(linesearch);
+        let result = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000).counting(true))
+            .func_eval_budget(
+                FuncEvalBudget::new()
+                    .with_weight("gradient_count", 3.0)
+                    .unwrap()
+                    .with_total(100.0)
+                    .unwrap(),
+            )
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            result.state().get_termination_reason(),
+            Some(&TerminationReason::MaxFuncEvalsReached)
+        );
+        let counts = &result.problem().counts;
+        let total = counts.get("cost_count").copied().unwrap_or(0) as f64
+            + 3.0 * counts["gradient_count"] as f64;
+        assert!(total <= 100.0);
+        assert_relative_eq!(result.problem().func_eval_budget().unwrap().total(), total);
+        assert!(result.state().get_best_cost() < 24.2);
+    }
+
+    #[test]
+    fn test_func_eval_budget_exhausted_in_init() {
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let result = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+            .func_eval_budget(FuncEvalBudget::new().with_limit("cost_count", 0))
+            .run()
+            .unwrap();
+
+        assert_eq!(result.state().get_iter(), 0);
+        assert_eq!(
+            result.state().get_termination_reason(),
+            Some(&TerminationReason::MaxFuncEvalsReached)
+        );
+    }
+
+    #[test]
+    fn test_func_eval_budget_cost_and_gradient() {
+        let mut problem = Problem::new(Rosenbrock {});
+        problem.budget = Some(std::sync::Arc::new(
+            FuncEvalBudget::new().with_limit("gradient_count", 1),
+        ));
+        let param = vec![-1.2, 1.0];
+        problem.cost_and_gradient(&param).unwrap();
+        assert!(problem.cost_and_gradient(&param).is_err());
+
+        // The refused call neither counts nor charges the cost function evaluation
+        assert_eq!(problem.counts["cost_count"], 1);
+        assert_eq!(problem.counts["gradient_count"], 1);
+        let budget = problem.func_eval_budget().unwrap();
+        assert_eq!(budget.spent("cost_count"), 1);
+        assert_eq!(budget.spent("gradient_count"), 1);
+    }
+
     #[test]
     fn test_timeout() {
         let solver = TestSolver::new();
@@ -0,0 +1,770 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    count_evaluations, CostFunction, Error, Gradient, Hessian, Jacobian, Operator, SendAlias,
+    SyncAlias,
+};
+use num_traits::{Float, FromPrimitive};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::ops::AddAssign;
+
+#[cfg(feature = "finitediff-nalgebra")]
+use argmin_math::nalgebra;
+#[cfg(feature = "finitediff-ndarray")]
+use argmin_math::ndarray;
+
+/// Finite difference scheme used by [`FiniteDiffGradient`]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FiniteDiffMethod {
+    /// Forward differences
+    ///
+    /// Gradients and Jacobians require `n+1` evaluations, Hessians `(n+1)*(n+2)/2` evaluations
+    /// (for parameter vectors of dimension `n`). Hessians are rather inaccurate.
+    #[default]
+    Forward,
+    /// Central differences
+    ///
+    /// More accurate, but gradients and Jacobians require `2*n` evaluations and Hessians (central
+    /// differences of the approximated gradient) `4*n*n` evaluations.
+    Central,
+}
+
+/// Parameter vectors for which derivatives can be approximated with the `finitediff` crate
+///
+/// Implemented for `Vec<F>` and `[F; N]`, for `ndarray::Array1<F>` if the `finitediff-ndarray`
+/// feature is enabled and for `nalgebra::DVector<F>` if the `finitediff-nalgebra` feature is
+/// enabled. These features enable the latest `ndarray` and `nalgebra` versions supported by
+/// `argmin-math` (`ndarray_latest-nolinalg` and `nalgebra_latest`, respectively).
+pub trait FiniteDiffParam: Sized {
+    /// Type of the elements of the parameter vector
+    type Float;
+    /// Type of Jacobians and Hessians
+    type Matrix;
+
+    /// Approximates the gradient of `func` at `self`
+    fn gradient(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self::Float, Error>,
+    ) -> Result<Self, Error>;
+
+    /// Approximates the Jacobian of `func` at `self`
+    fn jacobian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self, Error>,
+    ) -> Result<Self::Matrix, Error>;
+
+    /// Approximates the Hessian of `func` at `self`
+    fn hessian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self::Float, Error>,
+    ) -> Result<Self::Matrix, Error>;
+}
+
+impl<F> FiniteDiffParam for Vec<F>
+where
+    F: Float + FromPrimitive + AddAssign,
+{
+    type Float = F;
+    type Matrix = Vec<Vec<F>>;
+
+    fn gradient(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::vec::forward_diff(func)(self),
+            FiniteDiffMethod::Central => finitediff::vec::central_diff(func)(self),
+        }
+    }
+
+    fn jacobian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::vec::forward_jacobian(func)(self),
+            FiniteDiffMethod::Central => finitediff::vec::central_jacobian(func)(self),
+        }
+    }
+
+    fn hessian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::vec::forward_hessian_nograd(func)(self),
+            FiniteDiffMethod::Central => {
+                let grad = finitediff::vec::central_diff(func);
+                let hessian = finitediff::vec::central_hessian(&grad);
+                hessian(self)
+            }
+        }
+    }
+}
+
+impl<F, const N: usize> FiniteDiffParam for [F; N]
+where
+    F: Float + FromPrimitive + AddAssign,
+{
+    type Float = F;
+    type Matrix = [[F; N]; N];
+
+    fn gradient(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::array::forward_diff(func)(self),
+            FiniteDiffMethod::Central => finitediff::array::central_diff(func)(self),
+        }
+    }
+
+    fn jacobian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::array::forward_jacobian(func)(self),
+            FiniteDiffMethod::Central => finitediff::array::central_jacobian(func)(self),
+        }
+    }
+
+    fn hessian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::array::forward_hessian_nograd(func)(self),
+            FiniteDiffMethod::Central => {
+                let grad = finitediff::array::central_diff(func);
+                let hessian = finitediff::array::central_hessian(&grad);
+                hessian(self)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "finitediff-ndarray")]
+impl<F> FiniteDiffParam for ndarray::Array1<F>
+where
+    F: Float + FromPrimitive + AddAssign,
+{
+    type Float = F;
+    type Matrix = ndarray::Array2<F>;
+
+    fn gradient(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::ndarr::forward_diff(func)(self),
+            FiniteDiffMethod::Central => finitediff::ndarr::central_diff(func)(self),
+        }
+    }
+
+    fn jacobian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::ndarr::forward_jacobian(func)(self),
+            FiniteDiffMethod::Central => finitediff::ndarr::central_jacobian(func)(self),
+        }
+    }
+
+    fn hessian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        match method {
+            FiniteDiffMethod::Forward => finitediff::ndarr::forward_hessian_nograd(func)(self),
+            FiniteDiffMethod::Central => {
+                let grad = finitediff::ndarr::central_diff(func);
+                let hessian = finitediff::ndarr::central_hessian(&grad);
+                hessian(self)
+            }
+        }
+    }
+}
+
+/// `finitediff` does not support `nalgebra`, therefore the parameter vectors are converted to
+/// `Vec`s and back.
+#[cfg(feature = "finitediff-nalgebra")]
+impl<F> FiniteDiffParam for nalgebra::DVector<F>
+where
+    F: nalgebra::Scalar + Float + FromPrimitive + AddAssign,
+{
+    type Float = F;
+    type Matrix = nalgebra::DMatrix<F>;
+
+    fn gradient(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self, Error> {
+        let func = |x: &Vec<F>| func(&nalgebra::DVector::from_column_slice(x));
+        let grad = self.as_slice().to_vec().gradient(method, &func)?;
+        Ok(nalgebra::DVector::from_vec(grad))
+    }
+
+    fn jacobian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<Self, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        let func = |x: &Vec<F>| {
+            func(&nalgebra::DVector::from_column_slice(x)).map(|y| y.as_slice().to_vec())
+        };
+        let jacobian = self.as_slice().to_vec().jacobian(method, &func)?;
+        Ok(to_dmatrix(&jacobian, self.len()))
+    }
+
+    fn hessian(
+        &self,
+        method: FiniteDiffMethod,
+        func: &dyn Fn(&Self) -> Result<F, Error>,
+    ) -> Result<Self::Matrix, Error> {
+        let func = |x: &Vec<F>| func(&nalgebra::DVector::from_column_slice(x));
+        let hessian = self.as_slice().to_vec().hessian(method, &func)?;
+        Ok(to_dmatrix(&hessian, self.len()))
+    }
+}
+
+/// Converts a row-major `Vec<Vec<F>>` with `ncols` columns into a `DMatrix`.
+#[cfg(feature = "finitediff-nalgebra")]
+fn to_dmatrix<F: nalgebra::Scalar + Copy>(rows: &[Vec<F>], ncols: usize) -> nalgebra::DMatrix<F> {
+    nalgebra::DMatrix::from_fn(rows.len(), ncols, |i, j| rows[i][j])
+}
+
+/// Approximates derivatives of a problem via finite differences.
+///
+/// Wraps a problem which implements [`CostFunction`] and/or [`Operator`] and implements
+/// [`Gradient`] and [`Hessian`] (from the cost function) as well as [`Jacobian`] (from the
+/// operator) using the `finitediff` crate. This requires the `finitediff` feature. Supported
+/// parameter vectors are those implementing [`FiniteDiffParam`].
+///
+/// The wrapped problem is evaluated several times per derivative. These evaluations are reported
+/// via [`count_evaluations`] and therefore show up as `cost_count` (or `operator_count` for
+/// Jacobians) in the function evaluation counts of [`Problem`](`crate::core::Problem`), in
+/// addition to the `gradient_count`, `hessian_count` or `jacobian_count` of the derivative
+/// itself. They are also accounted for in a
+/// [`FuncEvalBudget`](`crate::core::FuncEvalBudget`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Executor, FiniteDiffGradient, FiniteDiffMethod};
+/// # use argmin::solver::gradientdescent::SteepestDescent;
+/// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+/// # use argmin_testfunctions::rosenbrock;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// struct Rosenbrock {}
+///
+/// // Only the cost function needs to be implemented
+/// impl CostFunction for Rosenbrock {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(rosenbrock(p))
+///     }
+/// }
+///
+/// let problem = FiniteDiffGradient::new(Rosenbrock {}).with_method(FiniteDiffMethod::Central);
+///
+/// let solver = SteepestDescent::new(MoreThuenteLineSearch::new());
+/// let res = Executor::new(problem, solver)
+///     .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10))
+///     .run()?;
+///
+/// // Each gradient requires 4 evaluations of the cost function (central differences)
+/// let counts = &res.problem().counts;
+/// assert!(counts["cost_count"] >= 4 * counts["gradient_count"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FiniteDiffGradient<O> {
+    /// Wrapped problem
+    problem: O,
+    /// Finite difference scheme
+    method: FiniteDiffMethod,
+}
+
+impl<O> FiniteDiffGradient<O> {
+    /// Wraps `problem`. Derivatives are approximated with forward differences by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::FiniteDiffGradient;
+    /// # struct UserDefinedProblem {}
+    /// let problem = FiniteDiffGradient::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(problem: O) -> Self {
+        FiniteDiffGradient {
+            problem,
+            method: FiniteDiffMethod::Forward,
+        }
+    }
+
+    /// Sets the finite difference scheme for gradients and Jacobians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{FiniteDiffGradient, FiniteDiffMethod};
+    /// # struct UserDefinedProblem {}
+    /// let problem =
+    ///     FiniteDiffGradient::new(UserDefinedProblem {}).with_method(FiniteDiffMethod::Central);
+    /// ```
+    #[must_use]
+    pub fn with_method(mut self, method: FiniteDiffMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Returns a reference to the wrapped problem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::FiniteDiffGradient;
+    /// # struct UserDefinedProblem {}
+    /// let problem = FiniteDiffGradient::new(UserDefinedProblem {});
+    /// let inner: &UserDefinedProblem = problem.inner();
+    /// ```
+    pub fn inner(&self) -> &O {
+        &self.problem
+    }
+
+    /// Calls `func` with a counting version of `eval` and returns the result together with the
+    /// number of calls of `eval`.
+    fn counted<P, T, U>(
+        eval: impl Fn(&P) -> Result<T, Error>,
+        func: impl FnOnce(&dyn Fn(&P) -> Result<T, Error>) -> Result<U, Error>,
+    ) -> (Result<U, Error>, u64) {
+        let evaluations = Cell::new(0u64);
+        let res = func(&|p: &P| {
+            evaluations.set(evaluations.get() + 1);
+            eval(p)
+        });
+        (res, evaluations.get())
+    }
+
+    /// Computes `func` for all `params` and reports the evaluations of the wrapped problem on the
+    /// calling thread (`rayon` worker threads are not visible to `Problem`).
+    fn bulk_counted<Q, P, T>(
+        params: &[P],
+        parallelize: bool,
+        counts_string: &'static str,
+        func: impl Fn(&Q) -> (Result<T, Error>, u64) + SendAlias + SyncAlias,
+    ) -> Result<Vec<T>, Error>
+    where
+        Q: ?Sized,
+        P: Borrow<Q> + SyncAlias,
+        T: SendAlias,
+    {
+        #[cfg(feature = "rayon")]
+        let results: Vec<_> = if parallelize {
+            params.par_iter().map(|p| func(p.borrow())).collect()
+        } else {
+            params.iter().map(|p| func(p.borrow())).collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let results: Vec<_> = {
+            let _ = parallelize;
+            params.iter().map(|p| func(p.borrow())).collect()
+        };
+        count_evaluations(counts_string, results.iter().map(|(_, n)| n).sum());
+        results.into_iter().map(|(res, _)| res).collect()
+    }
+}
+
+impl<O> FiniteDiffGradient<O>
+where
+    O: CostFunction,
+    O::Param: FiniteDiffParam<Float = O::Output>,
+{
+    fn counted_gradient(&self, param: &O::Param) -> (Result<O::Param, Error>, u64) {
+        Self::counted(
+            |p| self.problem.cost(p),
+            |cost| param.gradient(self.method, cost),
+        )
+    }
+
+    fn counted_hessian(
+        &self,
+        param: &O::Param,
+    ) -> (Result<<O::Param as FiniteDiffParam>::Matrix, Error>, u64) {
+        Self::counted(
+            |p| self.problem.cost(p),
+            |cost| param.hessian(self.method, cost),
+        )
+    }
+}
+
+impl<O> FiniteDiffGradient<O>
+where
+    O: Operator<Output = <O as Operator>::Param>,
+    O::Param: FiniteDiffParam,
+{
+    fn counted_jacobian(
+        &self,
+        param: &O::Param,
+    ) -> (Result<<O::Param as FiniteDiffParam>::Matrix, Error>, u64) {
+        Self::counted(
+            |p| self.problem.apply(p),
+            |apply| param.jacobian(self.method, apply),
+        )
+    }
+}
+
+impl<O: Operator> Operator for FiniteDiffGradient<O> {
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.apply(param)
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O: CostFunction> CostFunction for FiniteDiffGradient<O> {
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(param)
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O> Gradient for FiniteDiffGradient<O>
+where
+    O: CostFunction,
+    O::Param: FiniteDiffParam<Float = O::Output>,
+{
+    type Param = O::Param;
+    type Gradient = O::Param;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let (res, evaluations) = self.counted_gradient(param);
+        count_evaluations("cost_count", evaluations);
+        res
+    }
+
+    fn bulk_gradient<P>(&self, params: &[P]) -> Result<Vec<Self::Gradient>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Gradient: SendAlias,
+        Self: SyncAlias,
+    {
+        Self::bulk_counted(params, self.problem.parallelize(), "cost_count", |p| {
+            self.counted_gradient(p)
+        })
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O> Hessian for FiniteDiffGradient<O>
+where
+    O: CostFunction,
+    O::Param: FiniteDiffParam<Float = O::Output>,
+{
+    type Param = O::Param;
+    type Hessian = <O::Param as FiniteDiffParam>::Matrix;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let (res, evaluations) = self.counted_hessian(param);
+        count_evaluations("cost_count", evaluations);
+        res
+    }
+
+    fn bulk_hessian<P>(&self, params: &[P]) -> Result<Vec<Self::Hessian>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Hessian: SendAlias,
+        Self: SyncAlias,
+    {
+        Self::bulk_counted(params, self.problem.parallelize(), "cost_count", |p| {
+            self.counted_hessian(p)
+        })
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O> Jacobian for FiniteDiffGradient<O>
+where
+    O: Operator<Output = <O as Operator>::Param>,
+    O::Param: FiniteDiffParam,
+{
+    type Param = O::Param;
+    type Jacobian = <O::Param as FiniteDiffParam>::Matrix;
+
+    fn jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        let (res, evaluations) = self.counted_jacobian(param);
+        count_evaluations("operator_count", evaluations);
+        res
+    }
+
+    fn bulk_jacobian<P>(&self, params: &[P]) -> Result<Vec<Self::Jacobian>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Jacobian: SendAlias,
+        Self: SyncAlias,
+    {
+        Self::bulk_counted(params, self.problem.parallelize(), "operator_count", |p| {
+            self.counted_jacobian(p)
+        })
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FuncEvalBudget, Problem};
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+    use std::sync::Arc;
+
+    send_sync_test!(finitediff_gradient, FiniteDiffGradient<()>);
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    struct Square {}
+
+    impl Operator for Square {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0].powi(2), p[0] * p[1], p[1].powi(2)])
+        }
+    }
+
+    #[test]
+    fn test_gradient() {
+        let param = vec![-1.2, 1.0, 0.5];
+        let expected = rosenbrock_derivative(&param);
+        for (method, evaluations) in [
+            (FiniteDiffMethod::Forward, 4),
+            (FiniteDiffMethod::Central, 6),
+        ] {
+            let mut problem =
+                Problem::new(FiniteDiffGradient::new(Rosenbrock {}).with_method(method));
+            let grad = problem.gradient(&param).unwrap();
+            for (g, e) in grad.iter().zip(expected.iter()) {
+                assert_relative_eq!(g, e, epsilon = 1e-4);
+            }
+            assert_eq!(problem.counts["gradient_count"], 1);
+            assert_eq!(problem.counts["cost_count"], evaluations);
+        }
+    }
+
+    #[test]
+    fn test_bulk_gradient() {
+        let params = vec![vec![-1.2, 1.0], vec![1.0, 1.0]];
+        let mut problem = Problem::new(FiniteDiffGradient::new(Rosenbrock {}));
+        let grads = problem.bulk_gradient(&params).unwrap();
+        for (grad, param) in grads.iter().zip(params.iter()) {
+            for (g, e) in grad.iter().zip(rosenbrock_derivative(param).iter()) {
+                assert_relative_eq!(g, e, epsilon = 1e-4);
+            }
+        }
+        assert_eq!(problem.counts["gradient_count"], 2);
+        assert_eq!(problem.counts["cost_count"], 6);
+    }
+
+    #[test]
+    fn test_hessian() {
+        struct Quadratic {}
+
+        impl CostFunction for Quadratic {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p[0].powi(2) + 3.0 * p[0] * p[1] + 2.0 * p[1].powi(2))
+            }
+        }
+
+        let mut problem = Problem::new(
+            FiniteDiffGradient::new(Quadratic {}).with_method(FiniteDiffMethod::Central),
+        );
+        let hessian = problem.hessian(&vec![1.0, -2.0]).unwrap();
+        let expected = [[2.0, 3.0], [3.0, 4.0]];
+        for (h, e) in hessian.iter().flatten().zip(expected.iter().flatten()) {
+            assert_relative_eq!(h, e, epsilon = 1e-4);
+        }
+        assert_eq!(problem.counts["hessian_count"], 1);
+        assert_eq!(problem.counts["cost_count"], 16);
+
+        // Forward differences are cheaper
+        let mut problem = Problem::new(FiniteDiffGradient::new(Quadratic {}));
+        let hessian = problem.hessian(&vec![1.0, -2.0]).unwrap();
+        assert_eq!(hessian.len(), 2);
+        assert_eq!(problem.counts["cost_count"], 6);
+    }
+
+    #[test]
+    fn test_jacobian() {
+        let param = vec![2.0, 3.0];
+        let expected = [[4.0, 0.0], [3.0, 2.0], [0.0, 6.0]];
+        let mut problem =
+            Problem::new(FiniteDiffGradient::new(Square {}).with_method(FiniteDiffMethod::Central));
+        let jacobian = problem.jacobian(&param).unwrap();
+        assert_eq!(jacobian.len(), 3);
+        for (j, e) in jacobian.iter().flatten().zip(expected.iter().flatten()) {
+            assert_relative_eq!(j, e, epsilon = 1e-6);
+        }
+        assert_eq!(problem.counts["jacobian_count"], 1);
+        assert_eq!(problem.counts["operator_count"], 4);
+    }
+
+    #[test]
+    fn test_array() {
+        struct ArrayProblem {}
+
+        impl CostFunction for ArrayProblem {
+            type Param = [f64; 2];
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock(p))
+            }
+        }
+
+        let param = [-1.2, 1.0];
+        let grad = FiniteDiffGradient::new(ArrayProblem {})
+            .gradient(&param)
+            .unwrap();
+        for (g, e) in grad.iter().zip(rosenbrock_derivative(&param).iter()) {
+            assert_relative_eq!(g, e, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_budget() {
+        let budget = Arc::new(FuncEvalBudget::new().with_limit("cost_count", 5));
+        let mut problem = Problem::new(FiniteDiffGradient::new(Rosenbrock {}));
+        problem.budget = Some(budget.clone());
+        problem.gradient(&vec![1.0, 2.0]).unwrap();
+        assert_eq!(budget.spent("cost_count"), 3);
+        assert!(!budget.is_exhausted());
+        problem.gradient(&vec![1.0, 2.0]).unwrap();
+        assert_eq!(budget.spent("cost_count"), 6);
+        assert!(budget.is_exhausted());
+    }
+
+    #[test]
+    fn test_outside_of_problem() {
+        // Evaluations reported outside of `Problem` are dropped and don't leak into later calls
+        let grad = FiniteDiffGradient::new(Rosenbrock {})
+            .gradient(&vec![1.0, 1.0])
+            .unwrap();
+        assert_eq!(grad.len(), 2);
+        let mut problem = Problem::new(FiniteDiffGradient::new(Rosenbrock {}));
+        problem.cost(&vec![1.0, 1.0]).unwrap();
+        assert_eq!(problem.counts.get("cost_count"), Some(&1));
+    }
+
+    #[cfg(feature = "finitediff-ndarray")]
+    #[test]
+    fn test_ndarray() {
+        struct NdarrayProblem {}
+
+        impl CostFunction for NdarrayProblem {
+            type Param = ndarray::Array1<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock(p.as_slice().unwrap()))
+            }
+        }
+
+        let param = ndarray::array![-1.2, 1.0];
+        let mut problem = Problem::new(FiniteDiffGradient::new(NdarrayProblem {}));
+        let grad = problem.gradient(&param).unwrap();
+        for (g, e) in grad
+            .iter()
+            .zip(rosenbrock_derivative(param.as_slice().unwrap()).iter())
+        {
+            assert_relative_eq!(g, e, epsilon = 1e-4);
+        }
+        assert_eq!(problem.counts["cost_count"], 3);
+        let hessian = problem.hessian(&param).unwrap();
+        assert_eq!(hessian.shape(), &[2, 2]);
+    }
+
+    #[cfg(feature = "finitediff-nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        struct NalgebraProblem {}
+
+        impl Operator for NalgebraProblem {
+            type Param = nalgebra::DVector<f64>;
+            type Output = nalgebra::DVector<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(nalgebra::DVector::from_vec(vec![p[0].powi(2), p[0] * p[1]]))
+            }
+        }
+
+        let param = nalgebra::DVector::from_vec(vec![2.0, 3.0]);
+        let mut problem = Problem::new(FiniteDiffGradient::new(NalgebraProblem {}));
+        let jacobian = problem.jacobian(&param).unwrap();
+        let expected = nalgebra::DMatrix::from_row_slice(2, 2, &[4.0, 0.0, 3.0, 2.0]);
+        for (j, e) in jacobian.iter().zip(expected.iter()) {
+            assert_relative_eq!(j, e, epsilon = 1e-6);
+        }
+        assert_eq!(problem.counts["operator_count"], 3);
+    }
+}
@@ -237,6 +237,22 @@ impl From<f32> for KvValue {
     }
 }
 
+/// Only the real part is stored.
+#[cfg(feature = "autodiff")]
+impl<T: Into<KvValue>> From<argmin_math::dual::Dual<T>> for KvValue {
+    fn from(x: argmin_math::dual::Dual<T>) -> KvValue {
+        x.re.into()
+    }
+}
+
+/// Only the real part is stored.
+#[cfg(feature = "autodiff")]
+impl<T: Into<KvValue>> From<argmin_math::dual::HyperDual<T>> for KvValue {
+    fn from(x: argmin_math::dual::HyperDual<T>) -> KvValue {
+        x.re.into()
+    }
+}
+
 impl From<i64> for KvValue {
     fn from(x: i64) -> KvValue {
         KvValue::Int(x)
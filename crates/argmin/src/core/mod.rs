@@ -13,11 +13,26 @@
 /// Macros
 #[macro_use]
 pub mod macros;
+/// Async variants of problem traits
+#[cfg(feature = "async")]
+mod async_problem;
+/// Forward-mode automatic differentiation
+#[cfg(feature = "autodiff")]
+mod autodiff;
+/// Batch execution of independent optimization runs
+mod batch_executor;
+/// Budget of function evaluations
+mod budget;
+/// Caching of evaluations of problems
+mod cache;
 pub mod checkpointing;
 /// Error handling
 mod errors;
 /// Executor
 mod executor;
+/// Finite difference approximations of derivatives
+#[cfg(feature = "finitediff")]
+mod finitediff;
 /// Trait alias for float types
 mod float;
 /// Key value data structure
@@ -35,20 +50,38 @@ mod solver;
 mod state;
 /// Definition of termination reasons
 mod termination;
+pub mod termination_criteria;
 /// Convenience utilities for testing
 pub mod test_utils;
+/// Parameter uncertainty of least squares fits
+mod uncertainty;
 
 pub use crate::solver::conjugategradient::beta::NLCGBetaUpdate;
 pub use crate::solver::linesearch::LineSearch;
 pub use crate::solver::trustregion::TrustRegionRadius;
 pub use anyhow::Error;
+#[cfg(feature = "async")]
+pub use async_problem::{AsyncCostFunction, AsyncGradient, AsyncProblem};
+#[cfg(feature = "autodiff")]
+pub use autodiff::{AutoDiffFloat, ForwardDiff, GenericCostFunction};
+pub use batch_executor::{BatchExecutor, DefaultSetup};
+pub use budget::FuncEvalBudget;
+pub use cache::{CacheKey, CachedProblem, ExactKey, RoundedKey};
+pub(crate) use errors::is_interruption;
 pub use errors::ArgminError;
 pub use executor::Executor;
+#[cfg(feature = "finitediff")]
+pub use finitediff::{FiniteDiffGradient, FiniteDiffMethod, FiniteDiffParam};
 pub use float::ArgminFloat;
 pub use kv::{KvValue, KV};
 pub use parallelization::{SendAlias, SyncAlias};
-pub use problem::{CostFunction, Gradient, Hessian, Jacobian, LinearProgram, Operator, Problem};
+pub use problem::{
+    count_evaluations, BoundsHandling, BoxConstraint, CostFunction, EqualityConstraints, Gradient,
+    Hessian, IgnoreBounds, InequalityConstraints, Jacobian, LinearProgram, Operator, Problem,
+    ProjectOntoBounds,
+};
 pub use result::OptimizationResult;
 pub use solver::Solver;
 pub use state::{IterState, LinearProgramState, PopulationState, State};
 pub use termination::{TerminationReason, TerminationStatus};
+pub use uncertainty::ParameterUncertainty;
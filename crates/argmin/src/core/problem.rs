@@ -5,22 +5,115 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use crate::core::{ArgminFloat, Error, SendAlias, SyncAlias};
+use crate::core::{ArgminFloat, Error, FuncEvalBudget, SendAlias, SyncAlias};
+use argmin_math::ArgminProject;
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+thread_local! {
+    /// Evaluations reported via [`count_evaluations`] and [`count_cache_hits`] by the problems which
+    /// are currently being evaluated on this thread (innermost last)
+    static REPORTED_COUNTS: RefCell<Vec<ReportedCounts>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Evaluations reported during a single call of a method of a problem
+#[derive(Default)]
+struct ReportedCounts {
+    /// Additional evaluations per counter
+    evaluations: HashMap<&'static str, u64>,
+    /// Cache hits per pair of counter and counter of the cache hits
+    cache_hits: HashMap<(&'static str, &'static str), u64>,
+}
+
+/// Reports `num` evaluations of `counts_string` performed inside a method of a problem.
+///
+/// Wrappers which evaluate the problem they wrap several times per call (such as
+/// `FiniteDiffGradient`, which evaluates the cost function in order to approximate the gradient)
+/// call this function to make these evaluations visible.
+/// The [`Problem`] which invoked the method adds them to its function evaluation counts and to
+/// its [`FuncEvalBudget`], if any. Outside of a method invoked by a [`Problem`] (or on another
+/// thread), this function has no effect.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{count_evaluations, CostFunction, Error, Problem};
+///
+/// struct TwoEvaluations {}
+///
+/// impl CostFunction for TwoEvaluations {
+///     type Param = f64;
+///     type Output = f64;
+///
+///     fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+///         // Pretend that the result is obtained by evaluating some other function twice.
+///         count_evaluations("other_count", 2);
+///         Ok(*param)
+///     }
+/// }
+///
+/// let mut problem = Problem::new(TwoEvaluations {});
+/// problem.cost(&1.0)?;
+///
+/// assert_eq!(problem.counts["cost_count"], 1);
+/// assert_eq!(problem.counts["other_count"], 2);
+/// # Ok::<(), Error>(())
+/// ```
+pub fn count_evaluations(counts_string: &'static str, num: u64) {
+    REPORTED_COUNTS.with(|reported| {
+        if let Some(counts) = reported.borrow_mut().last_mut() {
+            *counts.evaluations.entry(counts_string).or_insert(0) += num;
+        }
+    })
+}
+
+/// Reports that `num` calls of the method labeled `counts_string` were served from a cache instead
+/// of evaluating the problem.
+///
+/// The [`Problem`] which invoked the method counts these calls as `hits_string` instead of
+/// `counts_string` and gives the evaluations back to its [`FuncEvalBudget`], if any. Outside of a
+/// method invoked by a [`Problem`] (or on another thread), this function has no effect.
+pub(crate) fn count_cache_hits(counts_string: &'static str, hits_string: &'static str, num: u64) {
+    REPORTED_COUNTS.with(|reported| {
+        if let Some(counts) = reported.borrow_mut().last_mut() {
+            *counts
+                .cache_hits
+                .entry((counts_string, hits_string))
+                .or_insert(0) += num;
+        }
+    })
+}
+
+/// Removes the innermost entry of `REPORTED_COUNTS` when dropped, even if the evaluation panics.
+struct ReportedCountsGuard;
+
+impl Drop for ReportedCountsGuard {
+    fn drop(&mut self) {
+        REPORTED_COUNTS.with(|reported| reported.borrow_mut().pop());
+    }
+}
 
 /// Wrapper around problems defined by users.
 ///
 /// Keeps track of how many times methods such as `apply`, `cost`, `gradient`, `jacobian`,
 /// `hessian`, `anneal` and so on are called. It is used to pass the problem from one iteration of
 /// a solver to the next.
+///
+/// Evaluations can be cached by wrapping the user defined problem into a
+/// [`CachedProblem`](`crate::core::CachedProblem`).
 #[derive(Clone, Debug, Default)]
 pub struct Problem<O> {
     /// Problem defined by user
     pub problem: Option<O>,
     /// Keeps track of how often methods of `problem` have been called.
     pub counts: HashMap<&'static str, u64>,
+    /// Budget of function evaluations, shared with nested problems
+    pub(crate) budget: Option<Arc<FuncEvalBudget>>,
 }
 
 impl<O> Problem<O> {
@@ -44,12 +137,15 @@ impl<O> Problem<O> {
         Problem {
             problem: Some(problem),
             counts: HashMap::new(),
+            budget: None,
         }
     }
 
     /// Gives access to the stored `problem` via the closure `func` and keeps track of how many
     /// times the function has been called. The function counts will be passed to observers labeled
     /// with `counts_string`. Per convention, `counts_string` is chosen as `<something>_count`.
+    /// If a [`FuncEvalBudget`] is attached and the evaluation would exceed it, `func` is not called
+    /// and an error is returned instead.
     ///
     /// # Example
     ///
@@ -129,9 +225,12 @@ impl<O> Problem<O> {
         counts_string: &'static str,
         func: F,
     ) -> Result<T, Error> {
+        if let Some(budget) = self.budget.as_ref() {
+            budget.consume(counts_string, 1)?;
+        }
         let count = self.counts.entry(counts_string).or_insert(0);
         *count += 1;
-        func(self.problem.as_ref().unwrap())
+        self.evaluate(func)
     }
 
     /// Gives access to the stored `problem` via the closure `func` and keeps track of how many
@@ -140,15 +239,50 @@ impl<O> Problem<O> {
     /// This is used by the `bulk_*` methods, which process multiple parameters at once.
     /// The function counts will be passed to observers labeled with `counts_string`.
     /// Per convention, `counts_string` is chosen as `<something>_count`.
+    /// If a [`FuncEvalBudget`] is attached and the evaluations would exceed it, `func` is not
+    /// called and an error is returned instead.
     pub fn bulk_problem<T, F: FnOnce(&O) -> Result<T, Error>>(
         &mut self,
         counts_string: &'static str,
         num_param_vecs: usize,
         func: F,
     ) -> Result<T, Error> {
+        if let Some(budget) = self.budget.as_ref() {
+            budget.consume(counts_string, num_param_vecs as u64)?;
+        }
         let count = self.counts.entry(counts_string).or_insert(0);
         *count += num_param_vecs as u64;
-        func(self.problem.as_ref().unwrap())
+        self.evaluate(func)
+    }
+
+    /// Calls `func` on the stored problem and adds the evaluations reported via
+    /// [`count_evaluations`] during the call to the function evaluation counts and the budget.
+    /// Cache hits reported via [`count_cache_hits`] are moved from the counter of the method to
+    /// the counter of the cache hits and are given back to the budget.
+    fn evaluate<T, F: FnOnce(&O) -> Result<T, Error>>(&mut self, func: F) -> Result<T, Error> {
+        REPORTED_COUNTS.with(|reported| reported.borrow_mut().push(ReportedCounts::default()));
+        let guard = ReportedCountsGuard;
+        let res = func(self.problem.as_ref().unwrap());
+        let reported = REPORTED_COUNTS
+            .with(|reported| reported.borrow_mut().last_mut().map(std::mem::take))
+            .unwrap_or_default();
+        drop(guard);
+        for (counts_string, num) in reported.evaluations {
+            if let Some(budget) = self.budget.as_ref() {
+                budget.charge(counts_string, num);
+            }
+            *self.counts.entry(counts_string).or_insert(0) += num;
+        }
+        for ((counts_string, hits_string), num) in reported.cache_hits {
+            if let Some(budget) = self.budget.as_ref() {
+                budget.refund(counts_string, num);
+            }
+            if let Some(count) = self.counts.get_mut(counts_string) {
+                *count = count.saturating_sub(num);
+            }
+            *self.counts.entry(hits_string).or_insert(0) += num;
+        }
+        res
     }
 
     /// Returns the internally stored problem and replaces it with `None`.
@@ -173,6 +307,23 @@ impl<O> Problem<O> {
         self.problem.take()
     }
 
+    /// Returns the [`FuncEvalBudget`] attached via
+    /// [`Executor::func_eval_budget`](`crate::core::Executor::func_eval_budget`), if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Problem;
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// let problem = Problem::new(UserDefinedProblem {});
+    /// assert!(problem.func_eval_budget().is_none());
+    /// ```
+    pub fn func_eval_budget(&self) -> Option<&FuncEvalBudget> {
+        self.budget.as_deref()
+    }
+
     /// Consumes another instance of `Problem`. The internally stored user defined problem of the
     /// passed `Problem` instance is moved to `Self`. The function evaluation counts are
     /// merged/summed up.
@@ -378,6 +529,61 @@ pub trait CostFunction {
     /// Compute cost function
     fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error>;
 
+    /// Compute cost function and gradient at the same time
+    ///
+    /// By default, this calls [`cost`](`CostFunction::cost`) and
+    /// [`gradient`](`Gradient::gradient`). It should be overridden if the gradient is obtained as
+    /// a byproduct of the cost function (for instance with adjoint methods) in order to avoid
+    /// doing the work twice. Solvers call this method via
+    /// [`Problem::cost_and_gradient`](`crate::core::Problem::cost_and_gradient`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::{CostFunction, Error, Gradient};
+    /// use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+    /// # use approx::assert_relative_eq;
+    ///
+    /// struct Rosenbrock {}
+    ///
+    /// impl CostFunction for Rosenbrock {
+    ///     type Param = Vec<f64>;
+    ///     type Output = f64;
+    ///
+    ///     fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    ///         Ok(rosenbrock(param))
+    ///     }
+    ///
+    ///     /// Compute cost function and gradient in one go
+    ///     fn cost_and_gradient(&self, param: &Self::Param) -> Result<(f64, Vec<f64>), Error> {
+    ///         Ok((rosenbrock(param), rosenbrock_derivative(param)))
+    ///     }
+    /// }
+    ///
+    /// impl Gradient for Rosenbrock {
+    ///     type Param = Vec<f64>;
+    ///     type Gradient = Vec<f64>;
+    ///
+    ///     fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+    ///         Ok(rosenbrock_derivative(param))
+    ///     }
+    /// }
+    ///
+    /// let (cost, gradient) = Rosenbrock {}.cost_and_gradient(&vec![1.0, 1.0])?;
+    /// # assert_relative_eq!(cost, 0.0);
+    /// # assert_eq!(gradient, vec![0.0, 0.0]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn cost_and_gradient(
+        &self,
+        param: &<Self as CostFunction>::Param,
+    ) -> Result<(<Self as CostFunction>::Output, <Self as Gradient>::Gradient), Error>
+    where
+        Self: Gradient<Param = <Self as CostFunction>::Param>,
+    {
+        Ok((self.cost(param)?, self.gradient(param)?))
+    }
+
     bulk!(cost, Self::Param, Self::Output);
 }
 
@@ -478,7 +684,179 @@ pub trait Jacobian {
     bulk!(jacobian, Self::Param, Self::Jacobian);
 }
 
-/// Defines a linear Program
+/// Defines box constraints on the parameter vector.
+///
+/// The feasible set is the box `lower <= param <= upper` (element-wise), where `lower` and
+/// `upper` are returned by `bounds`. Unbounded directions can be expressed with infinite bounds.
+///
+/// Solvers which support box constraints (such as
+/// [`SteepestDescent`](`crate::solver::gradientdescent::SteepestDescent`),
+/// [`LBFGS`](`crate::solver::quasinewton::LBFGS`),
+/// [`NelderMead`](`crate::solver::neldermead::NelderMead`),
+/// [`SimulatedAnnealing`](`crate::solver::simulatedannealing::SimulatedAnnealing`) and
+/// [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`)) ignore them by default.
+/// Calling `with_box_constraints` on the solver makes it project its iterates onto the box via
+/// [`ProjectOntoBounds`].
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{BoxConstraint, Error};
+///
+/// struct Problem {}
+///
+/// impl BoxConstraint for Problem {
+///     type Param = Vec<f64>;
+///
+///     fn bounds(&self) -> Result<(Self::Param, Self::Param), Error> {
+///         Ok((vec![-1.0, 0.0], vec![1.0, f64::INFINITY]))
+///     }
+/// }
+/// ```
+pub trait BoxConstraint {
+    /// Type of the parameter vector
+    type Param;
+
+    /// Lower and upper bounds on the parameter vector
+    fn bounds(&self) -> Result<(Self::Param, Self::Param), Error>;
+}
+
+/// Determines how a solver handles the box constraints of a problem.
+///
+/// Implemented by [`IgnoreBounds`] (the default of all solvers) and [`ProjectOntoBounds`].
+pub trait BoundsHandling<O, P> {
+    /// Projects `param` onto the feasible set of `problem`.
+    ///
+    /// Returns `None` if `param` is left unchanged.
+    fn project(problem: &Problem<O>, param: &P) -> Result<Option<P>, Error>;
+}
+
+/// Ignores box constraints; parameter vectors are never modified.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct IgnoreBounds;
+
+impl<O, P> BoundsHandling<O, P> for IgnoreBounds {
+    #[inline]
+    fn project(_problem: &Problem<O>, _param: &P) -> Result<Option<P>, Error> {
+        Ok(None)
+    }
+}
+
+/// Projects parameter vectors onto the box defined by [`BoxConstraint`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProjectOntoBounds;
+
+impl<O, P> BoundsHandling<O, P> for ProjectOntoBounds
+where
+    O: BoxConstraint<Param = P>,
+    P: ArgminProject,
+{
+    #[inline]
+    fn project(problem: &Problem<O>, param: &P) -> Result<Option<P>, Error> {
+        Ok(Some(problem.project(param)?))
+    }
+}
+
+/// Defines equality constraints `c(param) = 0` together with their Jacobian.
+///
+/// Solvers for constrained problems (such as
+/// [`AugmentedLagrangian`](`crate::solver::augmentedlagrangian::AugmentedLagrangian`)) use
+/// the values of the constraints as well as the Jacobian with respect to the parameter vector.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{EqualityConstraints, Error};
+///
+/// struct Problem {}
+///
+/// impl EqualityConstraints for Problem {
+///     type Param = Vec<f64>;
+///     type Output = Vec<f64>;
+///     type Jacobian = Vec<Vec<f64>>;
+///
+///     /// x + y - 1 = 0
+///     fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(vec![p[0] + p[1] - 1.0])
+///     }
+///
+///     fn equality_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+///         Ok(vec![vec![1.0, 1.0]])
+///     }
+/// }
+/// ```
+pub trait EqualityConstraints {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the vector of constraint values
+    type Output;
+    /// Type of the Jacobian of the constraints
+    type Jacobian;
+
+    /// Compute the values of the equality constraints
+    fn equality_constraints(&self, param: &Self::Param) -> Result<Self::Output, Error>;
+
+    /// Compute the Jacobian of the equality constraints
+    fn equality_jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error>;
+}
+
+/// Defines inequality constraints `c(param) <= 0` together with their Jacobian.
+///
+/// Solvers for constrained problems (such as
+/// [`AugmentedLagrangian`](`crate::solver::augmentedlagrangian::AugmentedLagrangian`)) use
+/// the values of the constraints as well as the Jacobian with respect to the parameter vector.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{InequalityConstraints, Error};
+///
+/// struct Problem {}
+///
+/// impl InequalityConstraints for Problem {
+///     type Param = Vec<f64>;
+///     type Output = Vec<f64>;
+///     type Jacobian = Vec<Vec<f64>>;
+///
+///     /// x^2 + y^2 - 1 <= 0
+///     fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+///     }
+///
+///     fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+///         Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+///     }
+/// }
+/// ```
+pub trait InequalityConstraints {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the vector of constraint values
+    type Output;
+    /// Type of the Jacobian of the constraints
+    type Jacobian;
+
+    /// Compute the values of the inequality constraints
+    fn inequality_constraints(&self, param: &Self::Param) -> Result<Self::Output, Error>;
+
+    /// Compute the Jacobian of the inequality constraints
+    fn inequality_jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error>;
+}
+
+/// Defines a linear program
+///
+/// The linear program is assumed to be given in standard form:
+///
+/// ```text
+/// minimize    c^T x
+/// subject to  A x = b
+///             x >= 0
+/// ```
+///
+/// Inequality constraints can be converted into equality constraints by introducing slack
+/// variables and free variables can be split into a difference of two non-negative variables.
 ///
 /// # Example
 ///
@@ -510,8 +888,7 @@ pub trait LinearProgram {
     /// Precision of floats
     type Float: ArgminFloat;
 
-    /// TODO c for linear programs
-    /// Those three could maybe be merged into a single function; name unclear
+    /// Coefficients `c` of the linear objective function `c^T x` (one per variable)
     fn c(&self) -> Result<Vec<Self::Float>, Error> {
         Err(argmin_error!(
             NotImplemented,
@@ -519,7 +896,7 @@ pub trait LinearProgram {
         ))
     }
 
-    /// TODO b for linear programs
+    /// Right-hand side `b` of the equality constraints `A x = b` (one per constraint)
     fn b(&self) -> Result<Vec<Self::Float>, Error> {
         Err(argmin_error!(
             NotImplemented,
@@ -527,7 +904,7 @@ pub trait LinearProgram {
         ))
     }
 
-    /// TODO A for linear programs
+    /// Constraint matrix `A` of the equality constraints `A x = b`, given as a list of rows
     #[allow(non_snake_case)]
     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
         Err(argmin_error!(
@@ -779,6 +1156,67 @@ impl<O: Gradient> Problem<O> {
     }
 }
 
+/// Wraps a call to `cost_and_gradient` defined in the `CostFunction` trait and as such allows to
+/// call `cost_and_gradient` on an instance of `Problem`. Internally, the evaluation is counted as
+/// one evaluation of `cost` and one evaluation of `gradient`.
+impl<O> Problem<O>
+where
+    O: CostFunction + Gradient<Param = <O as CostFunction>::Param>,
+{
+    /// Calls `cost_and_gradient` defined in the `CostFunction` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, CostFunction, Gradient, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl CostFunction for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = f64;
+    /// #
+    /// #     fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(4.0f64)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Gradient for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #
+    /// #     fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+    /// #         Ok(vec![1.0f64, 1.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `CostFunction` and `Gradient`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let (cost, gradient) = problem1.cost_and_gradient(&param)?;
+    ///
+    /// assert_eq!(problem1.counts["cost_count"], 1);
+    /// assert_eq!(problem1.counts["gradient_count"], 1);
+    /// # assert_eq!(cost, 4.0f64);
+    /// # assert_eq!(gradient, vec![1.0f64, 1.0f64]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn cost_and_gradient(
+        &mut self,
+        param: &<O as CostFunction>::Param,
+    ) -> Result<(<O as CostFunction>::Output, O::Gradient), Error> {
+        if let Some(budget) = self.budget.as_ref() {
+            budget.consume_all(&[("cost_count", 1), ("gradient_count", 1)])?;
+        }
+        *self.counts.entry("cost_count").or_insert(0) += 1;
+        *self.counts.entry("gradient_count").or_insert(0) += 1;
+        self.evaluate(|problem| problem.cost_and_gradient(param))
+    }
+}
+
 /// Wraps a call to `hessian` defined in the `Hessian` trait and as such allows to call `hessian` on
 /// an instance of `Problem`. Internally, the number of evaluations of `hessian` is counted.
 impl<O: Hessian> Problem<O> {
@@ -938,6 +1376,243 @@ impl<O: Jacobian> Problem<O> {
     }
 }
 
+/// Wraps a call to `bounds` defined in the `BoxConstraint` trait and as such allows to call
+/// `bounds` and `project` on an instance of `Problem`.
+impl<O: BoxConstraint> Problem<O> {
+    /// Calls `bounds` defined in the `BoxConstraint` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, BoxConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl BoxConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn bounds(&self) -> Result<(Self::Param, Self::Param), Error> {
+    /// #         Ok((vec![-1.0f64, 0.0f64], vec![1.0f64, 2.0f64]))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `BoxConstraint`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let (lower, upper) = problem1.bounds()?;
+    ///
+    /// # assert_eq!(lower, vec![-1.0f64, 0.0f64]);
+    /// # assert_eq!(upper, vec![1.0f64, 2.0f64]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn bounds(&self) -> Result<(O::Param, O::Param), Error> {
+        self.problem.as_ref().unwrap().bounds()
+    }
+
+    /// Projects `param` onto the box defined by the `bounds` of the `BoxConstraint` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, BoxConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl BoxConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn bounds(&self) -> Result<(Self::Param, Self::Param), Error> {
+    /// #         Ok((vec![-1.0f64, 0.0f64], vec![1.0f64, 2.0f64]))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `BoxConstraint`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = problem1.project(&vec![-3.0f64, 1.0f64])?;
+    ///
+    /// # assert_eq!(param, vec![-1.0f64, 1.0f64]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn project(&self, param: &O::Param) -> Result<O::Param, Error>
+    where
+        O::Param: ArgminProject,
+    {
+        let (lower, upper) = self.bounds()?;
+        Ok(param.project(&lower, &upper))
+    }
+}
+
+/// Wraps calls to `equality_constraints` and `equality_jacobian` defined in the
+/// `EqualityConstraints` trait and as such allows to call them on an instance of `Problem`.
+/// Internally, the number of evaluations is counted.
+impl<O: EqualityConstraints> Problem<O> {
+    /// Calls `equality_constraints` defined in the `EqualityConstraints` trait and keeps track of
+    /// the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraints, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraints for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![p[0] + p[1] - 1.0])
+    /// #     }
+    /// #
+    /// #     fn equality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![1.0, 1.0]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraints`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.equality_constraints(&param);
+    ///
+    /// assert_eq!(problem1.counts["equality_constraints_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![2.0f64]);
+    /// ```
+    pub fn equality_constraints(&mut self, param: &O::Param) -> Result<O::Output, Error> {
+        self.problem("equality_constraints_count", |problem| {
+            problem.equality_constraints(param)
+        })
+    }
+
+    /// Calls `equality_jacobian` defined in the `EqualityConstraints` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraints, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraints for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![p[0] + p[1] - 1.0])
+    /// #     }
+    /// #
+    /// #     fn equality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![1.0, 1.0]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraints`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.equality_jacobian(&param);
+    ///
+    /// assert_eq!(problem1.counts["equality_jacobian_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 1.0f64]]);
+    /// ```
+    pub fn equality_jacobian(&mut self, param: &O::Param) -> Result<O::Jacobian, Error> {
+        self.problem("equality_jacobian_count", |problem| {
+            problem.equality_jacobian(param)
+        })
+    }
+}
+
+/// Wraps calls to `inequality_constraints` and `inequality_jacobian` defined in the
+/// `InequalityConstraints` trait and as such allows to call them on an instance of `Problem`.
+/// Internally, the number of evaluations is counted.
+impl<O: InequalityConstraints> Problem<O> {
+    /// Calls `inequality_constraints` defined in the `InequalityConstraints` trait and keeps track of
+    /// the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, InequalityConstraints, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl InequalityConstraints for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![p[0] * p[1] - 1.0])
+    /// #     }
+    /// #
+    /// #     fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![p[1], p[0]]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `InequalityConstraints`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.inequality_constraints(&param);
+    ///
+    /// assert_eq!(problem1.counts["inequality_constraints_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64]);
+    /// ```
+    pub fn inequality_constraints(&mut self, param: &O::Param) -> Result<O::Output, Error> {
+        self.problem("inequality_constraints_count", |problem| {
+            problem.inequality_constraints(param)
+        })
+    }
+
+    /// Calls `inequality_jacobian` defined in the `InequalityConstraints` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, InequalityConstraints, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl InequalityConstraints for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![p[0] * p[1] - 1.0])
+    /// #     }
+    /// #
+    /// #     fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![p[1], p[0]]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `InequalityConstraints`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.inequality_jacobian(&param);
+    ///
+    /// assert_eq!(problem1.counts["inequality_jacobian_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 2.0f64]]);
+    /// ```
+    pub fn inequality_jacobian(&mut self, param: &O::Param) -> Result<O::Jacobian, Error> {
+        self.problem("inequality_jacobian_count", |problem| {
+            problem.inequality_jacobian(param)
+        })
+    }
+}
+
 /// Wraps a calls to `c`, `b` and `A` defined in the `LinearProgram` trait and as such allows to
 /// call those methods on an instance of `Problem`.
 impl<O: LinearProgram> Problem<O> {
@@ -26,6 +26,7 @@ use web_time::Duration;
 /// * cost function value of current and previous iteration
 /// * current and previous best cost function value
 /// * target cost function value
+/// * constraint violation of the current and the best parameter vector (constrained problems)
 /// * current iteration number
 /// * iteration number where the last best parameter vector was found
 /// * maximum number of iterations that will be executed
@@ -74,6 +75,10 @@ pub struct IterState<P, G, J, H, R, F> {
     pub residuals: Option<R>,
     /// Value of residuals from previous call to apply
     pub prev_residuals: Option<R>,
+    /// Current constraint violation (only set by solvers for constrained problems)
+    pub constraint_violation: Option<F>,
+    /// Constraint violation of the current best parameter vector
+    pub best_constraint_violation: Option<F>,
     /// Current iteration
     pub iter: u64,
     /// Iteration number of last best cost
@@ -313,6 +318,28 @@ where
         self
     }
 
+    /// Set the constraint violation of the current parameter vector.
+    ///
+    /// Solvers for constrained problems report the constraint violation here. If it is set, a
+    /// parameter vector is considered better than the current best one if its constraint
+    /// violation is smaller, or if the constraint violations are equal and its cost is lower (see
+    /// [`update`](`State::update`)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State};
+    /// # let state: IterState<Vec<f64>, (), (), (), (), f64> = IterState::new();
+    /// # assert!(state.constraint_violation.is_none());
+    /// let state = state.constraint_violation(0.1);
+    /// # assert_eq!(state.constraint_violation.unwrap().to_ne_bytes(), 0.1f64.to_ne_bytes());
+    /// ```
+    #[must_use]
+    pub fn constraint_violation(mut self, violation: F) -> Self {
+        self.constraint_violation = Some(violation);
+        self
+    }
+
     /// Returns the current cost function value
     ///
     /// # Example
@@ -389,6 +416,40 @@ where
         self.target_cost
     }
 
+    /// Returns the constraint violation of the current parameter vector
+    ///
+    /// Returns `None` if the solver does not handle constraints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State};
+    /// # let state: IterState<Vec<f64>, (), (), (), (), f64> = IterState::new();
+    /// # let state = state.constraint_violation(0.1);
+    /// let violation = state.get_constraint_violation();
+    /// # assert_eq!(violation.unwrap().to_ne_bytes(), 0.1f64.to_ne_bytes());
+    /// ```
+    pub fn get_constraint_violation(&self) -> Option<F> {
+        self.constraint_violation
+    }
+
+    /// Returns the constraint violation of the current best parameter vector
+    ///
+    /// Returns `None` if the solver does not handle constraints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State};
+    /// # let mut state: IterState<Vec<f64>, (), (), (), (), f64> = IterState::new();
+    /// # state.best_constraint_violation = Some(0.1);
+    /// let best_violation = state.get_best_constraint_violation();
+    /// # assert_eq!(best_violation.unwrap().to_ne_bytes(), 0.1f64.to_ne_bytes());
+    /// ```
+    pub fn get_best_constraint_violation(&self) -> Option<F> {
+        self.best_constraint_violation
+    }
+
     /// Moves the current parameter vector out and replaces it internally with `None`
     ///
     /// # Example
@@ -1052,6 +1113,8 @@ where
             prev_jacobian: None,
             residuals: None,
             prev_residuals: None,
+            constraint_violation: None,
+            best_constraint_violation: None,
             iter: 0,
             last_best_iter: 0,
             max_iters: u64::MAX,
@@ -1112,11 +1175,19 @@ where
         // value (such as the Newton method). Those will always have `Inf` cost. Therefore if both
         // the new value and the previous best value are `Inf`, the solution is also accepted. Care
         // is taken that both `Inf` also have the same sign.
-        if self.cost < self.best_cost
+        let better_cost = self.cost < self.best_cost
             || (self.cost.is_infinite()
                 && self.best_cost.is_infinite()
-                && self.cost.is_sign_positive() == self.best_cost.is_sign_positive())
-        {
+                && self.cost.is_sign_positive() == self.best_cost.is_sign_positive());
+        // For constrained problems, the constraint violation takes precedence over the cost.
+        let better = match (self.constraint_violation, self.best_constraint_violation) {
+            (Some(violation), Some(best_violation)) => {
+                violation < best_violation || (violation == best_violation && better_cost)
+            }
+            (Some(_), None) => true,
+            (None, _) => better_cost,
+        };
+        if better {
             // If there is no parameter vector, then also don't set the best param.
             if let Some(param) = self.param.as_ref().cloned() {
                 std::mem::swap(&mut self.prev_best_param, &mut self.best_param);
@@ -1124,6 +1195,7 @@ where
             }
             std::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
             self.best_cost = self.cost;
+            self.best_constraint_violation = self.constraint_violation;
             self.last_best_iter = self.iter;
         }
     }
@@ -1626,4 +1698,34 @@ mod tests {
         assert!(!func_counts.contains_key("jacobian_count"));
         assert!(!func_counts.contains_key("modify_count"));
     }
+
+    #[test]
+    fn test_update_constraint_violation() {
+        let mut state: IterState<Vec<f64>, (), (), (), (), f64> = IterState::new();
+
+        // First parameter vector is always the best one
+        state = state.param(vec![1.0]).cost(1.0).constraint_violation(1.0);
+        state.update();
+        assert_eq!(state.get_best_param().unwrap(), &vec![1.0]);
+
+        // Lower cost but larger constraint violation
+        state = state.param(vec![2.0]).cost(0.5).constraint_violation(2.0);
+        state.update();
+        assert_eq!(state.get_best_param().unwrap(), &vec![1.0]);
+
+        // Larger cost but smaller constraint violation
+        state = state.param(vec![3.0]).cost(2.0).constraint_violation(0.5);
+        state.update();
+        assert_eq!(state.get_best_param().unwrap(), &vec![3.0]);
+        assert_eq!(state.get_best_cost().to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(
+            state.get_best_constraint_violation().unwrap().to_ne_bytes(),
+            0.5f64.to_ne_bytes()
+        );
+
+        // Same constraint violation, lower cost
+        state = state.param(vec![4.0]).cost(1.5).constraint_violation(0.5);
+        state.update();
+        assert_eq!(state.get_best_param().unwrap(), &vec![4.0]);
+    }
 }
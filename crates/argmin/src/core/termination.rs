@@ -32,6 +32,13 @@ impl TerminationStatus {
     /// assert!(TerminationStatus::Terminated(TerminationReason::SolverConverged).terminated());
     /// assert!(TerminationStatus::Terminated(TerminationReason::Interrupt).terminated());
     /// assert!(TerminationStatus::Terminated(TerminationReason::Timeout).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::Infeasible).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::Unbounded).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::GradientToleranceReached).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::CostChangeToleranceReached).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::ParamChangeToleranceReached).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::Stalled).terminated());
+    /// assert!(TerminationStatus::Terminated(TerminationReason::MaxFuncEvalsReached).terminated());
     /// assert!(TerminationStatus::Terminated(TerminationReason::SolverExit("Exit reason".to_string())).terminated());
     /// ```
     pub fn terminated(&self) -> bool {
@@ -62,6 +69,22 @@ pub enum TerminationReason {
     SolverConverged,
     /// Timeout reached
     Timeout,
+    /// Problem has no feasible solution
+    Infeasible,
+    /// Problem is unbounded
+    Unbounded,
+    /// Gradient norm below tolerance
+    GradientToleranceReached,
+    /// Change of cost function value below tolerance
+    CostChangeToleranceReached,
+    /// Change of parameter vector below tolerance
+    ParamChangeToleranceReached,
+    /// Best cost function value did not improve for a given number of iterations
+    Stalled,
+    /// Reached maximum number of function evaluations
+    MaxFuncEvalsReached,
+    /// Reached maximum wall time
+    WallTimeReached,
     /// Solver exit with given reason
     SolverExit(String),
 }
@@ -95,6 +118,38 @@ impl TerminationReason {
     ///     "Timeout reached"
     /// );
     /// assert_eq!(
+    ///     TerminationReason::Infeasible.text(),
+    ///     "Problem is infeasible"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::Unbounded.text(),
+    ///     "Problem is unbounded"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::GradientToleranceReached.text(),
+    ///     "Gradient norm below tolerance"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::CostChangeToleranceReached.text(),
+    ///     "Cost change below tolerance"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::ParamChangeToleranceReached.text(),
+    ///     "Parameter change below tolerance"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::Stalled.text(),
+    ///     "Best cost did not improve"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::MaxFuncEvalsReached.text(),
+    ///     "Maximum number of function evaluations reached"
+    /// );
+    /// assert_eq!(
+    ///     TerminationReason::WallTimeReached.text(),
+    ///     "Maximum wall time reached"
+    /// );
+    /// assert_eq!(
     ///     TerminationReason::SolverExit("Aborted".to_string()).text(),
     ///     "Aborted"
     /// );
@@ -106,6 +161,16 @@ impl TerminationReason {
             TerminationReason::Interrupt => "Interrupt",
             TerminationReason::SolverConverged => "Solver converged",
             TerminationReason::Timeout => "Timeout reached",
+            TerminationReason::Infeasible => "Problem is infeasible",
+            TerminationReason::Unbounded => "Problem is unbounded",
+            TerminationReason::GradientToleranceReached => "Gradient norm below tolerance",
+            TerminationReason::CostChangeToleranceReached => "Cost change below tolerance",
+            TerminationReason::ParamChangeToleranceReached => "Parameter change below tolerance",
+            TerminationReason::Stalled => "Best cost did not improve",
+            TerminationReason::MaxFuncEvalsReached => {
+                "Maximum number of function evaluations reached"
+            }
+            TerminationReason::WallTimeReached => "Maximum wall time reached",
             TerminationReason::SolverExit(reason) => reason.as_ref(),
         }
     }
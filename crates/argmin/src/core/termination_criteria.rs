@@ -0,0 +1,816 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Termination criteria
+//!
+//! Termination criteria which are independent of the solver can be attached to the
+//! [`Executor`](`crate::core::Executor`) via
+//! [`add_termination_criterion`](`crate::core::Executor::add_termination_criterion`). They are
+//! checked before each iteration, after the termination criteria of the solver itself (see
+//! [`Solver::terminate`](`crate::core::Solver::terminate`)). Each criterion implements the
+//! [`TerminationCriterion`] trait and terminates with a dedicated [`TerminationReason`].
+//!
+//! The following criteria are available:
+//!
+//! * [`GradientNorm`]: L2 norm of the gradient below a tolerance
+//! * [`CostChange`]: absolute or relative change of the cost function value below a tolerance
+//! * [`ParamChange`]: L2 norm of the change of the parameter vector below a tolerance
+//! * [`Stall`]: best cost function value did not improve for a number of iterations
+//! * [`MaxFuncEvals`]: maximum number of function evaluations reached
+//! * [`WallTime`]: maximum wall time reached
+//!
+//! Criteria can be combined with [`And`] and [`Or`], either via the constructors or via the `and`
+//! and `or` methods of the criteria.
+//!
+//! # Example
+//!
+//! ```
+//! # use argmin::core::{Error, Executor};
+//! # use argmin::core::test_utils::{TestSolver, TestProblem};
+//! use argmin::core::termination_criteria::{CostChange, ParamChange, Stall, WallTime};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Error> {
+//! # let solver = TestSolver::new();
+//! # let problem = TestProblem::new();
+//! let res = Executor::new(problem, solver)
+//!     .configure(|state| state.param(vec![1.0f64, 0.0]).max_iters(100))
+//!     // Stop if both the cost and the parameter vector do not change anymore ...
+//!     .add_termination_criterion(
+//!         CostChange::absolute(1e-10)?.and(ParamChange::new(1e-8)?)
+//!     )
+//!     // ... or if there was no improvement in 20 iterations or after 10 seconds
+//!     .add_termination_criterion(Stall::new(20).or(WallTime::new(Duration::from_secs(10))))
+//!     .run()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::{ArgminFloat, Error, IterState, State, TerminationReason, TerminationStatus};
+use argmin_math::{ArgminL2Norm, ArgminSub};
+use web_time::{Duration, Instant};
+
+/// Interface for termination criteria
+///
+/// `check` is called by the [`Executor`](`crate::core::Executor`) before each iteration. Criteria
+/// which depend on the history of the optimization (such as [`CostChange`]) keep track of it
+/// themselves, therefore `check` takes `&mut self`.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{State, TerminationReason, TerminationStatus};
+/// use argmin::core::termination_criteria::TerminationCriterion;
+///
+/// /// Terminates once the cost function value is negative
+/// struct NegativeCost {}
+///
+/// impl<I: State> TerminationCriterion<I> for NegativeCost {
+///     fn check(&mut self, state: &I) -> TerminationStatus {
+///         if state.get_cost() < num_traits::Zero::zero() {
+///             TerminationStatus::Terminated(TerminationReason::SolverExit(
+///                 "Negative cost".to_string(),
+///             ))
+///         } else {
+///             TerminationStatus::NotTerminated
+///         }
+///     }
+/// }
+/// ```
+pub trait TerminationCriterion<I> {
+    /// Checks whether the optimization should terminate given the current state.
+    fn check(&mut self, state: &I) -> TerminationStatus;
+}
+
+/// Implements the `and` and `or` methods for combining criteria.
+macro_rules! combinators {
+    ($name:ident $(<$($generic:ident),*>)?) => {
+        impl$(<$($generic),*>)? $name$(<$($generic),*>)? {
+            /// Combines `self` and `other` such that the optimization terminates only if both
+            /// criteria are met. See [`And`].
+            #[must_use]
+            pub fn and<C>(self, other: C) -> And<Self, C> {
+                And::new(self, other)
+            }
+
+            /// Combines `self` and `other` such that the optimization terminates if either
+            /// criterion is met. See [`Or`].
+            #[must_use]
+            pub fn or<C>(self, other: C) -> Or<Self, C> {
+                Or::new(self, other)
+            }
+        }
+    };
+}
+
+/// Terminates if both criteria are met
+///
+/// Both criteria are checked in every iteration (there is no short-circuiting), such that
+/// criteria which depend on the history of the optimization stay up to date. The termination
+/// reason is the one of the first criterion.
+#[derive(Clone, Debug)]
+pub struct And<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> And<A, B> {
+    /// Construct a new instance of `And`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// use argmin::core::termination_criteria::{And, CostChange, GradientNorm};
+    ///
+    /// let criterion: And<GradientNorm<f64>, CostChange<f64>> =
+    ///     And::new(GradientNorm::new(1e-6)?, CostChange::absolute(1e-10)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(first: A, second: B) -> Self {
+        And { first, second }
+    }
+}
+
+combinators!(And<A, B>);
+
+impl<I, A, B> TerminationCriterion<I> for And<A, B>
+where
+    A: TerminationCriterion<I>,
+    B: TerminationCriterion<I>,
+{
+    fn check(&mut self, state: &I) -> TerminationStatus {
+        let first = self.first.check(state);
+        let second = self.second.check(state);
+        if first.terminated() && second.terminated() {
+            first
+        } else {
+            TerminationStatus::NotTerminated
+        }
+    }
+}
+
+/// Terminates if either criterion is met
+///
+/// Both criteria are checked in every iteration (there is no short-circuiting), such that
+/// criteria which depend on the history of the optimization stay up to date. If both criteria are
+/// met, the termination reason is the one of the first criterion.
+#[derive(Clone, Debug)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Or<A, B> {
+    /// Construct a new instance of `Or`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::termination_criteria::{Or, Stall, MaxFuncEvals};
+    ///
+    /// let criterion = Or::new(Stall::new(20), MaxFuncEvals::new(1000));
+    /// ```
+    pub fn new(first: A, second: B) -> Self {
+        Or { first, second }
+    }
+}
+
+combinators!(Or<A, B>);
+
+impl<I, A, B> TerminationCriterion<I> for Or<A, B>
+where
+    A: TerminationCriterion<I>,
+    B: TerminationCriterion<I>,
+{
+    fn check(&mut self, state: &I) -> TerminationStatus {
+        let first = self.first.check(state);
+        let second = self.second.check(state);
+        if first.terminated() {
+            first
+        } else {
+            second
+        }
+    }
+}
+
+/// Terminates if the L2 norm of the gradient is below a tolerance
+///
+/// Requires a solver which stores the gradient in the [`IterState`]. Terminates with
+/// [`TerminationReason::GradientToleranceReached`].
+#[derive(Clone, Debug)]
+pub struct GradientNorm<F> {
+    tolerance: F,
+}
+
+impl<F: ArgminFloat> GradientNorm<F> {
+    /// Construct a new instance of `GradientNorm`
+    ///
+    /// The tolerance must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// use argmin::core::termination_criteria::GradientNorm;
+    ///
+    /// let criterion: GradientNorm<f64> = GradientNorm::new(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(tolerance: F) -> Result<Self, Error> {
+        if tolerance < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GradientNorm`: tolerance must be >= 0."
+            ));
+        }
+        Ok(GradientNorm { tolerance })
+    }
+}
+
+combinators!(GradientNorm<F>);
+
+impl<P, G, J, H, R, F> TerminationCriterion<IterState<P, G, J, H, R, F>> for GradientNorm<F>
+where
+    IterState<P, G, J, H, R, F>: State<Float = F>,
+    G: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn check(&mut self, state: &IterState<P, G, J, H, R, F>) -> TerminationStatus {
+        match state.get_gradient() {
+            Some(grad) if grad.l2_norm() <= self.tolerance => {
+                TerminationStatus::Terminated(TerminationReason::GradientToleranceReached)
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+/// Terminates if the change of the cost function value between two iterations is below a
+/// tolerance
+///
+/// The change is either absolute (`|c_k - c_{k-1}| <= tol`) or relative
+/// (`|c_k - c_{k-1}| <= tol * |c_{k-1}|`). Terminates with
+/// [`TerminationReason::CostChangeToleranceReached`].
+#[derive(Clone, Debug)]
+pub struct CostChange<F> {
+    tolerance: F,
+    relative: bool,
+    prev_cost: Option<F>,
+}
+
+impl<F: ArgminFloat> CostChange<F> {
+    /// Terminate if the absolute change of the cost function value is below `tolerance`
+    ///
+    /// The tolerance must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// use argmin::core::termination_criteria::CostChange;
+    ///
+    /// let criterion: CostChange<f64> = CostChange::absolute(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn absolute(tolerance: F) -> Result<Self, Error> {
+        Self::new(tolerance, false)
+    }
+
+    /// Terminate if the change of the cost function value relative to the previous cost function
+    /// value is below `tolerance`
+    ///
+    /// The tolerance must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// use argmin::core::termination_criteria::CostChange;
+    ///
+    /// let criterion: CostChange<f64> = CostChange::relative(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn relative(tolerance: F) -> Result<Self, Error> {
+        Self::new(tolerance, true)
+    }
+
+    fn new(tolerance: F, relative: bool) -> Result<Self, Error> {
+        if tolerance < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CostChange`: tolerance must be >= 0."
+            ));
+        }
+        Ok(CostChange {
+            tolerance,
+            relative,
+            prev_cost: None,
+        })
+    }
+}
+
+combinators!(CostChange<F>);
+
+impl<I, F> TerminationCriterion<I> for CostChange<F>
+where
+    I: State<Float = F>,
+    F: ArgminFloat,
+{
+    fn check(&mut self, state: &I) -> TerminationStatus {
+        let cost = state.get_cost();
+        let prev_cost = self.prev_cost.replace(cost);
+        match prev_cost {
+            Some(prev_cost) if cost.is_finite() && prev_cost.is_finite() => {
+                let tolerance = if self.relative {
+                    self.tolerance * prev_cost.abs()
+                } else {
+                    self.tolerance
+                };
+                if (cost - prev_cost).abs() <= tolerance {
+                    TerminationStatus::Terminated(TerminationReason::CostChangeToleranceReached)
+                } else {
+                    TerminationStatus::NotTerminated
+                }
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+/// Terminates if the L2 norm of the change of the parameter vector between two iterations is
+/// below a tolerance
+///
+/// Terminates with [`TerminationReason::ParamChangeToleranceReached`].
+#[derive(Clone, Debug)]
+pub struct ParamChange<P, F> {
+    tolerance: F,
+    prev_param: Option<P>,
+}
+
+impl<P, F: ArgminFloat> ParamChange<P, F> {
+    /// Construct a new instance of `ParamChange`
+    ///
+    /// The tolerance must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// use argmin::core::termination_criteria::ParamChange;
+    ///
+    /// let criterion: ParamChange<Vec<f64>, f64> = ParamChange::new(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(tolerance: F) -> Result<Self, Error> {
+        if tolerance < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParamChange`: tolerance must be >= 0."
+            ));
+        }
+        Ok(ParamChange {
+            tolerance,
+            prev_param: None,
+        })
+    }
+}
+
+combinators!(ParamChange<P, F>);
+
+impl<I, P, F> TerminationCriterion<I> for ParamChange<P, F>
+where
+    I: State<Param = P, Float = F>,
+    P: Clone + ArgminSub<P, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn check(&mut self, state: &I) -> TerminationStatus {
+        let Some(param) = state.get_param() else {
+            return TerminationStatus::NotTerminated;
+        };
+        match self.prev_param.replace(param.clone()) {
+            Some(prev_param) if param.sub(&prev_param).l2_norm() <= self.tolerance => {
+                TerminationStatus::Terminated(TerminationReason::ParamChangeToleranceReached)
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+/// Terminates if the best cost function value did not improve for a given number of iterations
+///
+/// Terminates with [`TerminationReason::Stalled`].
+#[derive(Clone, Debug)]
+pub struct Stall {
+    iters: u64,
+}
+
+impl Stall {
+    /// Construct a new instance of `Stall`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::termination_criteria::Stall;
+    ///
+    /// let criterion = Stall::new(50);
+    /// ```
+    pub fn new(iters: u64) -> Self {
+        Stall { iters }
+    }
+}
+
+combinators!(Stall);
+
+impl<I: State> TerminationCriterion<I> for Stall {
+    fn check(&mut self, state: &I) -> TerminationStatus {
+        if state.get_iter().saturating_sub(state.get_last_best_iter()) >= self.iters {
+            TerminationStatus::Terminated(TerminationReason::Stalled)
+        } else {
+            TerminationStatus::NotTerminated
+        }
+    }
+}
+
+/// Terminates once the number of function evaluations reaches a maximum
+///
+/// By default, the evaluations of the cost function (`cost_count`) are counted. Other (or
+/// additional) counters can be chosen via [`with_counters`](`MaxFuncEvals::with_counters`), in
+/// which case the sum of all chosen counters is compared to the maximum.
+///
+/// Function evaluations are only tracked by the state if counting is enabled, e.g. via
+/// `.configure(|state| state.counting(true))`.
+///
+/// Terminates with [`TerminationReason::MaxFuncEvalsReached`].
+///
+/// This criterion is only checked in between iterations. A hard limit which is also enforced
+/// within iterations (including nested line searches) can be set with
+/// [`FuncEvalBudget`](`crate::core::FuncEvalBudget`).
+#[derive(Clone, Debug)]
+pub struct MaxFuncEvals {
+    max_evals: u64,
+    counters: Vec<String>,
+}
+
+impl MaxFuncEvals {
+    /// Construct a new instance of `MaxFuncEvals`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::termination_criteria::MaxFuncEvals;
+    ///
+    /// let criterion = MaxFuncEvals::new(1000);
+    /// ```
+    pub fn new(max_evals: u64) -> Self {
+        MaxFuncEvals {
+            max_evals,
+            counters: vec!["cost_count".to_string()],
+        }
+    }
+
+    /// Set the counters which are summed up and compared to the maximum
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::termination_criteria::MaxFuncEvals;
+    ///
+    /// let criterion = MaxFuncEvals::new(1000).with_counters(["cost_count", "gradient_count"]);
+    /// ```
+    #[must_use]
+    pub fn with_counters<S: ToString>(mut self, counters: impl IntoIterator<Item = S>) -> Self {
+        self.counters = counters.into_iter().map(|c| c.to_string()).collect();
+        self
+    }
+}
+
+combinators!(MaxFuncEvals);
+
+impl<I: State> TerminationCriterion<I> for MaxFuncEvals {
+    fn check(&mut self, state: &I) -> TerminationStatus {
+        let counts = state.get_func_counts();
+        let evals: u64 = self
+            .counters
+            .iter()
+            .filter_map(|counter| counts.get(counter))
+            .sum();
+        if evals >= self.max_evals {
+            TerminationStatus::Terminated(TerminationReason::MaxFuncEvalsReached)
+        } else {
+            TerminationStatus::NotTerminated
+        }
+    }
+}
+
+/// Terminates once a given wall time has passed
+///
+/// Time is measured from the first check, which happens right after the initialization of the
+/// solver. In contrast to [`Executor::timeout`](`crate::core::Executor::timeout`), this criterion
+/// can be combined with other criteria.
+///
+/// Terminates with [`TerminationReason::WallTimeReached`].
+#[derive(Clone, Debug)]
+pub struct WallTime {
+    duration: Duration,
+    start: Option<Instant>,
+}
+
+impl WallTime {
+    /// Construct a new instance of `WallTime`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::termination_criteria::WallTime;
+    /// use std::time::Duration;
+    ///
+    /// let criterion = WallTime::new(Duration::from_secs(60));
+    /// ```
+    pub fn new(duration: Duration) -> Self {
+        WallTime {
+            duration,
+            start: None,
+        }
+    }
+}
+
+combinators!(WallTime);
+
+impl<I> TerminationCriterion<I> for WallTime {
+    fn check(&mut self, _state: &I) -> TerminationStatus {
+        let start = self.start.get_or_insert_with(Instant::now);
+        if start.elapsed() >= self.duration {
+            TerminationStatus::Terminated(TerminationReason::WallTimeReached)
+        } else {
+            TerminationStatus::NotTerminated
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+
+    type TestState = IterState<Vec<f64>, Vec<f64>, (), (), (), f64>;
+
+    fn terminated(reason: TerminationReason) -> TerminationStatus {
+        TerminationStatus::Terminated(reason)
+    }
+
+    #[test]
+    fn test_invalid_tolerances() {
+        assert_error!(
+            GradientNorm::new(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`GradientNorm`: tolerance must be >= 0.\""
+        );
+        assert_error!(
+            CostChange::absolute(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`CostChange`: tolerance must be >= 0.\""
+        );
+        assert_error!(
+            CostChange::relative(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`CostChange`: tolerance must be >= 0.\""
+        );
+        assert_error!(
+            ParamChange::<Vec<f64>, f64>::new(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`ParamChange`: tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_gradient_norm() {
+        let mut criterion = GradientNorm::new(1e-3).unwrap();
+        let state = TestState::new();
+        assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+        let state = state.gradient(vec![1e-2, 0.0]);
+        assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+        let state = state.gradient(vec![1e-4, 1e-4]);
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::GradientToleranceReached)
+        );
+    }
+
+    #[test]
+    fn test_cost_change_absolute() {
+        let mut criterion = CostChange::absolute(1e-3).unwrap();
+        // Infinite cost after construction of the state is ignored
+        assert_eq!(
+            criterion.check(&TestState::new()),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().cost(10.0)),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().cost(9.0)),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().cost(8.9995)),
+            terminated(TerminationReason::CostChangeToleranceReached)
+        );
+    }
+
+    #[test]
+    fn test_cost_change_relative() {
+        let mut criterion = CostChange::relative(1e-3).unwrap();
+        assert_eq!(
+            criterion.check(&TestState::new().cost(1000.0)),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().cost(999.5)),
+            terminated(TerminationReason::CostChangeToleranceReached)
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().cost(0.1)),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().cost(0.0999)),
+            TerminationStatus::NotTerminated
+        );
+    }
+
+    #[test]
+    fn test_param_change() {
+        let mut criterion = ParamChange::new(1e-3).unwrap();
+        assert_eq!(
+            criterion.check(&TestState::new()),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().param(vec![1.0, 1.0])),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().param(vec![1.1, 1.0])),
+            TerminationStatus::NotTerminated
+        );
+        assert_eq!(
+            criterion.check(&TestState::new().param(vec![1.1, 1.0005])),
+            terminated(TerminationReason::ParamChangeToleranceReached)
+        );
+    }
+
+    #[test]
+    fn test_stall() {
+        let mut criterion = Stall::new(3);
+        let mut state = TestState::new().param(vec![1.0]).cost(1.0);
+        state.update();
+        for _ in 0..2 {
+            state.increment_iter();
+            assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+        }
+        state.increment_iter();
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::Stalled)
+        );
+    }
+
+    #[test]
+    fn test_max_func_evals() {
+        let mut problem = crate::core::Problem::new(());
+        problem.counts.insert("cost_count", 5);
+        problem.counts.insert("gradient_count", 5);
+        let mut state = TestState::new().counting(true);
+        state.func_counts(&problem);
+
+        let mut criterion = MaxFuncEvals::new(6);
+        assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+        let mut criterion = MaxFuncEvals::new(6).with_counters(["cost_count", "gradient_count"]);
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::MaxFuncEvalsReached)
+        );
+        let mut criterion = MaxFuncEvals::new(5);
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::MaxFuncEvalsReached)
+        );
+    }
+
+    #[test]
+    fn test_wall_time() {
+        let mut criterion = WallTime::new(Duration::from_millis(20));
+        let state = TestState::new();
+        assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::WallTimeReached)
+        );
+    }
+
+    #[test]
+    fn test_and_or() {
+        let state = TestState::new().gradient(vec![0.0]);
+
+        let mut criterion = GradientNorm::new(1e-3).unwrap().and(Stall::new(10));
+        assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+
+        let mut criterion = GradientNorm::new(1e-3).unwrap().and(Stall::new(0));
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::GradientToleranceReached)
+        );
+
+        let mut criterion = Stall::new(10).or(MaxFuncEvals::new(0));
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::MaxFuncEvalsReached)
+        );
+
+        let mut criterion = Stall::new(0).or(MaxFuncEvals::new(0));
+        assert_eq!(
+            criterion.check(&state),
+            terminated(TerminationReason::Stalled)
+        );
+
+        let mut criterion = Stall::new(10).or(MaxFuncEvals::new(10));
+        assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+    }
+
+    #[test]
+    fn test_and_updates_history_of_both() {
+        // `CostChange` must see every cost even if the first criterion is not met
+        let mut criterion = Stall::new(10).and(CostChange::absolute(1e-3).unwrap());
+        let mut or_criterion = Stall::new(10).or(CostChange::absolute(1e-3).unwrap());
+        for cost in [10.0, 5.0] {
+            let state = TestState::new().cost(cost);
+            assert_eq!(criterion.check(&state), TerminationStatus::NotTerminated);
+            assert_eq!(or_criterion.check(&state), TerminationStatus::NotTerminated);
+        }
+        let state = TestState::new().cost(5.0);
+        assert_eq!(
+            or_criterion.check(&state),
+            terminated(TerminationReason::CostChangeToleranceReached)
+        );
+        assert_eq!(criterion.second.prev_cost, Some(5.0));
+    }
+
+    #[test]
+    fn test_gradient_norm_with_solver() {
+        use crate::core::{CostFunction, Executor, Gradient};
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+        use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock(p))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_derivative(p))
+            }
+        }
+
+        // Disable the gradient based stopping criterion of the solver
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 7)
+            .with_tolerance_grad(0.0)
+            .unwrap();
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+            .add_termination_criterion(GradientNorm::new(1e-4).unwrap())
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state().get_termination_reason(),
+            Some(&TerminationReason::GradientToleranceReached)
+        );
+    }
+}
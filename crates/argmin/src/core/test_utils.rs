@@ -6,7 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    CostFunction, Error, Gradient, Hessian, IterState, Jacobian, Operator, Problem, Solver, KV,
+    BoxConstraint, CostFunction, Error, Gradient, Hessian, IterState, Jacobian, Operator, Problem,
+    Solver, KV,
 };
 use crate::solver::simulatedannealing::Anneal;
 #[cfg(feature = "serde1")]
@@ -303,6 +304,140 @@ impl Gradient for TestSparseProblem {
     }
 }
 
+/// A problem with box constraints useful for testing
+///
+/// cost = (x_0 - 2)^2 + (x_1 + 2)^2 subject to -1 <= x_i <= 1
+///
+/// The unconstrained minimum `[2, -2]` lies outside of the box, the constrained minimum is
+/// `[1, -1]` with cost `2`.
+///
+/// Implements [`CostFunction`], [`Gradient`], [`BoxConstraint`] and [`Anneal`].
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct TestBoxProblem {}
+
+impl TestBoxProblem {
+    /// Create an instance of `TestBoxProblem`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::test_utils::TestBoxProblem;
+    ///
+    /// let problem = TestBoxProblem::new();
+    /// # assert_eq!(problem, TestBoxProblem {});
+    /// ```
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        TestBoxProblem {}
+    }
+}
+
+impl CostFunction for TestBoxProblem {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    /// Returns the squared distance to `[2, -2]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::test_utils::TestBoxProblem;
+    /// use argmin::core::CostFunction;
+    /// # use argmin::core::Error;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let problem = TestBoxProblem::new();
+    ///
+    /// let res = problem.cost(&vec![1.0, -1.0])?;
+    /// # assert_eq!(res, 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok((p[0] - 2.0).powi(2) + (p[1] + 2.0).powi(2))
+    }
+}
+
+impl Gradient for TestBoxProblem {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    /// Returns the gradient of the cost function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::test_utils::TestBoxProblem;
+    /// use argmin::core::Gradient;
+    /// # use argmin::core::Error;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let problem = TestBoxProblem::new();
+    ///
+    /// let res = problem.gradient(&vec![1.0, -1.0])?;
+    /// # assert_eq!(res, vec![-2.0, 2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] + 2.0)])
+    }
+}
+
+impl BoxConstraint for TestBoxProblem {
+    type Param = Vec<f64>;
+
+    /// Returns the bounds `[-1, -1]` and `[1, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::test_utils::TestBoxProblem;
+    /// use argmin::core::BoxConstraint;
+    /// # use argmin::core::Error;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let problem = TestBoxProblem::new();
+    ///
+    /// let (lower, upper) = problem.bounds()?;
+    /// # assert_eq!(lower, vec![-1.0, -1.0]);
+    /// # assert_eq!(upper, vec![1.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn bounds(&self) -> Result<(Self::Param, Self::Param), Error> {
+        Ok((vec![-1.0, -1.0], vec![1.0, 1.0]))
+    }
+}
+
+impl Anneal for TestBoxProblem {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+    type Float = f64;
+
+    /// Deterministically moves `p` by `t` towards the unconstrained minimum, overshooting the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::test_utils::TestBoxProblem;
+    /// use argmin::solver::simulatedannealing::Anneal;
+    /// # use argmin::core::Error;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let problem = TestBoxProblem::new();
+    ///
+    /// let res = problem.anneal(&vec![0.0, 0.0], 2.0)?;
+    /// # assert_eq!(res, vec![2.0, -2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn anneal(&self, p: &Self::Param, t: Self::Float) -> Result<Self::Output, Error> {
+        Ok(vec![p[0] + t, p[1] - t])
+    }
+}
+
 /// A (non-working) solver useful for testing
 ///
 /// Implements the [`Solver`] trait.
@@ -0,0 +1,516 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error, Jacobian, Operator, OptimizationResult, State};
+use argmin_math::{
+    ArgminAdd, ArgminDiag, ArgminDot, ArgminInv, ArgminL2Norm, ArgminLen, ArgminMap, ArgminMul,
+    ArgminScaleRows, ArgminSub, ArgminTranspose,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Uncertainty of the parameters of a least squares fit
+///
+/// Computed by [`OptimizationResult::parameter_uncertainty`] from the residuals `r` and the
+/// Jacobian `J` at the best parameter vector. With `m` residuals and `n` parameters, the residual
+/// variance is `s^2 = |r|^2 / (m - n)` and the covariance of the parameters is
+/// `s^2 (J^T J)^-1`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ParameterUncertainty<P, J, F> {
+    /// Best parameter vector
+    pub param: P,
+    /// Covariance matrix of the parameters
+    pub covariance: J,
+    /// Standard errors of the parameters (square root of the diagonal of the covariance matrix)
+    pub standard_errors: P,
+    /// Correlation matrix of the parameters
+    pub correlation: J,
+    /// Estimated variance of the residuals
+    pub residual_variance: F,
+    /// Degrees of freedom (number of residuals minus number of parameters)
+    pub degrees_of_freedom: usize,
+}
+
+impl<P, J, F> ParameterUncertainty<P, J, F>
+where
+    P: ArgminAdd<P, P> + ArgminSub<P, P> + ArgminMul<F, P>,
+    F: ArgminFloat,
+{
+    /// Returns the lower and upper bounds of the two-sided confidence intervals of the parameters
+    /// at confidence `level` (for instance `0.95`), based on Student's t-distribution.
+    ///
+    /// Returns an error if `level` is not in `(0, 1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, ParameterUncertainty};
+    /// # fn main() -> Result<(), Error> {
+    /// # let uncertainty = ParameterUncertainty {
+    /// #     param: vec![1.0f64],
+    /// #     covariance: vec![vec![0.04f64]],
+    /// #     standard_errors: vec![0.2f64],
+    /// #     correlation: vec![vec![1.0f64]],
+    /// #     residual_variance: 0.1f64,
+    /// #     degrees_of_freedom: 10,
+    /// # };
+    /// let (lower, upper) = uncertainty.confidence_intervals(0.95)?;
+    /// # assert!((upper[0] - lower[0] - 2.0 * 2.2281388 * 0.2).abs() < 1e-6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn confidence_intervals(&self, level: F) -> Result<(P, P), Error> {
+        if !(level > float!(0.0) && level < float!(1.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParameterUncertainty`: confidence level must be in (0, 1)."
+            ));
+        }
+        let p = (float!(1.0) + level) / float!(2.0);
+        let p = p.to_f64().ok_or_else(argmin_error_closure!(
+            ConditionViolated,
+            "`ParameterUncertainty`: cannot convert confidence level to `f64`."
+        ))?;
+        let t = student_t_quantile(p, self.degrees_of_freedom as f64);
+        let t = F::from_f64(t).ok_or_else(argmin_error_closure!(
+            ConditionViolated,
+            "`ParameterUncertainty`: cannot convert quantile of the t-distribution from `f64`."
+        ))?;
+        let half_width = self.standard_errors.mul(&t);
+        Ok((self.param.sub(&half_width), self.param.add(&half_width)))
+    }
+}
+
+impl<O, S, I> OptimizationResult<O, S, I>
+where
+    I: State,
+{
+    /// Computes covariance, standard errors and correlation of the best parameter vector of a
+    /// least squares problem.
+    ///
+    /// Requires the problem to implement [`Operator`] (returning the residuals) and [`Jacobian`].
+    /// Both are evaluated once at the best parameter vector; these evaluations are counted in the
+    /// function evaluation counts of the problem.
+    ///
+    /// Returns an error if no best parameter vector is available, if there are not more residuals
+    /// than parameters or if `J^T J` cannot be inverted.
+    ///
+    /// The covariance matrix is dense in general, therefore `J^T J` is inverted explicitly via
+    /// [`ArgminInv`](argmin_math::ArgminInv). This restricts the Jacobian to the dense backends
+    /// (`Vec`, arrays, `ndarray`, `nalgebra` and `faer`); sparse `sprs` matrices do not implement
+    /// `ArgminInv` and are not supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, IterState, Jacobian, Operator, OptimizationResult, Problem, State};
+    /// # fn main() -> Result<(), Error> {
+    /// struct Line {
+    ///     x: Vec<f64>,
+    ///     y: Vec<f64>,
+    /// }
+    ///
+    /// impl Operator for Line {
+    ///     type Param = Vec<f64>;
+    ///     type Output = Vec<f64>;
+    ///
+    ///     fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+    ///         Ok(self.x.iter().zip(self.y.iter()).map(|(x, y)| p[0] * x + p[1] - y).collect())
+    ///     }
+    /// }
+    ///
+    /// impl Jacobian for Line {
+    ///     type Param = Vec<f64>;
+    ///     type Jacobian = Vec<Vec<f64>>;
+    ///
+    ///     fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+    ///         Ok(self.x.iter().map(|x| vec![*x, 1.0]).collect())
+    ///     }
+    /// }
+    ///
+    /// # let problem = Line { x: vec![0.0, 1.0, 2.0, 3.0], y: vec![1.1, 2.9, 5.2, 6.8] };
+    /// # let state = IterState::<Vec<f64>, (), (), (), (), f64>::new().param(vec![1.94, 1.09]);
+    /// # let mut result = OptimizationResult::new(Problem::new(problem), (), state);
+    /// # result.state.update();
+    /// // `result` is the `OptimizationResult` returned by an `Executor`
+    /// let uncertainty = result.parameter_uncertainty()?;
+    /// let (lower, upper) = uncertainty.confidence_intervals(0.95)?;
+    /// # assert_eq!(uncertainty.degrees_of_freedom, 2);
+    /// # assert!(lower[0] < 1.94 && upper[0] > 1.94);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parameter_uncertainty<P, U, J, F>(
+        &mut self,
+    ) -> Result<ParameterUncertainty<P, J, F>, Error>
+    where
+        O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
+        I: State<Param = P, Float = F>,
+        P: Clone + ArgminMap<F> + ArgminLen,
+        U: ArgminLen + ArgminL2Norm<F>,
+        J: Clone
+            + ArgminTranspose<J>
+            + ArgminDot<J, J>
+            + ArgminInv<J>
+            + ArgminMul<F, J>
+            + ArgminDiag<P>
+            + ArgminScaleRows<P>,
+        F: ArgminFloat,
+    {
+        let param = self
+            .state
+            .get_best_param()
+            .ok_or_else(argmin_error_closure!(
+                NotInitialized,
+                "`parameter_uncertainty`: no best parameter vector available."
+            ))?
+            .clone();
+        let residuals = self.problem.apply(&param)?;
+        let jacobian = self.problem.jacobian(&param)?;
+
+        let num_residuals = residuals.len();
+        let num_params = param.len();
+        if num_residuals <= num_params {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`parameter_uncertainty`: number of residuals must exceed number of parameters."
+            ));
+        }
+        let dof = num_residuals - num_params;
+
+        let norm = residuals.l2_norm();
+        let residual_variance = norm * norm / float!(dof as f64);
+
+        let jtj: J = jacobian.clone().t().dot(&jacobian);
+        let covariance: J = jtj.inv()?.mul(&residual_variance);
+        let standard_errors: P = covariance.diag().map(|v| v.sqrt());
+        let inv_standard_errors: P = standard_errors.map(|v| float!(1.0) / v);
+        let correlation: J = covariance
+            .scale_rows(&inv_standard_errors)
+            .t()
+            .scale_rows(&inv_standard_errors);
+
+        Ok(ParameterUncertainty {
+            param,
+            covariance,
+            standard_errors,
+            correlation,
+            residual_variance,
+            degrees_of_freedom: dof,
+        })
+    }
+}
+
+/// Quantile of Student's t-distribution with `dof` degrees of freedom for `0.5 <= p < 1`.
+fn student_t_quantile(p: f64, dof: f64) -> f64 {
+    let cdf = |t: f64| 1.0 - 0.5 * regularized_incomplete_beta(dof / (dof + t * t), dof / 2.0, 0.5);
+    let mut upper = 1.0;
+    while cdf(upper) < p {
+        upper *= 2.0;
+    }
+    let mut lower = 0.0;
+    for _ in 0..200 {
+        let mid = 0.5 * (lower + upper);
+        if cdf(mid) < p {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+        if upper - lower <= f64::EPSILON * upper {
+            break;
+        }
+    }
+    0.5 * (lower + upper)
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`.
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    // The continued fraction converges quickly for x < (a + 1) / (a + b + 2)
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Continued fraction of the incomplete beta function (modified Lentz's method).
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    let tiny = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < tiny {
+        d = tiny;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..300 {
+        let m = f64::from(m);
+        let m2 = 2.0 * m;
+        let aa = m * (b - m) * x / ((a + m2 - 1.0) * (a + m2));
+        for (i, aa) in [aa, -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0))]
+            .into_iter()
+            .enumerate()
+        {
+            d = 1.0 + aa * d;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = 1.0 + aa / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if i == 1 && (delta - 1.0).abs() < 1e-15 {
+                return h;
+            }
+        }
+    }
+    h
+}
+
+/// Natural logarithm of the gamma function (Lanczos approximation) for `x > 0`.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFS[0], |acc, (i, c)| acc + c / (x + i as f64));
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, IterState, Problem};
+    use approx::assert_relative_eq;
+
+    send_sync_test!(
+        parameter_uncertainty,
+        ParameterUncertainty<Vec<f64>, Vec<Vec<f64>>, f64>
+    );
+
+    #[test]
+    fn test_student_t_quantile() {
+        assert_relative_eq!(
+            student_t_quantile(0.975, 1.0),
+            12.706_204_736,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            student_t_quantile(0.975, 2.0),
+            4.302_652_730,
+            epsilon = 1e-7
+        );
+        assert_relative_eq!(
+            student_t_quantile(0.975, 10.0),
+            2.228_138_852,
+            epsilon = 1e-7
+        );
+        assert_relative_eq!(
+            student_t_quantile(0.995, 5.0),
+            4.032_142_984,
+            epsilon = 1e-7
+        );
+        assert_relative_eq!(student_t_quantile(0.9, 3.0), 1.637_744_354, epsilon = 1e-7);
+        assert_relative_eq!(student_t_quantile(0.975, 1e6), 1.959_966_4, epsilon = 1e-5);
+        assert_relative_eq!(student_t_quantile(0.5, 4.0), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_relative_eq!(ln_gamma(1.0), 0.0, epsilon = 1e-14);
+        assert_relative_eq!(ln_gamma(5.0), 24.0f64.ln(), epsilon = 1e-13);
+        assert_relative_eq!(
+            ln_gamma(0.5),
+            std::f64::consts::PI.sqrt().ln(),
+            epsilon = 1e-14
+        );
+    }
+
+    struct Line {
+        x: Vec<f64>,
+        y: Vec<f64>,
+    }
+
+    impl Operator for Line {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self
+                .x
+                .iter()
+                .zip(self.y.iter())
+                .map(|(x, y)| p[0] * x + p[1] - y)
+                .collect())
+        }
+    }
+
+    impl Jacobian for Line {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(self.x.iter().map(|x| vec![*x, 1.0]).collect())
+        }
+    }
+
+    fn result(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        param: Vec<f64>,
+    ) -> OptimizationResult<Line, (), IterState<Vec<f64>, (), (), (), (), f64>> {
+        let mut state = IterState::new().param(param);
+        state.update();
+        OptimizationResult::new(Problem::new(Line { x, y }), (), state)
+    }
+
+    #[test]
+    fn test_line_fit() {
+        // Least squares solution of y = a x + b
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = vec![1.0, 3.2, 4.8, 7.1, 9.0];
+        let n = x.len() as f64;
+        let sx: f64 = x.iter().sum();
+        let sy: f64 = y.iter().sum();
+        let sxx: f64 = x.iter().map(|x| x * x).sum();
+        let sxy: f64 = x.iter().zip(y.iter()).map(|(x, y)| x * y).sum();
+        let det = n * sxx - sx * sx;
+        let a = (n * sxy - sx * sy) / det;
+        let b = (sy - a * sx) / n;
+
+        let mut res = result(x.clone(), y.clone(), vec![a, b]);
+        let uncertainty = res.parameter_uncertainty().unwrap();
+
+        let ssr: f64 = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| (a * x + b - y).powi(2))
+            .sum();
+        let s2 = ssr / (n - 2.0);
+        let var_a = s2 * n / det;
+        let var_b = s2 * sxx / det;
+        let cov_ab = -s2 * sx / det;
+
+        assert_eq!(uncertainty.degrees_of_freedom, 3);
+        assert_eq!(uncertainty.param, vec![a, b]);
+        assert_relative_eq!(uncertainty.residual_variance, s2, epsilon = 1e-12);
+        assert_relative_eq!(uncertainty.covariance[0][0], var_a, epsilon = 1e-12);
+        assert_relative_eq!(uncertainty.covariance[1][1], var_b, epsilon = 1e-12);
+        assert_relative_eq!(uncertainty.covariance[0][1], cov_ab, epsilon = 1e-12);
+        assert_relative_eq!(uncertainty.covariance[1][0], cov_ab, epsilon = 1e-12);
+        assert_relative_eq!(
+            uncertainty.standard_errors[0],
+            var_a.sqrt(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            uncertainty.standard_errors[1],
+            var_b.sqrt(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(uncertainty.correlation[0][0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(uncertainty.correlation[1][1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(
+            uncertainty.correlation[0][1],
+            cov_ab / (var_a * var_b).sqrt(),
+            epsilon = 1e-12
+        );
+
+        let (lower, upper) = uncertainty.confidence_intervals(0.95).unwrap();
+        let t = 3.182_446_305;
+        assert_relative_eq!(lower[0], a - t * var_a.sqrt(), epsilon = 1e-8);
+        assert_relative_eq!(upper[0], a + t * var_a.sqrt(), epsilon = 1e-8);
+        assert_relative_eq!(lower[1], b - t * var_b.sqrt(), epsilon = 1e-8);
+        assert_relative_eq!(upper[1], b + t * var_b.sqrt(), epsilon = 1e-8);
+
+        assert_eq!(res.problem.counts["operator_count"], 1);
+        assert_eq!(res.problem.counts["jacobian_count"], 1);
+    }
+
+    #[test]
+    fn test_confidence_level_errors() {
+        let mut res = result(vec![0.0, 1.0, 2.0], vec![0.1, 0.9, 2.1], vec![1.0, 0.0]);
+        let uncertainty = res.parameter_uncertainty().unwrap();
+        for level in [0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            assert_error!(
+                uncertainty.confidence_intervals(level),
+                ArgminError,
+                "Invalid parameter: \"`ParameterUncertainty`: confidence level must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_enough_residuals() {
+        let mut res = result(vec![0.0, 1.0], vec![0.1, 0.9], vec![1.0, 0.0]);
+        assert_error!(
+            res.parameter_uncertainty(),
+            ArgminError,
+            concat!(
+                "Condition violated: \"`parameter_uncertainty`: number of residuals must exceed ",
+                "number of parameters.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_no_param() {
+        let mut res: OptimizationResult<Line, (), IterState<Vec<f64>, (), (), (), (), f64>> =
+            OptimizationResult::new(
+                Problem::new(Line {
+                    x: vec![],
+                    y: vec![],
+                }),
+                (),
+                IterState::new(),
+            );
+        assert_error!(
+            res.parameter_uncertainty(),
+            ArgminError,
+            "Not initialized: \"`parameter_uncertainty`: no best parameter vector available.\""
+        );
+    }
+
+    #[test]
+    fn test_singular() {
+        // All x identical: slope and intercept cannot be separated
+        let mut res = result(vec![1.0, 1.0, 1.0], vec![0.1, 0.9, 2.1], vec![1.0, 0.0]);
+        assert_eq!(
+            res.parameter_uncertainty().unwrap_err().to_string(),
+            "Non-invertible matrix"
+        );
+    }
+}
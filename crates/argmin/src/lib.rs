@@ -59,6 +59,7 @@
 //! - [Quasi-Newton methods](`crate::solver::quasinewton`)
 //!   - [BFGS](`crate::solver::quasinewton::BFGS`)
 //!   - [L-BFGS](`crate::solver::quasinewton::LBFGS`)
+//!   - [L-BFGS-B](`crate::solver::quasinewton::LBFGSB`)
 //!   - [DFP](`crate::solver::quasinewton::DFP`)
 //!   - [SR1](`crate::solver::quasinewton::SR1`)
 //!   - [SR1-TrustRegion](`crate::solver::quasinewton::SR1TrustRegion`)
@@ -67,6 +68,10 @@
 //!   - [Gauss-Newton method](`crate::solver::gaussnewton::GaussNewton`)
 //!   - [Gauss-Newton method with linesearch](`crate::solver::gaussnewton::GaussNewtonLS`)
 //!
+//! - [Levenberg-Marquardt method](`crate::solver::levenbergmarquardt::LevenbergMarquardt`)
+//!
+//! - [Robust loss functions for least squares problems](`crate::solver::robustloss`)
+//!
 //! - [Golden-section search](`crate::solver::goldensectionsearch::GoldenSectionSearch`)
 //!
 //! - [Landweber iteration](`crate::solver::landweber::Landweber`)
@@ -81,6 +86,22 @@
 //!
 //! - [Particle Swarm Optimization](`crate::solver::particleswarm::ParticleSwarm`)
 //!
+//! - [CMA-ES](`crate::solver::cmaes::CMAES`)
+//!
+//! - [Differential Evolution](`crate::solver::differentialevolution::DifferentialEvolution`)
+//!
+//! - [Simplex method for linear programs](`crate::solver::simplex::Simplex`)
+//!
+//! - [Augmented Lagrangian method](`crate::solver::augmentedlagrangian::AugmentedLagrangian`)
+//!
+//! - [Sequential quadratic programming (SQP)](`crate::solver::sqp::SQP`)
+//!
+//! - [Chain of solvers](`crate::solver::chain::Chain`)
+//!
+//! - [Multi-start](`crate::solver::multistart::MultiStart`)
+//!
+//! - [Basin-hopping](`crate::solver::multistart::BasinHopping`)
+//!
 //! ## External solvers compatible with argmin
 //!
 //! External solvers which implement the `Solver` trait are compatible with argmins `Executor`,
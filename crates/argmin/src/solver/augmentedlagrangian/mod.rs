@@ -0,0 +1,1273 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Augmented Lagrangian method
+//!
+//! Solves problems with general nonlinear equality and inequality constraints by a sequence of
+//! unconstrained subproblems, each of which is solved by an arbitrary (unconstrained) solver.
+//! For details see [`AugmentedLagrangian`].
+//!
+//! ## Reference
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, CostFunction, EqualityConstraints, Error, Executor, Gradient,
+    InequalityConstraints, IterState, OptimizationResult, Problem, Solver, State,
+    TerminationReason, KV,
+};
+use argmin_math::{
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminTranspose, ArgminZeroLike,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// # Augmented Lagrangian method
+///
+/// Minimizes `f(x)` subject to equality constraints `h(x) = 0` and inequality constraints
+/// `g(x) <= 0` by repeatedly minimizing the augmented Lagrangian
+///
+/// `L(x) = f(x) + λ^T h(x) + ρ/2 ||h(x)||^2 + 1/(2ρ) (||max(0, μ + ρ g(x))||^2 - ||μ||^2)`
+///
+/// with respect to `x` using an inner solver, followed by an update of the Lagrange multipliers
+/// `λ` and `μ`. The penalty parameter `ρ` is increased whenever the constraint violation does not
+/// decrease sufficiently.
+///
+/// Any solver which can solve unconstrained problems (for instance
+/// [`LBFGS`](`crate::solver::quasinewton::LBFGS`)) can be used as inner solver. Each outer
+/// iteration runs a fresh copy of the inner solver for at most `inner_max_iters` iterations
+/// (see [`with_inner_max_iters`](`AugmentedLagrangian::with_inner_max_iters`)), starting from the
+/// current parameter vector.
+///
+/// Constraints are enabled via
+/// [`with_equality_constraints`](`AugmentedLagrangian::with_equality_constraints`) and
+/// [`with_inequality_constraints`](`AugmentedLagrangian::with_inequality_constraints`). The
+/// multipliers are initialized to zero.
+///
+/// The state holds the value of the cost function `f` and the constraint violation
+/// ([`IterState::get_constraint_violation`]) of the current parameter vector. Violations below the
+/// constraint tolerance are stored as zero. Since the constraint violation takes precedence over
+/// the cost when determining the best parameter vector, the best parameter vector is the feasible
+/// one with the lowest cost or, if no feasible one was found yet, the one with the smallest
+/// constraint violation. The
+/// multipliers are of the output type of the constraints, which has no place in [`IterState`].
+/// They are available via [`equality_multipliers`](`AugmentedLagrangian::equality_multipliers`)
+/// and [`inequality_multipliers`](`AugmentedLagrangian::inequality_multipliers`), the penalty
+/// parameter via [`penalty`](`AugmentedLagrangian::penalty`). After each iteration, the constraint
+/// violation, the penalty parameter and the l2-norms of the multipliers are reported via `KV`.
+///
+/// The algorithm terminates with [`TerminationReason::SolverConverged`] once the constraint
+/// violation falls below the tolerance set via
+/// [`with_constraint_tolerance`](`AugmentedLagrangian::with_constraint_tolerance`).
+///
+/// Evaluations of the constraints and their Jacobians are counted (as
+/// `equality_constraints_count`, `equality_jacobian_count`, `inequality_constraints_count` and
+/// `inequality_jacobian_count`) and are subject to the
+/// [`FuncEvalBudget`](`crate::core::FuncEvalBudget`), if any.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`EqualityConstraints`]
+/// and/or [`InequalityConstraints`] (depending on which constraints are enabled) as well as
+/// everything required by the inner solver. If the inner solver needs the gradient, the problem
+/// needs to implement [`Gradient`] as well.
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AugmentedLagrangian<S, F, E = NoConstraints, I = NoConstraints> {
+    /// inner solver
+    inner: S,
+    /// maximum number of iterations of the inner solver per outer iteration
+    inner_max_iters: u64,
+    /// penalty parameter
+    penalty: F,
+    /// factor by which the penalty parameter is increased
+    penalty_increase: F,
+    /// required relative decrease of the constraint violation
+    violation_decrease: F,
+    /// tolerance on the constraint violation
+    constraint_tolerance: F,
+    /// constraint violation of the previous iteration
+    violation: F,
+    /// equality constraints
+    equality: E,
+    /// inequality constraints
+    inequality: I,
+}
+
+impl<S, F> AugmentedLagrangian<S, F, NoConstraints, NoConstraints>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`AugmentedLagrangian`]
+    ///
+    /// Takes the inner solver which is used to solve the unconstrained subproblems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # let inner_solver = ();
+    /// let al: AugmentedLagrangian<_, f64> = AugmentedLagrangian::new(inner_solver);
+    /// ```
+    pub fn new(inner: S) -> Self {
+        AugmentedLagrangian {
+            inner,
+            inner_max_iters: 1000,
+            penalty: float!(10.0),
+            penalty_increase: float!(10.0),
+            violation_decrease: float!(0.25),
+            constraint_tolerance: F::epsilon().sqrt(),
+            violation: F::infinity(),
+            equality: NoConstraints,
+            inequality: NoConstraints,
+        }
+    }
+}
+
+impl<S, F, I> AugmentedLagrangian<S, F, NoConstraints, I> {
+    /// Take the equality constraints of the problem into account
+    ///
+    /// Requires the problem to implement [`EqualityConstraints`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # let inner_solver = ();
+    /// let al = AugmentedLagrangian::<_, f64>::new(inner_solver)
+    ///     .with_equality_constraints::<Vec<f64>>();
+    /// ```
+    pub fn with_equality_constraints<C>(
+        self,
+    ) -> AugmentedLagrangian<S, F, EqualityMultipliers<C>, I> {
+        AugmentedLagrangian {
+            inner: self.inner,
+            inner_max_iters: self.inner_max_iters,
+            penalty: self.penalty,
+            penalty_increase: self.penalty_increase,
+            violation_decrease: self.violation_decrease,
+            constraint_tolerance: self.constraint_tolerance,
+            violation: self.violation,
+            equality: EqualityMultipliers { multipliers: None },
+            inequality: self.inequality,
+        }
+    }
+}
+
+impl<S, F, E> AugmentedLagrangian<S, F, E, NoConstraints> {
+    /// Take the inequality constraints of the problem into account
+    ///
+    /// Requires the problem to implement [`InequalityConstraints`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # let inner_solver = ();
+    /// let al = AugmentedLagrangian::<_, f64>::new(inner_solver)
+    ///     .with_inequality_constraints::<Vec<f64>>();
+    /// ```
+    pub fn with_inequality_constraints<C>(
+        self,
+    ) -> AugmentedLagrangian<S, F, E, InequalityMultipliers<C>> {
+        AugmentedLagrangian {
+            inner: self.inner,
+            inner_max_iters: self.inner_max_iters,
+            penalty: self.penalty,
+            penalty_increase: self.penalty_increase,
+            violation_decrease: self.violation_decrease,
+            constraint_tolerance: self.constraint_tolerance,
+            violation: self.violation,
+            equality: self.equality,
+            inequality: InequalityMultipliers { multipliers: None },
+        }
+    }
+}
+
+impl<S, F, C, I> AugmentedLagrangian<S, F, EqualityMultipliers<C>, I> {
+    /// Returns the current Lagrange multipliers of the equality constraints
+    ///
+    /// Returns `None` if the solver has not been initialized yet.
+    pub fn equality_multipliers(&self) -> Option<&C> {
+        self.equality.multipliers()
+    }
+}
+
+impl<S, F, E, C> AugmentedLagrangian<S, F, E, InequalityMultipliers<C>> {
+    /// Returns the current Lagrange multipliers of the inequality constraints
+    ///
+    /// Returns `None` if the solver has not been initialized yet.
+    pub fn inequality_multipliers(&self) -> Option<&C> {
+        self.inequality.multipliers()
+    }
+}
+
+impl<S, F, E, I> AugmentedLagrangian<S, F, E, I>
+where
+    F: ArgminFloat,
+{
+    /// Returns the constraint violation of the current parameter vector
+    ///
+    /// Returns infinity if the solver has not been initialized yet.
+    pub fn constraint_violation(&self) -> F {
+        self.violation
+    }
+
+    /// Returns the current penalty parameter
+    pub fn penalty(&self) -> F {
+        self.penalty
+    }
+
+    /// Constraint violation stored in the state: zero if the current parameter vector is feasible
+    fn state_violation(&self) -> F {
+        if self.violation <= self.constraint_tolerance {
+            float!(0.0)
+        } else {
+            self.violation
+        }
+    }
+
+    /// Set maximum number of iterations of the inner solver per outer iteration
+    ///
+    /// Defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # let inner_solver = ();
+    /// let al: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(inner_solver).with_inner_max_iters(100);
+    /// ```
+    #[must_use]
+    pub fn with_inner_max_iters(mut self, iters: u64) -> Self {
+        self.inner_max_iters = iters;
+        self
+    }
+
+    /// Set initial penalty parameter
+    ///
+    /// Must be larger than 0 and defaults to `10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner_solver = ();
+    /// let al: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(inner_solver).with_penalty(100.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty(mut self, penalty: F) -> Result<Self, Error> {
+        if penalty <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: penalty must be > 0."
+            ));
+        }
+        self.penalty = penalty;
+        Ok(self)
+    }
+
+    /// Set factor by which the penalty parameter is increased
+    ///
+    /// The penalty parameter is multiplied by this factor whenever the constraint violation did
+    /// not decrease sufficiently (see
+    /// [`with_violation_decrease`](`AugmentedLagrangian::with_violation_decrease`)).
+    /// Must be larger than 1 and defaults to `10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner_solver = ();
+    /// let al: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(inner_solver).with_penalty_increase(5.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty_increase(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: penalty increase factor must be > 1."
+            ));
+        }
+        self.penalty_increase = factor;
+        Ok(self)
+    }
+
+    /// Set required relative decrease of the constraint violation
+    ///
+    /// If the constraint violation of an iteration is larger than this factor times the violation
+    /// of the previous iteration, the penalty parameter is increased.
+    /// Must be in `(0, 1)` and defaults to `0.25`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner_solver = ();
+    /// let al: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(inner_solver).with_violation_decrease(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_violation_decrease(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(0.0) || factor >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: violation decrease factor must be in (0, 1)."
+            ));
+        }
+        self.violation_decrease = factor;
+        Ok(self)
+    }
+
+    /// Set tolerance on the constraint violation
+    ///
+    /// The algorithm terminates once the constraint violation is below this tolerance.
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner_solver = ();
+    /// let al: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(inner_solver).with_constraint_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_constraint_tolerance(mut self, tolerance: F) -> Result<Self, Error> {
+        if tolerance < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: constraint tolerance must be >= 0."
+            ));
+        }
+        self.constraint_tolerance = tolerance;
+        Ok(self)
+    }
+}
+
+impl<O, S, P, G, F, E, I> Solver<O, IterState<P, G, (), (), (), F>>
+    for AugmentedLagrangian<S, F, E, I>
+where
+    O: CostFunction<Param = P, Output = F>,
+    S: Clone + Solver<AugmentedLagrangianProblem<O, F, E, I>, IterState<P, G, (), (), (), F>>,
+    P: Clone,
+    G: Clone,
+    F: ArgminFloat,
+    E: Clone + ConstraintHandling<O, P, F>,
+    I: Clone + ConstraintHandling<O, P, F>,
+{
+    fn name(&self) -> &str {
+        "Augmented Lagrangian"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`AugmentedLagrangian` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let eq_violation = self.equality.init(problem, &param)?;
+        let ineq_violation = self.inequality.init(problem, &param)?;
+        self.violation = eq_violation.hypot(ineq_violation);
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        Ok((
+            state
+                .param(param)
+                .cost(cost)
+                .constraint_violation(self.state_violation()),
+            Some(kv!("penalty" => self.penalty; "violation" => self.violation;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`AugmentedLagrangian`: Parameter vector in state not set."
+        ))?;
+
+        // The user defined problem is shared between the cost function and gradient (which are
+        // evaluated without locking) and the constraints, which are evaluated via a separate
+        // `Problem` sharing the budget.
+        let user_problem = Arc::new(problem.take_problem().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`AugmentedLagrangian`: Failed to take `problem` for inner solver"
+        ))?);
+        let mut constraint_problem = Problem::new(SharedProblem(Arc::clone(&user_problem)));
+        constraint_problem.budget = problem.budget.clone();
+        let inner_problem = AugmentedLagrangianProblem {
+            problem: Arc::clone(&user_problem),
+            constraints: Mutex::new(constraint_problem),
+            penalty: self.penalty,
+            equality: self.equality.clone(),
+            inequality: self.inequality.clone(),
+        };
+
+        // Solve the unconstrained subproblem
+        let result = Executor::new(inner_problem, self.inner.clone())
+            .configure(|config| config.param(param).max_iters(self.inner_max_iters))
+            .inherit_func_eval_budget(problem)
+            .ctrlc(false)
+            .run();
+
+        // Get back function evaluation counts. The counts of the inner problem are the
+        // evaluations of cost function and gradient, the counts of `constraint_problem` are the
+        // evaluations of the constraints. Once the inner problem is dropped, the user defined
+        // problem is put back, also if the inner solver failed.
+        let inner_state = result.map(
+            |OptimizationResult {
+                 problem: mut inner_problem,
+                 state,
+                 ..
+             }| {
+                if let Some(AugmentedLagrangianProblem { constraints, .. }) =
+                    inner_problem.take_problem()
+                {
+                    problem.consume_func_counts(
+                        constraints
+                            .into_inner()
+                            .unwrap_or_else(PoisonError::into_inner),
+                    );
+                }
+                problem.consume_func_counts(inner_problem);
+                state
+            },
+        );
+        problem.problem = Some(Arc::try_unwrap(user_problem).ok().ok_or_else(
+            argmin_error_closure!(
+                PotentialBug,
+                "`AugmentedLagrangian`: Failed to get back `problem` from inner solver"
+            ),
+        )?);
+        let mut inner_state = inner_state?;
+
+        let inner_iters = inner_state.get_iter();
+        let param = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`AugmentedLagrangian`: No `param` returned by inner solver"
+            ))?;
+
+        // Update multipliers and penalty parameter
+        let eq_violation = self.equality.update(problem, &param, self.penalty)?;
+        let ineq_violation = self.inequality.update(problem, &param, self.penalty)?;
+        let violation = eq_violation.hypot(ineq_violation);
+        if violation > self.violation_decrease * self.violation {
+            self.penalty = self.penalty * self.penalty_increase;
+        }
+        self.violation = violation;
+
+        let cost = problem.cost(&param)?;
+
+        let kv = kv!(
+            "penalty" => self.penalty;
+            "violation" => violation;
+            "inner_iters" => inner_iters;
+        )
+        .merge(self.equality.kv())
+        .merge(self.inequality.kv());
+
+        let state = state
+            .param(param)
+            .cost(cost)
+            .constraint_violation(self.state_violation());
+        Ok((
+            if violation <= self.constraint_tolerance {
+                state.terminate_with(TerminationReason::SolverConverged)
+            } else {
+                state
+            },
+            Some(kv),
+        ))
+    }
+}
+
+/// Handling of one kind of constraints within the [`AugmentedLagrangian`] method.
+///
+/// Implemented by [`NoConstraints`], [`EqualityMultipliers`] and [`InequalityMultipliers`].
+pub trait ConstraintHandling<O, P, F> {
+    /// Initializes the multipliers and returns the constraint violation at `param`
+    fn init(&mut self, problem: &mut Problem<O>, param: &P) -> Result<F, Error>;
+
+    /// Contribution of the constraints to the augmented Lagrangian at `param`
+    fn value(&self, problem: &mut Problem<O>, param: &P, penalty: F) -> Result<F, Error>;
+
+    /// Updates the multipliers and returns the constraint violation at `param`
+    fn update(&mut self, problem: &mut Problem<O>, param: &P, penalty: F) -> Result<F, Error>;
+
+    /// Key-value pairs reported after each iteration
+    fn kv(&self) -> KV;
+}
+
+/// Gradient of one kind of constraints within the [`AugmentedLagrangian`] method.
+pub trait ConstraintGradient<O, P, G, F> {
+    /// Contribution of the constraints to the gradient of the augmented Lagrangian at `param`
+    ///
+    /// Returns `None` if there is no contribution.
+    fn gradient(&self, problem: &mut Problem<O>, param: &P, penalty: F)
+        -> Result<Option<G>, Error>;
+}
+
+/// No constraints of this kind.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NoConstraints;
+
+impl<O, P, F: ArgminFloat> ConstraintHandling<O, P, F> for NoConstraints {
+    fn init(&mut self, _problem: &mut Problem<O>, _param: &P) -> Result<F, Error> {
+        Ok(float!(0.0))
+    }
+
+    fn value(&self, _problem: &mut Problem<O>, _param: &P, _penalty: F) -> Result<F, Error> {
+        Ok(float!(0.0))
+    }
+
+    fn update(&mut self, _problem: &mut Problem<O>, _param: &P, _penalty: F) -> Result<F, Error> {
+        Ok(float!(0.0))
+    }
+
+    fn kv(&self) -> KV {
+        KV::new()
+    }
+}
+
+impl<O, P, G, F> ConstraintGradient<O, P, G, F> for NoConstraints {
+    fn gradient(
+        &self,
+        _problem: &mut Problem<O>,
+        _param: &P,
+        _penalty: F,
+    ) -> Result<Option<G>, Error> {
+        Ok(None)
+    }
+}
+
+/// Lagrange multipliers of the equality constraints `h(x) = 0`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct EqualityMultipliers<C> {
+    multipliers: Option<C>,
+}
+
+impl<C> EqualityMultipliers<C> {
+    /// Returns the current Lagrange multipliers
+    pub fn multipliers(&self) -> Option<&C> {
+        self.multipliers.as_ref()
+    }
+
+    fn get(&self) -> Result<&C, Error> {
+        self.multipliers.as_ref().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            "`AugmentedLagrangian`: Multipliers of equality constraints not initialized."
+        ))
+    }
+}
+
+impl<O, P, C, F> ConstraintHandling<O, P, F> for EqualityMultipliers<C>
+where
+    O: EqualityConstraints<Param = P, Output = C>,
+    C: ArgminAdd<C, C> + ArgminMul<F, C> + ArgminDot<C, F> + ArgminL2Norm<F> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    fn init(&mut self, problem: &mut Problem<O>, param: &P) -> Result<F, Error> {
+        let h = problem.equality_constraints(param)?;
+        if self.multipliers.is_none() {
+            self.multipliers = Some(h.zero_like());
+        }
+        Ok(h.l2_norm())
+    }
+
+    fn value(&self, problem: &mut Problem<O>, param: &P, penalty: F) -> Result<F, Error> {
+        let h = problem.equality_constraints(param)?;
+        Ok(self.get()?.dot(&h) + penalty * float!(0.5) * h.dot(&h))
+    }
+
+    fn update(&mut self, problem: &mut Problem<O>, param: &P, penalty: F) -> Result<F, Error> {
+        let h = problem.equality_constraints(param)?;
+        self.multipliers = Some(self.get()?.add(&h.mul(&penalty)));
+        Ok(h.l2_norm())
+    }
+
+    fn kv(&self) -> KV {
+        match self.multipliers.as_ref() {
+            Some(multipliers) => kv!("equality_multipliers_norm" => multipliers.l2_norm();),
+            None => KV::new(),
+        }
+    }
+}
+
+impl<O, P, G, C, J, F> ConstraintGradient<O, P, G, F> for EqualityMultipliers<C>
+where
+    O: EqualityConstraints<Param = P, Output = C, Jacobian = J>,
+    C: ArgminAdd<C, C> + ArgminMul<F, C>,
+    J: ArgminTranspose<J> + ArgminDot<C, G>,
+{
+    fn gradient(
+        &self,
+        problem: &mut Problem<O>,
+        param: &P,
+        penalty: F,
+    ) -> Result<Option<G>, Error> {
+        let h = problem.equality_constraints(param)?;
+        let jacobian = problem.equality_jacobian(param)?;
+        Ok(Some(jacobian.t().dot(&self.get()?.add(&h.mul(&penalty)))))
+    }
+}
+
+/// Lagrange multipliers of the inequality constraints `g(x) <= 0`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct InequalityMultipliers<C> {
+    multipliers: Option<C>,
+}
+
+impl<C> InequalityMultipliers<C> {
+    /// Returns the current Lagrange multipliers
+    pub fn multipliers(&self) -> Option<&C> {
+        self.multipliers.as_ref()
+    }
+
+    fn get(&self) -> Result<&C, Error> {
+        self.multipliers.as_ref().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            "`AugmentedLagrangian`: Multipliers of inequality constraints not initialized."
+        ))
+    }
+}
+
+impl<C> InequalityMultipliers<C> {
+    /// Computes `max(0, μ + ρ g)`
+    fn shifted<F>(&self, g: &C, penalty: F) -> Result<C, Error>
+    where
+        C: ArgminAdd<C, C> + ArgminMul<F, C> + ArgminMinMax + ArgminZeroLike,
+    {
+        let mu = self.get()?;
+        Ok(C::max(&mu.add(&g.mul(&penalty)), &mu.zero_like()))
+    }
+}
+
+impl<O, P, C, F> ConstraintHandling<O, P, F> for InequalityMultipliers<C>
+where
+    O: InequalityConstraints<Param = P, Output = C>,
+    C: ArgminAdd<C, C>
+        + ArgminMul<F, C>
+        + ArgminDot<C, F>
+        + ArgminL2Norm<F>
+        + ArgminMinMax
+        + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    fn init(&mut self, problem: &mut Problem<O>, param: &P) -> Result<F, Error> {
+        let g = problem.inequality_constraints(param)?;
+        let zero = g.zero_like();
+        let violation = C::max(&g, &zero).l2_norm();
+        if self.multipliers.is_none() {
+            self.multipliers = Some(zero);
+        }
+        Ok(violation)
+    }
+
+    fn value(&self, problem: &mut Problem<O>, param: &P, penalty: F) -> Result<F, Error> {
+        let g = problem.inequality_constraints(param)?;
+        let s = self.shifted(&g, penalty)?;
+        let mu = self.get()?;
+        Ok((s.dot(&s) - mu.dot(mu)) / (float!(2.0) * penalty))
+    }
+
+    fn update(&mut self, problem: &mut Problem<O>, param: &P, penalty: F) -> Result<F, Error> {
+        let g = problem.inequality_constraints(param)?;
+        // Violation also accounts for complementarity of inactive constraints
+        let violation = C::max(&g, &self.get()?.mul(&(float!(-1.0) / penalty))).l2_norm();
+        self.multipliers = Some(self.shifted(&g, penalty)?);
+        Ok(violation)
+    }
+
+    fn kv(&self) -> KV {
+        match self.multipliers.as_ref() {
+            Some(multipliers) => kv!("inequality_multipliers_norm" => multipliers.l2_norm();),
+            None => KV::new(),
+        }
+    }
+}
+
+impl<O, P, G, C, J, F> ConstraintGradient<O, P, G, F> for InequalityMultipliers<C>
+where
+    O: InequalityConstraints<Param = P, Output = C, Jacobian = J>,
+    C: ArgminAdd<C, C> + ArgminMul<F, C> + ArgminMinMax + ArgminZeroLike,
+    J: ArgminTranspose<J> + ArgminDot<C, G>,
+{
+    fn gradient(
+        &self,
+        problem: &mut Problem<O>,
+        param: &P,
+        penalty: F,
+    ) -> Result<Option<G>, Error> {
+        let g = problem.inequality_constraints(param)?;
+        let jacobian = problem.inequality_jacobian(param)?;
+        Ok(Some(jacobian.t().dot(&self.shifted(&g, penalty)?)))
+    }
+}
+
+/// Unconstrained subproblem solved by the inner solver of [`AugmentedLagrangian`].
+///
+/// The cost function is the augmented Lagrangian of the wrapped problem for fixed multipliers and
+/// penalty parameter. Evaluations of cost function and gradient are counted by the inner solver,
+/// evaluations of the constraints are counted by a separate [`Problem`]. Only the evaluation of
+/// the constraints is serialized, therefore cost function and gradient of the wrapped problem can
+/// be evaluated in parallel (for instance in `bulk_cost`).
+pub struct AugmentedLagrangianProblem<O, F, E, I> {
+    /// user defined problem
+    problem: Arc<O>,
+    /// user defined problem for evaluating and counting the constraints
+    constraints: Mutex<Problem<SharedProblem<O>>>,
+    /// penalty parameter
+    penalty: F,
+    /// equality constraints
+    equality: E,
+    /// inequality constraints
+    inequality: I,
+}
+
+impl<O, F, E, I> AugmentedLagrangianProblem<O, F, E, I> {
+    /// Gives access to the problem for evaluating the constraints.
+    ///
+    /// The counts are consistent even if the evaluation of the constraints panicked in another
+    /// thread, therefore a poisoned lock is recovered.
+    fn constraints(&self) -> MutexGuard<'_, Problem<SharedProblem<O>>> {
+        self.constraints
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<O, P, F, E, I> CostFunction for AugmentedLagrangianProblem<O, F, E, I>
+where
+    O: CostFunction<Param = P, Output = F>,
+    F: ArgminFloat,
+    E: ConstraintHandling<SharedProblem<O>, P, F>,
+    I: ConstraintHandling<SharedProblem<O>, P, F>,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let cost = self.problem.cost(param)?;
+        let mut constraints = self.constraints();
+        Ok(cost
+            + self.equality.value(&mut constraints, param, self.penalty)?
+            + self
+                .inequality
+                .value(&mut constraints, param, self.penalty)?)
+    }
+}
+
+impl<O, P, G, F, E, I> Gradient for AugmentedLagrangianProblem<O, F, E, I>
+where
+    O: Gradient<Param = P, Gradient = G>,
+    G: ArgminAdd<G, G>,
+    F: ArgminFloat,
+    E: ConstraintGradient<SharedProblem<O>, P, G, F>,
+    I: ConstraintGradient<SharedProblem<O>, P, G, F>,
+{
+    type Param = P;
+    type Gradient = G;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let mut grad = self.problem.gradient(param)?;
+        let mut constraints = self.constraints();
+        if let Some(g) = self
+            .equality
+            .gradient(&mut constraints, param, self.penalty)?
+        {
+            grad = grad.add(&g);
+        }
+        if let Some(g) = self
+            .inequality
+            .gradient(&mut constraints, param, self.penalty)?
+        {
+            grad = grad.add(&g);
+        }
+        Ok(grad)
+    }
+}
+
+/// User defined problem shared between [`AugmentedLagrangianProblem`] and the [`Problem`] via
+/// which it evaluates the constraints.
+pub struct SharedProblem<O>(Arc<O>);
+
+impl<O: EqualityConstraints> EqualityConstraints for SharedProblem<O> {
+    type Param = O::Param;
+    type Output = O::Output;
+    type Jacobian = O::Jacobian;
+
+    fn equality_constraints(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.0.equality_constraints(param)
+    }
+
+    fn equality_jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        self.0.equality_jacobian(param)
+    }
+}
+
+impl<O: InequalityConstraints> InequalityConstraints for SharedProblem<O> {
+    type Param = O::Param;
+    type Output = O::Output;
+    type Jacobian = O::Jacobian;
+
+    fn inequality_constraints(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.0.inequality_constraints(param)
+    }
+
+    fn inequality_jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        self.0.inequality_jacobian(param)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, FuncEvalBudget};
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::quasinewton::LBFGS;
+    use approx::assert_relative_eq;
+
+    type Inner = LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+
+    test_trait_impl!(
+        augmented_lagrangian,
+        AugmentedLagrangian<
+            Inner,
+            f64,
+            EqualityMultipliers<Vec<f64>>,
+            InequalityMultipliers<Vec<f64>>,
+        >
+    );
+
+    /// min (x - 2)^2 + (y - 1)^2
+    /// s.t. x + y - 1 = 0 and x^2 + y^2 - 1 <= 0
+    #[derive(Clone)]
+    struct Constrained {}
+
+    impl CostFunction for Constrained {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Constrained {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl EqualityConstraints for Constrained {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0] + p[1] - 1.0])
+        }
+
+        fn equality_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![1.0, 1.0]])
+        }
+    }
+
+    impl InequalityConstraints for Constrained {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+        }
+
+        fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+        }
+    }
+
+    fn inner() -> Inner {
+        LBFGS::new(MoreThuenteLineSearch::new(), 5)
+    }
+
+    #[test]
+    fn test_new() {
+        let AugmentedLagrangian {
+            inner: _,
+            inner_max_iters,
+            penalty,
+            penalty_increase,
+            violation_decrease,
+            constraint_tolerance,
+            violation,
+            equality,
+            inequality,
+        } = AugmentedLagrangian::<_, f64>::new(inner());
+
+        assert_eq!(inner_max_iters, 1000);
+        assert_eq!(penalty.to_ne_bytes(), 10.0f64.to_ne_bytes());
+        assert_eq!(penalty_increase.to_ne_bytes(), 10.0f64.to_ne_bytes());
+        assert_eq!(violation_decrease.to_ne_bytes(), 0.25f64.to_ne_bytes());
+        assert_eq!(
+            constraint_tolerance.to_ne_bytes(),
+            f64::EPSILON.sqrt().to_ne_bytes()
+        );
+        assert_eq!(violation.to_ne_bytes(), f64::INFINITY.to_ne_bytes());
+        assert_eq!(equality, NoConstraints);
+        assert_eq!(inequality, NoConstraints);
+    }
+
+    #[test]
+    fn test_with_constraints() {
+        let al = AugmentedLagrangian::<_, f64>::new(inner())
+            .with_inequality_constraints::<Vec<f64>>()
+            .with_equality_constraints::<Vec<f64>>();
+        assert_eq!(al.equality, EqualityMultipliers { multipliers: None });
+        assert_eq!(al.inequality, InequalityMultipliers { multipliers: None });
+    }
+
+    #[test]
+    fn test_with_penalty() {
+        for penalty in [f64::EPSILON, 1.0, 100.0] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_penalty(penalty);
+            assert!(al.is_ok());
+            assert_eq!(al.unwrap().penalty.to_ne_bytes(), penalty.to_ne_bytes());
+        }
+
+        for penalty in [0.0, -1.0] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_penalty(penalty);
+            assert_error!(
+                al,
+                ArgminError,
+                "Invalid parameter: \"`AugmentedLagrangian`: penalty must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_penalty_increase() {
+        for factor in [1.0 + f64::EPSILON, 2.0, 100.0] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_penalty_increase(factor);
+            assert!(al.is_ok());
+            assert_eq!(
+                al.unwrap().penalty_increase.to_ne_bytes(),
+                factor.to_ne_bytes()
+            );
+        }
+
+        for factor in [1.0, 0.5, -1.0] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_penalty_increase(factor);
+            assert_error!(
+                al,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`AugmentedLagrangian`: ",
+                    "penalty increase factor must be > 1.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_violation_decrease() {
+        for factor in [f64::EPSILON, 0.5, 1.0 - f64::EPSILON] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_violation_decrease(factor);
+            assert!(al.is_ok());
+            assert_eq!(
+                al.unwrap().violation_decrease.to_ne_bytes(),
+                factor.to_ne_bytes()
+            );
+        }
+
+        for factor in [0.0, 1.0, -1.0, 2.0] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_violation_decrease(factor);
+            assert_error!(
+                al,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`AugmentedLagrangian`: ",
+                    "violation decrease factor must be in (0, 1).\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_constraint_tolerance() {
+        for tol in [0.0, 1e-6, 1.0] {
+            let al = AugmentedLagrangian::<_, f64>::new(()).with_constraint_tolerance(tol);
+            assert!(al.is_ok());
+            assert_eq!(
+                al.unwrap().constraint_tolerance.to_ne_bytes(),
+                tol.to_ne_bytes()
+            );
+        }
+
+        let al = AugmentedLagrangian::<_, f64>::new(()).with_constraint_tolerance(-1.0);
+        assert_error!(
+            al,
+            ArgminError,
+            "Invalid parameter: \"`AugmentedLagrangian`: constraint tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut al = AugmentedLagrangian::<_, f64>::new(inner());
+        let res = al.init(
+            &mut Problem::new(TestProblem::new()),
+            IterState::<Vec<f64>, Vec<f64>, (), (), (), f64>::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`AugmentedLagrangian` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_equality_constraints() {
+        let al = AugmentedLagrangian::<_, f64>::new(inner()).with_equality_constraints();
+        let res = Executor::new(Constrained {}, al)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50).counting(true))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-6);
+        let multipliers = res.solver.equality_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], 2.0, epsilon = 1e-5);
+        assert!(res.solver.constraint_violation() <= f64::EPSILON.sqrt());
+
+        // Every evaluation of the augmented Lagrangian and its gradient evaluates the constraints
+        // once. In addition, the constraints are evaluated once in `init` and once in `update`
+        // per iteration, exactly as often as the cost function alone.
+        let counts = res.state.get_func_counts();
+        assert_eq!(
+            counts["equality_constraints_count"],
+            counts["cost_count"] + counts["gradient_count"]
+        );
+        assert_eq!(counts["equality_jacobian_count"], counts["gradient_count"]);
+    }
+
+    #[test]
+    fn test_state_constraint_violation() {
+        let al = AugmentedLagrangian::<_, f64>::new(inner()).with_inequality_constraints();
+        let res = Executor::new(Constrained {}, al)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50))
+            .run()
+            .unwrap();
+
+        // The state holds the actual cost and the constraint violation of the parameter vector
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(
+            res.state.get_cost(),
+            Constrained {}.cost(param).unwrap(),
+            epsilon = f64::EPSILON
+        );
+        assert!(res.solver.constraint_violation() <= f64::EPSILON.sqrt());
+        assert_eq!(
+            res.state.get_constraint_violation().unwrap().to_ne_bytes(),
+            0.0f64.to_ne_bytes()
+        );
+
+        // The initial parameter vector is feasible as well, but the final one has a lower cost
+        let best_param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best_param[0], param[0]);
+        assert_relative_eq!(best_param[1], param[1]);
+        assert!(res.state.get_best_cost() < Constrained {}.cost(&vec![0.0, 0.0]).unwrap());
+    }
+
+    #[test]
+    fn test_func_eval_budget() {
+        let al = AugmentedLagrangian::<_, f64>::new(inner()).with_equality_constraints();
+        let res = Executor::new(Constrained {}, al)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50).counting(true))
+            .func_eval_budget(FuncEvalBudget::new().with_limit("equality_jacobian_count", 5))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::MaxFuncEvalsReached)
+        );
+        assert_eq!(res.state.get_func_counts()["equality_jacobian_count"], 5);
+    }
+
+    #[test]
+    fn test_inequality_constraints() {
+        let al = AugmentedLagrangian::<_, f64>::new(inner()).with_inequality_constraints();
+        let res = Executor::new(Constrained {}, al)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 2.0 / 5.0f64.sqrt(), epsilon = 1e-6);
+        assert_relative_eq!(param[1], 1.0 / 5.0f64.sqrt(), epsilon = 1e-6);
+        let multipliers = res.solver.inequality_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], 5.0f64.sqrt() - 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_inactive_inequality_constraints() {
+        let al = AugmentedLagrangian::<_, f64>::new(inner())
+            .with_equality_constraints()
+            .with_inequality_constraints();
+        let res = Executor::new(Constrained {}, al)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50))
+            .run()
+            .unwrap();
+
+        // The solution of the equality constrained problem is feasible w.r.t. the inequality
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-6);
+        let multipliers = res.solver.inequality_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_kv() {
+        let mut al = AugmentedLagrangian::<_, f64>::new(inner()).with_equality_constraints();
+        let mut problem = Problem::new(Constrained {});
+        let (state, kv) = al
+            .init(&mut problem, IterState::new().param(vec![0.0, 0.0]))
+            .unwrap();
+        let kv = kv.unwrap();
+        assert_relative_eq!(kv.get("penalty").unwrap().get_float().unwrap(), 10.0);
+        assert_relative_eq!(kv.get("violation").unwrap().get_float().unwrap(), 1.0);
+
+        let (_, kv) = al.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+        assert!(kv.get("violation").is_some());
+        assert!(kv.get("inner_iters").is_some());
+        assert!(kv
+            .get("equality_multipliers_norm")
+            .unwrap()
+            .get_float()
+            .is_some());
+        assert!(problem.problem.is_some());
+    }
+
+    /// `Constrained` with a gradient which cannot be computed
+    struct FailingGradient {}
+
+    impl CostFunction for FailingGradient {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Constrained {}.cost(p)
+        }
+    }
+
+    impl Gradient for FailingGradient {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, _p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Err(argmin_error!(NotImplemented, "No gradient"))
+        }
+    }
+
+    impl EqualityConstraints for FailingGradient {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Constrained {}.equality_constraints(p)
+        }
+
+        fn equality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Constrained {}.equality_jacobian(p)
+        }
+    }
+
+    #[test]
+    fn test_inner_solver_error() {
+        let mut al = AugmentedLagrangian::<_, f64>::new(inner()).with_equality_constraints();
+        let mut problem = Problem::new(FailingGradient {});
+        let (state, _) = al
+            .init(&mut problem, IterState::new().param(vec![0.0, 0.0]))
+            .unwrap();
+
+        // The problem is put back even though the inner solver failed, therefore further
+        // iterations fail for the same reason.
+        for _ in 0..2 {
+            assert_error!(
+                al.next_iter(&mut problem, state.clone()),
+                ArgminError,
+                "Not implemented: \"No gradient\""
+            );
+            assert!(problem.problem.is_some());
+        }
+    }
+}
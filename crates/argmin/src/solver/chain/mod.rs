@@ -0,0 +1,513 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Chain of solvers
+//!
+//! Runs two solvers in sequence, for instance a global solver such as
+//! [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`) followed by a local solver
+//! such as [`LBFGS`](`crate::solver::quasinewton::LBFGS`) which polishes the best parameter vector
+//! found by the global solver. For details see [`Chain`].
+
+use crate::core::{
+    ArgminFloat, Error, IterState, LinearProgramState, PopulationState, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::particleswarm::Particle;
+use argmin_math::ArgminZeroLike;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Conversion of states for chaining solvers
+///
+/// Allows [`Chain`] to pass the best parameter vector found by one solver on to the next solver,
+/// even if the two solvers use different kinds of state (such as [`PopulationState`] and
+/// [`IterState`]). `P` is the type of the parameter vector which is passed on.
+pub trait ChainState<P>: State {
+    /// Returns a copy of the best parameter vector found so far (if any).
+    fn best_param(&self) -> Option<P>;
+
+    /// Sets `param` as the initial parameter vector.
+    ///
+    /// The cost is deliberately not passed on: the next solver evaluates it itself such that its
+    /// convergence criteria do not mistake the copied cost for a lack of progress.
+    #[must_use]
+    fn seed(self, param: P) -> Self;
+
+    /// Sets the maximum number of iterations.
+    #[must_use]
+    fn max_iters(self, iters: u64) -> Self;
+}
+
+impl<P, G, J, H, R, F> ChainState<P> for IterState<P, G, J, H, R, F>
+where
+    Self: State<Float = F, Param = P>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    fn best_param(&self) -> Option<P> {
+        self.get_best_param().cloned()
+    }
+
+    fn seed(self, param: P) -> Self {
+        self.param(param)
+    }
+
+    fn max_iters(self, iters: u64) -> Self {
+        IterState::max_iters(self, iters)
+    }
+}
+
+impl<P, F> ChainState<P> for PopulationState<P, F>
+where
+    Self: State<Float = F, Param = P>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    fn best_param(&self) -> Option<P> {
+        self.get_best_param().cloned()
+    }
+
+    fn seed(self, param: P) -> Self {
+        self.individual(param)
+    }
+
+    fn max_iters(self, iters: u64) -> Self {
+        PopulationState::max_iters(self, iters)
+    }
+}
+
+/// The position of the best particle is passed on.
+impl<P, F> ChainState<P> for PopulationState<Particle<P, F>, F>
+where
+    Self: State<Float = F, Param = Particle<P, F>>,
+    P: Clone + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    fn best_param(&self) -> Option<P> {
+        self.get_best_param()
+            .map(|particle| particle.position.clone())
+    }
+
+    fn seed(self, param: P) -> Self {
+        let velocity = param.zero_like();
+        self.individual(Particle::new(param, F::infinity(), velocity))
+    }
+
+    fn max_iters(self, iters: u64) -> Self {
+        PopulationState::max_iters(self, iters)
+    }
+}
+
+impl<P, F> ChainState<P> for LinearProgramState<P, F>
+where
+    Self: State<Float = F, Param = P>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    fn best_param(&self) -> Option<P> {
+        self.get_best_param().cloned()
+    }
+
+    fn seed(self, param: P) -> Self {
+        self.param(param)
+    }
+
+    fn max_iters(self, iters: u64) -> Self {
+        LinearProgramState::max_iters(self, iters)
+    }
+}
+
+/// # Chain of solvers
+///
+/// Runs the solver `first` until it terminates (or for at most `first_max_iters` iterations, see
+/// [`with_first_max_iters`](`Chain::with_first_max_iters`)) and then continues with the solver
+/// `second`, starting from the best parameter vector found by `first`. A typical use case is a
+/// global solver which explores the parameter space followed by a local solver which polishes
+/// the result.
+///
+/// Both solvers are stepped by the [`Executor`](`crate::core::Executor`) running `Chain`:
+/// `init` initializes the first solver (starting from the initial parameter vector, if
+/// provided) and each iteration of `Chain` performs one iteration of the first solver until it
+/// terminates. Then the second solver is initialized and performs the remaining iterations.
+/// Therefore observers see the iterations of both solvers, including the `KV` returned by them.
+/// The state used by `Chain` is the state of the second solver. Its iteration number counts the
+/// iterations of both solvers and its settings (such as `max_iters`) apply to the whole chain.
+/// While the first solver is running, the state holds the best parameter vector found so far,
+/// but not its cost. Function evaluation counts of both solvers are accumulated. When switching
+/// to the second solver, the key facts about the first run are reported to the observers via
+/// `KV` (`first_solver`, `first_iters`, `first_best_cost` and `first_termination_reason`).
+///
+/// The two solvers may use different kinds of state, which are converted into each other via
+/// [`ChainState`]. More than two solvers can be chained by nesting `Chain`s.
+///
+/// Note that solvers which construct their initial parameter vectors themselves (such as the
+/// simplex of [`NelderMead`](`crate::solver::neldermead::NelderMead`) or the particles of
+/// [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`)) ignore the parameter vector
+/// passed on to them.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement everything required by both solvers.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Chain<S1, S2, I1> {
+    /// first solver
+    first: S1,
+    /// second solver
+    second: S2,
+    /// maximum number of iterations of the first solver
+    first_max_iters: u64,
+    /// state of the first solver while it is running
+    first_state: Option<I1>,
+}
+
+impl<S1, S2, I1> Chain<S1, S2, I1> {
+    /// Construct a new instance of [`Chain`]
+    ///
+    /// Takes the solver which is run first and the solver which continues from the best parameter
+    /// vector found by the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::chain::Chain;
+    /// # use argmin::core::IterState;
+    /// # let global_solver = ();
+    /// # let local_solver = ();
+    /// let chain: Chain<_, _, IterState<Vec<f64>, (), (), (), (), f64>> =
+    ///     Chain::new(global_solver, local_solver);
+    /// ```
+    pub fn new(first: S1, second: S2) -> Self {
+        Chain {
+            first,
+            second,
+            first_max_iters: 1000,
+            first_state: None,
+        }
+    }
+
+    /// Set maximum number of iterations of the first solver
+    ///
+    /// Defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::chain::Chain;
+    /// # use argmin::core::IterState;
+    /// # let global_solver = ();
+    /// # let local_solver = ();
+    /// let chain: Chain<_, _, IterState<Vec<f64>, (), (), (), (), f64>> =
+    ///     Chain::new(global_solver, local_solver).with_first_max_iters(100);
+    /// ```
+    #[must_use]
+    pub fn with_first_max_iters(mut self, iters: u64) -> Self {
+        self.first_max_iters = iters;
+        self
+    }
+}
+
+impl<S1, S2, I1> Chain<S1, S2, I1> {
+    /// Processes the state of the first solver after its initialization or an iteration.
+    ///
+    /// Passes the best parameter vector found so far on to `state`. Once the first solver has
+    /// terminated, the second solver is initialized.
+    fn continue_first<O, I2, P>(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: I2,
+        mut first_state: I1,
+        kv: Option<KV>,
+    ) -> Result<(I2, Option<KV>), Error>
+    where
+        S1: Solver<O, I1>,
+        S2: Solver<O, I2>,
+        I1: ChainState<P>,
+        I2: ChainState<P, Float = I1::Float, Param = P>,
+    {
+        first_state.func_counts(problem);
+        if !first_state.terminated() {
+            if let TerminationStatus::Terminated(reason) =
+                self.first.terminate_internal(&first_state)
+            {
+                first_state = first_state.terminate_with(reason);
+            }
+        }
+
+        let state = match first_state.best_param() {
+            Some(param) => state.seed(param),
+            None => state,
+        };
+
+        if !first_state.terminated() {
+            self.first_state = Some(first_state);
+            return Ok((state, kv));
+        }
+
+        let param = first_state.best_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`Chain`: No `param` returned by first solver"
+        ))?;
+        let first_reason = first_state
+            .get_termination_reason()
+            .map(|reason| reason.text().to_string())
+            .unwrap_or_default();
+        let first_kv = kv!(
+            "first_solver" => self.first.name().to_string();
+            "first_iters" => first_state.get_iter();
+            "first_best_cost" => first_state.get_best_cost();
+            "first_termination_reason" => first_reason;
+        );
+        let kv = match kv {
+            Some(kv) => kv.merge(first_kv),
+            None => first_kv,
+        };
+
+        // Do not start the second solver if the first one used up the budget of function
+        // evaluations
+        if problem
+            .func_eval_budget()
+            .map(|budget| budget.is_exhausted())
+            .unwrap_or(false)
+        {
+            return Ok((
+                state.terminate_with(TerminationReason::MaxFuncEvalsReached),
+                Some(kv),
+            ));
+        }
+
+        let (state, second_kv) = self.second.init(problem, state.seed(param))?;
+        let kv = match second_kv {
+            Some(second_kv) => kv.merge(second_kv),
+            None => kv,
+        };
+        Ok((state, Some(kv)))
+    }
+}
+
+impl<O, S1, S2, I1, I2, P> Solver<O, I2> for Chain<S1, S2, I1>
+where
+    S1: Solver<O, I1>,
+    S2: Solver<O, I2>,
+    I1: ChainState<P>,
+    I2: ChainState<P, Float = I1::Float, Param = P>,
+    P: Clone,
+{
+    fn name(&self) -> &str {
+        "Chain"
+    }
+
+    fn init(&mut self, problem: &mut Problem<O>, state: I2) -> Result<(I2, Option<KV>), Error> {
+        let mut first_state = I1::new().max_iters(self.first_max_iters);
+        if let Some(param) = state.get_param() {
+            first_state = first_state.seed(param.clone());
+        }
+
+        let (mut first_state, kv) = self.first.init(problem, first_state)?;
+        first_state.update();
+        self.continue_first(problem, state, first_state, kv)
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: I2,
+    ) -> Result<(I2, Option<KV>), Error> {
+        match self.first_state.take() {
+            Some(first_state) => {
+                let (mut first_state, kv) = self.first.next_iter(problem, first_state)?;
+                first_state.update();
+                first_state.increment_iter();
+                self.continue_first(problem, state, first_state, kv)
+            }
+            None => self.second.next_iter(problem, state),
+        }
+    }
+
+    fn terminate(&mut self, state: &I2) -> TerminationStatus {
+        if self.first_state.is_some() {
+            return TerminationStatus::NotTerminated;
+        }
+        self.second.terminate(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::observers::{Observe, ObserverMode};
+    use crate::core::{CostFunction, Executor, Gradient};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::neldermead::NelderMead;
+    use crate::solver::particleswarm::ParticleSwarm;
+    use crate::solver::quasinewton::LBFGS;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+    use std::sync::{Arc, Mutex};
+
+    test_trait_impl!(
+        chain,
+        Chain<
+            NelderMead<Vec<f64>, f64>,
+            SteepestDescent<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>>,
+            IterState<Vec<f64>, (), (), (), (), f64>,
+        >
+    );
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_derivative(p))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let chain: Chain<(), (), IterState<Vec<f64>, (), (), (), (), f64>> =
+            Chain::new((), ()).with_first_max_iters(12);
+        let Chain {
+            first_max_iters, ..
+        } = chain;
+        assert_eq!(first_max_iters, 12);
+    }
+
+    #[test]
+    fn test_particleswarm_lbfgs() {
+        let pso: ParticleSwarm<_, f64, _> =
+            ParticleSwarm::new((vec![-2.0, -2.0], vec![2.0, 2.0]), 20);
+        let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let solver = Chain::new(pso, lbfgs).with_first_max_iters(10);
+
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.max_iters(100).counting(true))
+            .run()
+            .unwrap();
+
+        let param = res.state().get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+
+        // Evaluations of both solvers are counted: 20 particles for 11 evaluations of the swarm
+        let counts = &res.problem().counts;
+        assert!(counts["cost_count"] >= 220);
+        assert!(counts["gradient_count"] > 0);
+        assert_eq!(
+            res.state().get_func_counts()["cost_count"],
+            counts["cost_count"]
+        );
+    }
+
+    #[test]
+    fn test_initial_param() {
+        // Nelder-Mead with a single iteration starting from the best vertex of the simplex,
+        // followed by steepest descent.
+        let nm = NelderMead::new(vec![vec![-1.2, 1.0], vec![-1.0, 1.0], vec![-1.2, 1.2]]);
+        let sd = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let solver = Chain::new(nm, sd).with_first_max_iters(1);
+
+        let mut executor = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1));
+        // Initialization and the only iteration of the first solver
+        assert!(!executor.step().unwrap().terminated());
+        let state = executor.state();
+        assert_eq!(state.get_iter(), 1);
+        // The second solver starts from the best parameter vector of the first one
+        let param = state.get_param().unwrap();
+        assert!(rosenbrock(param) < rosenbrock(&[-1.2, 1.0]));
+        assert!(executor.step().unwrap().terminated());
+        assert_eq!(
+            executor.state().get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+    }
+
+    #[test]
+    fn test_observers() {
+        #[derive(Default)]
+        struct Log {
+            init: Vec<String>,
+            iters: Vec<Vec<String>>,
+        }
+
+        struct TestObs(Arc<Mutex<Log>>);
+
+        impl<I> Observe<I> for TestObs {
+            fn observe_init(&mut self, _name: &str, _state: &I, kv: &KV) -> Result<(), Error> {
+                self.0.lock().unwrap().init = kv.keys().into_iter().map(|(k, _)| k).collect();
+                Ok(())
+            }
+
+            fn observe_iter(&mut self, _state: &I, kv: &KV) -> Result<(), Error> {
+                let keys = kv.keys().into_iter().map(|(k, _)| k).collect();
+                self.0.lock().unwrap().iters.push(keys);
+                Ok(())
+            }
+        }
+
+        let nm = NelderMead::new(vec![vec![-1.2, 1.0], vec![-1.0, 1.0], vec![-1.2, 1.2]]);
+        let sd = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let solver = Chain::new(nm, sd).with_first_max_iters(5);
+
+        let log = Arc::new(Mutex::new(Log::default()));
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(8))
+            .add_observer(TestObs(log.clone()), ObserverMode::Always)
+            .run()
+            .unwrap();
+        assert_eq!(res.state().get_iter(), 8);
+
+        // Every iteration of the first solver is observed, including its `KV`
+        let log = log.lock().unwrap();
+        assert!(log.init.is_empty());
+        assert_eq!(log.iters.len(), 8);
+        for keys in log.iters[..5].iter() {
+            assert!(keys.contains(&"action".to_string()));
+        }
+        for key in [
+            "first_solver",
+            "first_iters",
+            "first_best_cost",
+            "first_termination_reason",
+        ] {
+            assert!(log.iters[4].contains(&key.to_string()));
+        }
+        assert!(!log.iters[5].contains(&"action".to_string()));
+    }
+
+    #[test]
+    fn test_chain_of_chains() {
+        let nm = NelderMead::new(vec![vec![-1.2, 1.0], vec![-1.0, 1.0], vec![-1.2, 1.2]]);
+        let sd = SteepestDescent::new(MoreThuenteLineSearch::new());
+        let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let solver =
+            Chain::new(Chain::new(nm, sd).with_first_max_iters(10), lbfgs).with_first_max_iters(10);
+
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        let param = res.state().get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,1086 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Covariance Matrix Adaptation Evolution Strategy (CMA-ES)
+//!
+//! Derivative-free optimization of non-linear, non-convex and rugged functions.
+//!
+//! For details see [`CMAES`].
+//!
+//! ## References
+//!
+//! \[0\] Nikolaus Hansen (2016). The CMA Evolution Strategy: A Tutorial.
+//! arXiv:1604.00772. <https://arxiv.org/abs/1604.00772>
+//!
+//! \[1\] Anne Auger and Nikolaus Hansen (2005). A restart CMA evolution strategy with increasing
+//! population size. 2005 IEEE Congress on Evolutionary Computation.
+//! <https://doi.org/10.1109/CEC.2005.1554902>
+//!
+//! \[2\] Nikolaus Hansen (2009). Benchmarking a BI-population CMA-ES on the BBOB-2009 function
+//! testbed. GECCO '09. <https://doi.org/10.1145/1570256.1570333>
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, PopulationState, Problem, Solver, State, SyncAlias,
+    TerminationReason, KV,
+};
+use argmin_math::{ArgminEye, ArgminL2Norm};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Restart strategy of [`CMAES`]
+///
+/// Restarts are triggered whenever a run of the evolution strategy stagnates. Each restart starts
+/// again from the initial mean.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum RestartStrategy {
+    /// No restarts; the solver terminates as soon as the first run stagnates.
+    None,
+    /// Restarts with doubled population size (IPOP-CMA-ES).
+    IPOP,
+    /// Restarts which interlace a regime with increasing population size and a regime with
+    /// small population size and small initial step size (BIPOP-CMA-ES) as proposed by Hansen
+    /// (2009) \[2\]. The first restart uses the large population regime. Afterwards, each
+    /// restart uses the regime which spent fewer function evaluations so far; the evaluations of
+    /// the first run with default population size are not accounted to either regime.
+    BIPOP,
+}
+
+/// Internal state of a single run of the evolution strategy
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct Strategy<F> {
+    /// Population size
+    lambda: usize,
+    /// Recombination weights
+    weights: Vec<F>,
+    /// Variance effective selection mass
+    mu_eff: F,
+    /// Learning rate of the rank-one update path
+    c_c: F,
+    /// Learning rate of the step size control path
+    c_sigma: F,
+    /// Learning rate of the rank-one update
+    c_1: F,
+    /// Learning rate of the rank-mu update
+    c_mu: F,
+    /// Damping of the step size adaptation
+    d_sigma: F,
+    /// Expectation of the norm of a standard normally distributed vector
+    chi_n: F,
+    /// Mean of the search distribution
+    mean: Vec<F>,
+    /// Step size
+    sigma: F,
+    /// Covariance matrix
+    cov: Vec<Vec<F>>,
+    /// Eigenvectors of the covariance matrix (columns)
+    b: Vec<Vec<F>>,
+    /// Square roots of the eigenvalues of the covariance matrix
+    d: Vec<F>,
+    /// Evolution path of the covariance matrix
+    p_c: Vec<F>,
+    /// Evolution path of the step size
+    p_sigma: Vec<F>,
+    /// Number of generations of this run
+    generation: u64,
+    /// Generation of the last eigendecomposition
+    eigen_generation: u64,
+    /// Best cost function values of the most recent generations
+    history: VecDeque<F>,
+    /// Whether this run belongs to the small population regime of BIPOP
+    small_regime: bool,
+}
+
+impl<F> Strategy<F>
+where
+    Vec<F>: ArgminL2Norm<F>,
+    Vec<Vec<F>>: ArgminEye,
+    F: ArgminFloat,
+{
+    /// Creates a new run with default strategy parameters for the given population size
+    fn new(mean: Vec<F>, sigma: F, lambda: usize, small_regime: bool) -> Self {
+        let n = mean.len();
+        let nf: F = float!(n as f64);
+        let mu = lambda / 2;
+
+        let weights: Vec<F> = (1..=mu)
+            .map(|i| float!(((lambda as f64 + 1.0) / 2.0).ln() - (i as f64).ln()))
+            .collect();
+        let weights_sum = weights.iter().fold(float!(0.0), |acc: F, &w| acc + w);
+        let weights: Vec<F> = weights.iter().map(|&w| w / weights_sum).collect();
+        let mu_eff = float!(1.0) / weights.iter().fold(float!(0.0), |acc: F, &w| acc + w * w);
+
+        let c_c = (float!(4.0) + mu_eff / nf) / (nf + float!(4.0) + float!(2.0) * mu_eff / nf);
+        let c_sigma = (mu_eff + float!(2.0)) / (nf + mu_eff + float!(5.0));
+        let c_1 = float!(2.0) / ((nf + float!(1.3)).powi(2) + mu_eff);
+        let c_mu = (float!(1.0) - c_1).min(
+            float!(2.0) * (mu_eff - float!(2.0) + float!(1.0) / mu_eff)
+                / ((nf + float!(2.0)).powi(2) + mu_eff),
+        );
+        let d_sigma = float!(1.0)
+            + float!(2.0)
+                * float!(0.0)
+                    .max(((mu_eff - float!(1.0)) / (nf + float!(1.0))).sqrt() - float!(1.0))
+            + c_sigma;
+        let chi_n = nf.sqrt()
+            * (float!(1.0) - float!(1.0) / (float!(4.0) * nf)
+                + float!(1.0) / (float!(21.0) * nf * nf));
+
+        Strategy {
+            lambda,
+            weights,
+            mu_eff,
+            c_c,
+            c_sigma,
+            c_1,
+            c_mu,
+            d_sigma,
+            chi_n,
+            mean,
+            sigma,
+            cov: Vec::eye(n),
+            b: Vec::eye(n),
+            d: vec![float!(1.0); n],
+            p_c: vec![float!(0.0); n],
+            p_sigma: vec![float!(0.0); n],
+            generation: 0,
+            eigen_generation: 0,
+            history: VecDeque::new(),
+            small_regime,
+        }
+    }
+
+    /// Samples a new population. Returns the samples `y_k = B D z_k` and the candidates
+    /// `x_k = m + sigma * y_k`.
+    fn sample<R: Rng>(&self, rng: &mut R) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
+        let n = self.mean.len();
+        let y: Vec<Vec<F>> = (0..self.lambda)
+            .map(|_| {
+                let dz: Vec<F> = self
+                    .d
+                    .iter()
+                    .map(|&di| di * standard_normal::<F, R>(rng))
+                    .collect();
+                (0..n)
+                    .map(|i| (0..n).fold(float!(0.0), |acc, j| acc + self.b[i][j] * dz[j]))
+                    .collect()
+            })
+            .collect();
+        let x = y
+            .iter()
+            .map(|yk| {
+                self.mean
+                    .iter()
+                    .zip(yk.iter())
+                    .map(|(&m, &yi)| m + self.sigma * yi)
+                    .collect()
+            })
+            .collect();
+        (y, x)
+    }
+
+    /// Updates mean, evolution paths, covariance matrix and step size given the samples `y`
+    /// sorted by their cost function values.
+    fn update(&mut self, y_sorted: &[&Vec<F>]) {
+        let n = self.mean.len();
+        self.generation += 1;
+
+        // Weighted mean of the selected steps
+        let y_w: Vec<F> = (0..n)
+            .map(|i| {
+                self.weights
+                    .iter()
+                    .zip(y_sorted.iter())
+                    .fold(float!(0.0), |acc, (&w, yk)| acc + w * yk[i])
+            })
+            .collect();
+        for (m, &yi) in self.mean.iter_mut().zip(y_w.iter()) {
+            *m = *m + self.sigma * yi;
+        }
+
+        // C^{-1/2} y_w = B D^{-1} B^T y_w
+        let bt_yw: Vec<F> = (0..n)
+            .map(|j| (0..n).fold(float!(0.0), |acc, i| acc + self.b[i][j] * y_w[i]))
+            .collect();
+        let c_inv_sqrt_yw: Vec<F> = (0..n)
+            .map(|i| {
+                (0..n).fold(float!(0.0), |acc, j| {
+                    acc + self.b[i][j] * bt_yw[j] / self.d[j]
+                })
+            })
+            .collect();
+
+        let cs_factor = (self.c_sigma * (float!(2.0) - self.c_sigma) * self.mu_eff).sqrt();
+        for (ps, &v) in self.p_sigma.iter_mut().zip(c_inv_sqrt_yw.iter()) {
+            *ps = (float!(1.0) - self.c_sigma) * *ps + cs_factor * v;
+        }
+        let ps_norm = self.p_sigma.l2_norm();
+
+        let generations: F = float!(self.generation as f64);
+        let h_sigma = ps_norm
+            / (float!(1.0) - (float!(1.0) - self.c_sigma).powf(float!(2.0) * generations)).sqrt()
+            < (float!(1.4) + float!(2.0) / (float!(n as f64) + float!(1.0))) * self.chi_n;
+        let h_sigma: F = if h_sigma { float!(1.0) } else { float!(0.0) };
+
+        let cc_factor = (self.c_c * (float!(2.0) - self.c_c) * self.mu_eff).sqrt();
+        for (pc, &yi) in self.p_c.iter_mut().zip(y_w.iter()) {
+            *pc = (float!(1.0) - self.c_c) * *pc + h_sigma * cc_factor * yi;
+        }
+
+        // Rank-one and rank-mu update of the covariance matrix
+        let delta_h = (float!(1.0) - h_sigma) * self.c_c * (float!(2.0) - self.c_c);
+        let decay = float!(1.0) + self.c_1 * delta_h - self.c_1 - self.c_mu;
+        for i in 0..n {
+            for j in 0..=i {
+                let rank_mu = self
+                    .weights
+                    .iter()
+                    .zip(y_sorted.iter())
+                    .fold(float!(0.0), |acc, (&w, yk)| acc + w * yk[i] * yk[j]);
+                let cij = decay * self.cov[i][j]
+                    + self.c_1 * self.p_c[i] * self.p_c[j]
+                    + self.c_mu * rank_mu;
+                self.cov[i][j] = cij;
+                self.cov[j][i] = cij;
+            }
+        }
+
+        // Cumulative step size adaptation
+        self.sigma = self.sigma
+            * ((self.c_sigma / self.d_sigma) * (ps_norm / self.chi_n - float!(1.0))).exp();
+
+        // The eigendecomposition is only updated every few generations to reduce the
+        // computational cost to O(n^2) per generation.
+        let gap =
+            float!(self.lambda as f64) / ((self.c_1 + self.c_mu) * float!(n as f64) * float!(10.0));
+        if float!((self.generation - self.eigen_generation) as f64) > gap {
+            self.update_eigendecomposition();
+        }
+    }
+
+    /// Recomputes `B` and `D` from the covariance matrix
+    fn update_eigendecomposition(&mut self) {
+        self.eigen_generation = self.generation;
+        let (eigenvalues, eigenvectors) = symmetric_eigen(self.cov.clone());
+        self.d = eigenvalues
+            .iter()
+            .map(|&ev| ev.max(F::epsilon()).sqrt())
+            .collect();
+        self.b = eigenvectors;
+    }
+
+    /// Condition number of the covariance matrix
+    fn condition(&self) -> F {
+        let (min, max) = self
+            .d
+            .iter()
+            .fold((F::infinity(), float!(0.0)), |(min, max): (F, F), &di| {
+                (min.min(di), max.max(di))
+            });
+        (max / min).powi(2)
+    }
+
+    /// Checks whether this run stagnated
+    fn stagnated(&self, costs: &[F], tol_fun: F, tol_x: F) -> bool {
+        let n = self.mean.len();
+
+        // TolFun: range of the best cost function values of the recent generations and of the
+        // current generation below `tol_fun`
+        let history_len = 10 + (30 * n).div_ceil(self.lambda);
+        if self.history.len() >= history_len {
+            let range = |values: &mut dyn Iterator<Item = &F>| {
+                let (min, max) = values.fold(
+                    (F::infinity(), F::neg_infinity()),
+                    |(min, max): (F, F), &c| (min.min(c), max.max(c)),
+                );
+                max - min
+            };
+            if range(&mut self.history.iter()) < tol_fun && range(&mut costs.iter()) < tol_fun {
+                return true;
+            }
+        }
+
+        // TolX: standard deviations in all coordinates and the evolution path below `tol_x`
+        if (0..n).all(|i| {
+            self.sigma * self.p_c[i].abs() < tol_x && self.sigma * self.cov[i][i].sqrt() < tol_x
+        }) {
+            return true;
+        }
+
+        // ConditionCov: covariance matrix is badly conditioned
+        self.condition() > float!(1e14)
+    }
+}
+
+/// # Covariance Matrix Adaptation Evolution Strategy (CMA-ES)
+///
+/// CMA-ES samples a population of candidate solutions from a multivariate normal distribution in
+/// each iteration. The mean of the distribution is moved towards the best candidates, while the
+/// covariance matrix is adapted with a rank-one update (based on the evolution path) and a
+/// rank-mu update (based on the successful steps of the current population). The overall step
+/// size is controlled by cumulative step size adaptation. This makes CMA-ES well suited for
+/// rugged, non-separable and badly conditioned problems, where no gradient is available.
+///
+/// The solver requires an initial mean and an initial step size `sigma`, which should be chosen
+/// such that the optimum is expected to lie within `mean +- 2 * sigma` in each coordinate. The
+/// population size defaults to `4 + floor(3 * ln(n))` and can be changed with
+/// [`with_population_size`](`CMAES::with_population_size`).
+///
+/// A run stagnates if either
+///
+/// * the range of the best cost function values of the recent generations and of all cost
+///   function values of the current generation is below `tol_fun` (default: `1e-12`),
+/// * the standard deviation in all coordinates is below `tol_x` (default: `1e-12`) or
+/// * the condition number of the covariance matrix exceeds `1e14`.
+///
+/// Without restarts the solver terminates once the run stagnates. With
+/// [`with_restarts`](`CMAES::with_restarts`) either IPOP or BIPOP restarts can be enabled,
+/// which restart the evolution strategy from the initial mean with a modified population size.
+///
+/// In each iteration, the best candidate of the current generation is stored as individual in the
+/// [`PopulationState`], together with the entire (sorted) population.
+///
+/// The population is evaluated with [`Problem::bulk_cost`]. The `rayon` feature therefore
+/// enables parallel computation of the cost function. This can be beneficial for expensive cost
+/// functions, but may cause a drop in performance for cheap cost functions. Be sure to benchmark
+/// both parallel and sequential computation.
+///
+/// The random number generator defaults to `Xoshiro256PlusPlus` seeded from entropy. A different
+/// (or seeded) generator can be provided via
+/// [`with_rng_generator`](`CMAES::with_rng_generator`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] operating on `Vec<F>`. The
+/// required math traits for `Vec<F>` are provided by the `vec` feature of `argmin-math`.
+///
+/// ## References
+///
+/// \[0\] Nikolaus Hansen (2016). The CMA Evolution Strategy: A Tutorial.
+/// arXiv:1604.00772. <https://arxiv.org/abs/1604.00772>
+///
+/// \[1\] Anne Auger and Nikolaus Hansen (2005). A restart CMA evolution strategy with increasing
+/// population size. 2005 IEEE Congress on Evolutionary Computation.
+/// <https://doi.org/10.1109/CEC.2005.1554902>
+///
+/// \[2\] Nikolaus Hansen (2009). Benchmarking a BI-population CMA-ES on the BBOB-2009 function
+/// testbed. GECCO '09. <https://doi.org/10.1145/1570256.1570333>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CMAES<F, R> {
+    /// Initial mean of the search distribution
+    initial_mean: Vec<F>,
+    /// Initial step size
+    initial_sigma: F,
+    /// Population size of the first run
+    population_size: Option<usize>,
+    /// Restart strategy
+    restart_strategy: RestartStrategy,
+    /// Maximum number of restarts
+    max_restarts: usize,
+    /// Tolerance on the range of cost function values
+    tol_fun: F,
+    /// Tolerance on the standard deviations
+    tol_x: F,
+    /// Current run
+    strategy: Option<Strategy<F>>,
+    /// Number of restarts performed so far
+    restarts: usize,
+    /// Population size of the most recent run of the large population regime
+    large_population_size: usize,
+    /// Function evaluations spent in the large population regime (BIPOP)
+    evaluations_large: u64,
+    /// Function evaluations spent in the small population regime (BIPOP)
+    evaluations_small: u64,
+    /// Random number generator
+    rng_generator: R,
+}
+
+impl<F> CMAES<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `CMAES`
+    ///
+    /// Takes the initial mean of the search distribution and the initial step size as inputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cmaes::CMAES;
+    /// let cmaes: CMAES<f64, _> = CMAES::new(vec![1.0, -1.0], 0.5);
+    /// ```
+    pub fn new(initial_mean: Vec<F>, initial_sigma: F) -> Self {
+        CMAES {
+            initial_mean,
+            initial_sigma,
+            population_size: None,
+            restart_strategy: RestartStrategy::None,
+            max_restarts: 0,
+            tol_fun: float!(1e-12),
+            tol_x: float!(1e-12),
+            strategy: None,
+            restarts: 0,
+            large_population_size: 0,
+            evaluations_large: 0,
+            evaluations_small: 0,
+            rng_generator: Xoshiro256PlusPlus::from_entropy(),
+        }
+    }
+}
+
+impl<F, R0> CMAES<F, R0>
+where
+    F: ArgminFloat,
+    R0: Rng,
+{
+    /// Set the random number generator
+    ///
+    /// Defaults to `rand_xoshiro::Xoshiro256PlusPlus::from_entropy()`
+    ///
+    /// # Example
+    /// ```
+    /// # use argmin::solver::cmaes::CMAES;
+    /// # use rand::SeedableRng;
+    /// let cmaes: CMAES<f64, _> = CMAES::new(vec![1.0, -1.0], 0.5)
+    ///     .with_rng_generator(rand_xoshiro::Xoroshiro128Plus::seed_from_u64(1729));
+    /// ```
+    pub fn with_rng_generator<R1: Rng>(self, generator: R1) -> CMAES<F, R1> {
+        CMAES {
+            initial_mean: self.initial_mean,
+            initial_sigma: self.initial_sigma,
+            population_size: self.population_size,
+            restart_strategy: self.restart_strategy,
+            max_restarts: self.max_restarts,
+            tol_fun: self.tol_fun,
+            tol_x: self.tol_x,
+            strategy: self.strategy,
+            restarts: self.restarts,
+            large_population_size: self.large_population_size,
+            evaluations_large: self.evaluations_large,
+            evaluations_small: self.evaluations_small,
+            rng_generator: generator,
+        }
+    }
+}
+
+impl<F, R> CMAES<F, R>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Set the population size of the first run
+    ///
+    /// Must be at least 2. Defaults to `4 + floor(3 * ln(n))`, where `n` is the number of
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cmaes::CMAES;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cmaes: CMAES<f64, _> = CMAES::new(vec![1.0, -1.0], 0.5).with_population_size(20)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_population_size(mut self, population_size: usize) -> Result<Self, Error> {
+        if population_size < 2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CMAES`: population size must be >= 2."
+            ));
+        }
+        self.population_size = Some(population_size);
+        Ok(self)
+    }
+
+    /// Enable restarts
+    ///
+    /// Performs at most `max_restarts` restarts according to `strategy`. Restarts are disabled by
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cmaes::{CMAES, RestartStrategy};
+    /// let cmaes: CMAES<f64, _> =
+    ///     CMAES::new(vec![1.0, -1.0], 0.5).with_restarts(RestartStrategy::BIPOP, 9);
+    /// ```
+    #[must_use]
+    pub fn with_restarts(mut self, strategy: RestartStrategy, max_restarts: usize) -> Self {
+        self.restart_strategy = strategy;
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Set tolerance on the range of cost function values
+    ///
+    /// Must be non-negative. Defaults to `1e-12`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cmaes::CMAES;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cmaes: CMAES<f64, _> = CMAES::new(vec![1.0, -1.0], 0.5).with_tolerance_fun(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_fun(mut self, tol_fun: F) -> Result<Self, Error> {
+        if tol_fun < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CMAES`: cost function tolerance must be >= 0."
+            ));
+        }
+        self.tol_fun = tol_fun;
+        Ok(self)
+    }
+
+    /// Set tolerance on the standard deviations of the search distribution
+    ///
+    /// Must be non-negative. Defaults to `1e-12`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cmaes::CMAES;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cmaes: CMAES<f64, _> = CMAES::new(vec![1.0, -1.0], 0.5).with_tolerance_x(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_x(mut self, tol_x: F) -> Result<Self, Error> {
+        if tol_x < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CMAES`: parameter tolerance must be >= 0."
+            ));
+        }
+        self.tol_x = tol_x;
+        Ok(self)
+    }
+
+    /// Default population size `4 + floor(3 * ln(n))`
+    fn default_population_size(&self) -> usize {
+        4 + (3.0 * (self.initial_mean.len() as f64).ln()).floor() as usize
+    }
+
+    /// Population size of the first run
+    fn initial_population_size(&self) -> usize {
+        self.population_size
+            .unwrap_or_else(|| self.default_population_size())
+    }
+}
+
+impl<F, R> CMAES<F, R>
+where
+    Vec<F>: ArgminL2Norm<F>,
+    Vec<Vec<F>>: ArgminEye,
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Sets up the run following a stagnated run according to the restart strategy
+    fn restart(&mut self, previous: &Strategy<F>) {
+        // The first run with default population size belongs to neither regime
+        if self.restarts > 0 {
+            let evaluations = previous.generation * previous.lambda as u64;
+            if previous.small_regime {
+                self.evaluations_small += evaluations;
+            } else {
+                self.evaluations_large += evaluations;
+            }
+        }
+        self.restarts += 1;
+
+        let lambda_default = self.initial_population_size();
+        let mean = self.initial_mean.clone();
+        let strategy = match self.restart_strategy {
+            RestartStrategy::BIPOP if self.evaluations_small < self.evaluations_large => {
+                let u1: f64 = self.rng_generator.gen();
+                let u2: f64 = self.rng_generator.gen();
+                let ratio = 0.5 * self.large_population_size as f64 / lambda_default as f64;
+                let lambda = ((lambda_default as f64) * ratio.powf(u1 * u1)).floor() as usize;
+                let sigma = self.initial_sigma * float!(10f64.powf(-2.0 * u2));
+                Strategy::new(mean, sigma, lambda.max(lambda_default), true)
+            }
+            _ => {
+                self.large_population_size *= 2;
+                Strategy::new(mean, self.initial_sigma, self.large_population_size, false)
+            }
+        };
+        self.strategy = Some(strategy);
+    }
+}
+
+impl<O, F, R> Solver<O, PopulationState<Vec<F>, F>> for CMAES<F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    Vec<F>: ArgminL2Norm<F>,
+    Vec<Vec<F>>: ArgminEye,
+    F: ArgminFloat + SyncAlias,
+    R: Rng,
+{
+    fn name(&self) -> &str {
+        "CMA-ES"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: PopulationState<Vec<F>, F>,
+    ) -> Result<(PopulationState<Vec<F>, F>, Option<KV>), Error> {
+        if self.initial_mean.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CMAES`: initial mean must not be empty."
+            ));
+        }
+        if self.initial_sigma <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CMAES`: initial step size must be > 0."
+            ));
+        }
+
+        let lambda = self.initial_population_size();
+        self.restarts = 0;
+        self.large_population_size = lambda;
+        self.evaluations_large = 0;
+        self.evaluations_small = 0;
+        self.strategy = Some(Strategy::new(
+            self.initial_mean.clone(),
+            self.initial_sigma,
+            lambda,
+            false,
+        ));
+
+        let cost = problem.cost(&self.initial_mean)?;
+        Ok((
+            state.individual(self.initial_mean.clone()).cost(cost),
+            Some(kv!("sigma" => self.initial_sigma; "population_size" => lambda as u64;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: PopulationState<Vec<F>, F>,
+    ) -> Result<(PopulationState<Vec<F>, F>, Option<KV>), Error> {
+        let mut strategy = self.strategy.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CMAES`: Evolution strategy not initialized."
+        ))?;
+
+        let (y, x) = strategy.sample(&mut self.rng_generator);
+        let costs = problem.bulk_cost(&x)?;
+
+        let mut order: Vec<usize> = (0..costs.len()).collect();
+        order.sort_by(|&a, &b| {
+            costs[a]
+                .partial_cmp(&costs[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let sorted_costs: Vec<F> = order.iter().map(|&k| costs[k]).collect();
+        let y_sorted: Vec<&Vec<F>> = order.iter().map(|&k| &y[k]).collect();
+
+        strategy.update(&y_sorted);
+        strategy.history.push_back(sorted_costs[0]);
+        let history_len = 10 + (30 * strategy.mean.len()).div_ceil(strategy.lambda);
+        while strategy.history.len() > history_len {
+            strategy.history.pop_front();
+        }
+
+        let mut population = x;
+        let mut sorted_population: Vec<Vec<F>> = Vec::with_capacity(population.len());
+        for &k in order.iter() {
+            sorted_population.push(std::mem::take(&mut population[k]));
+        }
+        let best = sorted_population[0].clone();
+
+        let kv = kv!(
+            "sigma" => strategy.sigma;
+            "population_size" => strategy.lambda as u64;
+            "condition" => strategy.condition();
+            "restarts" => self.restarts as u64;
+        );
+
+        let mut state = state
+            .individual(best)
+            .cost(sorted_costs[0])
+            .population(sorted_population);
+
+        if strategy.stagnated(&sorted_costs, self.tol_fun, self.tol_x) {
+            if self.restart_strategy != RestartStrategy::None && self.restarts < self.max_restarts {
+                self.restart(&strategy);
+                return Ok((state, Some(kv)));
+            }
+            state = state.terminate_with(TerminationReason::SolverConverged);
+        }
+        self.strategy = Some(strategy);
+
+        Ok((state, Some(kv)))
+    }
+}
+
+/// Draws a sample from the standard normal distribution using the Box-Muller transform
+fn standard_normal<F: ArgminFloat, R: Rng>(rng: &mut R) -> F {
+    // `gen` samples from [0, 1), therefore `1 - u1` is in (0, 1].
+    let u1: f64 = rng.gen();
+    let u2: f64 = rng.gen();
+    float!((-2.0 * (1.0 - u1).ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos())
+}
+
+/// Computes eigenvalues and eigenvectors (as columns) of a symmetric matrix with the cyclic
+/// Jacobi method.
+fn symmetric_eigen<F>(mut a: Vec<Vec<F>>) -> (Vec<F>, Vec<Vec<F>>)
+where
+    Vec<Vec<F>>: ArgminEye,
+    F: ArgminFloat,
+{
+    let n = a.len();
+    let mut v = Vec::eye(n);
+    for _ in 0..100 {
+        let off_diagonal = (0..n).fold(float!(0.0), |acc: F, i| {
+            (0..n)
+                .filter(|&j| j != i)
+                .fold(acc, |acc, j| acc + a[i][j] * a[i][j])
+        });
+        let diagonal = (0..n).fold(float!(0.0), |acc: F, i| acc + a[i][i] * a[i][i]);
+        if off_diagonal <= F::epsilon() * F::epsilon() * diagonal {
+            break;
+        }
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q].abs() <= F::min_positive_value() {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (float!(2.0) * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + float!(1.0)).sqrt());
+                let c = float!(1.0) / (t * t + float!(1.0)).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (x, y) = (*apk, *aqk);
+                    *apk = c * x - s * y;
+                    *aqk = s * x + c * y;
+                }
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| a[i][i]).collect(), v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor};
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rastrigin, rosenbrock, sphere};
+
+    test_trait_impl!(cmaes, CMAES<f64, Xoshiro256PlusPlus>);
+
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(sphere(p))
+        }
+    }
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    struct Rastrigin {}
+
+    impl CostFunction for Rastrigin {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rastrigin(p))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let cmaes: CMAES<f64, _> = CMAES::new(vec![1.0, 2.0], 0.5);
+        let CMAES {
+            initial_mean,
+            initial_sigma,
+            population_size,
+            restart_strategy,
+            max_restarts,
+            tol_fun,
+            tol_x,
+            strategy,
+            restarts,
+            ..
+        } = cmaes;
+
+        assert_eq!(initial_mean, vec![1.0, 2.0]);
+        assert_eq!(initial_sigma.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert!(population_size.is_none());
+        assert_eq!(restart_strategy, RestartStrategy::None);
+        assert_eq!(max_restarts, 0);
+        assert_eq!(tol_fun.to_ne_bytes(), 1e-12f64.to_ne_bytes());
+        assert_eq!(tol_x.to_ne_bytes(), 1e-12f64.to_ne_bytes());
+        assert!(strategy.is_none());
+        assert_eq!(restarts, 0);
+    }
+
+    #[test]
+    fn test_with_population_size() {
+        for size in [2, 10, 100] {
+            let res = CMAES::new(vec![1.0f64, 2.0], 0.5).with_population_size(size);
+            assert_eq!(res.unwrap().population_size, Some(size));
+        }
+
+        for size in [0, 1] {
+            let res = CMAES::new(vec![1.0f64, 2.0], 0.5).with_population_size(size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`CMAES`: population size must be >= 2.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_restarts() {
+        let cmaes = CMAES::new(vec![1.0f64, 2.0], 0.5).with_restarts(RestartStrategy::IPOP, 4);
+        assert_eq!(cmaes.restart_strategy, RestartStrategy::IPOP);
+        assert_eq!(cmaes.max_restarts, 4);
+    }
+
+    #[test]
+    fn test_with_tolerances() {
+        let cmaes = CMAES::new(vec![1.0f64, 2.0], 0.5)
+            .with_tolerance_fun(1e-6)
+            .unwrap()
+            .with_tolerance_x(0.0)
+            .unwrap();
+        assert_eq!(cmaes.tol_fun.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert_eq!(cmaes.tol_x.to_ne_bytes(), 0.0f64.to_ne_bytes());
+
+        let res = CMAES::new(vec![1.0f64, 2.0], 0.5).with_tolerance_fun(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`CMAES`: cost function tolerance must be >= 0.\""
+        );
+        let res = CMAES::new(vec![1.0f64, 2.0], 0.5).with_tolerance_x(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`CMAES`: parameter tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_invalid() {
+        let mut cmaes = CMAES::new(vec![], 0.5);
+        let res = cmaes.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`CMAES`: initial mean must not be empty.\""
+        );
+
+        let mut cmaes = CMAES::new(vec![1.0, 2.0], 0.0);
+        let res = cmaes.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`CMAES`: initial step size must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_strategy_parameters() {
+        // Default parameters for n = 10 according to the tutorial of Hansen
+        let strategy: Strategy<f64> = Strategy::new(vec![0.0; 10], 1.0, 10, false);
+        assert_eq!(strategy.weights.len(), 5);
+        assert_relative_eq!(strategy.weights.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+        assert!(strategy.weights.windows(2).all(|w| w[0] > w[1]));
+        assert_relative_eq!(strategy.mu_eff, 3.1672992, epsilon = 1e-6);
+        assert_relative_eq!(strategy.c_sigma, 0.2844286, epsilon = 1e-6);
+        assert_relative_eq!(strategy.chi_n, 3.0847265, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let a = vec![
+            vec![4.0, 1.0, -2.0],
+            vec![1.0, 2.0, 0.0],
+            vec![-2.0, 0.0, 3.0],
+        ];
+        let (values, vectors) = symmetric_eigen(a.clone());
+        for (k, &lambda) in values.iter().enumerate() {
+            for i in 0..3 {
+                let av = (0..3).fold(0.0, |acc, j| acc + a[i][j] * vectors[j][k]);
+                assert_relative_eq!(av, lambda * vectors[i][k], epsilon = 1e-10);
+            }
+        }
+        assert_relative_eq!(values.iter().sum::<f64>(), 9.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_sphere() {
+        let solver = CMAES::new(vec![3.0, -2.0, 1.0, 4.0], 1.0)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+
+        let res = Executor::new(Sphere {}, solver)
+            .configure(|state| state.max_iters(1000))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        for p in res.state.get_best_param().unwrap() {
+            assert!(p.abs() < 1e-5);
+        }
+        assert!(res.state.get_best_cost() < 1e-10);
+        assert_eq!(res.state.get_population().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_rosenbrock() {
+        let solver = CMAES::new(vec![-1.2, 1.0], 0.5)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.max_iters(2000))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_seed_reproducibility() {
+        let run = || {
+            let solver = CMAES::new(vec![3.0, -2.0], 1.0)
+                .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(1729));
+            Executor::new(Sphere {}, solver)
+                .configure(|state| state.max_iters(20))
+                .run()
+                .unwrap()
+                .state
+        };
+        let state1 = run();
+        let state2 = run();
+        assert_eq!(state1.get_best_param(), state2.get_best_param());
+        assert_eq!(
+            state1.get_best_cost().to_ne_bytes(),
+            state2.get_best_cost().to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_bulk_cost_counts() {
+        let solver = CMAES::new(vec![3.0, -2.0], 1.0)
+            .with_population_size(12)
+            .unwrap()
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(1729));
+        let res = Executor::new(Sphere {}, solver)
+            .configure(|state| state.max_iters(5).counting(true))
+            .run()
+            .unwrap();
+        // One evaluation of the initial mean plus 12 evaluations per iteration
+        assert_eq!(res.state.get_func_counts()["cost_count"], 1 + 5 * 12);
+    }
+
+    #[test]
+    fn test_restarts() {
+        for strategy in [RestartStrategy::IPOP, RestartStrategy::BIPOP] {
+            let solver = CMAES::new(vec![3.0, 3.0, 3.0], 2.0)
+                .with_restarts(strategy, 20)
+                .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(7));
+
+            let res = Executor::new(Rastrigin {}, solver)
+                .configure(|state| state.max_iters(10000).target_cost(1e-8))
+                .run()
+                .unwrap();
+
+            assert!(res.state.get_best_cost() < 1e-8);
+            assert!(res.solver.restarts > 0);
+        }
+    }
+
+    #[test]
+    fn test_bipop_regimes() {
+        let mut cmaes = CMAES::new(vec![3.0f64, 3.0, 3.0], 2.0)
+            .with_restarts(RestartStrategy::BIPOP, 20)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(7));
+        let lambda_default = cmaes.initial_population_size();
+        cmaes.large_population_size = lambda_default;
+        let run = |cmaes: &mut CMAES<f64, _>, generations: u64| {
+            let mut previous = cmaes
+                .strategy
+                .take()
+                .unwrap_or_else(|| Strategy::new(vec![3.0, 3.0, 3.0], 2.0, lambda_default, false));
+            previous.generation = generations;
+            cmaes.restart(&previous);
+            let strategy = cmaes.strategy.as_ref().unwrap();
+            (strategy.small_regime, strategy.lambda)
+        };
+
+        // The first restart uses the large regime, regardless of the default run
+        assert_eq!(run(&mut cmaes, 100), (false, 2 * lambda_default));
+        assert_eq!(cmaes.evaluations_large, 0);
+        assert_eq!(cmaes.evaluations_small, 0);
+        // The large regime spent more evaluations than the small one
+        assert!(run(&mut cmaes, 10).0);
+        assert_eq!(cmaes.evaluations_large, 10 * 2 * lambda_default as u64);
+        // The small regime runs until it spent at least as many evaluations as the large one
+        let (small, lambda) = run(&mut cmaes, 1);
+        assert!(small);
+        assert!(lambda >= lambda_default && lambda <= 2 * lambda_default);
+        let small_lambda = cmaes.strategy.as_ref().unwrap().lambda as u64;
+        assert!(!run(&mut cmaes, 20 * lambda_default as u64 / small_lambda + 1).0);
+        assert_eq!(cmaes.large_population_size, 4 * lambda_default);
+    }
+}
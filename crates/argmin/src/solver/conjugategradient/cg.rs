@@ -322,4 +322,50 @@ mod tests {
         assert_relative_eq!(norm, state.get_cost());
         assert_relative_eq!(new_param, state.get_param().unwrap()[0]);
     }
+
+    #[cfg(feature = "_sprs")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{Executor, Operator};
+        use argmin_math::ArgminDot;
+        use ndarray::Array1;
+        use sprs::{CsMat, TriMat};
+
+        // Symmetric positive definite tridiagonal system
+        struct SparseProblem {
+            a: CsMat<f64>,
+        }
+
+        impl Operator for SparseProblem {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self.a.dot(p))
+            }
+        }
+
+        let n = 20;
+        let mut a = TriMat::new((n, n));
+        for i in 0..n {
+            a.add_triplet(i, i, 4.0);
+            if i + 1 < n {
+                a.add_triplet(i, i + 1, -1.0);
+                a.add_triplet(i + 1, i, -1.0);
+            }
+        }
+        let a: CsMat<f64> = a.to_csr();
+        let x = Array1::from_shape_fn(n, |i| i as f64);
+        let b = a.dot(&x);
+
+        let solver: ConjugateGradient<_, f64> = ConjugateGradient::new(b);
+        let res = Executor::new(SparseProblem { a }, solver)
+            .configure(|state| state.param(Array1::zeros(n)).max_iters(n as u64))
+            .run()
+            .unwrap();
+        let param = res.state().get_param().unwrap();
+        for i in 0..n {
+            assert_relative_eq!(param[i], x[i], epsilon = 1e-8);
+        }
+    }
 }
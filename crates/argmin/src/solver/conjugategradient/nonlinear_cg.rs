@@ -144,8 +144,7 @@ where
                 "Please provide an initial guess via `Executor`s `configure` method."
             )
         ))?;
-        let cost = problem.cost(param)?;
-        let grad = problem.gradient(param)?;
+        let (cost, grad) = problem.cost_and_gradient(param)?;
         self.p = Some(grad.mul(&(float!(-1.0))));
         Ok((state.cost(cost).gradient(grad), None))
     }
@@ -185,6 +184,7 @@ where
             self.linesearch.clone(),
         )
         .configure(|state| state.param(xk).gradient(grad.clone()).cost(cur_cost))
+        .inherit_func_eval_budget(problem)
         .ctrlc(false)
         .run()?;
 
@@ -196,8 +196,9 @@ where
             "`NonlinearConjugateGradient`: No `param` returned by line search"
         ))?;
 
+        let (cost, new_grad) = problem.cost_and_gradient(&xk1)?;
+
         // Update of beta
-        let new_grad = problem.gradient(&xk1)?;
 
         let restart_orthogonality = match self.restart_orthogonality {
             Some(v) => new_grad.dot(&grad).abs() / new_grad.l2_norm().powi(2) >= v,
@@ -216,9 +217,6 @@ where
         // Update of p
         self.p = Some(new_grad.mul(&(float!(-1.0))).add(&p.mul(&self.beta)));
 
-        // Housekeeping
-        let cost = problem.cost(&xk1)?;
-
         Ok((
             state.param(xk1).cost(cost).gradient(new_grad),
             Some(kv!("beta" => self.beta;
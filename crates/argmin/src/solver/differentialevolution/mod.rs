@@ -0,0 +1,1026 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Differential Evolution (DE)
+//!
+//! Population based, derivative-free optimization of non-linear and non-convex functions within
+//! box constraints.
+//!
+//! For details see [`DifferentialEvolution`].
+//!
+//! ## References
+//!
+//! \[0\] Rainer Storn and Kenneth Price (1997). Differential Evolution – A Simple and Efficient
+//! Heuristic for global Optimization over Continuous Spaces. Journal of Global Optimization 11,
+//! 341–359. <https://doi.org/10.1023/A:1008202821328>
+//!
+//! \[1\] Jingqiao Zhang and Arthur C. Sanderson (2009). JADE: Adaptive Differential Evolution
+//! With Optional External Archive. IEEE Transactions on Evolutionary Computation 13(5), 945–958.
+//! <https://doi.org/10.1109/TEVC.2009.2014613>
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, PopulationState, Problem, Solver, SyncAlias, KV,
+};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Mutation and crossover strategy of [`DifferentialEvolution`]
+///
+/// All strategies use binomial crossover.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Strategy {
+    /// DE/rand/1/bin: `v = x_r1 + F * (x_r2 - x_r3)`
+    Rand1Bin,
+    /// DE/best/1/bin: `v = x_best + F * (x_r1 - x_r2)`
+    Best1Bin,
+    /// DE/current-to-best/1/bin: `v = x_i + F * (x_best - x_i) + F * (x_r1 - x_r2)`
+    CurrentToBest1Bin,
+    /// JADE: DE/current-to-pbest/1/bin with external archive and self-adaptive mutation factor
+    /// and crossover probability.
+    JADE,
+}
+
+/// # Differential Evolution (DE)
+///
+/// Differential evolution maintains a population of candidate solutions within a box defined by
+/// lower and upper bounds. In each iteration, a mutant vector is created for every member of the
+/// population by adding scaled differences of other members to a base vector. The mutant is
+/// combined with the member via binomial crossover, and the resulting trial vector replaces the
+/// member if it has an equal or lower cost.
+///
+/// The following strategies are available (see [`Strategy`]):
+///
+/// * DE/rand/1/bin (default)
+/// * DE/best/1/bin
+/// * DE/current-to-best/1/bin
+/// * JADE, which adapts the mutation factor and the crossover probability of each member from
+///   the successful trial vectors of the previous generations and uses the current-to-pbest
+///   mutation with an external archive of replaced members.
+///
+/// The mutation factor and the crossover probability default to `0.8` and `0.9`, respectively.
+/// For JADE these values serve as initial means of the adaptive distributions; the defaults for
+/// JADE are `0.5` for both values as suggested in \[1\], which can be obtained by setting them
+/// explicitly.
+///
+/// Components of trial vectors which violate the bounds are set to the midpoint between the
+/// bound and the corresponding component of the parent.
+///
+/// The population is initialized uniformly within the bounds unless a population is provided
+/// via the [`PopulationState`]. In each iteration, the best member is stored as individual in the
+/// state and the population holds all members. The cost function values of the population are
+/// part of the solver and therefore checkpointed along with it when the `serde1` feature is
+/// enabled.
+///
+/// The population is evaluated with [`Problem::bulk_cost`]. The `rayon` feature therefore
+/// enables parallel computation of the cost function. This can be beneficial for expensive cost
+/// functions, but may cause a drop in performance for cheap cost functions. Be sure to benchmark
+/// both parallel and sequential computation.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## References
+///
+/// \[0\] Rainer Storn and Kenneth Price (1997). Differential Evolution – A Simple and Efficient
+/// Heuristic for global Optimization over Continuous Spaces. Journal of Global Optimization 11,
+/// 341–359. <https://doi.org/10.1023/A:1008202821328>
+///
+/// \[1\] Jingqiao Zhang and Arthur C. Sanderson (2009). JADE: Adaptive Differential Evolution
+/// With Optional External Archive. IEEE Transactions on Evolutionary Computation 13(5), 945–958.
+/// <https://doi.org/10.1109/TEVC.2009.2014613>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct DifferentialEvolution<F, R> {
+    /// Bounds on parameter space
+    bounds: (Vec<F>, Vec<F>),
+    /// Population size
+    population_size: usize,
+    /// Mutation and crossover strategy
+    strategy: Strategy,
+    /// Mutation factor (mean of the mutation factor distribution for JADE)
+    mutation_factor: F,
+    /// Crossover probability (mean of the crossover probability distribution for JADE)
+    crossover_probability: F,
+    /// JADE: adaptation rate of the means
+    adaptation_rate: F,
+    /// JADE: fraction of the population considered as the `p` best members
+    greediness: F,
+    /// Cost function values of the population
+    costs: Vec<F>,
+    /// JADE: archive of replaced members
+    archive: Vec<Vec<F>>,
+    /// Random number generator
+    rng_generator: R,
+}
+
+impl<F> DifferentialEvolution<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `DifferentialEvolution`
+    ///
+    /// Takes the bounds on the search space and the population size as inputs. `bounds` is a
+    /// tuple `(lower_bound, upper_bound)` where both bounds have the same length as the
+    /// parameter vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::differentialevolution::DifferentialEvolution;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let de: DifferentialEvolution<f64, _> =
+    ///     DifferentialEvolution::new((lower_bound, upper_bound), 40);
+    /// ```
+    pub fn new(bounds: (Vec<F>, Vec<F>), population_size: usize) -> Self {
+        DifferentialEvolution {
+            bounds,
+            population_size,
+            strategy: Strategy::Rand1Bin,
+            mutation_factor: float!(0.8),
+            crossover_probability: float!(0.9),
+            adaptation_rate: float!(0.1),
+            greediness: float!(0.05),
+            costs: vec![],
+            archive: vec![],
+            rng_generator: Xoshiro256PlusPlus::from_entropy(),
+        }
+    }
+}
+
+impl<F, R0> DifferentialEvolution<F, R0>
+where
+    F: ArgminFloat,
+    R0: Rng,
+{
+    /// Set the random number generator
+    ///
+    /// Defaults to `rand_xoshiro::Xoshiro256PlusPlus::from_entropy()`
+    ///
+    /// # Example
+    /// ```
+    /// # use argmin::solver::differentialevolution::DifferentialEvolution;
+    /// # use rand::SeedableRng;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let de: DifferentialEvolution<f64, _> =
+    ///     DifferentialEvolution::new((lower_bound, upper_bound), 40)
+    ///     .with_rng_generator(rand_xoshiro::Xoroshiro128Plus::seed_from_u64(1729));
+    /// ```
+    pub fn with_rng_generator<R1: Rng>(self, generator: R1) -> DifferentialEvolution<F, R1> {
+        DifferentialEvolution {
+            bounds: self.bounds,
+            population_size: self.population_size,
+            strategy: self.strategy,
+            mutation_factor: self.mutation_factor,
+            crossover_probability: self.crossover_probability,
+            adaptation_rate: self.adaptation_rate,
+            greediness: self.greediness,
+            costs: self.costs,
+            archive: self.archive,
+            rng_generator: generator,
+        }
+    }
+}
+
+impl<F, R> DifferentialEvolution<F, R>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Set mutation and crossover strategy
+    ///
+    /// Defaults to [`Strategy::Rand1Bin`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::differentialevolution::{DifferentialEvolution, Strategy};
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let de: DifferentialEvolution<f64, _> =
+    ///     DifferentialEvolution::new((lower_bound, upper_bound), 40)
+    ///     .with_strategy(Strategy::Best1Bin);
+    /// ```
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set mutation factor
+    ///
+    /// Must be in `(0, 2]`. Defaults to `0.8`. For JADE this is the initial mean of the mutation
+    /// factor distribution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::differentialevolution::DifferentialEvolution;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let de: DifferentialEvolution<f64, _> =
+    ///     DifferentialEvolution::new((lower_bound, upper_bound), 40).with_mutation_factor(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_mutation_factor(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(0.0) || factor > float!(2.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: mutation factor must be in (0, 2]."
+            ));
+        }
+        self.mutation_factor = factor;
+        Ok(self)
+    }
+
+    /// Set crossover probability
+    ///
+    /// Must be in `[0, 1]`. Defaults to `0.9`. For JADE this is the initial mean of the
+    /// crossover probability distribution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::differentialevolution::DifferentialEvolution;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let de: DifferentialEvolution<f64, _> = DifferentialEvolution::new((lower_bound, upper_bound), 40)
+    ///     .with_crossover_probability(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_crossover_probability(mut self, probability: F) -> Result<Self, Error> {
+        if probability < float!(0.0) || probability > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: crossover probability must be in [0, 1]."
+            ));
+        }
+        self.crossover_probability = probability;
+        Ok(self)
+    }
+
+    /// Set the parameters of JADE
+    ///
+    /// `adaptation_rate` (`c` in \[1\]) controls how fast the means of the mutation factor and
+    /// crossover probability distributions adapt and must be in `(0, 1]`. `greediness` (`p` in
+    /// \[1\]) is the fraction of the population from which the `pbest` member is chosen and must
+    /// be in `(0, 1]`. Default to `0.1` and `0.05`, respectively. Only used by
+    /// [`Strategy::JADE`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::differentialevolution::{DifferentialEvolution, Strategy};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let de: DifferentialEvolution<f64, _> = DifferentialEvolution::new((lower_bound, upper_bound), 40)
+    ///     .with_strategy(Strategy::JADE)
+    ///     .with_jade_parameters(0.1, 0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_jade_parameters(
+        mut self,
+        adaptation_rate: F,
+        greediness: F,
+    ) -> Result<Self, Error> {
+        if adaptation_rate <= float!(0.0) || adaptation_rate > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: adaptation rate must be in (0, 1]."
+            ));
+        }
+        if greediness <= float!(0.0) || greediness > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: greediness must be in (0, 1]."
+            ));
+        }
+        self.adaptation_rate = adaptation_rate;
+        self.greediness = greediness;
+        Ok(self)
+    }
+
+    /// Draws `k` distinct indices from `0..n` which are different from all indices in `exclude`
+    fn distinct_indices(&mut self, n: usize, k: usize, exclude: &[usize]) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(k);
+        while indices.len() < k {
+            let idx = self.rng_generator.gen_range(0..n);
+            if !exclude.contains(&idx) && !indices.contains(&idx) {
+                indices.push(idx);
+            }
+        }
+        indices
+    }
+
+    /// Returns the indices of the `ceil(p * NP)` members with the lowest costs, from which JADE
+    /// picks the "p-best" member. Empty for all other strategies.
+    fn pbest_indices(&self) -> Vec<usize> {
+        if self.strategy != Strategy::JADE {
+            return vec![];
+        }
+        let np = self.costs.len();
+        let num_best = ((self.greediness * float!(np as f64))
+            .ceil()
+            .to_usize()
+            .unwrap_or(1))
+        .clamp(1, np);
+        let mut order: Vec<usize> = (0..np).collect();
+        order.sort_by(|&a, &b| {
+            self.costs[a]
+                .partial_cmp(&self.costs[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        order.truncate(num_best);
+        order
+    }
+
+    /// Creates a mutant vector for member `i`. Returns the mutant as well as the mutation
+    /// factor and crossover probability to be used for this member.
+    ///
+    /// `pbest` holds the indices of the best members as returned by
+    /// [`pbest_indices`](`DifferentialEvolution::pbest_indices`).
+    fn mutate(
+        &mut self,
+        population: &[Vec<F>],
+        i: usize,
+        best: usize,
+        pbest: &[usize],
+    ) -> (Vec<F>, F, F) {
+        let np = population.len();
+        let f = self.mutation_factor;
+        let cr = self.crossover_probability;
+        let combine = |base: &[F], terms: &[(F, &[F], &[F])]| -> Vec<F> {
+            (0..base.len())
+                .map(|j| {
+                    terms
+                        .iter()
+                        .fold(base[j], |acc, (factor, a, b)| acc + *factor * (a[j] - b[j]))
+                })
+                .collect()
+        };
+        match self.strategy {
+            Strategy::Rand1Bin => {
+                let r = self.distinct_indices(np, 3, &[i]);
+                let v = combine(
+                    &population[r[0]],
+                    &[(f, &population[r[1]], &population[r[2]])],
+                );
+                (v, f, cr)
+            }
+            Strategy::Best1Bin => {
+                let r = self.distinct_indices(np, 2, &[i, best]);
+                let v = combine(
+                    &population[best],
+                    &[(f, &population[r[0]], &population[r[1]])],
+                );
+                (v, f, cr)
+            }
+            Strategy::CurrentToBest1Bin => {
+                let r = self.distinct_indices(np, 2, &[i, best]);
+                let v = combine(
+                    &population[i],
+                    &[
+                        (f, &population[best], &population[i]),
+                        (f, &population[r[0]], &population[r[1]]),
+                    ],
+                );
+                (v, f, cr)
+            }
+            Strategy::JADE => {
+                let f = self.sample_mutation_factor();
+                let cr = self.sample_crossover_probability();
+
+                let pbest = pbest[self.rng_generator.gen_range(0..pbest.len())];
+
+                let r1 = self.distinct_indices(np, 1, &[i])[0];
+                // r2 is drawn from the union of population and archive
+                let r2 = loop {
+                    let idx = self.rng_generator.gen_range(0..(np + self.archive.len()));
+                    if idx != i && idx != r1 {
+                        break idx;
+                    }
+                };
+                let x_r2 = if r2 < np {
+                    population[r2].clone()
+                } else {
+                    self.archive[r2 - np].clone()
+                };
+                let v = combine(
+                    &population[i],
+                    &[
+                        (f, &population[pbest], &population[i]),
+                        (f, &population[r1], &x_r2),
+                    ],
+                );
+                (v, f, cr)
+            }
+        }
+    }
+
+    /// JADE: samples a mutation factor from a Cauchy distribution with location `mu_F` and scale
+    /// `0.1`, truncated to `(0, 1]`.
+    fn sample_mutation_factor(&mut self) -> F {
+        loop {
+            let u: f64 = self.rng_generator.gen();
+            let f = self.mutation_factor + float!(0.1 * (std::f64::consts::PI * (u - 0.5)).tan());
+            if f > float!(0.0) {
+                return f.min(float!(1.0));
+            }
+        }
+    }
+
+    /// JADE: samples a crossover probability from a normal distribution with mean `mu_CR` and
+    /// standard deviation `0.1`, truncated to `[0, 1]`.
+    fn sample_crossover_probability(&mut self) -> F {
+        // Box-Muller transform; `gen` samples from [0, 1), therefore `1 - u1` is in (0, 1].
+        let u1: f64 = self.rng_generator.gen();
+        let u2: f64 = self.rng_generator.gen();
+        let z = (-2.0 * (1.0 - u1).ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        (self.crossover_probability + float!(0.1 * z))
+            .max(float!(0.0))
+            .min(float!(1.0))
+    }
+
+    /// Binomial crossover of `target` and `mutant`; out of bounds components are set to the
+    /// midpoint between the bound and the target.
+    fn crossover(&mut self, target: &[F], mutant: &[F], cr: F) -> Vec<F> {
+        let j_rand = self.rng_generator.gen_range(0..target.len());
+        let (lower, upper) = &self.bounds;
+        let mut trial = Vec::with_capacity(target.len());
+        for (j, (&t, &m)) in target.iter().zip(mutant.iter()).enumerate() {
+            let u: F = float!(self.rng_generator.gen::<f64>());
+            let value = if j == j_rand || u < cr { m } else { t };
+            let value = if value < lower[j] {
+                (lower[j] + t) / float!(2.0)
+            } else if value > upper[j] {
+                (upper[j] + t) / float!(2.0)
+            } else {
+                value
+            };
+            trial.push(value);
+        }
+        trial
+    }
+}
+
+impl<O, F, R> Solver<O, PopulationState<Vec<F>, F>> for DifferentialEvolution<F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng,
+{
+    fn name(&self) -> &str {
+        "Differential Evolution"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: PopulationState<Vec<F>, F>,
+    ) -> Result<(PopulationState<Vec<F>, F>, Option<KV>), Error> {
+        let (lower, upper) = &self.bounds;
+        if lower.len() != upper.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: lower and upper bounds must have the same length."
+            ));
+        }
+        if lower.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: bounds must not be empty."
+            ));
+        }
+        if lower.iter().zip(upper.iter()).any(|(l, u)| l > u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: lower bounds must be <= upper bounds."
+            ));
+        }
+        if self.population_size < 4 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`DifferentialEvolution`: population size must be >= 4."
+            ));
+        }
+
+        // Users can provide a population or it will be randomly created.
+        let population = match state.take_population() {
+            Some(population) if population.len() == self.population_size => population,
+            Some(population) => {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    format!(
+                        "`DifferentialEvolution`: Provided population is of length {}, expected {}",
+                        population.len(),
+                        self.population_size
+                    )
+                ))
+            }
+            None => {
+                let (lower, upper) = &self.bounds;
+                let rng = &mut self.rng_generator;
+                (0..self.population_size)
+                    .map(|_| {
+                        lower
+                            .iter()
+                            .zip(upper.iter())
+                            .map(|(&l, &u)| l + (u - l) * float!(rng.gen::<f64>()))
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<F>>>()
+            }
+        };
+
+        self.costs = problem.bulk_cost(&population)?;
+        self.archive = vec![];
+
+        let best = best_index(&self.costs);
+        Ok((
+            state
+                .individual(population[best].clone())
+                .cost(self.costs[best])
+                .population(population),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: PopulationState<Vec<F>, F>,
+    ) -> Result<(PopulationState<Vec<F>, F>, Option<KV>), Error> {
+        let mut population = state.take_population().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`DifferentialEvolution`: No population in state."
+        ))?;
+        if population.len() != self.costs.len() {
+            return Err(argmin_error!(
+                PotentialBug,
+                "`DifferentialEvolution`: Population and costs are of different length."
+            ));
+        }
+
+        let best = best_index(&self.costs);
+        // The costs only change once per generation, therefore the best members are determined
+        // here rather than for every mutation.
+        let pbest = self.pbest_indices();
+        let mut trials = Vec::with_capacity(population.len());
+        let mut parameters = Vec::with_capacity(population.len());
+        for i in 0..population.len() {
+            let (mutant, f, cr) = self.mutate(&population, i, best, &pbest);
+            trials.push(self.crossover(&population[i], &mutant, cr));
+            parameters.push((f, cr));
+        }
+
+        let trial_costs = problem.bulk_cost(&trials)?;
+
+        let mut successful_f = vec![];
+        let mut successful_cr = vec![];
+        for (i, (trial, cost)) in trials.into_iter().zip(trial_costs).enumerate() {
+            if cost <= self.costs[i] {
+                if cost < self.costs[i] && self.strategy == Strategy::JADE {
+                    successful_f.push(parameters[i].0);
+                    successful_cr.push(parameters[i].1);
+                    let replaced = std::mem::replace(&mut population[i], trial);
+                    self.archive.push(replaced);
+                } else {
+                    population[i] = trial;
+                }
+                self.costs[i] = cost;
+            }
+        }
+
+        let kv = if self.strategy == Strategy::JADE {
+            // Limit the archive to the population size by removing random members
+            while self.archive.len() > self.population_size {
+                let idx = self.rng_generator.gen_range(0..self.archive.len());
+                self.archive.swap_remove(idx);
+            }
+
+            let c = self.adaptation_rate;
+            if !successful_cr.is_empty() {
+                let (sum, sum_sq) = successful_f
+                    .iter()
+                    .fold((float!(0.0), float!(0.0)), |(s, s2): (F, F), &f| {
+                        (s + f, s2 + f * f)
+                    });
+                let mean_cr = successful_cr
+                    .iter()
+                    .fold(float!(0.0), |acc: F, &cr| acc + cr)
+                    / float!(successful_cr.len() as f64);
+                self.crossover_probability =
+                    (float!(1.0) - c) * self.crossover_probability + c * mean_cr;
+                // Lehmer mean of the successful mutation factors
+                self.mutation_factor = (float!(1.0) - c) * self.mutation_factor + c * sum_sq / sum;
+            }
+            Some(kv!(
+                "mu_f" => self.mutation_factor;
+                "mu_cr" => self.crossover_probability;
+                "archive_size" => self.archive.len() as u64;
+            ))
+        } else {
+            None
+        };
+
+        let best = best_index(&self.costs);
+        Ok((
+            state
+                .individual(population[best].clone())
+                .cost(self.costs[best])
+                .population(population),
+            kv,
+        ))
+    }
+}
+
+/// Index of the lowest cost function value
+fn best_index<F: ArgminFloat>(costs: &[F]) -> usize {
+    costs
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, State};
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rastrigin, rosenbrock};
+
+    test_trait_impl!(
+        differentialevolution,
+        DifferentialEvolution<f64, Xoshiro256PlusPlus>
+    );
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    struct Rastrigin {}
+
+    impl CostFunction for Rastrigin {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rastrigin(p))
+        }
+    }
+
+    fn bounds() -> (Vec<f64>, Vec<f64>) {
+        (vec![-5.0, -5.0], vec![5.0, 5.0])
+    }
+
+    #[test]
+    fn test_new() {
+        let de: DifferentialEvolution<f64, _> = DifferentialEvolution::new(bounds(), 20);
+        let DifferentialEvolution {
+            bounds: (lower, upper),
+            population_size,
+            strategy,
+            mutation_factor,
+            crossover_probability,
+            adaptation_rate,
+            greediness,
+            costs,
+            archive,
+            ..
+        } = de;
+
+        assert_eq!(lower, vec![-5.0, -5.0]);
+        assert_eq!(upper, vec![5.0, 5.0]);
+        assert_eq!(population_size, 20);
+        assert_eq!(strategy, Strategy::Rand1Bin);
+        assert_eq!(mutation_factor.to_ne_bytes(), 0.8f64.to_ne_bytes());
+        assert_eq!(crossover_probability.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(adaptation_rate.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(greediness.to_ne_bytes(), 0.05f64.to_ne_bytes());
+        assert!(costs.is_empty());
+        assert!(archive.is_empty());
+    }
+
+    #[test]
+    fn test_with_strategy() {
+        for strategy in [
+            Strategy::Rand1Bin,
+            Strategy::Best1Bin,
+            Strategy::CurrentToBest1Bin,
+            Strategy::JADE,
+        ] {
+            let de = DifferentialEvolution::new(bounds(), 20).with_strategy(strategy);
+            assert_eq!(de.strategy, strategy);
+        }
+    }
+
+    #[test]
+    fn test_with_mutation_factor() {
+        for factor in [0.1, 1.0, 2.0] {
+            let de = DifferentialEvolution::new(bounds(), 20)
+                .with_mutation_factor(factor)
+                .unwrap();
+            assert_eq!(de.mutation_factor.to_ne_bytes(), factor.to_ne_bytes());
+        }
+
+        for factor in [-1.0, 0.0, 2.1] {
+            let res = DifferentialEvolution::new(bounds(), 20).with_mutation_factor(factor);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`DifferentialEvolution`: mutation factor must be in (0, 2].\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_crossover_probability() {
+        for probability in [0.0, 0.5, 1.0] {
+            let de = DifferentialEvolution::new(bounds(), 20)
+                .with_crossover_probability(probability)
+                .unwrap();
+            assert_eq!(
+                de.crossover_probability.to_ne_bytes(),
+                probability.to_ne_bytes()
+            );
+        }
+
+        for probability in [-0.1, 1.1] {
+            let res =
+                DifferentialEvolution::new(bounds(), 20).with_crossover_probability(probability);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`DifferentialEvolution`: crossover probability must be in [0, 1].\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_jade_parameters() {
+        let de = DifferentialEvolution::new(bounds(), 20)
+            .with_jade_parameters(0.2, 0.1)
+            .unwrap();
+        assert_eq!(de.adaptation_rate.to_ne_bytes(), 0.2f64.to_ne_bytes());
+        assert_eq!(de.greediness.to_ne_bytes(), 0.1f64.to_ne_bytes());
+
+        for rate in [0.0, 1.1] {
+            let res = DifferentialEvolution::new(bounds(), 20).with_jade_parameters(rate, 0.1);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`DifferentialEvolution`: adaptation rate must be in (0, 1].\""
+            );
+        }
+
+        for greediness in [0.0, 1.1] {
+            let res =
+                DifferentialEvolution::new(bounds(), 20).with_jade_parameters(0.1, greediness);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`DifferentialEvolution`: greediness must be in (0, 1].\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_pbest_indices() {
+        let mut de = DifferentialEvolution::new(bounds(), 5)
+            .with_strategy(Strategy::JADE)
+            .with_jade_parameters(0.1, 0.5)
+            .unwrap();
+        de.costs = vec![4.0, 1.0, 3.0, 0.0, 2.0];
+        // ceil(0.5 * 5) = 3 best members, sorted by cost
+        assert_eq!(de.pbest_indices(), vec![3, 1, 4]);
+
+        let mut de = DifferentialEvolution::new(bounds(), 5);
+        de.costs = vec![4.0, 1.0, 3.0, 0.0, 2.0];
+        assert!(de.pbest_indices().is_empty());
+    }
+
+    #[test]
+    fn test_init_invalid() {
+        let mut de = DifferentialEvolution::new((vec![-1.0, -1.0], vec![1.0]), 20);
+        let res = de.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`DifferentialEvolution`: lower and upper bounds must have the same length.\""
+        );
+
+        let mut de = DifferentialEvolution::new((vec![], vec![]), 20);
+        let res = de.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`DifferentialEvolution`: bounds must not be empty.\""
+        );
+
+        let mut de = DifferentialEvolution::new((vec![-1.0, 1.0], vec![1.0, -1.0]), 20);
+        let res = de.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`DifferentialEvolution`: lower bounds must be <= upper bounds.\""
+        );
+
+        let mut de = DifferentialEvolution::new(bounds(), 3);
+        let res = de.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`DifferentialEvolution`: population size must be >= 4.\""
+        );
+
+        let mut de = DifferentialEvolution::new(bounds(), 5);
+        let res = de.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new().population(vec![vec![1.0, 2.0]; 4]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`DifferentialEvolution`: Provided population is of length 4, expected 5\""
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut de = DifferentialEvolution::new(bounds(), 20)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+        let (mut state, kv) = de
+            .init(&mut Problem::new(Rosenbrock {}), PopulationState::new())
+            .unwrap();
+        assert!(kv.is_none());
+        let population = state.take_population().unwrap();
+        assert_eq!(population.len(), 20);
+        assert_eq!(de.costs.len(), 20);
+        for member in population.iter() {
+            assert_eq!(member.len(), 2);
+            assert!(member.iter().all(|x| (-5.0..=5.0).contains(x)));
+        }
+        let best = de.costs.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert_eq!(state.get_cost().to_ne_bytes(), best.to_ne_bytes());
+
+        // provided population is used as is
+        let provided = vec![vec![1.0, 1.0]; 20];
+        let (mut state, _) = de
+            .init(
+                &mut Problem::new(Rosenbrock {}),
+                PopulationState::new().population(provided.clone()),
+            )
+            .unwrap();
+        assert_eq!(state.take_population().unwrap(), provided);
+        assert_eq!(state.get_cost().to_ne_bytes(), 0.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_rosenbrock() {
+        for strategy in [
+            Strategy::Rand1Bin,
+            Strategy::Best1Bin,
+            Strategy::CurrentToBest1Bin,
+            Strategy::JADE,
+        ] {
+            let solver = DifferentialEvolution::new(bounds(), 30)
+                .with_strategy(strategy)
+                .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+
+            let res = Executor::new(Rosenbrock {}, solver)
+                .configure(|state| state.max_iters(1000))
+                .run()
+                .unwrap();
+
+            let param = res.state.get_best_param().unwrap();
+            assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+            assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_rastrigin_jade() {
+        let lower = vec![-5.12; 5];
+        let upper = vec![5.12; 5];
+        let solver = DifferentialEvolution::new((lower.clone(), upper.clone()), 50)
+            .with_strategy(Strategy::JADE)
+            .with_mutation_factor(0.5)
+            .unwrap()
+            .with_crossover_probability(0.5)
+            .unwrap()
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+
+        let res = Executor::new(Rastrigin {}, solver)
+            .configure(|state| state.max_iters(1000))
+            .run()
+            .unwrap();
+
+        assert!(res.state.get_best_cost() < 1e-8);
+        for member in res.state.get_population().unwrap() {
+            for (x, (l, u)) in member.iter().zip(lower.iter().zip(upper.iter())) {
+                assert!(l <= x && x <= u);
+            }
+        }
+        assert!(res.solver.archive.len() <= 50);
+    }
+
+    #[test]
+    fn test_seed_reproducibility() {
+        let run = || {
+            let solver = DifferentialEvolution::new(bounds(), 20)
+                .with_strategy(Strategy::JADE)
+                .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(1729));
+            Executor::new(Rosenbrock {}, solver)
+                .configure(|state| state.max_iters(20))
+                .run()
+                .unwrap()
+                .state
+        };
+        let state1 = run();
+        let state2 = run();
+        assert_eq!(state1.get_best_param(), state2.get_best_param());
+        assert_eq!(state1.get_population(), state2.get_population());
+    }
+
+    #[test]
+    fn test_bulk_cost_counts() {
+        let solver = DifferentialEvolution::new(bounds(), 12)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(1729));
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.max_iters(5).counting(true))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_func_counts()["cost_count"], 6 * 12);
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_checkpointing() {
+        fn assert_checkpointable<T: Serialize + for<'de> Deserialize<'de>>() {}
+        assert_checkpointable::<DifferentialEvolution<f64, Xoshiro256PlusPlus>>();
+        assert_checkpointable::<PopulationState<Vec<f64>, f64>>();
+    }
+
+    #[test]
+    fn test_resume() {
+        // The solver holds the entire internal state (costs, archive, adapted parameters and
+        // random number generator), therefore a copy of solver and state (as restored from a
+        // checkpoint) continues exactly like the original.
+        let mut solver = DifferentialEvolution::new(bounds(), 20)
+            .with_strategy(Strategy::JADE)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+        let problem = &mut Problem::new(Rosenbrock {});
+        let (mut state, _) = solver.init(problem, PopulationState::new()).unwrap();
+        for _ in 0..3 {
+            state = solver.next_iter(problem, state).unwrap().0;
+        }
+
+        let mut restored_solver = solver.clone();
+        let restored_state = state.clone();
+
+        let (mut state1, _) = solver.next_iter(problem, state).unwrap();
+        let (mut state2, _) = restored_solver.next_iter(problem, restored_state).unwrap();
+        assert_eq!(state1.take_population(), state2.take_population());
+        assert_eq!(solver.costs, restored_solver.costs);
+        assert_eq!(solver.archive, restored_solver.archive);
+    }
+}
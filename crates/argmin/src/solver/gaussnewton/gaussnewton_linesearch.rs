@@ -9,7 +9,7 @@ use crate::core::{
     ArgminFloat, CostFunction, Error, Executor, Gradient, IterState, Jacobian, LineSearch,
     Operator, OptimizationResult, Problem, Solver, TerminationReason, TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminTranspose};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminQR, ArgminTranspose};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -86,12 +86,7 @@ where
     P: Clone + ArgminMul<F, P>,
     G: Clone,
     U: ArgminL2Norm<F>,
-    J: Clone
-        + ArgminTranspose<J>
-        + ArgminInv<J>
-        + ArgminDot<J, J>
-        + ArgminDot<G, P>
-        + ArgminDot<U, G>,
+    J: Clone + ArgminTranspose<J> + ArgminDot<U, G> + ArgminQR<U, P>,
     L: Clone + LineSearch<P, F> + Solver<LineSearchProblem<O, F>, IterState<P, G, (), (), R, F>>,
     F: ArgminFloat,
     R: Clone,
@@ -114,10 +109,11 @@ where
         ))?;
         let residuals = problem.apply(&param)?;
         let jacobian = problem.jacobian(&param)?;
-        let jacobian_t = jacobian.clone().t();
-        let grad = jacobian_t.dot(&residuals);
+        let grad = jacobian.clone().t().dot(&residuals);
 
-        let p: P = jacobian_t.dot(&jacobian).inv()?.dot(&grad);
+        // Least squares solution of `J * p = r`, equivalent to solving the normal equations
+        // `J^T * J * p = J^T * r` without forming `J^T * J`.
+        let p: P = jacobian.qr_solve(&residuals)?;
 
         self.linesearch.search_direction(p.mul(&(float!(-1.0))));
 
@@ -134,6 +130,7 @@ where
             self.linesearch.clone(),
         )
         .configure(|config| config.param(param).gradient(grad).cost(residuals.l2_norm()))
+        .inherit_func_eval_budget(problem)
         .ctrlc(false)
         .run()?;
 
@@ -465,4 +462,67 @@ mod tests {
             epsilon = f64::EPSILON
         );
     }
+
+    #[cfg(feature = "_sprs")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{Executor, State, TerminationReason};
+        use ndarray::Array1;
+        use sprs::{CsMat, TriMat};
+
+        // Linear least squares problem with residuals `A * x - b` and a sparse Jacobian `A`
+        struct SparseProblem {
+            a: CsMat<f64>,
+            b: Array1<f64>,
+        }
+
+        impl Operator for SparseProblem {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(&self.a * p - &self.b)
+            }
+        }
+
+        impl Jacobian for SparseProblem {
+            type Param = Array1<f64>;
+            type Jacobian = CsMat<f64>;
+
+            fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        // Least squares fit of a polynomial of degree 11 to perturbed data on [0, 1]. The monomial
+        // columns are nearly linearly dependent, hence the Jacobian is badly conditioned.
+        let m = 20;
+        let mut a = TriMat::new((m, 12));
+        let mut b = Array1::zeros(m);
+        for i in 0..m {
+            let t = i as f64 / (m - 1) as f64;
+            for j in 0..12 {
+                a.add_triplet(i, j, t.powi(j as i32));
+            }
+            b[i] = 1.0 - 2.0 * t + 3.0 * t.powi(2) + 0.01 * (-1.0f64).powi(i as i32);
+        }
+        let problem = SparseProblem { a: a.to_csr(), b };
+
+        let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(0.0001).unwrap());
+        let res = Executor::new(problem, GaussNewtonLS::<_, f64>::new(linesearch))
+            .configure(|state| state.param(Array1::zeros(12)).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state().get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // The best parameter vector satisfies the normal equations
+        let param = res.state().get_best_param().unwrap();
+        let r = res.problem.problem.as_ref().unwrap().apply(param).unwrap();
+        let j = res.problem.problem.as_ref().unwrap().a.clone();
+        let a_norm = j.data().iter().map(|v| v * v).sum::<f64>().sqrt();
+        let s = &j.transpose_view() * &r;
+        assert!(s.dot(&s).sqrt() <= 1e-6 * a_norm * r.dot(&r).sqrt());
+    }
 }
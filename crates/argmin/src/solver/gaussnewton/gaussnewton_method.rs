@@ -9,7 +9,7 @@ use crate::core::{
     ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, State, TerminationReason,
     TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose};
+use argmin_math::{ArgminL2Norm, ArgminMul, ArgminQR, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -114,12 +114,7 @@ where
     O: Operator<Param = P, Output = R> + Jacobian<Param = P, Jacobian = J>,
     P: Clone + ArgminSub<P, P> + ArgminMul<F, P>,
     R: ArgminL2Norm<F>,
-    J: Clone
-        + ArgminTranspose<J>
-        + ArgminInv<J>
-        + ArgminDot<J, J>
-        + ArgminDot<R, P>
-        + ArgminDot<P, P>,
+    J: ArgminQR<R, P>,
     F: ArgminFloat,
 {
     fn name(&self) -> &str {
@@ -161,12 +156,9 @@ where
         ))?;
         let jacobian = problem.jacobian(param)?;
 
-        let p = jacobian
-            .clone()
-            .t()
-            .dot(&jacobian)
-            .inv()?
-            .dot(&jacobian.t().dot(residuals));
+        // Solves the linear least squares problem `min_p ||J * p - r||` instead of the normal
+        // equations to avoid squaring the condition number of the Jacobian.
+        let p = jacobian.qr_solve(residuals)?;
 
         let new_param = param.sub(&p.mul(&self.gamma));
         let residuals = problem.apply(&new_param)?;
@@ -487,4 +479,66 @@ mod tests {
         // Assert that cost matches residual:
         assert_relative_eq!(state.get_residuals().unwrap().l2_norm(), state.get_cost());
     }
+
+    #[cfg(feature = "_sprs")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{Executor, State};
+        use ndarray::Array1;
+        use sprs::{CsMat, TriMat};
+
+        // Linear least squares problem with residuals `A * x - b` and a sparse Jacobian `A`
+        struct SparseProblem {
+            a: CsMat<f64>,
+            b: Array1<f64>,
+        }
+
+        impl Operator for SparseProblem {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(&self.a * p - &self.b)
+            }
+        }
+
+        impl Jacobian for SparseProblem {
+            type Param = Array1<f64>;
+            type Jacobian = CsMat<f64>;
+
+            fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        // Least squares fit of a polynomial of degree 11 to perturbed data on [0, 1]. The monomial
+        // columns are nearly linearly dependent, hence the Jacobian is badly conditioned.
+        let m = 20;
+        let mut a = TriMat::new((m, 12));
+        let mut b = Array1::zeros(m);
+        for i in 0..m {
+            let t = i as f64 / (m - 1) as f64;
+            for j in 0..12 {
+                a.add_triplet(i, j, t.powi(j as i32));
+            }
+            b[i] = 1.0 - 2.0 * t + 3.0 * t.powi(2) + 0.01 * (-1.0f64).powi(i as i32);
+        }
+        let problem = SparseProblem { a: a.to_csr(), b };
+
+        let res = Executor::new(problem, GaussNewton::<f64>::new())
+            .configure(|state| state.param(Array1::zeros(12)).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state().get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // The best parameter vector satisfies the normal equations
+        let param = res.state().get_best_param().unwrap();
+        let r = res.problem.problem.as_ref().unwrap().apply(param).unwrap();
+        let j = res.problem.problem.as_ref().unwrap().a.clone();
+        let a_norm = j.data().iter().map(|v| v * v).sum::<f64>().sqrt();
+        let s = &j.transpose_view() * &r;
+        assert!(s.dot(&s).sqrt() <= 1e-6 * a_norm * r.dot(&r).sqrt());
+    }
 }
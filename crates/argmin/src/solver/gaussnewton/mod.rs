@@ -10,6 +10,9 @@
 //! * [Gauss-Newton method](`GaussNewton`)
 //! * [Gauss-Newton method with line search](`GaussNewtonLS`)
 //!
+//! Robust loss functions which reduce the influence of outliers can be applied to the residuals
+//! via [`RobustLeastSquares`](`crate::solver::robustloss::RobustLeastSquares`).
+//!
 //! ## Reference
 //!
 //! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
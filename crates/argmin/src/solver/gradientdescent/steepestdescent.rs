@@ -6,12 +6,13 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, Executor, Gradient, IterState, LineSearch,
-    OptimizationResult, Problem, Solver, State, KV,
+    ArgminFloat, BoundsHandling, CostFunction, Error, Executor, Gradient, IgnoreBounds, IterState,
+    LineSearch, OptimizationResult, Problem, ProjectOntoBounds, Solver, State, KV,
 };
 use argmin_math::ArgminMul;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
 /// # Steepest descent
 ///
@@ -22,18 +23,28 @@ use serde::{Deserialize, Serialize};
 ///
 /// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
 ///
+/// ## Box constraints
+///
+/// With [`with_box_constraints`](`SteepestDescent::with_box_constraints`), the problem is
+/// additionally required to implement [`BoxConstraint`](`crate::core::BoxConstraint`). The
+/// parameter vector is then projected onto the feasible box after each line search (projected
+/// gradient descent). Note that the line search itself may evaluate the problem outside of the
+/// box.
+///
 /// ## Reference
 ///
 /// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct SteepestDescent<L> {
+pub struct SteepestDescent<L, B = IgnoreBounds> {
     /// line search
     linesearch: L,
+    /// handling of box constraints
+    box_constraints: PhantomData<B>,
 }
 
-impl<L> SteepestDescent<L> {
+impl<L> SteepestDescent<L, IgnoreBounds> {
     /// Construct a new instance of [`SteepestDescent`]
     ///
     /// Requires a line search.
@@ -46,17 +57,40 @@ impl<L> SteepestDescent<L> {
     /// let sd = SteepestDescent::new(linesearch);
     /// ```
     pub fn new(linesearch: L) -> Self {
-        SteepestDescent { linesearch }
+        SteepestDescent {
+            linesearch,
+            box_constraints: PhantomData,
+        }
+    }
+
+    /// Respect the box constraints of the problem
+    ///
+    /// Requires the problem to implement [`BoxConstraint`](`crate::core::BoxConstraint`).
+    /// Iterates are projected onto the feasible box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::SteepestDescent;
+    /// # let linesearch = ();
+    /// let sd = SteepestDescent::new(linesearch).with_box_constraints();
+    /// ```
+    pub fn with_box_constraints(self) -> SteepestDescent<L, ProjectOntoBounds> {
+        SteepestDescent {
+            linesearch: self.linesearch,
+            box_constraints: PhantomData,
+        }
     }
 }
 
-impl<O, L, P, G, F> Solver<O, IterState<P, G, (), (), (), F>> for SteepestDescent<L>
+impl<O, L, P, G, F, B> Solver<O, IterState<P, G, (), (), (), F>> for SteepestDescent<L, B>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone,
     G: Clone + ArgminMul<F, G>,
     L: Clone + LineSearch<G, F> + Solver<O, IterState<P, G, (), (), (), F>>,
     F: ArgminFloat,
+    B: BoundsHandling<O, P>,
 {
     fn name(&self) -> &str {
         "Steepest Descent"
@@ -67,7 +101,7 @@ where
         problem: &mut Problem<O>,
         state: IterState<P, G, (), (), (), F>,
     ) -> Result<(IterState<P, G, (), (), (), F>, Option<KV>), Error> {
-        let param_new = state
+        let mut param_new = state
             .get_param()
             .ok_or_else(argmin_error_closure!(
                 NotInitialized,
@@ -77,8 +111,10 @@ where
                 )
             ))?
             .clone();
-        let new_cost = problem.cost(&param_new)?;
-        let new_grad = problem.gradient(&param_new)?;
+        if let Some(projected) = B::project(problem, &param_new)? {
+            param_new = projected;
+        }
+        let (new_cost, new_grad) = problem.cost_and_gradient(&param_new)?;
 
         self.linesearch
             .search_direction(new_grad.mul(&(float!(-1.0))));
@@ -96,32 +132,33 @@ where
             self.linesearch.clone(),
         )
         .configure(|config| config.param(param_new).gradient(new_grad).cost(new_cost))
+        .inherit_func_eval_budget(problem)
         .ctrlc(false)
         .run()?;
 
         // Get back problem and function evaluation counts
         problem.consume_problem(line_problem);
 
-        Ok((
-            state
-                .param(
-                    linesearch_state
-                        .take_param()
-                        .ok_or_else(argmin_error_closure!(
-                            PotentialBug,
-                            "`GradientDescent`: No `param` returned by line search"
-                        ))?,
-                )
-                .cost(linesearch_state.get_cost()),
-            None,
-        ))
+        let mut param = linesearch_state
+            .take_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`GradientDescent`: No `param` returned by line search"
+            ))?;
+        let mut cost = linesearch_state.get_cost();
+        if let Some(projected) = B::project(problem, &param)? {
+            param = projected;
+            cost = problem.cost(&param)?;
+        }
+
+        Ok((state.param(param).cost(cost), None))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::test_utils::TestProblem;
+    use crate::core::test_utils::{TestBoxProblem, TestProblem};
     use crate::core::ArgminError;
     use crate::solver::linesearch::{
         condition::ArmijoCondition, BacktrackingLineSearch, MoreThuenteLineSearch,
@@ -137,8 +174,12 @@ mod tests {
     fn test_new() {
         let linesearch: BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64> =
             BacktrackingLineSearch::new(ArmijoCondition::new(0.2).unwrap());
-        let SteepestDescent { linesearch: ls } = SteepestDescent::new(linesearch.clone());
+        let SteepestDescent {
+            linesearch: ls,
+            box_constraints,
+        } = SteepestDescent::new(linesearch.clone());
         assert_eq!(ls, linesearch);
+        assert_eq!(box_constraints, PhantomData::<IgnoreBounds>);
     }
 
     #[test]
@@ -217,4 +258,59 @@ mod tests {
         );
         assert_relative_eq!(state.cost, 1.048820000000001, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_next_iter_cost_and_gradient() {
+        struct AdjointProblem {}
+
+        impl CostFunction for AdjointProblem {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p[0].powi(2) + p[1].powi(2))
+            }
+
+            fn cost_and_gradient(&self, p: &Self::Param) -> Result<(f64, Vec<f64>), Error> {
+                Ok((p[0].powi(2) + p[1].powi(2), vec![2.0 * p[0], 2.0 * p[1]]))
+            }
+        }
+
+        impl Gradient for AdjointProblem {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, _p: &Self::Param) -> Result<Self::Param, Error> {
+                Err(argmin_error!(PotentialBug, "gradient must not be called"))
+            }
+        }
+
+        let linesearch: BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64> =
+            BacktrackingLineSearch::new(ArmijoCondition::new(0.2).unwrap());
+        let mut sd = SteepestDescent::new(linesearch);
+        let mut problem = Problem::new(AdjointProblem {});
+        let (state, _) = sd
+            .next_iter(&mut problem, IterState::new().param(vec![1.0, 2.0]))
+            .unwrap();
+
+        assert_relative_eq!(state.cost, 1.048820000000001, epsilon = f64::EPSILON);
+        assert_eq!(problem.counts["gradient_count"], 1);
+        assert!(problem.counts["cost_count"] > 1);
+    }
+
+    #[test]
+    fn test_box_constraints() {
+        let linesearch: BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64> =
+            BacktrackingLineSearch::new(ArmijoCondition::new(0.2).unwrap());
+        let sd = SteepestDescent::new(linesearch).with_box_constraints();
+        let res = Executor::new(TestBoxProblem::new(), sd)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(20))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(param[1], -1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(res.state.get_best_cost(), 2.0, epsilon = f64::EPSILON);
+    }
 }
@@ -135,4 +135,47 @@ mod tests {
         assert_relative_eq!(new_param[0], 1.0, epsilon = f64::EPSILON);
         assert_relative_eq!(new_param[1], 2.0, epsilon = f64::EPSILON);
     }
+
+    #[cfg(feature = "_sprs")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{Executor, Gradient};
+        use argmin_math::{ArgminDot, ArgminSub, ArgminTranspose};
+        use ndarray::{array, Array1};
+        use sprs::{CsMat, TriMat};
+
+        // Least squares problem `min_x 1/2 ||A * x - b||^2` with a sparse matrix `A`
+        struct SparseProblem {
+            a: CsMat<f64>,
+            b: Array1<f64>,
+        }
+
+        impl Gradient for SparseProblem {
+            type Param = Array1<f64>;
+            type Gradient = Array1<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                let residuals = self.a.dot(p).sub(&self.b);
+                Ok(self.a.clone().t().dot(&residuals))
+            }
+        }
+
+        let mut a = TriMat::new((3, 2));
+        a.add_triplet(0, 0, 1.0);
+        a.add_triplet(1, 1, 1.0);
+        a.add_triplet(2, 0, 1.0);
+        a.add_triplet(2, 1, 1.0);
+        let problem = SparseProblem {
+            a: a.to_csr(),
+            b: array![1.0, 2.0, 3.0],
+        };
+
+        let res = Executor::new(problem, Landweber::new(0.3))
+            .configure(|state| state.param(array![0.0, 0.0]).max_iters(200))
+            .run()
+            .unwrap();
+        let param = res.state().get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(param[1], 2.0, epsilon = 1e-8);
+    }
 }
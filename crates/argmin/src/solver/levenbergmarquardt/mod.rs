@@ -0,0 +1,746 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Levenberg-Marquardt method
+//!
+//! Damped Gauss-Newton method for non-linear least squares problems.
+//! See [`LevenbergMarquardt`] for details.
+//!
+//! ## References
+//!
+//! Kaj Madsen, Hans Bruun Nielsen and Ole Tingleff (2004). Methods for Non-Linear Least Squares
+//! Problems. Informatics and Mathematical Modelling, Technical University of Denmark.
+//!
+//! Jorge J. Moré (1978). The Levenberg-Marquardt algorithm: Implementation and theory.
+//! Numerical Analysis, Lecture Notes in Mathematics 630, 105-116.
+//! DOI: <https://doi.org/10.1007/BFb0067700>
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+use argmin_math::{
+    ArgminAdd, ArgminCholesky, ArgminDot, ArgminEye, ArgminL2Norm, ArgminMinMax, ArgminMul,
+    ArgminSub, ArgminTranspose,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Levenberg-Marquardt method
+///
+/// The Levenberg-Marquardt method solves non-linear least squares problems. In contrast to the
+/// Gauss-Newton method, the linear system solved in each iteration is regularized by a damping
+/// term, which makes the method robust against ill-conditioned or rank-deficient Jacobians:
+///
+/// `(J^T J + mu * D) p = J^T r`
+///
+/// Here, `J` is the Jacobian of the residuals `r` and `D` is either the identity or a diagonal
+/// scaling matrix. The new parameter vector `x - p` is only accepted if it reduces the sum of
+/// squared residuals. The damping parameter `mu` is adapted according to the gain ratio between
+/// actual and predicted reduction as proposed by Nielsen.
+///
+/// With diagonal scaling enabled (see
+/// [`with_diagonal_scaling`](`LevenbergMarquardt::with_diagonal_scaling`)), `D` contains the
+/// largest diagonal of `J^T J` encountered so far, as done in MINPACK. This makes the method
+/// invariant to the scaling of the parameters.
+///
+/// The algorithm stops if either
+///
+/// * the norm of the residuals is below `tol_residual`,
+/// * the norm of the gradient `J^T r` is below `tol_grad` or
+/// * the norm of the step `p` is below `tol_step * (||x|| + tol_step)`.
+///
+/// All tolerances default to `sqrt(EPSILON)`.
+///
+/// Like the Gauss-Newton solvers, the cost is the L2 norm of the residuals. Residuals and Jacobian
+/// of the current parameter vector are stored in the [`IterState`].
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] and [`Jacobian`].
+///
+/// ## References
+///
+/// Kaj Madsen, Hans Bruun Nielsen and Ole Tingleff (2004). Methods for Non-Linear Least Squares
+/// Problems. Informatics and Mathematical Modelling, Technical University of Denmark.
+///
+/// Jorge J. Moré (1978). The Levenberg-Marquardt algorithm: Implementation and theory.
+/// Numerical Analysis, Lecture Notes in Mathematics 630, 105-116.
+/// DOI: <https://doi.org/10.1007/BFb0067700>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LevenbergMarquardt<J, F> {
+    /// Initial damping parameter
+    mu_init: F,
+    /// Current damping parameter
+    mu: F,
+    /// Factor by which the damping parameter is increased after a rejected step
+    nu: F,
+    /// Whether diagonal scaling is used
+    diagonal_scaling: bool,
+    /// Diagonal scaling matrix
+    diag: Option<J>,
+    /// Tolerance for the stopping criterion based on the norm of the residuals
+    tol_residual: F,
+    /// Tolerance for the stopping criterion based on the norm of the gradient
+    tol_grad: F,
+    /// Tolerance for the stopping criterion based on the norm of the step
+    tol_step: F,
+}
+
+impl<J, F: ArgminFloat> LevenbergMarquardt<J, F> {
+    /// Construct a new instance of [`LevenbergMarquardt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::levenbergmarquardt::LevenbergMarquardt;
+    /// let lm: LevenbergMarquardt<Vec<Vec<f64>>, f64> = LevenbergMarquardt::new();
+    /// ```
+    pub fn new() -> Self {
+        LevenbergMarquardt {
+            mu_init: float!(1e-3),
+            mu: float!(1e-3),
+            nu: float!(2.0),
+            diagonal_scaling: false,
+            diag: None,
+            tol_residual: F::epsilon().sqrt(),
+            tol_grad: F::epsilon().sqrt(),
+            tol_step: F::epsilon().sqrt(),
+        }
+    }
+
+    /// Set the initial damping parameter.
+    ///
+    /// Must be larger than zero and defaults to `1e-3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::levenbergmarquardt::LevenbergMarquardt;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let lm: LevenbergMarquardt<Vec<Vec<f64>>, f64> =
+    ///     LevenbergMarquardt::new().with_initial_damping(1.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_initial_damping(mut self, mu: F) -> Result<Self, Error> {
+        if mu <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LevenbergMarquardt`: initial damping must be > 0."
+            ));
+        }
+        self.mu_init = mu;
+        self.mu = mu;
+        Ok(self)
+    }
+
+    /// Enable or disable diagonal scaling.
+    ///
+    /// If enabled, the damping term is scaled with the largest diagonal of `J^T J` encountered so
+    /// far instead of the identity. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::levenbergmarquardt::LevenbergMarquardt;
+    /// let lm: LevenbergMarquardt<Vec<Vec<f64>>, f64> =
+    ///     LevenbergMarquardt::new().with_diagonal_scaling(true);
+    /// ```
+    #[must_use]
+    pub fn with_diagonal_scaling(mut self, diagonal_scaling: bool) -> Self {
+        self.diagonal_scaling = diagonal_scaling;
+        self
+    }
+
+    /// Set tolerance for the stopping criterion based on the norm of the residuals.
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::levenbergmarquardt::LevenbergMarquardt;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let lm: LevenbergMarquardt<Vec<Vec<f64>>, f64> =
+    ///     LevenbergMarquardt::new().with_tolerance_residual(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_residual(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LevenbergMarquardt`: residual tolerance must be >= 0."
+            ));
+        }
+        self.tol_residual = tol;
+        Ok(self)
+    }
+
+    /// Set tolerance for the stopping criterion based on the norm of the gradient `J^T r`.
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::levenbergmarquardt::LevenbergMarquardt;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let lm: LevenbergMarquardt<Vec<Vec<f64>>, f64> =
+    ///     LevenbergMarquardt::new().with_tolerance_grad(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_grad(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LevenbergMarquardt`: gradient tolerance must be >= 0."
+            ));
+        }
+        self.tol_grad = tol;
+        Ok(self)
+    }
+
+    /// Set tolerance for the stopping criterion based on the norm of the step.
+    ///
+    /// The algorithm stops if `||p|| <= tol_step * (||x|| + tol_step)`. Must be non-negative and
+    /// defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::levenbergmarquardt::LevenbergMarquardt;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let lm: LevenbergMarquardt<Vec<Vec<f64>>, f64> =
+    ///     LevenbergMarquardt::new().with_tolerance_step(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_step(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LevenbergMarquardt`: step tolerance must be >= 0."
+            ));
+        }
+        self.tol_step = tol;
+        Ok(self)
+    }
+}
+
+impl<J, F: ArgminFloat> Default for LevenbergMarquardt<J, F> {
+    fn default() -> LevenbergMarquardt<J, F> {
+        LevenbergMarquardt::new()
+    }
+}
+
+impl<O, P, J, R, F> Solver<O, IterState<P, (), J, (), R, F>> for LevenbergMarquardt<J, F>
+where
+    O: Operator<Param = P, Output = R> + Jacobian<Param = P, Jacobian = J>,
+    P: Clone
+        + ArgminSub<P, P>
+        + ArgminAdd<P, P>
+        + ArgminMul<F, P>
+        + ArgminDot<P, F>
+        + ArgminL2Norm<F>,
+    R: ArgminL2Norm<F>,
+    J: Clone
+        + ArgminTranspose<J>
+        + ArgminCholesky<P, P>
+        + ArgminDot<J, J>
+        + ArgminDot<R, P>
+        + ArgminDot<P, P>
+        + ArgminEye
+        + ArgminAdd<J, J>
+        + ArgminMul<F, J>
+        + ArgminMul<J, J>
+        + ArgminMinMax,
+    F: ArgminFloat,
+{
+    fn name(&self) -> &str {
+        "Levenberg-Marquardt method"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), J, (), R, F>,
+    ) -> Result<(IterState<P, (), J, (), R, F>, Option<KV>), Error> {
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`LevenbergMarquardt` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let residuals = problem.apply(&init_param)?;
+        let jacobian = problem.jacobian(&init_param)?;
+        let cost = residuals.l2_norm();
+
+        self.mu = self.mu_init;
+        self.nu = float!(2.0);
+        self.diag = None;
+
+        Ok((
+            state
+                .param(init_param)
+                .residuals(residuals)
+                .jacobian(jacobian)
+                .cost(cost),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), J, (), R, F>,
+    ) -> Result<(IterState<P, (), J, (), R, F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LevenbergMarquardt`: `param` not set"
+        ))?;
+        let residuals = state.get_residuals().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LevenbergMarquardt`: `residuals` not set"
+        ))?;
+        let jacobian = match state.get_jacobian() {
+            Some(jacobian) => jacobian.clone(),
+            None => problem.jacobian(param)?,
+        };
+
+        let jacobian_t = jacobian.clone().t();
+        let jtj = jacobian_t.dot(&jacobian);
+        let grad: P = jacobian_t.dot(residuals);
+
+        if grad.l2_norm() <= self.tol_grad {
+            return Ok((
+                state.terminate_with(TerminationReason::SolverConverged),
+                Some(kv!("mu" => self.mu;)),
+            ));
+        }
+
+        let eye = jtj.eye_like();
+        let diag = if self.diagonal_scaling {
+            let jtj_diag = jtj.mul(&eye);
+            let diag = match self.diag.take() {
+                Some(diag) => J::max(&diag, &jtj_diag),
+                // Columns of `J` which are zero would lead to zeros on the diagonal.
+                None => J::max(&jtj_diag, &eye.mul(&F::epsilon())),
+            };
+            self.diag = Some(diag.clone());
+            diag
+        } else {
+            eye
+        };
+
+        // `JᵀJ + μD` is symmetric positive definite for `μ > 0`
+        let p: P = jtj.add(&diag.mul(&self.mu)).cholesky_solve(&grad)?;
+
+        let param_norm = param.l2_norm();
+        if p.l2_norm() <= self.tol_step * (param_norm + self.tol_step) {
+            return Ok((
+                state.terminate_with(TerminationReason::SolverConverged),
+                Some(kv!("mu" => self.mu;)),
+            ));
+        }
+
+        let new_param = param.sub(&p);
+        let new_residuals = problem.apply(&new_param)?;
+        let cost = state.get_cost();
+        let new_cost = new_residuals.l2_norm();
+
+        // Gain ratio between actual and predicted reduction of `0.5 * ||r||^2`
+        let actual_reduction = float!(0.5) * (cost * cost - new_cost * new_cost);
+        let predicted_reduction = float!(0.5) * p.dot(&diag.dot(&p).mul(&self.mu).add(&grad));
+        let rho = actual_reduction / predicted_reduction;
+
+        if rho > float!(0.0) {
+            let factor = float!(1.0) - (float!(2.0) * rho - float!(1.0)).powi(3);
+            self.mu = self.mu * factor.max(float!(1.0 / 3.0));
+            self.nu = float!(2.0);
+            let new_jacobian = problem.jacobian(&new_param)?;
+            Ok((
+                state
+                    .param(new_param)
+                    .residuals(new_residuals)
+                    .jacobian(new_jacobian)
+                    .cost(new_cost),
+                Some(kv!("mu" => self.mu; "gain_ratio" => rho; "accepted" => true;)),
+            ))
+        } else {
+            self.mu = self.mu * self.nu;
+            self.nu = float!(2.0) * self.nu;
+            if state.get_jacobian().is_none() {
+                state = state.jacobian(jacobian);
+            }
+            Ok((
+                state,
+                Some(kv!("mu" => self.mu; "gain_ratio" => rho; "accepted" => false;)),
+            ))
+        }
+    }
+
+    fn terminate(&mut self, state: &IterState<P, (), J, (), R, F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol_residual {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    #[cfg(feature = "_ndarrayl")]
+    use crate::core::Executor;
+    #[cfg(feature = "_ndarrayl")]
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(levenberg_marquardt, LevenbergMarquardt<Vec<Vec<f64>>, f64>);
+
+    #[test]
+    fn test_new() {
+        let LevenbergMarquardt {
+            mu_init,
+            mu,
+            nu,
+            diagonal_scaling,
+            diag,
+            tol_residual,
+            tol_grad,
+            tol_step,
+        } = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new();
+
+        assert_eq!(mu_init.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(mu.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(nu.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert!(!diagonal_scaling);
+        assert!(diag.is_none());
+        assert_eq!(
+            tol_residual.to_ne_bytes(),
+            f64::EPSILON.sqrt().to_ne_bytes()
+        );
+        assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(tol_step.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_initial_damping() {
+        for mu in [1e-6, 1.0, 100.0] {
+            let lm = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new()
+                .with_initial_damping(mu)
+                .unwrap();
+            assert_eq!(lm.mu_init.to_ne_bytes(), mu.to_ne_bytes());
+            assert_eq!(lm.mu.to_ne_bytes(), mu.to_ne_bytes());
+        }
+
+        for mu in [0.0, -1.0] {
+            let res = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new().with_initial_damping(mu);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`LevenbergMarquardt`: initial damping must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_diagonal_scaling() {
+        let lm = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new().with_diagonal_scaling(true);
+        assert!(lm.diagonal_scaling);
+        let lm = lm.with_diagonal_scaling(false);
+        assert!(!lm.diagonal_scaling);
+    }
+
+    #[test]
+    fn test_tolerances() {
+        let lm = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new()
+            .with_tolerance_residual(1e-4)
+            .unwrap()
+            .with_tolerance_grad(1e-5)
+            .unwrap()
+            .with_tolerance_step(0.0)
+            .unwrap();
+        assert_eq!(lm.tol_residual.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(lm.tol_grad.to_ne_bytes(), 1e-5f64.to_ne_bytes());
+        assert_eq!(lm.tol_step.to_ne_bytes(), 0.0f64.to_ne_bytes());
+
+        let res = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new().with_tolerance_residual(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`LevenbergMarquardt`: residual tolerance must be >= 0.\""
+        );
+        let res = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new().with_tolerance_grad(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`LevenbergMarquardt`: gradient tolerance must be >= 0.\""
+        );
+        let res = LevenbergMarquardt::<Vec<Vec<f64>>, f64>::new().with_tolerance_step(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`LevenbergMarquardt`: step tolerance must be >= 0.\""
+        );
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    mod ndarray_tests {
+        use super::*;
+        use ndarray::{Array1, Array2};
+
+        /// Model `rate = (V_{max} * [S]) / (K_M + [S])`, see the Gauss-Newton examples.
+        struct MichaelisMenten {
+            data: Vec<(f64, f64)>,
+        }
+
+        impl MichaelisMenten {
+            fn new() -> Self {
+                MichaelisMenten {
+                    data: vec![
+                        (0.038, 0.050),
+                        (0.194, 0.127),
+                        (0.425, 0.094),
+                        (0.626, 0.2122),
+                        (1.253, 0.2729),
+                        (2.5, 0.2665),
+                        (3.74, 0.3317),
+                    ],
+                }
+            }
+        }
+
+        impl Operator for MichaelisMenten {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self
+                    .data
+                    .iter()
+                    .map(|(s, rate)| rate - (p[0] * s) / (p[1] + s))
+                    .collect())
+            }
+        }
+
+        impl Jacobian for MichaelisMenten {
+            type Param = Array1<f64>;
+            type Jacobian = Array2<f64>;
+
+            fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(Array2::from_shape_fn((self.data.len(), 2), |(si, i)| {
+                    let s = self.data[si].0;
+                    if i == 0 {
+                        -s / (p[1] + s)
+                    } else {
+                        p[0] * s / (p[1] + s).powi(2)
+                    }
+                }))
+            }
+        }
+
+        /// Rosenbrock function as least squares problem with residuals
+        /// `r = [10 * (x_1 - x_0^2), 1 - x_0]`.
+        struct RosenbrockResiduals {}
+
+        impl Operator for RosenbrockResiduals {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(Array1::from_vec(vec![
+                    10.0 * (p[1] - p[0].powi(2)),
+                    1.0 - p[0],
+                ]))
+            }
+        }
+
+        impl Jacobian for RosenbrockResiduals {
+            type Param = Array1<f64>;
+            type Jacobian = Array2<f64>;
+
+            fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(Array2::from_shape_vec(
+                    (2, 2),
+                    vec![-20.0 * p[0], 10.0, -1.0, 0.0],
+                )?)
+            }
+        }
+
+        #[test]
+        fn test_init_param_not_initialized() {
+            let mut lm = LevenbergMarquardt::<Array2<f64>, f64>::new();
+            let res = lm.init(&mut Problem::new(RosenbrockResiduals {}), IterState::new());
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Not initialized: \"`LevenbergMarquardt` requires an initial parameter ",
+                    "vector. Please provide an initial guess via `Executor`s `configure` method.\""
+                )
+            );
+        }
+
+        #[test]
+        fn test_init() {
+            let mut lm = LevenbergMarquardt::<Array2<f64>, f64>::new();
+            let (state, kv) = lm
+                .init(
+                    &mut Problem::new(RosenbrockResiduals {}),
+                    IterState::new().param(Array1::from_vec(vec![0.0, 1.0])),
+                )
+                .unwrap();
+            assert!(kv.is_none());
+            assert_eq!(
+                state.get_residuals().unwrap(),
+                &Array1::from_vec(vec![10.0, 1.0])
+            );
+            assert_eq!(
+                state.get_jacobian().unwrap(),
+                &Array2::from_shape_vec((2, 2), vec![0.0, 10.0, -1.0, 0.0]).unwrap()
+            );
+            assert_relative_eq!(state.get_cost(), 101.0f64.sqrt());
+        }
+
+        #[test]
+        fn test_next_iter_param_not_initialized() {
+            let mut lm = LevenbergMarquardt::<Array2<f64>, f64>::new();
+            let res = lm.next_iter(&mut Problem::new(RosenbrockResiduals {}), IterState::new());
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Potential bug: \"`LevenbergMarquardt`: ",
+                    "`param` not set\". This is potentially a bug. ",
+                    "Please file a report on https://github.com/argmin-rs/argmin/issues"
+                )
+            );
+        }
+
+        #[test]
+        fn test_next_iter_residuals_not_initialized() {
+            let mut lm = LevenbergMarquardt::<Array2<f64>, f64>::new();
+            let res = lm.next_iter(
+                &mut Problem::new(RosenbrockResiduals {}),
+                IterState::new().param(Array1::from_vec(vec![0.0, 1.0])),
+            );
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Potential bug: \"`LevenbergMarquardt`: ",
+                    "`residuals` not set\". This is potentially a bug. ",
+                    "Please file a report on https://github.com/argmin-rs/argmin/issues"
+                )
+            );
+        }
+
+        #[test]
+        fn test_rosenbrock() {
+            for diagonal_scaling in [false, true] {
+                let solver = LevenbergMarquardt::new().with_diagonal_scaling(diagonal_scaling);
+                let res = Executor::new(RosenbrockResiduals {}, solver)
+                    .configure(|state| {
+                        state
+                            .param(Array1::from_vec(vec![-1.2, 1.0]))
+                            .max_iters(100)
+                    })
+                    .run()
+                    .unwrap();
+
+                assert_eq!(
+                    res.state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                let param = res.state.get_best_param().unwrap();
+                assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+                assert_relative_eq!(param[1], 1.0, epsilon = 1e-6);
+            }
+        }
+
+        #[test]
+        fn test_michaelis_menten() {
+            for diagonal_scaling in [false, true] {
+                let solver = LevenbergMarquardt::new().with_diagonal_scaling(diagonal_scaling);
+                let res = Executor::new(MichaelisMenten::new(), solver)
+                    .configure(|state| state.param(Array1::from_vec(vec![0.9, 0.2])).max_iters(100))
+                    .run()
+                    .unwrap();
+
+                let state = res.state;
+                assert_eq!(
+                    state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                let param = state.get_best_param().unwrap();
+                assert_relative_eq!(param[0], 0.3618, epsilon = 1e-4);
+                assert_relative_eq!(param[1], 0.5563, epsilon = 1e-4);
+                assert_relative_eq!(state.get_residuals().unwrap().l2_norm(), state.get_cost());
+            }
+        }
+
+        #[test]
+        fn test_rank_deficient_jacobian() {
+            // The residuals do not depend on the third parameter, therefore `J^T J` is singular
+            // and the Gauss-Newton method cannot be applied.
+            struct RankDeficient {}
+
+            impl Operator for RankDeficient {
+                type Param = Array1<f64>;
+                type Output = Array1<f64>;
+
+                fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                    RosenbrockResiduals {}.apply(&p.slice(ndarray::s![0..2]).to_owned())
+                }
+            }
+
+            impl Jacobian for RankDeficient {
+                type Param = Array1<f64>;
+                type Jacobian = Array2<f64>;
+
+                fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                    let jacobian =
+                        RosenbrockResiduals {}.jacobian(&p.slice(ndarray::s![0..2]).to_owned())?;
+                    let mut out = Array2::zeros((2, 3));
+                    out.slice_mut(ndarray::s![.., 0..2]).assign(&jacobian);
+                    Ok(out)
+                }
+            }
+
+            let solver = LevenbergMarquardt::new();
+            let res = Executor::new(RankDeficient {}, solver)
+                .configure(|state| {
+                    state
+                        .param(Array1::from_vec(vec![-1.2, 1.0, 3.0]))
+                        .max_iters(100)
+                })
+                .run()
+                .unwrap();
+
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let param = res.state.get_best_param().unwrap();
+            assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(param[1], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(param[2], 3.0, epsilon = 1e-6);
+        }
+    }
+}
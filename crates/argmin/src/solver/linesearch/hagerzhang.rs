@@ -345,8 +345,7 @@ where
             let mut bh_x = c_x;
             loop {
                 let d_x = (float!(1.0) - self.theta) * ah_x + self.theta * bh_x;
-                let d_f = self.calc(problem, d_x)?;
-                let d_g = self.calc_grad(problem, d_x)?;
+                let (d_f, d_g) = self.calc_cost_and_grad(problem, d_x)?;
                 if d_g >= float!(0.0) {
                     return Ok(((ah_x, ah_f, ah_g), (d_x, d_f, d_g)));
                 }
@@ -385,8 +384,7 @@ where
     {
         // S1
         let c_x = self.secant(a_x, a_g, b_x, b_g);
-        let c_f = self.calc(problem, c_x)?;
-        let c_g = self.calc_grad(problem, c_x)?;
+        let (c_f, c_g) = self.calc_cost_and_grad(problem, c_x)?;
         let mut c_bar_x: F = float!(0.0);
 
         let ((aa_x, aa_f, aa_g), (bb_x, bb_f, bb_g)) =
@@ -404,8 +402,7 @@ where
 
         // S4
         if (c_x - aa_x).abs() < F::epsilon() || (c_x - bb_x).abs() < F::epsilon() {
-            let c_bar_f = self.calc(problem, c_bar_x)?;
-            let c_bar_g = self.calc_grad(problem, c_bar_x)?;
+            let (c_bar_f, c_bar_g) = self.calc_cost_and_grad(problem, c_bar_x)?;
 
             let (a_bar, b_bar) = self.update(
                 problem,
@@ -419,35 +416,20 @@ where
         }
     }
 
-    fn calc<O>(&mut self, problem: &mut Problem<O>, alpha: F) -> Result<F, Error>
+    fn calc_cost_and_grad<O>(&mut self, problem: &mut Problem<O>, alpha: F) -> Result<(F, F), Error>
     where
-        O: CostFunction<Param = P, Output = F>,
-    {
-        let tmp = self
-            .init_param
-            .as_ref()
-            .ok_or_else(argmin_error_closure!(
-                PotentialBug,
-                "`HagerZhangLineSearch`: `init_param` is `None` in `calc`."
-            ))?
-            .scaled_add(&alpha, self.search_direction.as_ref().unwrap());
-        problem.cost(&tmp)
-    }
-
-    fn calc_grad<O>(&mut self, problem: &mut Problem<O>, alpha: F) -> Result<F, Error>
-    where
-        O: Gradient<Param = P, Gradient = G>,
+        O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     {
         let tmp = self
             .init_param
             .as_ref()
             .ok_or_else(argmin_error_closure!(
                 PotentialBug,
-                "`HagerZhangLineSearch`: `init_param` is `None` in `calc_grad`."
+                "`HagerZhangLineSearch`: `init_param` is `None` in `calc_cost_and_grad`."
             ))?
             .scaled_add(&alpha, self.search_direction.as_ref().unwrap());
-        let grad = problem.gradient(&tmp)?;
-        Ok(self.search_direction.as_ref().unwrap().dot(&grad))
+        let (cost, grad) = problem.cost_and_gradient(&tmp)?;
+        Ok((cost, self.search_direction.as_ref().unwrap().dot(&grad)))
     }
 
     fn set_best(&mut self) {
@@ -528,29 +510,23 @@ where
         ))?);
 
         let cost = state.get_cost();
-        self.finit = if cost.is_infinite() {
-            problem.cost(self.init_param.as_ref().unwrap())?
-        } else {
-            cost
+        let init_param = self.init_param.as_ref().unwrap();
+        let (finit, init_grad) = match (cost.is_infinite(), state.take_gradient()) {
+            (true, None) => problem.cost_and_gradient(init_param)?,
+            (true, Some(grad)) => (problem.cost(init_param)?, grad),
+            (false, None) => (cost, problem.gradient(init_param)?),
+            (false, Some(grad)) => (cost, grad),
         };
-
-        self.init_grad = Some(
-            state
-                .take_gradient()
-                .map(Result::Ok)
-                .unwrap_or_else(|| problem.gradient(self.init_param.as_ref().unwrap()))?,
-        );
+        self.finit = finit;
+        self.init_grad = Some(init_grad);
 
         self.a_x = self.a_x_init;
         self.b_x = self.b_x_init;
         self.c_x = self.c_x_init;
 
-        self.a_f = self.calc(problem, self.a_x)?;
-        self.a_g = self.calc_grad(problem, self.a_x)?;
-        self.b_f = self.calc(problem, self.b_x)?;
-        self.b_g = self.calc_grad(problem, self.b_x)?;
-        self.c_f = self.calc(problem, self.c_x)?;
-        self.c_g = self.calc_grad(problem, self.c_x)?;
+        (self.a_f, self.a_g) = self.calc_cost_and_grad(problem, self.a_x)?;
+        (self.b_f, self.b_g) = self.calc_cost_and_grad(problem, self.b_x)?;
+        (self.c_f, self.c_g) = self.calc_cost_and_grad(problem, self.c_x)?;
 
         self.epsilon_k = self.epsilon * self.finit.abs();
 
@@ -590,8 +566,7 @@ where
                 .as_ref()
                 .unwrap()
                 .scaled_add(&c_x, self.search_direction.as_ref().unwrap());
-            let c_f = problem.cost(&tmp)?;
-            let grad = problem.gradient(&tmp)?;
+            let (c_f, grad) = problem.cost_and_gradient(&tmp)?;
             let c_g = self.search_direction.as_ref().unwrap().dot(&grad);
             let ((an_x, an_f, an_g), (bn_x, bn_f, bn_g)) = self.update(
                 problem,
@@ -319,27 +319,29 @@ where
             )
         );
 
-        self.init_param = Some(state.take_param().ok_or_else(argmin_error_closure!(
-            NotInitialized,
-            concat!(
-                "`MoreThuenteLineSearch` requires an initial parameter vector. ",
-                "Please provide an initial guess via `Executor`s `configure` method."
-            )
-        ))?);
+        self.init_param = Some(
+            state
+                .get_param()
+                .ok_or_else(argmin_error_closure!(
+                    NotInitialized,
+                    concat!(
+                        "`MoreThuenteLineSearch` requires an initial parameter vector. ",
+                        "Please provide an initial guess via `Executor`s `configure` method."
+                    )
+                ))?
+                .clone(),
+        );
 
         let cost = state.get_cost();
-        self.finit = if cost.is_infinite() {
-            problem.cost(self.init_param.as_ref().unwrap())?
-        } else {
-            cost
+        let init_param = self.init_param.as_ref().unwrap();
+        let (finit, init_grad) = match (cost.is_infinite(), state.take_gradient()) {
+            (true, None) => problem.cost_and_gradient(init_param)?,
+            (true, Some(grad)) => (problem.cost(init_param)?, grad),
+            (false, None) => (cost, problem.gradient(init_param)?),
+            (false, Some(grad)) => (cost, grad),
         };
-
-        self.init_grad = Some(
-            state
-                .take_gradient()
-                .map(Result::Ok)
-                .unwrap_or_else(|| problem.gradient(self.init_param.as_ref().unwrap()))?,
-        );
+        self.finit = finit;
+        self.init_grad = Some(init_grad);
 
         self.dginit = self
             .init_grad
@@ -367,7 +369,10 @@ where
         self.stx = Step::new(float!(0.0), self.finit, self.dginit);
         self.sty = Step::new(float!(0.0), self.finit, self.dginit);
 
-        Ok((state, None))
+        // The initial parameter vector remains in the state, such that it is returned if the line
+        // search terminates right away (for instance because the budget of function evaluations
+        // is exhausted)
+        Ok((state.cost(self.finit), None))
     }
 
     fn next_iter(
@@ -405,8 +410,8 @@ where
             .as_ref()
             .unwrap()
             .scaled_add(&self.stp.x, self.search_direction.as_ref().unwrap());
-        self.f = problem.cost(&new_param)?;
-        let new_grad = problem.gradient(&new_param)?;
+        let (f, new_grad) = problem.cost_and_gradient(&new_param)?;
+        self.f = f;
         let cur_cost = self.f;
         let cur_param = new_param;
         let cur_grad = new_grad.clone();
@@ -924,4 +929,37 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_init_keeps_param() {
+        use crate::core::{Executor, FuncEvalBudget, State, TerminationReason};
+
+        let mut mtls: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> = MoreThuenteLineSearch::new();
+        mtls.search_direction(vec![-1.0f64]);
+        let (state, _) = mtls
+            .init(
+                &mut Problem::new(TestProblem::new()),
+                IterState::new().param(vec![1.0f64]),
+            )
+            .unwrap();
+        assert_eq!(state.get_param().unwrap(), &vec![1.0f64]);
+        assert_eq!(state.get_cost().to_ne_bytes(), 1.0f64.to_ne_bytes());
+
+        // If the budget is exhausted by the initialization, the initial parameter vector is the
+        // result of the line search
+        let res = Executor::new(TestProblem::new(), mtls)
+            .configure(|state| state.param(vec![1.0f64]))
+            .func_eval_budget(FuncEvalBudget::new().with_limit("cost_count", 1))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::MaxFuncEvalsReached)
+        );
+        assert_eq!(res.state.get_best_param().unwrap(), &vec![1.0f64]);
+        assert_eq!(
+            res.state.get_best_cost().to_ne_bytes(),
+            1.0f64.to_ne_bytes()
+        );
+    }
 }
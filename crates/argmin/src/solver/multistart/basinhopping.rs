@@ -0,0 +1,626 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{best_minimum, insert_minimum, run_local_solvers};
+use crate::core::{ArgminFloat, Error, IterState, Problem, SendAlias, Solver, SyncAlias, KV};
+use crate::solver::chain::ChainState;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// # Basin-hopping
+///
+/// Starting from the local minimum found by the local solver from the initial parameter vector,
+/// each iteration perturbs the current local minimum by adding uniformly distributed random
+/// numbers in `[-step_size, step_size]` to each coordinate (see
+/// [`with_step_size`](`BasinHopping::with_step_size`)) and runs the local solver from the
+/// perturbed point. The new local minimum is accepted as current local minimum if its cost is
+/// lower, otherwise it is accepted with probability `exp(-(c_new - c_current) / T)` where `T` is
+/// the temperature (see [`with_temperature`](`BasinHopping::with_temperature`)). Perturbed points
+/// are clamped to the bounds if these are set via [`with_bounds`](`BasinHopping::with_bounds`).
+///
+/// Several perturbations of the current local minimum can be tried per iteration (see
+/// [`with_trials`](`BasinHopping::with_trials`)), in which case the best of the resulting local
+/// minima is subject to the acceptance test. If the `rayon` feature is enabled, the trials run in
+/// parallel (unless turned off via [`with_parallel`](`BasinHopping::with_parallel`)).
+///
+/// The parameter vector of the state is the current local minimum, the best parameter vector of
+/// the state is the best local minimum found overall. The distinct local minima found are
+/// available via [`local_minima`](`BasinHopping::local_minima`). Two local minima are considered
+/// identical if their Euclidean distance is below a tolerance (see
+/// [`with_distinct_tolerance`](`BasinHopping::with_distinct_tolerance`)).
+///
+/// The solver only terminates due to the maximum number of iterations (or other termination
+/// criteria such as [`Stall`](`crate::core::termination_criteria::Stall`)).
+///
+/// A run of the local solver from a perturbed point which fails with an error is skipped; the
+/// number of failed runs of an iteration is reported as `failed_starts` in the key-value store of
+/// the iteration. The run from the initial parameter vector however must succeed.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement everything required by the local solver and
+/// [`Clone`], since every run of the local solver works on its own copy of the problem. The
+/// parameter vector is required to be `Vec<F>`.
+///
+/// ## Reference
+///
+/// David J. Wales and Jonathan P. K. Doye (1997). Global Optimization by Basin-Hopping and the
+/// Lowest Energy Structures of Lennard-Jones Clusters Containing up to 110 Atoms. J. Phys. Chem. A
+/// 101, 5111–5116. <https://doi.org/10.1021/jp970984n>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BasinHopping<S, I, F, R> {
+    /// local solver
+    local_solver: S,
+    /// bounds on parameter space
+    bounds: Option<(Vec<F>, Vec<F>)>,
+    /// maximum perturbation of each coordinate
+    step_size: F,
+    /// temperature of the acceptance test
+    temperature: F,
+    /// number of perturbations per iteration
+    trials: usize,
+    /// maximum number of iterations of the local solver
+    local_max_iters: u64,
+    /// tolerance for distinguishing local minima
+    distinct_tolerance: F,
+    /// run the trials of an iteration in parallel
+    parallel: bool,
+    /// current local minimum and its cost
+    current: Option<(Vec<F>, F)>,
+    /// distinct local minima found so far
+    local_minima: Vec<(Vec<F>, F)>,
+    /// random number generator
+    rng_generator: R,
+    /// state of the local solver
+    local_state: PhantomData<I>,
+}
+
+impl<S, I, F> BasinHopping<S, I, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `BasinHopping`
+    ///
+    /// Takes the local solver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver);
+    /// ```
+    pub fn new(local_solver: S) -> Self {
+        BasinHopping {
+            local_solver,
+            bounds: None,
+            step_size: float!(0.5),
+            temperature: float!(1.0),
+            trials: 1,
+            local_max_iters: 1000,
+            distinct_tolerance: float!(1e-3),
+            parallel: true,
+            current: None,
+            local_minima: vec![],
+            rng_generator: Xoshiro256PlusPlus::from_entropy(),
+            local_state: PhantomData,
+        }
+    }
+}
+
+impl<S, I, F, R0> BasinHopping<S, I, F, R0>
+where
+    F: ArgminFloat,
+{
+    /// Set the random number generator
+    ///
+    /// Defaults to `rand_xoshiro::Xoshiro256PlusPlus::from_entropy()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::IterState;
+    /// # use rand::SeedableRng;
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver)
+    ///         .with_rng_generator(rand_xoshiro::Xoroshiro128Plus::seed_from_u64(1729));
+    /// ```
+    pub fn with_rng_generator<R1: Rng>(self, generator: R1) -> BasinHopping<S, I, F, R1> {
+        BasinHopping {
+            local_solver: self.local_solver,
+            bounds: self.bounds,
+            step_size: self.step_size,
+            temperature: self.temperature,
+            trials: self.trials,
+            local_max_iters: self.local_max_iters,
+            distinct_tolerance: self.distinct_tolerance,
+            parallel: self.parallel,
+            current: self.current,
+            local_minima: self.local_minima,
+            rng_generator: generator,
+            local_state: PhantomData,
+        }
+    }
+
+    /// Set bounds on the parameter space
+    ///
+    /// Perturbed points are clamped to the box defined by `bounds`, a tuple
+    /// `(lower_bound, upper_bound)`. Note that this does not constrain the local solver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_bounds((vec![-1.0, -1.0], vec![1.0, 1.0]))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_bounds(mut self, bounds: (Vec<F>, Vec<F>)) -> Result<Self, Error> {
+        if bounds.0.len() != bounds.1.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BasinHopping`: lower and upper bounds must have the same length."
+            ));
+        }
+        if bounds.0.iter().zip(bounds.1.iter()).any(|(l, u)| l > u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BasinHopping`: lower bounds must be <= upper bounds."
+            ));
+        }
+        self.bounds = Some(bounds);
+        Ok(self)
+    }
+
+    /// Set the step size of the perturbations
+    ///
+    /// Must be larger than 0 and defaults to `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_step_size(2.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_size(mut self, step_size: F) -> Result<Self, Error> {
+        if step_size <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BasinHopping`: step size must be > 0."
+            ));
+        }
+        self.step_size = step_size;
+        Ok(self)
+    }
+
+    /// Set the temperature of the acceptance test
+    ///
+    /// Must be non-negative and defaults to `1`. With a temperature of `0`, only improvements are
+    /// accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_temperature(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_temperature(mut self, temperature: F) -> Result<Self, Error> {
+        if temperature < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BasinHopping`: temperature must be >= 0."
+            ));
+        }
+        self.temperature = temperature;
+        Ok(self)
+    }
+
+    /// Set the number of perturbations per iteration
+    ///
+    /// Must be larger than 0 and defaults to `1`. If the `rayon` feature is enabled, the trials of
+    /// one iteration are performed in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_trials(4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_trials(mut self, trials: usize) -> Result<Self, Error> {
+        if trials == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BasinHopping`: number of trials must be > 0."
+            ));
+        }
+        self.trials = trials;
+        Ok(self)
+    }
+
+    /// Set maximum number of iterations of each run of the local solver
+    ///
+    /// Defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_local_max_iters(50);
+    /// ```
+    #[must_use]
+    pub fn with_local_max_iters(mut self, iters: u64) -> Self {
+        self.local_max_iters = iters;
+        self
+    }
+
+    /// Set the tolerance for distinguishing local minima
+    ///
+    /// Local minima with a Euclidean distance below this tolerance are considered identical.
+    /// Must be non-negative and defaults to `1e-3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_distinct_tolerance(1e-2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_distinct_tolerance(mut self, tolerance: F) -> Result<Self, Error> {
+        if tolerance < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BasinHopping`: distinct tolerance must be >= 0."
+            ));
+        }
+        self.distinct_tolerance = tolerance;
+        Ok(self)
+    }
+
+    /// Run the trials of an iteration in parallel
+    ///
+    /// Defaults to `true`. Only has an effect if the `rayon` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     BasinHopping::new(local_solver).with_parallel(false);
+    /// ```
+    #[must_use]
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Returns the distinct local minima found so far, sorted by cost
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::BasinHopping;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// # let bh: BasinHopping<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    /// #     BasinHopping::new(local_solver);
+    /// let minima: &[(Vec<f64>, f64)] = bh.local_minima();
+    /// ```
+    pub fn local_minima(&self) -> &[(Vec<F>, F)] {
+        &self.local_minima
+    }
+}
+
+impl<S, I, F, R> BasinHopping<S, I, F, R>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Randomly perturbs `param` and clamps the result to the bounds (if any).
+    fn perturb(&mut self, param: &[F]) -> Vec<F> {
+        let step_size = self.step_size;
+        let rng = &mut self.rng_generator;
+        let perturbed = param
+            .iter()
+            .map(|&x| x + step_size * float!(rng.gen_range(-1.0..=1.0)));
+        match self.bounds.as_ref() {
+            Some((lower, upper)) => perturbed
+                .zip(lower.iter().zip(upper.iter()))
+                .map(|(x, (&l, &u))| x.max(l).min(u))
+                .collect(),
+            None => perturbed.collect(),
+        }
+    }
+}
+
+impl<O, S, I, F, R> Solver<O, IterState<Vec<F>, (), (), (), (), F>> for BasinHopping<S, I, F, R>
+where
+    O: Clone + SendAlias + SyncAlias,
+    S: Clone + Solver<O, I> + SendAlias + SyncAlias,
+    I: Clone + ChainState<Vec<F>, Float = F> + SendAlias,
+    F: ArgminFloat,
+    R: Rng,
+{
+    fn name(&self) -> &str {
+        "Basin-hopping"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`BasinHopping` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let mut runs = run_local_solvers::<O, S, I, F>(
+            problem,
+            &self.local_solver,
+            vec![param],
+            self.local_max_iters,
+            self.parallel,
+        )?;
+        // Without an initial local minimum there is nothing to perturb
+        if let Some(e) = runs.errors.pop() {
+            return Err(e);
+        }
+        let (param, cost) = runs
+            .minima
+            .into_iter()
+            .next()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`BasinHopping`: No `param` returned by local solver"
+            ))?;
+
+        self.local_minima = vec![];
+        insert_minimum(
+            &mut self.local_minima,
+            param.clone(),
+            cost,
+            self.distinct_tolerance,
+        );
+        self.current = Some((param.clone(), cost));
+
+        Ok((state.param(param).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), (), F>, Option<KV>), Error> {
+        let (current_param, current_cost) = self.current.take().ok_or_else(
+            argmin_error_closure!(PotentialBug, "`BasinHopping`: No current local minimum"),
+        )?;
+
+        let starts: Vec<Vec<F>> = (0..self.trials)
+            .map(|_| self.perturb(&current_param))
+            .collect();
+        let runs = run_local_solvers::<O, S, I, F>(
+            problem,
+            &self.local_solver,
+            starts,
+            self.local_max_iters,
+            self.parallel,
+        )?;
+        let best = best_minimum(&runs.minima).cloned();
+        for (param, cost) in runs.minima {
+            insert_minimum(&mut self.local_minima, param, cost, self.distinct_tolerance);
+        }
+
+        // Metropolis acceptance test
+        let accepted = match best.as_ref() {
+            Some((_, cost)) if *cost < current_cost => true,
+            Some((_, cost)) if self.temperature > float!(0.0) => {
+                let p = (-(*cost - current_cost) / self.temperature).exp();
+                float!(self.rng_generator.gen::<f64>()) < p
+            }
+            _ => false,
+        };
+        let (param, cost) = match best {
+            Some(best) if accepted => best,
+            _ => (current_param, current_cost),
+        };
+        self.current = Some((param.clone(), cost));
+
+        let kv = kv!(
+            "accepted" => accepted;
+            "num_local_minima" => self.local_minima.len() as u64;
+            "failed_starts" => runs.errors.len() as u64;
+        );
+        Ok((state.param(param).cost(cost), Some(kv)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, CostFunction, Executor, Gradient, State};
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::quasinewton::LBFGS;
+    use argmin_testfunctions::{rastrigin, rastrigin_derivative};
+
+    test_trait_impl!(
+        basinhopping,
+        BasinHopping<
+            LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>,
+            IterState<Vec<f64>, Vec<f64>, (), (), (), f64>,
+            f64,
+            Xoshiro256PlusPlus,
+        >
+    );
+
+    #[derive(Clone)]
+    struct Rastrigin {}
+
+    impl CostFunction for Rastrigin {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rastrigin(p))
+        }
+    }
+
+    impl Gradient for Rastrigin {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rastrigin_derivative(p))
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let bh: BasinHopping<(), IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+            BasinHopping::new(());
+        assert_error!(
+            bh.clone().with_step_size(0.0),
+            ArgminError,
+            "Invalid parameter: \"`BasinHopping`: step size must be > 0.\""
+        );
+        assert_error!(
+            bh.clone().with_temperature(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`BasinHopping`: temperature must be >= 0.\""
+        );
+        assert_error!(
+            bh.clone().with_trials(0),
+            ArgminError,
+            "Invalid parameter: \"`BasinHopping`: number of trials must be > 0.\""
+        );
+        assert_error!(
+            bh.clone().with_distinct_tolerance(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`BasinHopping`: distinct tolerance must be >= 0.\""
+        );
+        assert_error!(
+            bh.clone().with_bounds((vec![0.0], vec![1.0, 2.0])),
+            ArgminError,
+            "Invalid parameter: \"`BasinHopping`: lower and upper bounds must have the same length.\""
+        );
+        assert_error!(
+            bh.with_bounds((vec![1.0], vec![0.0])),
+            ArgminError,
+            "Invalid parameter: \"`BasinHopping`: lower bounds must be <= upper bounds.\""
+        );
+    }
+
+    #[test]
+    fn test_perturb() {
+        let mut bh: BasinHopping<(), IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+            BasinHopping::new(())
+                .with_step_size(2.0)
+                .unwrap()
+                .with_bounds((vec![-1.0, -1.0], vec![1.0, 1.0]))
+                .unwrap()
+                .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+        for _ in 0..100 {
+            let p = bh.perturb(&[0.9, -0.9]);
+            assert!(p.iter().all(|x| (-1.0..=1.0).contains(x)));
+        }
+    }
+
+    #[test]
+    fn test_missing_param() {
+        let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let solver = BasinHopping::new(lbfgs);
+        let res = Executor::new(Rastrigin {}, solver)
+            .configure(|state| state.max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`BasinHopping` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_rastrigin() {
+        let init_param = vec![3.2, -2.9];
+
+        // A single run of the local solver gets stuck in the local minimum next to the
+        // initial parameter vector.
+        let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let res = Executor::new(Rastrigin {}, lbfgs.clone())
+            .configure(|state| state.param(init_param.clone()).max_iters(100))
+            .run()
+            .unwrap();
+        assert!(res.state().get_best_cost() > 10.0);
+
+        let solver = BasinHopping::new(lbfgs)
+            .with_step_size(1.0)
+            .unwrap()
+            .with_bounds((vec![-5.12, -5.12], vec![5.12, 5.12]))
+            .unwrap()
+            .with_trials(4)
+            .unwrap()
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+        let res = Executor::new(Rastrigin {}, solver)
+            .configure(|state| state.param(init_param).max_iters(100))
+            .run()
+            .unwrap();
+
+        assert_eq!(res.state().get_iter(), 100);
+        assert!(res.state().get_best_cost() < 1e-10);
+        let minima = res.solver().local_minima();
+        assert!(minima.len() > 1);
+        assert!(minima[0].1 < 1e-10);
+        assert!(minima.windows(2).all(|w| w[0].1 <= w[1].1));
+    }
+}
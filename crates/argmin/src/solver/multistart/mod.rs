@@ -0,0 +1,696 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Multi-start and basin-hopping
+//!
+//! Meta-solvers for multimodal problems which run a local solver many times:
+//!
+//! - [`MultiStart`] runs the local solver from many starting points within bounds (see
+//!   [`Sampling`]).
+//! - [`BasinHopping`] repeatedly perturbs the current local minimum, runs the local solver from
+//!   the perturbed point and accepts or rejects the new local minimum according to the Metropolis
+//!   criterion.
+//!
+//! Both keep track of the distinct local minima found.
+//!
+//! ## References
+//!
+//! David J. Wales and Jonathan P. K. Doye (1997). Global Optimization by Basin-Hopping and the
+//! Lowest Energy Structures of Lennard-Jones Clusters Containing up to 110 Atoms. J. Phys. Chem. A
+//! 101, 5111–5116. <https://doi.org/10.1021/jp970984n>
+
+mod basinhopping;
+mod sampling;
+
+pub use self::basinhopping::BasinHopping;
+pub use self::sampling::Sampling;
+
+use crate::core::{
+    ArgminFloat, Error, Executor, IterState, OptimizationResult, Problem, SendAlias, Solver,
+    SyncAlias, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::chain::ChainState;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// # Multi-start
+///
+/// Runs a local solver from `num_starts` starting points within the box defined by lower and
+/// upper bounds. The starting points are sampled uniformly at random (default), via Latin
+/// hypercube sampling or from the Sobol sequence (see [`with_sampling`](`MultiStart::with_sampling`)
+/// and [`Sampling`]). If an initial parameter vector is provided, it replaces the first sampled
+/// starting point.
+///
+/// Each iteration runs the local solver from `batch_size` starting points (see
+/// [`with_batch_size`](`MultiStart::with_batch_size`)), each for at most `local_max_iters`
+/// iterations (see [`with_local_max_iters`](`MultiStart::with_local_max_iters`)) in a nested
+/// [`Executor`]. If the `rayon` feature is enabled, the local solvers of a batch run in parallel
+/// (unless turned off via [`with_parallel`](`MultiStart::with_parallel`)). The parameter vector
+/// of the state is the best local minimum of the last batch, therefore the best parameter vector
+/// of the state is the best local minimum found overall.
+///
+/// The distinct local minima found are available via
+/// [`local_minima`](`MultiStart::local_minima`). Two local minima are considered identical if
+/// their Euclidean distance is below a tolerance (see
+/// [`with_distinct_tolerance`](`MultiStart::with_distinct_tolerance`)).
+///
+/// A run of the local solver which fails with an error (for instance because a line search does
+/// not find a suitable step in a flat region) is skipped. The number of failed runs of an
+/// iteration is reported as `failed_starts` in the key-value store of the iteration.
+///
+/// The solver terminates once all starting points are processed.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement everything required by the local solver and
+/// [`Clone`], since every run of the local solver works on its own copy of the problem. The
+/// parameter vector is required to be `Vec<F>`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct MultiStart<S, I, F, R> {
+    /// local solver
+    local_solver: S,
+    /// bounds on parameter space
+    bounds: (Vec<F>, Vec<F>),
+    /// number of starting points
+    num_starts: usize,
+    /// sampling of starting points
+    sampling: Sampling,
+    /// number of local solver runs per iteration
+    batch_size: usize,
+    /// maximum number of iterations of the local solver
+    local_max_iters: u64,
+    /// tolerance for distinguishing local minima
+    distinct_tolerance: F,
+    /// run the local solvers of a batch in parallel
+    parallel: bool,
+    /// starting points which have not been processed yet
+    starts: Vec<Vec<F>>,
+    /// distinct local minima found so far
+    local_minima: Vec<(Vec<F>, F)>,
+    /// random number generator
+    rng_generator: R,
+    /// state of the local solver
+    local_state: PhantomData<I>,
+}
+
+impl<S, I, F> MultiStart<S, I, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `MultiStart`
+    ///
+    /// Takes the local solver, the bounds on the search space and the number of starting points.
+    /// `bounds` is a tuple `(lower_bound, upper_bound)` where both bounds have the same length as
+    /// the parameter vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100);
+    /// ```
+    pub fn new(local_solver: S, bounds: (Vec<F>, Vec<F>), num_starts: usize) -> Self {
+        MultiStart {
+            local_solver,
+            bounds,
+            num_starts,
+            sampling: Sampling::Random,
+            batch_size: 1,
+            local_max_iters: 1000,
+            distinct_tolerance: float!(1e-3),
+            parallel: true,
+            starts: vec![],
+            local_minima: vec![],
+            rng_generator: Xoshiro256PlusPlus::from_entropy(),
+            local_state: PhantomData,
+        }
+    }
+}
+
+impl<S, I, F, R0> MultiStart<S, I, F, R0>
+where
+    F: ArgminFloat,
+{
+    /// Set the random number generator
+    ///
+    /// Defaults to `rand_xoshiro::Xoshiro256PlusPlus::from_entropy()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::IterState;
+    /// # use rand::SeedableRng;
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100)
+    ///         .with_rng_generator(rand_xoshiro::Xoroshiro128Plus::seed_from_u64(1729));
+    /// ```
+    pub fn with_rng_generator<R1: Rng>(self, generator: R1) -> MultiStart<S, I, F, R1> {
+        MultiStart {
+            local_solver: self.local_solver,
+            bounds: self.bounds,
+            num_starts: self.num_starts,
+            sampling: self.sampling,
+            batch_size: self.batch_size,
+            local_max_iters: self.local_max_iters,
+            distinct_tolerance: self.distinct_tolerance,
+            parallel: self.parallel,
+            starts: self.starts,
+            local_minima: self.local_minima,
+            rng_generator: generator,
+            local_state: PhantomData,
+        }
+    }
+
+    /// Set the sampling of the starting points
+    ///
+    /// Defaults to [`Sampling::Random`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::{MultiStart, Sampling};
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100)
+    ///         .with_sampling(Sampling::Sobol);
+    /// ```
+    #[must_use]
+    pub fn with_sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Set the number of local solver runs per iteration
+    ///
+    /// Must be larger than 0 and defaults to `1`. If the `rayon` feature is enabled, the runs of
+    /// one iteration are performed in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100).with_batch_size(8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_batch_size(mut self, batch_size: usize) -> Result<Self, Error> {
+        if batch_size == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MultiStart`: batch size must be > 0."
+            ));
+        }
+        self.batch_size = batch_size;
+        Ok(self)
+    }
+
+    /// Set maximum number of iterations of each run of the local solver
+    ///
+    /// Defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100)
+    ///         .with_local_max_iters(50);
+    /// ```
+    #[must_use]
+    pub fn with_local_max_iters(mut self, iters: u64) -> Self {
+        self.local_max_iters = iters;
+        self
+    }
+
+    /// Set the tolerance for distinguishing local minima
+    ///
+    /// Local minima with a Euclidean distance below this tolerance are considered identical.
+    /// Must be non-negative and defaults to `1e-3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::{Error, IterState};
+    /// # fn main() -> Result<(), Error> {
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100)
+    ///         .with_distinct_tolerance(1e-2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_distinct_tolerance(mut self, tolerance: F) -> Result<Self, Error> {
+        if tolerance < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MultiStart`: distinct tolerance must be >= 0."
+            ));
+        }
+        self.distinct_tolerance = tolerance;
+        Ok(self)
+    }
+
+    /// Run the local solvers of a batch in parallel
+    ///
+    /// Defaults to `true`. Only has an effect if the `rayon` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    ///     MultiStart::new(local_solver, (lower_bound, upper_bound), 100).with_parallel(false);
+    /// ```
+    #[must_use]
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Returns the distinct local minima found so far, sorted by cost
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::multistart::MultiStart;
+    /// # use argmin::core::IterState;
+    /// # let local_solver = ();
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// # let ms: MultiStart<_, IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+    /// #     MultiStart::new(local_solver, (lower_bound, upper_bound), 100);
+    /// let minima: &[(Vec<f64>, f64)] = ms.local_minima();
+    /// ```
+    pub fn local_minima(&self) -> &[(Vec<F>, F)] {
+        &self.local_minima
+    }
+}
+
+impl<O, S, I, F, R> Solver<O, IterState<Vec<F>, (), (), (), (), F>> for MultiStart<S, I, F, R>
+where
+    O: Clone + SendAlias + SyncAlias,
+    S: Clone + Solver<O, I> + SendAlias + SyncAlias,
+    I: Clone + ChainState<Vec<F>, Float = F> + SendAlias,
+    F: ArgminFloat,
+    R: Rng,
+{
+    fn name(&self) -> &str {
+        "Multi-start"
+    }
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), (), F>, Option<KV>), Error> {
+        if self.bounds.0.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MultiStart`: bounds must not be empty."
+            ));
+        }
+        if self.num_starts == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MultiStart`: number of starting points must be > 0."
+            ));
+        }
+
+        let mut starts =
+            self.sampling
+                .sample(&self.bounds, self.num_starts, &mut self.rng_generator)?;
+        if let Some(param) = state.take_param() {
+            starts[0] = param;
+        }
+        // Starting points are processed from the back
+        starts.reverse();
+        self.starts = starts;
+        self.local_minima = vec![];
+
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), (), F>, Option<KV>), Error> {
+        let num = self.batch_size.min(self.starts.len());
+        let batch = self.starts.split_off(self.starts.len() - num);
+
+        let runs = run_local_solvers::<O, S, I, F>(
+            problem,
+            &self.local_solver,
+            batch,
+            self.local_max_iters,
+            self.parallel,
+        )?;
+        let best = best_minimum(&runs.minima).cloned();
+        for (param, cost) in runs.minima {
+            insert_minimum(&mut self.local_minima, param, cost, self.distinct_tolerance);
+        }
+
+        let kv = kv!(
+            "num_local_minima" => self.local_minima.len() as u64;
+            "remaining_starts" => self.starts.len() as u64;
+            "failed_starts" => runs.errors.len() as u64;
+        );
+        Ok((
+            match best {
+                Some((param, cost)) => state.param(param).cost(cost),
+                None => state,
+            },
+            Some(kv),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<Vec<F>, (), (), (), (), F>) -> TerminationStatus {
+        if self.starts.is_empty() {
+            return TerminationStatus::Terminated(TerminationReason::SolverExit(
+                "All starting points processed".to_string(),
+            ));
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+/// Outcome of the runs of the local solver of one iteration
+struct LocalRuns<F> {
+    /// Best parameter vectors found by the successful runs together with their costs
+    minima: Vec<(Vec<F>, F)>,
+    /// Errors of the failed runs
+    errors: Vec<Error>,
+}
+
+/// Runs `solver` from each of the `starts` on its own copy of the problem and returns the best
+/// parameter vectors found together with their costs. A failing run (for instance due to a line
+/// search which does not find a suitable step) does not abort the other runs; its error is
+/// returned instead. The function evaluations of the successful runs are accounted for in
+/// `problem`.
+fn run_local_solvers<O, S, I, F>(
+    problem: &mut Problem<O>,
+    solver: &S,
+    starts: Vec<Vec<F>>,
+    max_iters: u64,
+    parallel: bool,
+) -> Result<LocalRuns<F>, Error>
+where
+    O: Clone + SendAlias + SyncAlias,
+    S: Clone + Solver<O, I> + SendAlias + SyncAlias,
+    I: Clone + ChainState<Vec<F>, Float = F> + SendAlias,
+    F: ArgminFloat,
+{
+    let outer: &Problem<O> = problem;
+    let user_problem = outer.problem.as_ref().ok_or_else(argmin_error_closure!(
+        PotentialBug,
+        "`MultiStart`: No `problem` available for local solver"
+    ))?;
+    let run = |start: Vec<F>| -> Result<OptimizationResult<O, S, I>, Error> {
+        Executor::new(user_problem.clone(), solver.clone())
+            .configure(|state: I| state.seed(start).max_iters(max_iters))
+            .inherit_func_eval_budget(outer)
+            .ctrlc(false)
+            .run()
+    };
+
+    #[cfg(feature = "rayon")]
+    let results: Vec<Result<OptimizationResult<O, S, I>, Error>> = if parallel {
+        starts.into_par_iter().map(run).collect()
+    } else {
+        starts.into_iter().map(run).collect()
+    };
+    #[cfg(not(feature = "rayon"))]
+    let results: Vec<Result<OptimizationResult<O, S, I>, Error>> = {
+        let _ = parallel;
+        starts.into_iter().map(run).collect()
+    };
+
+    let mut runs = LocalRuns {
+        minima: Vec::with_capacity(results.len()),
+        errors: vec![],
+    };
+    for result in results {
+        match result {
+            Ok(OptimizationResult {
+                problem: local_problem,
+                state,
+                ..
+            }) => {
+                problem.consume_func_counts(local_problem);
+                if let Some(param) = state.best_param() {
+                    runs.minima.push((param, state.get_best_cost()));
+                }
+            }
+            Err(e) => runs.errors.push(e),
+        }
+    }
+    Ok(runs)
+}
+
+/// Returns the local minimum with the lowest cost.
+fn best_minimum<F: ArgminFloat>(minima: &[(Vec<F>, F)]) -> Option<&(Vec<F>, F)> {
+    minima
+        .iter()
+        .filter(|(_, cost)| !cost.is_nan())
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+}
+
+/// Adds a local minimum to the list of distinct local minima (sorted by cost). If a local minimum
+/// within `tolerance` is already known, the one with the lower cost is kept.
+fn insert_minimum<F: ArgminFloat>(
+    minima: &mut Vec<(Vec<F>, F)>,
+    param: Vec<F>,
+    cost: F,
+    tolerance: F,
+) {
+    if cost.is_nan() {
+        return;
+    }
+    let known = minima.iter().position(|(p, _)| {
+        p.iter()
+            .zip(param.iter())
+            .map(|(&a, &b)| (a - b).powi(2))
+            .fold(float!(0.0), |acc: F, x| acc + x)
+            .sqrt()
+            <= tolerance
+    });
+    match known {
+        Some(idx) if minima[idx].1 <= cost => return,
+        Some(idx) => {
+            minima.remove(idx);
+        }
+        None => {}
+    }
+    let pos = minima.partition_point(|(_, c)| *c <= cost);
+    minima.insert(pos, (param, cost));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, CostFunction, Gradient, State};
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::quasinewton::LBFGS;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{himmelblau, himmelblau_derivative};
+
+    test_trait_impl!(
+        multistart,
+        MultiStart<
+            LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>,
+            IterState<Vec<f64>, Vec<f64>, (), (), (), f64>,
+            f64,
+            Xoshiro256PlusPlus,
+        >
+    );
+
+    #[derive(Clone)]
+    struct Himmelblau {}
+
+    impl CostFunction for Himmelblau {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(himmelblau(&[p[0], p[1]]))
+        }
+    }
+
+    impl Gradient for Himmelblau {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(himmelblau_derivative(&[p[0], p[1]]).to_vec())
+        }
+    }
+
+    #[test]
+    fn test_insert_minimum() {
+        let mut minima = vec![];
+        insert_minimum(&mut minima, vec![0.0, 0.0], 1.0f64, 0.1);
+        insert_minimum(&mut minima, vec![1.0, 0.0], 0.5, 0.1);
+        insert_minimum(&mut minima, vec![0.05, 0.0], 0.9, 0.1);
+        insert_minimum(&mut minima, vec![1.05, 0.0], 0.7, 0.1);
+        insert_minimum(&mut minima, vec![2.0, 0.0], f64::NAN, 0.1);
+        assert_eq!(minima, vec![(vec![1.0, 0.0], 0.5), (vec![0.05, 0.0], 0.9)]);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let ms: MultiStart<(), IterState<Vec<f64>, (), (), (), (), f64>, f64, _> =
+            MultiStart::new((), (vec![0.0], vec![1.0]), 10);
+        assert_error!(
+            ms.clone().with_batch_size(0),
+            ArgminError,
+            "Invalid parameter: \"`MultiStart`: batch size must be > 0.\""
+        );
+        assert_error!(
+            ms.with_distinct_tolerance(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`MultiStart`: distinct tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_himmelblau() {
+        for sampling in [Sampling::Random, Sampling::LatinHypercube, Sampling::Sobol] {
+            let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+            let solver = MultiStart::new(lbfgs, (vec![-5.0, -5.0], vec![5.0, 5.0]), 40)
+                .with_sampling(sampling)
+                .with_batch_size(8)
+                .unwrap()
+                .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+
+            let res = Executor::new(Himmelblau {}, solver)
+                .configure(|state| state.max_iters(100).counting(true))
+                .run()
+                .unwrap();
+
+            assert_eq!(res.state().get_iter(), 5);
+            assert_eq!(
+                res.state().get_termination_reason(),
+                Some(&TerminationReason::SolverExit(
+                    "All starting points processed".to_string()
+                ))
+            );
+            assert!(res.state().get_best_cost() < 1e-10);
+            assert!(res.problem().counts["gradient_count"] >= 40);
+
+            // Himmelblau's function has four minima with cost 0 (and a local maximum, which
+            // the local solver does not converge to).
+            let minima = res.solver().local_minima();
+            assert_eq!(minima.len(), 4);
+            for (param, cost) in minima {
+                assert_relative_eq!(*cost, 0.0, epsilon = 1e-10);
+                assert_relative_eq!(himmelblau(&[param[0], param[1]]), 0.0, epsilon = 1e-10);
+            }
+        }
+    }
+
+    /// Himmelblau's function, which is only defined for non-positive first coordinates
+    #[derive(Clone)]
+    struct HalfHimmelblau {}
+
+    impl CostFunction for HalfHimmelblau {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            if p[0] > 0.0 {
+                return Err(argmin_error!(InvalidParameter, "Outside of domain"));
+            }
+            Himmelblau {}.cost(p)
+        }
+    }
+
+    impl Gradient for HalfHimmelblau {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Himmelblau {}.gradient(p)
+        }
+    }
+
+    #[test]
+    fn test_failed_starts() {
+        let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let mut solver = MultiStart::new(lbfgs, (vec![-5.0, -5.0], vec![5.0, 5.0]), 8)
+            .with_batch_size(8)
+            .unwrap()
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+        let mut problem = Problem::new(HalfHimmelblau {});
+        let (state, _) = solver.init(&mut problem, IterState::new()).unwrap();
+        let (state, kv) = solver.next_iter(&mut problem, state).unwrap();
+
+        // Starts with a positive first coordinate fail right away, the others are not affected
+        let failed = kv
+            .unwrap()
+            .get("failed_starts")
+            .unwrap()
+            .get_uint()
+            .unwrap();
+        assert!(failed > 0 && failed < 8);
+        assert!(!solver.local_minima().is_empty());
+        assert!(state.get_param().unwrap()[0] <= 0.0);
+        assert!(state.get_cost() < 1e-10);
+        // Function evaluations of the successful runs are accounted for
+        assert!(problem.counts["gradient_count"] > 0);
+    }
+
+    #[test]
+    fn test_initial_param() {
+        let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+        let solver = MultiStart::new(lbfgs, (vec![-5.0, -5.0], vec![5.0, 5.0]), 1)
+            .with_rng_generator(Xoshiro256PlusPlus::seed_from_u64(42));
+
+        let res = Executor::new(Himmelblau {}, solver)
+            .configure(|state| state.param(vec![3.1, 2.1]))
+            .run()
+            .unwrap();
+
+        let param = res.state().get_best_param().unwrap();
+        assert_relative_eq!(param[0], 3.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 2.0, epsilon = 1e-6);
+    }
+}
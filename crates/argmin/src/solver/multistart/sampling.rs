@@ -0,0 +1,285 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error};
+use rand::seq::SliceRandom;
+use rand::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Direction numbers `(s, a, m_1, ..., m_s)` of the Sobol sequence for dimensions 2 to 21
+///
+/// Taken from S. Joe and F. Y. Kuo (2008). Constructing Sobol sequences with better
+/// two-dimensional projections. SIAM J. Sci. Comput. 30, 2635–2654.
+const SOBOL_DIRECTIONS: [(u32, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// Sampling of starting points within bounds
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Sampling {
+    /// Uniformly distributed random points
+    Random,
+    /// Latin hypercube sampling: the range of every coordinate is divided into as many intervals
+    /// as there are points and each interval contains exactly one point.
+    LatinHypercube,
+    /// Points of the (unscrambled) Sobol low-discrepancy sequence, starting with the center of
+    /// the box. Supports up to 21 dimensions and does not use the random number generator.
+    Sobol,
+}
+
+impl Sampling {
+    /// Samples `num` points within `bounds`, a tuple `(lower_bound, upper_bound)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::multistart::Sampling;
+    /// # use rand::SeedableRng;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let points: Vec<Vec<f64>> =
+    ///     Sampling::LatinHypercube.sample(&(vec![-1.0, -1.0], vec![1.0, 1.0]), 10, &mut rng)?;
+    /// # assert_eq!(points.len(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sample<F, R>(
+        &self,
+        bounds: &(Vec<F>, Vec<F>),
+        num: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<F>>, Error>
+    where
+        F: ArgminFloat,
+        R: Rng,
+    {
+        let (lower, upper) = bounds;
+        if lower.len() != upper.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Sampling`: lower and upper bounds must have the same length."
+            ));
+        }
+        if lower.iter().zip(upper.iter()).any(|(l, u)| l > u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Sampling`: lower bounds must be <= upper bounds."
+            ));
+        }
+        let dim = lower.len();
+
+        // Points in the unit cube
+        let unit: Vec<Vec<f64>> = match self {
+            Sampling::Random => (0..num)
+                .map(|_| (0..dim).map(|_| rng.gen::<f64>()).collect())
+                .collect(),
+            Sampling::LatinHypercube => {
+                let mut points = vec![vec![0.0; dim]; num];
+                let mut strata: Vec<usize> = (0..num).collect();
+                for d in 0..dim {
+                    strata.shuffle(rng);
+                    for (point, &stratum) in points.iter_mut().zip(strata.iter()) {
+                        point[d] = (stratum as f64 + rng.gen::<f64>()) / num as f64;
+                    }
+                }
+                points
+            }
+            Sampling::Sobol => sobol(dim, num)?,
+        };
+
+        Ok(unit
+            .into_iter()
+            .map(|point| {
+                point
+                    .into_iter()
+                    .zip(lower.iter().zip(upper.iter()))
+                    .map(|(x, (&l, &u))| l + (u - l) * float!(x))
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+/// Computes the first `num` points (excluding the origin) of the `dim` dimensional Sobol
+/// sequence.
+fn sobol(dim: usize, num: usize) -> Result<Vec<Vec<f64>>, Error> {
+    if dim > SOBOL_DIRECTIONS.len() + 1 {
+        return Err(argmin_error!(
+            InvalidParameter,
+            format!(
+                "`Sampling`: Sobol sampling supports at most {} dimensions.",
+                SOBOL_DIRECTIONS.len() + 1
+            )
+        ));
+    }
+    if num as u64 >= 1 << 32 {
+        return Err(argmin_error!(
+            InvalidParameter,
+            "`Sampling`: Sobol sampling supports less than 2^32 points."
+        ));
+    }
+
+    // Direction numbers, scaled by 2^32
+    let directions: Vec<[u32; 32]> = (0..dim)
+        .map(|d| {
+            let mut v = [0u32; 32];
+            if d == 0 {
+                for (i, vi) in v.iter_mut().enumerate() {
+                    *vi = 1 << (31 - i);
+                }
+            } else {
+                let (s, a, m) = SOBOL_DIRECTIONS[d - 1];
+                let s = s as usize;
+                for i in 0..s {
+                    v[i] = m[i] << (31 - i);
+                }
+                for i in s..32 {
+                    v[i] = v[i - s] ^ (v[i - s] >> s);
+                    for k in 1..s {
+                        if (a >> (s - 1 - k)) & 1 == 1 {
+                            v[i] ^= v[i - k];
+                        }
+                    }
+                }
+            }
+            v
+        })
+        .collect();
+
+    // Gray code construction
+    let mut x = vec![0u32; dim];
+    Ok((0..num as u32)
+        .map(|i| {
+            let c = (!i).trailing_zeros() as usize;
+            x.iter_mut()
+                .zip(directions.iter())
+                .map(|(xd, v)| {
+                    *xd ^= v[c];
+                    *xd as f64 / 4294967296.0
+                })
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn test_random() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let points = Sampling::Random
+            .sample(&(vec![-1.0f64, 2.0], vec![1.0, 3.0]), 100, &mut rng)
+            .unwrap();
+        assert_eq!(points.len(), 100);
+        for p in points {
+            assert!((-1.0..=1.0).contains(&p[0]));
+            assert!((2.0..=3.0).contains(&p[1]));
+        }
+    }
+
+    #[test]
+    fn test_latin_hypercube() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let n = 16;
+        let points = Sampling::LatinHypercube
+            .sample(&(vec![0.0f64; 3], vec![1.0; 3]), n, &mut rng)
+            .unwrap();
+        // Every interval of every coordinate contains exactly one point
+        for d in 0..3 {
+            let mut strata: Vec<usize> = points
+                .iter()
+                .map(|p| (p[d] * n as f64).floor() as usize)
+                .collect();
+            strata.sort();
+            assert_eq!(strata, (0..n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_sobol() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let points = Sampling::Sobol
+            .sample(&(vec![0.0f64; 2], vec![1.0; 2]), 7, &mut rng)
+            .unwrap();
+        let expected = [
+            [0.5, 0.5],
+            [0.75, 0.25],
+            [0.25, 0.75],
+            [0.375, 0.375],
+            [0.875, 0.875],
+            [0.625, 0.125],
+            [0.125, 0.625],
+        ];
+        for (p, e) in points.iter().zip(expected.iter()) {
+            assert_relative_eq!(p[0], e[0]);
+            assert_relative_eq!(p[1], e[1]);
+        }
+
+        // The first 2^k - 1 points (plus the origin) stratify every coordinate
+        let n = 31;
+        let points = Sampling::Sobol
+            .sample(&(vec![0.0f64; 21], vec![1.0; 21]), n, &mut rng)
+            .unwrap();
+        for d in 0..21 {
+            let mut strata: Vec<usize> = points
+                .iter()
+                .map(|p| (p[d] * (n + 1) as f64).floor() as usize)
+                .collect();
+            strata.push(0);
+            strata.sort();
+            assert_eq!(strata, (0..n + 1).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        assert_error!(
+            Sampling::Sobol.sample(&(vec![0.0f64; 22], vec![1.0; 22]), 2, &mut rng),
+            ArgminError,
+            "Invalid parameter: \"`Sampling`: Sobol sampling supports at most 21 dimensions.\""
+        );
+        assert_error!(
+            Sampling::Random.sample(&(vec![0.0f64; 2], vec![1.0]), 2, &mut rng),
+            ArgminError,
+            "Invalid parameter: \"`Sampling`: lower and upper bounds must have the same length.\""
+        );
+        assert_error!(
+            Sampling::Random.sample(&(vec![1.0f64], vec![0.0]), 2, &mut rng),
+            ArgminError,
+            "Invalid parameter: \"`Sampling`: lower bounds must be <= upper bounds.\""
+        );
+    }
+}
@@ -19,13 +19,14 @@
 //! <http://www.scholarpedia.org/article/Nelder-Mead_algorithm#Simplex_transformation_algorithm>
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, TerminationReason,
-    TerminationStatus, KV,
+    ArgminFloat, BoundsHandling, CostFunction, Error, IgnoreBounds, IterState, Problem,
+    ProjectOntoBounds, Solver, TerminationReason, TerminationStatus, KV,
 };
 use argmin_math::{ArgminAdd, ArgminMul, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::marker::PhantomData;
 
 /// # Nelder-Mead method
 ///
@@ -53,6 +54,13 @@ use std::fmt;
 ///
 /// The optimization problem is required to implement [`CostFunction`].
 ///
+/// ## Box constraints
+///
+/// With [`with_box_constraints`](`NelderMead::with_box_constraints`), the problem is additionally
+/// required to implement [`BoxConstraint`](`crate::core::BoxConstraint`). All vertices of the
+/// simplex, including the initial ones, are then projected onto the feasible box before they are
+/// evaluated.
+///
 /// ## References
 ///
 /// <https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method>
@@ -60,7 +68,7 @@ use std::fmt;
 /// <http://www.scholarpedia.org/article/Nelder-Mead_algorithm#Simplex_transformation_algorithm>
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct NelderMead<P, F> {
+pub struct NelderMead<P, F, B = IgnoreBounds> {
     /// alpha
     alpha: F,
     /// gamma
@@ -73,9 +81,11 @@ pub struct NelderMead<P, F> {
     params: Vec<(P, F)>,
     /// Sample standard deviation tolerance
     sd_tolerance: F,
+    /// handling of box constraints
+    box_constraints: PhantomData<B>,
 }
 
-impl<P, F> NelderMead<P, F>
+impl<P, F> NelderMead<P, F, IgnoreBounds>
 where
     P: Clone + ArgminAdd<P, P> + ArgminSub<P, P> + ArgminMul<F, P>,
     F: ArgminFloat,
@@ -100,9 +110,41 @@ where
             sigma: float!(0.5),
             params: params.into_iter().map(|p| (p, F::nan())).collect(),
             sd_tolerance: F::epsilon(),
+            box_constraints: PhantomData,
+        }
+    }
+
+    /// Respect the box constraints of the problem
+    ///
+    /// Requires the problem to implement [`BoxConstraint`](`crate::core::BoxConstraint`).
+    /// Vertices of the simplex are projected onto the feasible box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # let vec_of_parameters = vec![vec![1.0], vec![2.0], vec![3.0]];
+    /// let nm: NelderMead<Vec<f64>, f64, _> =
+    ///     NelderMead::new(vec_of_parameters).with_box_constraints();
+    /// ```
+    pub fn with_box_constraints(self) -> NelderMead<P, F, ProjectOntoBounds> {
+        NelderMead {
+            alpha: self.alpha,
+            gamma: self.gamma,
+            rho: self.rho,
+            sigma: self.sigma,
+            params: self.params,
+            sd_tolerance: self.sd_tolerance,
+            box_constraints: PhantomData,
         }
     }
+}
 
+impl<P, F, B> NelderMead<P, F, B>
+where
+    P: Clone + ArgminAdd<P, P> + ArgminSub<P, P> + ArgminMul<F, P>,
+    F: ArgminFloat,
+{
     /// Set sample standard deviation tolerance
     ///
     /// Must be non-negative and defaults to `EPSILON`.
@@ -278,7 +320,7 @@ where
     /// Shrink
     fn shrink<S>(&mut self, mut cost: S) -> Result<(), Error>
     where
-        S: FnMut(&P) -> Result<F, Error>,
+        S: FnMut(&mut P) -> Result<F, Error>,
     {
         // The best parameter vector unfortunately has to be cloned once.
         let x0 = self.params[0].0.clone();
@@ -295,6 +337,14 @@ where
     }
 }
 
+/// Project `param` onto the feasible box if box constraints are enabled
+fn project<O, P, B>(problem: &Problem<O>, param: P) -> Result<P, Error>
+where
+    B: BoundsHandling<O, P>,
+{
+    Ok(B::project(problem, &param)?.unwrap_or(param))
+}
+
 #[derive(Debug)]
 enum Action {
     Reflection,
@@ -316,11 +366,12 @@ impl fmt::Display for Action {
     }
 }
 
-impl<O, P, F> Solver<O, IterState<P, (), (), (), (), F>> for NelderMead<P, F>
+impl<O, P, F, B> Solver<O, IterState<P, (), (), (), (), F>> for NelderMead<P, F, B>
 where
     O: CostFunction<Param = P, Output = F>,
     P: Clone + ArgminSub<P, P> + ArgminAdd<P, P> + ArgminMul<F, P>,
     F: ArgminFloat + std::iter::Sum<F>,
+    B: BoundsHandling<O, P>,
 {
     fn name(&self) -> &str {
         "Nelder-Mead method"
@@ -331,9 +382,12 @@ where
         problem: &mut Problem<O>,
         state: IterState<P, (), (), (), (), F>,
     ) -> Result<(IterState<P, (), (), (), (), F>, Option<KV>), Error> {
-        self.params
-            .iter_mut()
-            .for_each(|(p, c)| *c = problem.cost(p).unwrap());
+        for (p, c) in self.params.iter_mut() {
+            if let Some(projected) = B::project(problem, p)? {
+                *p = projected;
+            }
+            *c = problem.cost(p)?;
+        }
 
         self.sort_param_vecs();
 
@@ -356,7 +410,7 @@ where
         let p_worst = &self.params[num_param_vecs - 1];
         let p_second_worst = &self.params[num_param_vecs - 2];
 
-        let xr = self.reflect(&x0, &p_worst.0);
+        let xr = project::<O, P, B>(problem, self.reflect(&x0, &p_worst.0))?;
         let xr_cost = problem.cost(&xr)?;
 
         let action = if xr_cost < p_second_worst.1 && xr_cost >= p_best.1 {
@@ -365,7 +419,7 @@ where
             Action::Reflection
         } else if xr_cost < p_best.1 {
             // expansion
-            let xe = self.expand(&x0, &xr);
+            let xe = project::<O, P, B>(problem, self.expand(&x0, &xr))?;
             let xe_cost = problem.cost(&xe)?;
             *self.params.last_mut().unwrap() = if xe_cost < xr_cost {
                 (xe, xe_cost)
@@ -377,26 +431,36 @@ where
             // contraction
             if xr_cost < p_worst.1 {
                 // Outside
-                let xc = self.contract(&x0, &xr);
+                let xc = project::<O, P, B>(problem, self.contract(&x0, &xr))?;
                 let xc_cost = problem.cost(&xc)?;
                 if xc_cost <= xr_cost {
                     *self.params.last_mut().unwrap() = (xc, xc_cost);
                     Action::ContractionOutside
                 } else {
                     // shrink
-                    self.shrink(|x| problem.cost(x))?;
+                    self.shrink(|x| {
+                        if let Some(projected) = B::project(problem, x)? {
+                            *x = projected;
+                        }
+                        problem.cost(x)
+                    })?;
                     Action::Shrink
                 }
             } else {
                 // Inside
-                let xc = self.contract(&x0, &p_worst.0);
+                let xc = project::<O, P, B>(problem, self.contract(&x0, &p_worst.0))?;
                 let xc_cost = problem.cost(&xc)?;
                 if xc_cost < p_worst.1 {
                     *self.params.last_mut().unwrap() = (xc, xc_cost);
                     Action::ContractionInside
                 } else {
                     // shrink
-                    self.shrink(|x| problem.cost(x))?;
+                    self.shrink(|x| {
+                        if let Some(projected) = B::project(problem, x)? {
+                            *x = projected;
+                        }
+                        problem.cost(x)
+                    })?;
                     Action::Shrink
                 }
             }
@@ -435,7 +499,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{test_utils::TestProblem, ArgminError, State};
+    use crate::core::test_utils::{TestBoxProblem, TestProblem};
+    use crate::core::{ArgminError, Executor, State};
     use approx::assert_relative_eq;
 
     test_trait_impl!(nelder_mead, NelderMead<TestProblem, f64>);
@@ -463,6 +528,7 @@ mod tests {
             sigma,
             params,
             sd_tolerance,
+            box_constraints,
         } = nm;
 
         assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
@@ -474,6 +540,7 @@ mod tests {
         assert_eq!(params[0].1.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(params[1].1.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(sd_tolerance.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+        assert_eq!(box_constraints, PhantomData::<IgnoreBounds>);
     }
 
     #[test]
@@ -868,4 +935,20 @@ mod tests {
         assert_relative_eq!(nm.params[2].0[1], 0.0f64, epsilon = f64::EPSILON);
         assert_relative_eq!(nm.params[2].1, 1.00f64, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_box_constraints() {
+        let nm = NelderMead::new(vec![vec![0.0, 0.0], vec![0.5, 0.0], vec![0.0, 0.5]])
+            .with_box_constraints();
+        let res = Executor::new(TestBoxProblem::new(), nm)
+            .configure(|state| state.max_iters(200))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert!(param.iter().all(|p| (-1.0..=1.0).contains(p)));
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -1.0, epsilon = 1e-6);
+        assert_relative_eq!(res.state.get_best_cost(), 2.0, epsilon = 1e-6);
+    }
 }
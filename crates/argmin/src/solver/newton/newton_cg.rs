@@ -115,7 +115,7 @@ where
         + ArgminMul<F, P>
         + ArgminConj
         + ArgminZeroLike,
-    G: ArgminL2Norm<F> + ArgminMul<F, P>,
+    G: Clone + ArgminL2Norm<F> + ArgminMul<F, P>,
     H: Clone + ArgminDot<P, P>,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), (), F>>,
     F: ArgminFloat + ArgminL2Norm<F>,
@@ -196,6 +196,7 @@ where
             ..
         } = Executor::new(problem.take_problem().unwrap(), self.linesearch.clone())
             .configure(|state| state.param(param).gradient(grad).cost(line_cost))
+            .inherit_func_eval_budget(problem)
             .ctrlc(false)
             .run()?;
 
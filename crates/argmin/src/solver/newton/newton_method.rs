@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Error, Gradient, Hessian, IterState, Problem, Solver, KV};
-use argmin_math::{ArgminDot, ArgminInv, ArgminScaledSub};
+use argmin_math::{ArgminScaledSub, ArgminSolve};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -88,7 +88,7 @@ impl<O, P, G, H, F> Solver<O, IterState<P, G, (), H, (), F>> for Newton<F>
 where
     O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
     P: Clone + ArgminScaledSub<P, F, P>,
-    H: ArgminInv<H> + ArgminDot<G, P>,
+    H: ArgminSolve<G, P>,
     F: ArgminFloat,
 {
     fn name(&self) -> &str {
@@ -109,7 +109,7 @@ where
         ))?;
         let grad = problem.gradient(&param)?;
         let hessian = problem.hessian(&param)?;
-        let new_param = param.scaled_sub(&self.gamma, &hessian.inv()?.dot(&grad));
+        let new_param = param.scaled_sub(&self.gamma, &hessian.solve(&grad)?);
         Ok((state.param(new_param), None))
     }
 }
@@ -21,12 +21,14 @@
 //! \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, PopulationState, Problem, Solver, SyncAlias, KV,
+    ArgminFloat, BoundsHandling, CostFunction, Error, IgnoreBounds, PopulationState, Problem,
+    ProjectOntoBounds, Solver, SyncAlias, KV,
 };
 use argmin_math::{ArgminAdd, ArgminMinMax, ArgminMul, ArgminRandom, ArgminSub, ArgminZeroLike};
 use rand::{Rng, SeedableRng};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
 /// # Particle Swarm Optimization (PSO)
 ///
@@ -41,6 +43,14 @@ use serde::{Deserialize, Serialize};
 ///
 /// The optimization problem is required to implement [`CostFunction`].
 ///
+/// ## Box constraints
+///
+/// The bounds passed to [`new`](`ParticleSwarm::new`) only define the search window in which
+/// particles are initialized and moved. With
+/// [`with_box_constraints`](`ParticleSwarm::with_box_constraints`), the problem is additionally
+/// required to implement [`BoxConstraint`](`crate::core::BoxConstraint`) and all particle
+/// positions are projected onto the feasible box of the problem before they are evaluated.
+///
 /// ## References
 ///
 /// \[0\] Zambrano-Bigiarini, M. et.al. (2013): Standard Particle Swarm Optimisation 2011 at
@@ -50,7 +60,7 @@ use serde::{Deserialize, Serialize};
 /// \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct ParticleSwarm<P, F, R> {
+pub struct ParticleSwarm<P, F, R, B = IgnoreBounds> {
     /// Inertia weight
     weight_inertia: F,
     /// Cognitive acceleration coefficient
@@ -63,6 +73,8 @@ pub struct ParticleSwarm<P, F, R> {
     num_particles: usize,
     /// Random number generator
     rng_generator: R,
+    /// handling of box constraints
+    box_constraints: PhantomData<B>,
 }
 
 impl<P, F> ParticleSwarm<P, F, rand::rngs::StdRng>
@@ -103,10 +115,11 @@ where
             bounds,
             num_particles,
             rng_generator: rand::rngs::StdRng::from_entropy(),
+            box_constraints: PhantomData,
         }
     }
 }
-impl<P, F, R0> ParticleSwarm<P, F, R0>
+impl<P, F, R0, B> ParticleSwarm<P, F, R0, B>
 where
     P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
     F: ArgminFloat,
@@ -130,7 +143,7 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    pub fn with_rng_generator<R1: Rng>(self, generator: R1) -> ParticleSwarm<P, F, R1> {
+    pub fn with_rng_generator<R1: Rng>(self, generator: R1) -> ParticleSwarm<P, F, R1, B> {
         ParticleSwarm {
             weight_inertia: self.weight_inertia,
             weight_cognitive: self.weight_cognitive,
@@ -138,11 +151,37 @@ where
             bounds: self.bounds,
             num_particles: self.num_particles,
             rng_generator: generator,
+            box_constraints: PhantomData,
+        }
+    }
+
+    /// Respect the box constraints of the problem
+    ///
+    /// Requires the problem to implement [`BoxConstraint`](`crate::core::BoxConstraint`).
+    /// Particle positions are projected onto the feasible box.
+    ///
+    /// # Example
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64, _, _> =
+    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_box_constraints();
+    /// ```
+    pub fn with_box_constraints(self) -> ParticleSwarm<P, F, R0, ProjectOntoBounds> {
+        ParticleSwarm {
+            weight_inertia: self.weight_inertia,
+            weight_cognitive: self.weight_cognitive,
+            weight_social: self.weight_social,
+            bounds: self.bounds,
+            num_particles: self.num_particles,
+            rng_generator: self.rng_generator,
+            box_constraints: PhantomData,
         }
     }
 }
 
-impl<P, F, R> ParticleSwarm<P, F, R>
+impl<P, F, R, B> ParticleSwarm<P, F, R, B>
 where
     P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
     F: ArgminFloat,
@@ -236,8 +275,15 @@ where
     fn initialize_particles<O: CostFunction<Param = P, Output = F> + SyncAlias>(
         &mut self,
         problem: &mut Problem<O>,
-    ) -> Result<Vec<Particle<P, F>>, Error> {
+    ) -> Result<Vec<Particle<P, F>>, Error>
+    where
+        B: BoundsHandling<O, P>,
+    {
         let (positions, velocities) = self.initialize_positions_and_velocities();
+        let positions = positions
+            .into_iter()
+            .map(|p| Ok(B::project(problem, &p)?.unwrap_or(p)))
+            .collect::<Result<Vec<_>, Error>>()?;
 
         let costs = problem.bulk_cost(&positions)?;
 
@@ -275,7 +321,7 @@ where
     }
 }
 
-impl<O, P, F, R> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F, R>
+impl<O, P, F, R, B> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F, R, B>
 where
     O: CostFunction<Param = P, Output = F> + SyncAlias,
     P: Clone
@@ -288,6 +334,7 @@ where
         + ArgminMinMax,
     F: ArgminFloat,
     R: Rng,
+    B: BoundsHandling<O, P>,
 {
     fn name(&self) -> &str {
         "Particle Swarm Optimization"
@@ -377,9 +424,12 @@ where
 
                 // Limit to search window
                 p.position = P::min(&P::max(&new_position, &self.bounds.0), &self.bounds.1);
-                &p.position
+                if let Some(projected) = B::project(problem, &p.position)? {
+                    p.position = projected;
+                }
+                Ok(&p.position)
             })
-            .collect();
+            .collect::<Result<_, Error>>()?;
 
         let costs = problem.bulk_cost(&positions)?;
 
@@ -453,7 +503,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{test_utils::TestProblem, ArgminError, State};
+    use crate::core::test_utils::{TestBoxProblem, TestProblem};
+    use crate::core::{ArgminError, Executor, State};
     use approx::assert_relative_eq;
 
     test_trait_impl!(
@@ -779,4 +830,23 @@ mod tests {
             assert_eq!(state.get_cost().to_ne_bytes(), (-3.0f64).to_ne_bytes());
         }
     }
+
+    #[test]
+    fn test_box_constraints() {
+        let pso = ParticleSwarm::new((vec![-4.0, -4.0], vec![4.0, 4.0]), 20)
+            .with_rng_generator(rand::rngs::StdRng::seed_from_u64(42))
+            .with_box_constraints();
+        let res = Executor::new(TestBoxProblem::new(), pso)
+            .configure(|state| state.max_iters(50))
+            .run()
+            .unwrap();
+
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best.position[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(best.position[1], -1.0, epsilon = 1e-6);
+        assert_relative_eq!(res.state.get_best_cost(), 2.0, epsilon = 1e-6);
+        for particle in res.state.get_population().unwrap() {
+            assert!(particle.position.iter().all(|x| (-1.0..=1.0).contains(x)));
+        }
+    }
 }
@@ -176,17 +176,13 @@ where
         ))?;
 
         let cost = state.get_cost();
-        let cost = if cost.is_infinite() {
-            problem.cost(&param)?
-        } else {
-            cost
+        let (cost, grad) = match (cost.is_infinite(), state.take_gradient()) {
+            (true, None) => problem.cost_and_gradient(&param)?,
+            (true, Some(grad)) => (problem.cost(&param)?, grad),
+            (false, None) => (cost, problem.gradient(&param)?),
+            (false, Some(grad)) => (cost, grad),
         };
 
-        let grad = state
-            .take_gradient()
-            .map(Result::Ok)
-            .unwrap_or_else(|| problem.gradient(&param))?;
-
         Ok((
             state
                 .param(param)
@@ -235,6 +231,7 @@ where
                     .gradient(prev_grad.clone())
                     .cost(cur_cost)
             })
+            .inherit_func_eval_budget(problem)
             .ctrlc(false)
             .run()?;
 
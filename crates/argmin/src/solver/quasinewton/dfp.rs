@@ -187,6 +187,7 @@ where
                     .gradient(prev_grad.clone())
                     .cost(cost)
             })
+            .inherit_func_eval_budget(problem)
             .ctrlc(false)
             .run()?;
 
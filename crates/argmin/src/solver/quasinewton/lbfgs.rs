@@ -6,8 +6,9 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, Executor, Gradient, IterState, LineSearch,
-    OptimizationResult, Problem, Solver, State, TerminationReason, TerminationStatus, KV,
+    is_interruption, ArgminFloat, BoundsHandling, CostFunction, Error, Executor, Gradient,
+    IgnoreBounds, IterState, LineSearch, OptimizationResult, Problem, ProjectOntoBounds, Solver,
+    State, TerminationReason, TerminationStatus, KV,
 };
 use argmin_math::{
     ArgminAdd, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminSignum,
@@ -66,6 +67,15 @@ where
 ///
 /// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
 ///
+/// ## Box constraints
+///
+/// With [`with_box_constraints`](`LBFGS::with_box_constraints`), the problem is additionally
+/// required to implement [`BoxConstraint`](`crate::core::BoxConstraint`). The search direction
+/// then points towards the projection of the unconstrained step onto the feasible box and the
+/// parameter vector is projected onto the box after each line search. This is a simple projection
+/// heuristic; for problems where many bounds are active at the solution,
+/// [`LBFGSB`](`crate::solver::quasinewton::LBFGSB`) is usually the better choice.
+///
 /// ## Reference
 ///
 /// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
@@ -75,7 +85,7 @@ where
 /// International Conference on Machine Learning.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct LBFGS<L, P, G, F> {
+pub struct LBFGS<L, P, G, F, B = IgnoreBounds> {
     /// line search
     linesearch: L,
     /// m
@@ -92,9 +102,11 @@ pub struct LBFGS<L, P, G, F> {
     l1_coeff: Option<F>,
     /// Unregularized gradient used for calculation of `y`.
     l1_prev_unreg_grad: Option<G>,
+    /// handling of box constraints
+    box_constraints: PhantomData<B>,
 }
 
-impl<L, P, G, F> LBFGS<L, P, G, F>
+impl<L, P, G, F> LBFGS<L, P, G, F, IgnoreBounds>
 where
     F: ArgminFloat,
 {
@@ -117,9 +129,42 @@ where
             tol_cost: F::epsilon(),
             l1_coeff: None,
             l1_prev_unreg_grad: None,
+            box_constraints: PhantomData,
         }
     }
 
+    /// Respect the box constraints of the problem
+    ///
+    /// Requires the problem to implement [`BoxConstraint`](`crate::core::BoxConstraint`).
+    /// Iterates are projected onto the feasible box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # let linesearch = ();
+    /// let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64, _> =
+    ///     LBFGS::new(linesearch, 5).with_box_constraints();
+    /// ```
+    pub fn with_box_constraints(self) -> LBFGS<L, P, G, F, ProjectOntoBounds> {
+        LBFGS {
+            linesearch: self.linesearch,
+            m: self.m,
+            s: self.s,
+            y: self.y,
+            tol_grad: self.tol_grad,
+            tol_cost: self.tol_cost,
+            l1_coeff: self.l1_coeff,
+            l1_prev_unreg_grad: self.l1_prev_unreg_grad,
+            box_constraints: PhantomData,
+        }
+    }
+}
+
+impl<L, P, G, F, B> LBFGS<L, P, G, F, B>
+where
+    F: ArgminFloat,
+{
     /// The algorithm stops if the norm of the gradient is below `tol_grad`.
     ///
     /// The provided value must be non-negative. Defaults to `sqrt(EPSILON)`.
@@ -301,7 +346,7 @@ where
     }
 }
 
-impl<O, L, P, G, F> Solver<O, IterState<P, G, (), (), (), F>> for LBFGS<L, P, G, F>
+impl<O, L, P, G, F, B> Solver<O, IterState<P, G, (), (), (), F>> for LBFGS<L, P, G, F, B>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
@@ -332,6 +377,7 @@ where
         + LineSearch<P, F>
         + Solver<LineSearchProblem<O, P, G, F>, IterState<P, G, (), (), (), F>>,
     F: ArgminFloat,
+    B: BoundsHandling<O, P>,
 {
     fn name(&self) -> &str {
         "L-BFGS"
@@ -342,7 +388,7 @@ where
         problem: &mut Problem<O>,
         mut state: IterState<P, G, (), (), (), F>,
     ) -> Result<(IterState<P, G, (), (), (), F>, Option<KV>), Error> {
-        let param = state.take_param().ok_or_else(argmin_error_closure!(
+        let mut param = state.take_param().ok_or_else(argmin_error_closure!(
             NotInitialized,
             concat!(
                 "`L-BFGS` requires an initial parameter vector. ",
@@ -350,21 +396,24 @@ where
             )
         ))?;
 
-        let cost = state.get_cost();
-        let cost = if cost.is_infinite() {
-            if let Some(l1_coeff) = self.l1_coeff {
-                problem.cost(&param)? + l1_coeff * param.l1_norm()
-            } else {
-                problem.cost(&param)?
-            }
-        } else {
-            cost
+        let mut cost = state.get_cost();
+        if let Some(projected) = B::project(problem, &param)? {
+            // cost and gradient provided by the user may belong to the infeasible parameter vector
+            param = projected;
+            cost = F::infinity();
+            state.take_gradient();
+        }
+        let cost_missing = cost.is_infinite();
+        let (cost, grad) = match (cost_missing, state.take_gradient()) {
+            (true, None) => problem.cost_and_gradient(&param)?,
+            (true, Some(grad)) => (problem.cost(&param)?, grad),
+            (false, None) => (cost, problem.gradient(&param)?),
+            (false, Some(grad)) => (cost, grad),
+        };
+        let cost = match self.l1_coeff {
+            Some(l1_coeff) if cost_missing => cost + l1_coeff * param.l1_norm(),
+            _ => cost,
         };
-
-        let grad = state
-            .take_gradient()
-            .map(Result::Ok)
-            .unwrap_or_else(|| problem.gradient(&param))?;
 
         Ok((state.param(param).cost(cost).gradient(grad), None))
     }
@@ -420,9 +469,7 @@ where
             r = r.add(&sk.mul(&(alpha[i] - beta)));
         }
 
-        let mut line_problem = LineSearchProblem::new(problem.take_problem().unwrap());
-        let d = if let Some(l1_coeff) = self.l1_coeff {
-            line_problem.with_l1_constraint(l1_coeff, &param, &prev_grad);
+        let d = if self.l1_coeff.is_some() {
             let zeros = r.zero_like();
             P::max(
                 &r.mul(&prev_grad).sub(&F::min_positive_value()).signum(),
@@ -434,6 +481,41 @@ where
             r.mul(&float!(-1.0))
         };
 
+        // With box constraints, the search direction is chosen such that a unit step ends on the
+        // projection of the unconstrained step. If this is not a descent direction, the projected
+        // steepest descent direction is used instead.
+        let d = if let Some(projected) = B::project(problem, &param.add(&d))? {
+            let d = projected.sub(&param);
+            if d.dot(&prev_grad) < float!(0.0) {
+                d
+            } else {
+                let steepest: P = prev_grad.mul(&float!(-1.0));
+                let d = B::project(problem, &param.add(&steepest))?
+                    .unwrap_or(steepest)
+                    .sub(&param);
+                if d.dot(&prev_grad) >= float!(0.0) {
+                    // No feasible descent direction left: the current parameter vector is a
+                    // stationary point of the constrained problem.
+                    return Ok((
+                        state
+                            .param(param)
+                            .cost(cur_cost)
+                            .gradient(prev_grad)
+                            .terminate_with(TerminationReason::SolverConverged),
+                        Some(kv!("gamma" => gamma;)),
+                    ));
+                }
+                d
+            }
+        } else {
+            d
+        };
+
+        let mut line_problem = LineSearchProblem::new(problem.take_problem().unwrap());
+        if let Some(l1_coeff) = self.l1_coeff {
+            line_problem.with_l1_constraint(l1_coeff, &param, &prev_grad);
+        }
+
         self.linesearch.search_direction(d);
 
         // Run line search
@@ -444,6 +526,7 @@ where
                     .gradient(prev_grad.clone())
                     .cost(cur_cost)
             })
+            .inherit_func_eval_budget(problem)
             .ctrlc(false)
             .run();
 
@@ -453,6 +536,8 @@ where
             ..
         } = match linesearch_result {
             Ok(res) => res,
+            // Interruptions by `Executor::run_async` must be passed on
+            Err(e) if is_interruption(&e) => return Err(e),
             Err(e) => {
                 return Ok((
                     state.terminate_with(TerminationReason::SolverExit(format!(
@@ -465,7 +550,7 @@ where
         };
 
         let mut xk1 = linesearch_state.take_param().unwrap();
-        let next_cost = linesearch_state.get_cost();
+        let mut next_cost = linesearch_state.get_cost();
 
         // take back problem and take care of function evaluation counts
         let mut internal_line_problem = line_problem.take_problem().unwrap();
@@ -476,14 +561,24 @@ where
             let zeros = xk1.zero_like();
             xk1 = P::max(&xk1.mul(&xi).signum(), &zeros).mul(&xk1);
         }
+        let grad = if let Some(projected) = B::project(problem, &xk1)? {
+            xk1 = projected;
+            let (cost, grad) = problem.cost_and_gradient(&xk1)?;
+            next_cost = if let Some(l1_coeff) = self.l1_coeff {
+                cost + l1_coeff * xk1.l1_norm()
+            } else {
+                cost
+            };
+            grad
+        } else {
+            problem.gradient(&xk1)?
+        };
 
         if state.get_iter() >= self.m as u64 {
             self.s.pop_front();
             self.y.pop_front();
         }
 
-        let grad = problem.gradient(&xk1)?;
-
         self.s.push_back(xk1.sub(&param));
         let grad = if let Some(l1_coeff) = self.l1_coeff {
             // Stores unregularized gradient and returns L1 gradient.
@@ -519,9 +614,11 @@ mod tests {
     use super::*;
     use crate::core::{
         test_utils::{TestProblem, TestSparseProblem},
-        ArgminError,
+        ArgminError, BoxConstraint,
     };
     use crate::solver::linesearch::MoreThuenteLineSearch;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
 
     test_trait_impl!(
         lbfgs,
@@ -543,6 +640,7 @@ mod tests {
             y,
             l1_coeff,
             l1_prev_unreg_grad,
+            box_constraints,
         } = lbfgs;
 
         assert_eq!(linesearch, MyFakeLineSearch {});
@@ -553,6 +651,7 @@ mod tests {
         assert!(y.capacity() >= 3);
         assert!(l1_coeff.is_none());
         assert!(l1_prev_unreg_grad.is_none());
+        assert_eq!(box_constraints, PhantomData::<IgnoreBounds>);
     }
 
     #[test]
@@ -742,4 +841,49 @@ mod tests {
             assert!((result_param[3]).abs() < 1e-6);
         }
     }
+
+    #[test]
+    fn test_box_constraints() {
+        struct BoundedRosenbrock {}
+
+        impl CostFunction for BoundedRosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock(p))
+            }
+        }
+
+        impl Gradient for BoundedRosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_derivative(p))
+            }
+        }
+
+        impl BoxConstraint for BoundedRosenbrock {
+            type Param = Vec<f64>;
+
+            fn bounds(&self) -> Result<(Self::Param, Self::Param), Error> {
+                Ok((vec![-2.0, -2.0], vec![0.5, 2.0]))
+            }
+        }
+
+        let linesearch = MoreThuenteLineSearch::new();
+        let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64, _> =
+            LBFGS::new(linesearch, 5).with_box_constraints();
+
+        let res = Executor::new(BoundedRosenbrock {}, lbfgs)
+            .configure(|state| state.param(vec![1.5, 1.5]).max_iters(100))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert!(param[0] <= 0.5);
+        assert_relative_eq!(param[0], 0.5, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 0.25, epsilon = 1e-4);
+    }
 }
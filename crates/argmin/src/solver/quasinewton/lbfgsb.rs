@@ -0,0 +1,1142 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Executor, Gradient, IterState, LineSearch,
+    OptimizationResult, Problem, Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminDot, ArgminProject, ArgminSolve};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// # Limited-memory BFGS method with bound constraints (L-BFGS-B)
+///
+/// L-BFGS-B minimizes a function subject to simple bounds `l <= x <= u` on the parameters. Each
+/// iteration consists of three steps:
+///
+/// 1. The generalized Cauchy point is computed by minimizing the quadratic model along the
+///    projected steepest descent path. This determines the set of variables which are at their
+///    bounds (active set).
+/// 2. The quadratic model is minimized with respect to the remaining free variables (subspace
+///    minimization) and the resulting step is truncated such that it stays within the bounds.
+/// 3. A line search is performed from the current parameter vector towards the subspace
+///    minimizer. Parameter vectors are projected onto the feasible box during the line search,
+///    therefore any of the line searches implementing [`LineSearch`] can be used.
+///
+/// The limited memory BFGS matrix is kept in compact representation (Byrd, Nocedal and Schnabel,
+/// 1994) with a history size of `m` correction pairs. Correction pairs which do not satisfy the
+/// curvature condition are skipped.
+///
+/// Bounds are set via [`with_bounds`](`LBFGSB::with_bounds`). Infinite values may be used to
+/// leave individual parameters unbounded from one or both sides. If no bounds are provided, all
+/// parameters are unbounded.
+///
+/// An initial guess for the parameter vector is required, which is to be provided via the
+/// [`configure`](`crate::core::Executor::configure`) method of the
+/// [`Executor`](`crate::core::Executor`) (See [`IterState`], in particular [`IterState::param`]).
+/// It is projected onto the feasible box if necessary. The initial gradient and cost function
+/// value can be provided in the same way. If these are not provided, they will be computed during
+/// initialization of the algorithm.
+///
+/// Two tolerances can be configured, which are both needed in the stopping criteria.
+/// One is a tolerance on the projected gradient (set with
+/// [`with_tolerance_grad`](`LBFGSB::with_tolerance_grad`)): If the infinity norm of the projected
+/// gradient `P(x - g) - x` is below said tolerance, the algorithm stops. It defaults to
+/// `sqrt(EPSILON)`. The other one is a tolerance on the change of the cost function from one
+/// iteration to the other. If the change is below this tolerance (default: `EPSILON`), the
+/// algorithm stops. This parameter can be set via
+/// [`with_tolerance_cost`](`LBFGSB::with_tolerance_cost`).
+///
+/// The solver operates on parameter vectors of type `Vec<F>`, because the computation of the
+/// generalized Cauchy point requires access to individual elements. The small dense systems of the
+/// compact representation are solved via the `Vec<Vec<F>>` implementation of [`ArgminSolve`],
+/// which requires the `vec` feature of `argmin-math`. If one of these systems is singular or the
+/// limited memory matrix does not yield a descent direction, the correction pairs are discarded
+/// and the search direction is recomputed. This is reported via the `memory_reset` entry of the
+/// key-value store of the iteration.
+///
+/// Errors of the line search are passed on, because the problem is owned by the line search at
+/// that point and cannot be recovered.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## References
+///
+/// Richard H. Byrd, Peihuang Lu, Jorge Nocedal and Ciyou Zhu (1995). A limited memory algorithm
+/// for bound constrained optimization. SIAM Journal on Scientific Computing 16(5), 1190-1208.
+/// DOI: <https://doi.org/10.1137/0916069>
+///
+/// Richard H. Byrd, Jorge Nocedal and Robert B. Schnabel (1994). Representations of quasi-Newton
+/// matrices and their use in limited memory methods. Mathematical Programming 63, 129-156.
+/// DOI: <https://doi.org/10.1007/BF01582063>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LBFGSB<L, F> {
+    /// line search
+    linesearch: L,
+    /// m
+    m: usize,
+    /// Lower and upper bounds
+    bounds: Option<(Vec<F>, Vec<F>)>,
+    /// s_{k-1}
+    s: VecDeque<Vec<F>>,
+    /// y_{k-1}
+    y: VecDeque<Vec<F>>,
+    /// Scaling factor of the BFGS matrix
+    theta: F,
+    /// Tolerance for the stopping criterion based on the projected gradient
+    tol_grad: F,
+    /// Tolerance for the stopping criterion based on the change of the cost stopping criterion
+    tol_cost: F,
+}
+
+impl<L, F> LBFGSB<L, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`LBFGSB`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGSB;
+    /// # let linesearch = ();
+    /// let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(linesearch, 5);
+    /// ```
+    pub fn new(linesearch: L, m: usize) -> Self {
+        LBFGSB {
+            linesearch,
+            m,
+            bounds: None,
+            s: VecDeque::with_capacity(m),
+            y: VecDeque::with_capacity(m),
+            theta: float!(1.0),
+            tol_grad: F::epsilon().sqrt(),
+            tol_cost: F::epsilon(),
+        }
+    }
+
+    /// Set lower and upper bounds of the parameters.
+    ///
+    /// Both vectors must be of the same length as the parameter vector and each lower bound must
+    /// be smaller than or equal to the corresponding upper bound. Use `F::neg_infinity()` and
+    /// `F::infinity()` for parameters which are unbounded from below or above, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGSB;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let lbfgsb: LBFGSB<_, f64> =
+    ///     LBFGSB::new(linesearch, 5).with_bounds(vec![-1.0, 0.0], vec![1.0, f64::INFINITY])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_bounds(mut self, lower: Vec<F>, upper: Vec<F>) -> Result<Self, Error> {
+        if lower.len() != upper.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L-BFGS-B`: lower and upper bounds must have the same length."
+            ));
+        }
+        if lower
+            .iter()
+            .zip(upper.iter())
+            .any(|(l, u)| l.is_nan() || u.is_nan() || l > u)
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L-BFGS-B`: lower bounds must be <= upper bounds."
+            ));
+        }
+        self.bounds = Some((lower, upper));
+        Ok(self)
+    }
+
+    /// The algorithm stops if the infinity norm of the projected gradient is below `tol_grad`.
+    ///
+    /// The provided value must be non-negative. Defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGSB;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(linesearch, 3).with_tolerance_grad(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_grad(mut self, tol_grad: F) -> Result<Self, Error> {
+        if tol_grad < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L-BFGS-B`: gradient tolerance must be >= 0."
+            ));
+        }
+        self.tol_grad = tol_grad;
+        Ok(self)
+    }
+
+    /// Sets tolerance for the stopping criterion based on the change of the cost stopping criterion
+    ///
+    /// The provided value must be non-negative. Defaults to `EPSILON`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGSB;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(linesearch, 3).with_tolerance_cost(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_cost(mut self, tol_cost: F) -> Result<Self, Error> {
+        if tol_cost < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L-BFGS-B`: cost tolerance must be >= 0."
+            ));
+        }
+        self.tol_cost = tol_cost;
+        Ok(self)
+    }
+
+    /// Returns the lower and upper bounds. Only call this after `init`.
+    fn get_bounds(&self) -> Result<(&Vec<F>, &Vec<F>), Error> {
+        let (lower, upper) = self.bounds.as_ref().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`L-BFGS-B`: Bounds not set."
+        ))?;
+        Ok((lower, upper))
+    }
+}
+
+impl<L, F> LBFGSB<L, F>
+where
+    F: ArgminFloat,
+    Vec<F>: ArgminDot<Vec<F>, F>,
+    Vec<Vec<F>>: ArgminSolve<Vec<F>, Vec<F>>,
+{
+    /// Row `i` of `W = [Y, theta * S]`
+    fn w_row(&self, i: usize) -> Vec<F> {
+        self.y
+            .iter()
+            .map(|yk| yk[i])
+            .chain(self.s.iter().map(|sk| self.theta * sk[i]))
+            .collect()
+    }
+
+    /// Computes `K = [[-D, L^T], [L, theta * S^T S]]`, the inverse of the middle matrix `M` of the
+    /// compact representation `B = theta * I - W M W^T`. Products with `M` are computed by solving
+    /// systems with `K`.
+    fn middle_matrix(&self) -> Vec<Vec<F>> {
+        let k = self.s.len();
+        let mut mat = vec![vec![float!(0.0); 2 * k]; 2 * k];
+        for i in 0..k {
+            for j in 0..k {
+                let sy = self.s[i].dot(&self.y[j]);
+                if i == j {
+                    mat[i][j] = -sy;
+                } else if i > j {
+                    mat[k + i][j] = sy;
+                    mat[j][k + i] = sy;
+                }
+                mat[k + i][k + j] = self.theta * self.s[i].dot(&self.s[j]);
+            }
+        }
+        mat
+    }
+
+    /// Computes the generalized Cauchy point. Returns the Cauchy point and the vector
+    /// `c = W^T (x_cp - x)`.
+    fn cauchy_point(
+        &self,
+        x: &[F],
+        g: &[F],
+        lower: &[F],
+        upper: &[F],
+        mid: &Vec<Vec<F>>,
+    ) -> Result<(Vec<F>, Vec<F>), Error> {
+        let n = x.len();
+        let k2 = mid.len();
+
+        // Breakpoints and search direction
+        let mut t = vec![F::infinity(); n];
+        let mut d = vec![float!(0.0); n];
+        for i in 0..n {
+            if g[i] < float!(0.0) {
+                t[i] = (x[i] - upper[i]) / g[i];
+            } else if g[i] > float!(0.0) {
+                t[i] = (x[i] - lower[i]) / g[i];
+            }
+            if t[i] > float!(0.0) {
+                d[i] = -g[i];
+            }
+        }
+        let mut moving: Vec<bool> = t.iter().map(|&ti| ti > float!(0.0)).collect();
+        let mut order: Vec<usize> = (0..n).filter(|&i| moving[i] && t[i].is_finite()).collect();
+        order.sort_by(|&i, &j| t[i].partial_cmp(&t[j]).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut x_cp = x.to_vec();
+        let mut c = vec![float!(0.0); k2];
+        let mut p: Vec<F> = (0..k2)
+            .map(|j| (0..n).fold(float!(0.0), |acc, i| acc + self.w_entry(i, j) * d[i]))
+            .collect();
+
+        let mut f1 = -d.dot(&d);
+        if f1 >= float!(0.0) {
+            return Ok((x_cp, c));
+        }
+        let f2_org = -self.theta * f1;
+        let mut f2 = f2_org - p.dot(&mid.solve(&p)?);
+        f2 = f2.max(F::epsilon() * f2_org);
+        let mut dt_min = -f1 / f2;
+        let mut t_old = float!(0.0);
+
+        for &b in order.iter() {
+            let dt = t[b] - t_old;
+            if dt_min < dt {
+                break;
+            }
+
+            // Examine the next segment of the projected steepest descent path
+            x_cp[b] = if d[b] > float!(0.0) {
+                upper[b]
+            } else {
+                lower[b]
+            };
+            let z_b = x_cp[b] - x[b];
+            c.iter_mut()
+                .zip(p.iter())
+                .for_each(|(ci, &pi)| *ci = *ci + dt * pi);
+            let g_b = g[b];
+            let w_b = self.w_row(b);
+            let m_w_b = mid.solve(&w_b)?;
+            f1 = f1 + dt * f2 + g_b * g_b + self.theta * g_b * z_b - g_b * m_w_b.dot(&c);
+            f2 = f2
+                - self.theta * g_b * g_b
+                - float!(2.0) * g_b * m_w_b.dot(&p)
+                - g_b * g_b * m_w_b.dot(&w_b);
+            f2 = f2.max(F::epsilon() * f2_org);
+            p.iter_mut()
+                .zip(w_b.iter())
+                .for_each(|(pi, &wi)| *pi = *pi + g_b * wi);
+            d[b] = float!(0.0);
+            moving[b] = false;
+            dt_min = -f1 / f2;
+            t_old = t[b];
+        }
+
+        let dt_min = dt_min.max(float!(0.0));
+        let t_old = t_old + dt_min;
+        for i in (0..n).filter(|&i| moving[i]) {
+            x_cp[i] = x[i] + t_old * d[i];
+        }
+        c.iter_mut()
+            .zip(p.iter())
+            .for_each(|(ci, &pi)| *ci = *ci + dt_min * pi);
+        Ok((x_cp, c))
+    }
+
+    /// Element `(i, j)` of `W = [Y, theta * S]`
+    fn w_entry(&self, i: usize, j: usize) -> F {
+        let k = self.s.len();
+        if j < k {
+            self.y[j][i]
+        } else {
+            self.theta * self.s[j - k][i]
+        }
+    }
+
+    /// Minimizes the quadratic model over the variables which are free at the Cauchy point and
+    /// truncates the step such that it remains within the bounds.
+    #[allow(clippy::too_many_arguments)]
+    fn subspace_minimization(
+        &self,
+        x: &[F],
+        g: &[F],
+        lower: &[F],
+        upper: &[F],
+        mid: &Vec<Vec<F>>,
+        x_cp: Vec<F>,
+        c: &Vec<F>,
+    ) -> Result<Vec<F>, Error> {
+        let free: Vec<usize> = (0..x.len())
+            .filter(|&i| x_cp[i] > lower[i] && x_cp[i] < upper[i])
+            .collect();
+        if free.is_empty() {
+            return Ok(x_cp);
+        }
+
+        let k2 = mid.len();
+        let m_c = mid.solve(c)?;
+
+        // Reduced gradient of the quadratic model at the Cauchy point
+        let r_c: Vec<F> = free
+            .iter()
+            .map(|&i| {
+                let w_m_c = (0..k2).fold(float!(0.0), |acc, j| acc + self.w_entry(i, j) * m_c[j]);
+                g[i] + self.theta * (x_cp[i] - x[i]) - w_m_c
+            })
+            .collect();
+
+        let inv_theta = float!(1.0) / self.theta;
+        let mut d_u: Vec<F> = r_c.iter().map(|&r| -inv_theta * r).collect();
+
+        if k2 > 0 {
+            // v = (I - 1/theta * M W_Z^T W_Z)^{-1} M W_Z^T r_c, which is the solution of
+            // (K - 1/theta * W_Z^T W_Z) v = W_Z^T r_c
+            let wz_r: Vec<F> = (0..k2)
+                .map(|j| {
+                    free.iter()
+                        .zip(r_c.iter())
+                        .fold(float!(0.0), |acc, (&i, &r)| acc + self.w_entry(i, j) * r)
+                })
+                .collect();
+            let reduced: Vec<Vec<F>> = mid
+                .iter()
+                .enumerate()
+                .map(|(a, row)| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(b, &k_ab)| {
+                            let wz_wz = free.iter().fold(float!(0.0), |acc, &i| {
+                                acc + self.w_entry(i, a) * self.w_entry(i, b)
+                            });
+                            k_ab - inv_theta * wz_wz
+                        })
+                        .collect()
+                })
+                .collect();
+            let v = reduced.solve(&wz_r)?;
+
+            // d_u = -1/theta * r_c - 1/theta^2 * W_Z v
+            for (du, &i) in d_u.iter_mut().zip(free.iter()) {
+                let wz_v = (0..k2).fold(float!(0.0), |acc, j| acc + self.w_entry(i, j) * v[j]);
+                *du = *du - inv_theta * inv_theta * wz_v;
+            }
+        }
+
+        // Truncate the step such that it remains feasible
+        let alpha = free
+            .iter()
+            .zip(d_u.iter())
+            .fold(float!(1.0), |alpha: F, (&i, &du)| {
+                if du > float!(0.0) {
+                    alpha.min((upper[i] - x_cp[i]) / du)
+                } else if du < float!(0.0) {
+                    alpha.min((lower[i] - x_cp[i]) / du)
+                } else {
+                    alpha
+                }
+            });
+
+        let mut x_bar = x_cp;
+        for (&i, &du) in free.iter().zip(d_u.iter()) {
+            x_bar[i] = x_bar[i] + alpha * du;
+        }
+        Ok(x_bar)
+    }
+
+    /// Computes the search direction `x_bar - x`, where `x_bar` is the (truncated) minimizer of
+    /// the quadratic model. Returns an error if one of the systems of the compact representation
+    /// is singular.
+    fn search_direction(&self, x: &[F], g: &[F]) -> Result<Vec<F>, Error> {
+        let (lower, upper) = self.get_bounds()?;
+        let mid = self.middle_matrix();
+        let (x_cp, c) = self.cauchy_point(x, g, lower, upper, &mid)?;
+        let x_bar = self.subspace_minimization(x, g, lower, upper, &mid, x_cp, &c)?;
+        Ok(x_bar
+            .iter()
+            .zip(x.iter())
+            .map(|(&xb, &xi)| xb - xi)
+            .collect())
+    }
+}
+
+/// Infinity norm of the projected gradient `P(x - g) - x`
+fn projected_gradient_norm<F: ArgminFloat>(x: &[F], g: &[F], lower: &[F], upper: &[F]) -> F {
+    x.iter()
+        .zip(g.iter())
+        .zip(lower.iter().zip(upper.iter()))
+        .fold(float!(0.0), |norm: F, ((&xi, &gi), (&l, &u))| {
+            norm.max(((xi - gi).max(l).min(u) - xi).abs())
+        })
+}
+
+/// Wrapper problem which projects parameter vectors onto the feasible box before evaluating the
+/// cost function and the gradient.
+struct ProjectedLineSearchProblem<O, F> {
+    problem: O,
+    lower: Vec<F>,
+    upper: Vec<F>,
+}
+
+impl<O, F> CostFunction for ProjectedLineSearchProblem<O, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F>,
+    Vec<F>: ArgminProject,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(&param.project(&self.lower, &self.upper))
+    }
+}
+
+impl<O, F> Gradient for ProjectedLineSearchProblem<O, F>
+where
+    O: Gradient<Param = Vec<F>, Gradient = Vec<F>>,
+    Vec<F>: ArgminProject,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.problem
+            .gradient(&param.project(&self.lower, &self.upper))
+    }
+}
+
+impl<O, L, F> Solver<O, IterState<Vec<F>, Vec<F>, (), (), (), F>> for LBFGSB<L, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + Gradient<Param = Vec<F>, Gradient = Vec<F>>,
+    L: Clone
+        + LineSearch<Vec<F>, F>
+        + Solver<ProjectedLineSearchProblem<O, F>, IterState<Vec<F>, Vec<F>, (), (), (), F>>,
+    F: ArgminFloat,
+    Vec<F>: ArgminDot<Vec<F>, F> + ArgminProject,
+    Vec<Vec<F>>: ArgminSolve<Vec<F>, Vec<F>>,
+{
+    fn name(&self) -> &str {
+        "L-BFGS-B"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`L-BFGS-B` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let n = param.len();
+        let (lower, upper) = self
+            .bounds
+            .get_or_insert_with(|| (vec![F::neg_infinity(); n], vec![F::infinity(); n]));
+        if lower.len() != n {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`L-BFGS-B`: Length of parameter vector ({}) does not match length of bounds ({}).",
+                    n,
+                    lower.len()
+                )
+            ));
+        }
+
+        let feasible = param
+            .iter()
+            .zip(lower.iter().zip(upper.iter()))
+            .all(|(xi, (l, u))| l <= xi && xi <= u);
+        let param = param.project(lower, upper);
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() || !feasible {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        let grad = match state.take_gradient() {
+            Some(grad) if feasible => grad,
+            _ => problem.gradient(&param)?,
+        };
+
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`L-BFGS-B`: Parameter vector in state not set."
+        ))?;
+        let cur_cost = state.get_cost();
+        let prev_grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`L-BFGS-B`: Gradient in state not set."
+        ))?;
+
+        // If one of the systems of the compact representation is singular or the limited memory
+        // matrix does not yield a descent direction, the memory is discarded and the direction is
+        // recomputed.
+        let d = self.search_direction(&param, &prev_grad);
+        let memory_reset =
+            !self.s.is_empty() && !matches!(&d, Ok(d) if d.dot(&prev_grad) < float!(0.0));
+        let d = if memory_reset {
+            self.s.clear();
+            self.y.clear();
+            self.theta = float!(1.0);
+            self.search_direction(&param, &prev_grad)
+        } else {
+            d
+        }?;
+        let d = match d {
+            d if d.dot(&prev_grad) < float!(0.0) => d,
+            _ => {
+                return Ok((
+                    state.param(param).gradient(prev_grad).terminate_with(
+                        TerminationReason::SolverExit("No descent direction found".to_string()),
+                    ),
+                    Some(kv!("theta" => self.theta; "memory_reset" => memory_reset;)),
+                ))
+            }
+        };
+
+        // Without curvature information the step is scaled to unit length.
+        let step_length = if self.s.is_empty() {
+            (float!(1.0) / d.dot(&d).sqrt()).min(float!(1.0))
+        } else {
+            float!(1.0)
+        };
+        self.linesearch.search_direction(d);
+        self.linesearch.initial_step_length(step_length)?;
+
+        let (lower, upper) = self.get_bounds()?;
+        let line_problem = ProjectedLineSearchProblem {
+            problem: problem.take_problem().unwrap(),
+            lower: lower.to_vec(),
+            upper: upper.to_vec(),
+        };
+
+        // Run line search
+        let linesearch_result = Executor::new(line_problem, self.linesearch.clone())
+            .configure(|config| {
+                config
+                    .param(param.clone())
+                    .gradient(prev_grad.clone())
+                    .cost(cur_cost)
+            })
+            .inherit_func_eval_budget(problem)
+            .ctrlc(false)
+            .run();
+
+        // The user problem has been moved into the line search, therefore errors cannot be
+        // recovered from and are passed on.
+        let OptimizationResult {
+            problem: mut line_problem,
+            state: mut linesearch_state,
+            ..
+        } = linesearch_result?;
+
+        // take back problem and take care of function evaluation counts
+        let internal_line_problem = line_problem.take_problem().unwrap();
+        problem.problem = Some(internal_line_problem.problem);
+        problem.consume_func_counts(line_problem);
+
+        let (lower, upper) = self.get_bounds()?;
+        let xk1 = linesearch_state.take_param().unwrap().project(lower, upper);
+        let next_cost = linesearch_state.get_cost();
+        let grad = problem.gradient(&xk1)?;
+
+        // Only store correction pairs which satisfy the curvature condition
+        let sk: Vec<F> = xk1.iter().zip(param.iter()).map(|(&a, &b)| a - b).collect();
+        let yk: Vec<F> = grad
+            .iter()
+            .zip(prev_grad.iter())
+            .map(|(&a, &b)| a - b)
+            .collect();
+        let sy = sk.dot(&yk);
+        let yy = yk.dot(&yk);
+        if sy > F::epsilon() * yy {
+            if self.s.len() >= self.m {
+                self.s.pop_front();
+                self.y.pop_front();
+            }
+            self.s.push_back(sk);
+            self.y.push_back(yk);
+            self.theta = yy / sy;
+        }
+
+        Ok((
+            state.param(xk1).cost(next_cost).gradient(grad),
+            Some(kv!("theta" => self.theta; "memory_reset" => memory_reset;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<Vec<F>, Vec<F>, (), (), (), F>) -> TerminationStatus {
+        if let (Some(param), Some(grad), Some((lower, upper))) = (
+            state.get_param(),
+            state.get_gradient(),
+            self.bounds.as_ref(),
+        ) {
+            if projected_gradient_norm(param, grad, lower, upper) < self.tol_grad {
+                return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+            }
+        }
+        if (state.get_prev_cost() - state.get_cost()).abs() < self.tol_cost {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError};
+    use crate::solver::linesearch::{HagerZhangLineSearch, MoreThuenteLineSearch};
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{beale, beale_derivative, rosenbrock, rosenbrock_derivative};
+
+    test_trait_impl!(
+        lbfgsb,
+        LBFGSB<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, f64>
+    );
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock(p))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_derivative(p))
+        }
+    }
+
+    struct Beale {}
+
+    impl CostFunction for Beale {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(beale(&[p[0], p[1]]))
+        }
+    }
+
+    impl Gradient for Beale {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(beale_derivative(&[p[0], p[1]]).to_vec())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        #[derive(Eq, PartialEq, Debug)]
+        struct MyFakeLineSearch {}
+
+        let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+        let LBFGSB {
+            linesearch,
+            m,
+            bounds,
+            s,
+            y,
+            theta,
+            tol_grad,
+            tol_cost,
+        } = lbfgsb;
+
+        assert_eq!(linesearch, MyFakeLineSearch {});
+        assert_eq!(m, 3);
+        assert!(bounds.is_none());
+        assert!(s.capacity() >= 3);
+        assert!(y.capacity() >= 3);
+        assert_eq!(theta.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(tol_cost.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_bounds() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeLineSearch {}
+
+        // correct parameters
+        let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+        let res = lbfgsb.with_bounds(vec![-1.0, 2.0, f64::NEG_INFINITY], vec![1.0, 2.0, 0.0]);
+        assert!(res.is_ok());
+        let (lower, upper) = res.unwrap().bounds.unwrap();
+        assert_eq!(lower, vec![-1.0, 2.0, f64::NEG_INFINITY]);
+        assert_eq!(upper, vec![1.0, 2.0, 0.0]);
+
+        // incorrect parameters
+        let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+        let res = lbfgsb.with_bounds(vec![-1.0, 2.0], vec![1.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`L-BFGS-B`: lower and upper bounds must have the same length.\""
+        );
+
+        for (lower, upper) in [
+            (vec![1.0, 0.0], vec![-1.0, 1.0]),
+            (vec![0.0, f64::NAN], vec![1.0, 1.0]),
+            (vec![0.0, f64::INFINITY], vec![1.0, 1.0]),
+        ] {
+            let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+            let res = lbfgsb.with_bounds(lower, upper);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`L-BFGS-B`: lower bounds must be <= upper bounds.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance_grad() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeLineSearch {}
+
+        // correct parameters
+        for tol in [1e-6, 0.0, 1e-2, 1.0, 2.0] {
+            let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+            let res = lbfgsb.with_tolerance_grad(tol);
+            assert!(res.is_ok());
+
+            let nm = res.unwrap();
+            assert_eq!(nm.tol_grad.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for tol in [-f64::EPSILON, -1.0, -100.0, -42.0] {
+            let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+            let res = lbfgsb.with_tolerance_grad(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`L-BFGS-B`: gradient tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance_cost() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeLineSearch {}
+
+        // correct parameters
+        for tol in [1e-6, 0.0, 1e-2, 1.0, 2.0] {
+            let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+            let res = lbfgsb.with_tolerance_cost(tol);
+            assert!(res.is_ok());
+
+            let nm = res.unwrap();
+            assert_eq!(nm.tol_cost.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for tol in [-f64::EPSILON, -1.0, -100.0, -42.0] {
+            let lbfgsb: LBFGSB<_, f64> = LBFGSB::new(MyFakeLineSearch {}, 3);
+            let res = lbfgsb.with_tolerance_cost(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`L-BFGS-B`: cost tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init() {
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+
+        let mut lbfgsb: LBFGSB<_, f64> = LBFGSB::new(linesearch, 3)
+            .with_bounds(vec![-0.5, 0.0], vec![0.5, 2.0])
+            .unwrap();
+
+        // Forgot to initialize the parameter vector
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), (), f64> = IterState::new();
+        let problem = TestProblem::new();
+        let res = lbfgsb.init(&mut Problem::new(problem), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`L-BFGS-B` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        // Wrong dimension
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), (), f64> =
+            IterState::new().param(vec![0.0; 3]);
+        let problem = TestProblem::new();
+        let res = lbfgsb.init(&mut Problem::new(problem), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`L-BFGS-B`: Length of parameter vector (3) does not match ",
+                "length of bounds (2).\""
+            )
+        );
+
+        // Infeasible initial parameter vector is projected onto the bounds. Provided cost and
+        // gradient are discarded since they belong to the infeasible parameter vector.
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), (), f64> = IterState::new()
+            .param(vec![-1.0, 1.0])
+            .cost(1234.0)
+            .gradient(vec![4.0, 9.0]);
+        let problem = TestProblem::new();
+        let (mut state_out, kv) = lbfgsb.init(&mut Problem::new(problem), state).unwrap();
+
+        assert!(kv.is_none());
+        assert_eq!(state_out.take_param().unwrap(), vec![-0.5, 1.0]);
+        assert_eq!(state_out.take_gradient().unwrap(), vec![-0.5, 1.0]);
+        assert_eq!(state_out.get_cost().to_ne_bytes(), 1.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_init_provided_cost_and_grad() {
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+
+        let mut lbfgsb: LBFGSB<_, f64> = LBFGSB::new(linesearch, 3);
+
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), (), f64> = IterState::new()
+            .param(vec![-1.0, 1.0])
+            .cost(1234.0)
+            .gradient(vec![4.0, 9.0]);
+
+        let problem = TestProblem::new();
+        let (mut state_out, kv) = lbfgsb.init(&mut Problem::new(problem), state).unwrap();
+
+        assert!(kv.is_none());
+        assert_eq!(state_out.take_gradient().unwrap(), vec![4.0, 9.0]);
+        assert_eq!(state_out.get_cost().to_ne_bytes(), 1234.0f64.to_ne_bytes());
+        let (lower, upper) = lbfgsb.bounds.unwrap();
+        assert_eq!(lower, vec![f64::NEG_INFINITY; 2]);
+        assert_eq!(upper, vec![f64::INFINITY; 2]);
+    }
+
+    #[test]
+    fn test_singular_middle_matrix() {
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+        let mut lbfgsb: LBFGSB<_, f64> = LBFGSB::new(linesearch, 3);
+
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), (), f64> =
+            IterState::new().param(vec![-1.2, 1.0]);
+        let mut problem = Problem::new(Rosenbrock {});
+        let (state, _) = lbfgsb.init(&mut problem, state).unwrap();
+        let cost = state.get_cost();
+
+        // A correction pair with `s^T y = 0` makes the middle matrix singular
+        lbfgsb.s.push_back(vec![1.0, 0.0]);
+        lbfgsb.y.push_back(vec![0.0, 1.0]);
+        let res = lbfgsb.search_direction(&[-1.2, 1.0], state.get_gradient().unwrap());
+        assert_eq!(res.unwrap_err().to_string(), "Singular matrix");
+
+        // The memory is discarded and a step along the steepest descent direction is taken
+        let (state, kv) = lbfgsb.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+        assert_eq!(kv.get("memory_reset").unwrap().get_bool(), Some(true));
+        assert!(state.get_cost() < cost);
+        assert_eq!(lbfgsb.s.len(), 1);
+
+        let (_, kv) = lbfgsb.next_iter(&mut problem, state).unwrap();
+        assert_eq!(
+            kv.unwrap().get("memory_reset").unwrap().get_bool(),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_rosenbrock_unconstrained() {
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+        let solver = LBFGSB::new(linesearch, 7).with_tolerance_cost(0.0).unwrap();
+
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_rosenbrock_active_bound() {
+        // Constraining the first parameter to `x <= 0.5` moves the minimum to `(0.5, 0.25)`.
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+        let solver = LBFGSB::new(linesearch, 7)
+            .with_bounds(vec![-2.0, -2.0], vec![0.5, 2.0])
+            .unwrap()
+            .with_tolerance_cost(0.0)
+            .unwrap();
+
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 0.5, epsilon = 1e-10);
+        assert_relative_eq!(param[1], 0.25, epsilon = 1e-6);
+        assert_relative_eq!(res.state.get_best_cost(), 0.25, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_rosenbrock_multidimensional_box() {
+        let linesearch = HagerZhangLineSearch::new();
+        let lower = vec![-2.0, 1.5, -2.0, -2.0, -2.0];
+        let upper = vec![2.0, 2.0, 2.0, 2.0, 0.8];
+        let solver = LBFGSB::new(linesearch, 5)
+            .with_bounds(lower.clone(), upper.clone())
+            .unwrap()
+            .with_tolerance_cost(0.0)
+            .unwrap();
+
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![0.0, 1.8, 0.0, 0.0, 0.0]).max_iters(200))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        for ((&p, &l), &u) in param.iter().zip(lower.iter()).zip(upper.iter()) {
+            assert!(l <= p && p <= u);
+        }
+        assert_relative_eq!(param[1], 1.5, epsilon = 1e-10);
+        assert_relative_eq!(param[4], 0.8, epsilon = 1e-10);
+        let grad = rosenbrock_derivative(param);
+        assert!(projected_gradient_norm(param, &grad, &lower, &upper) < 1e-6);
+    }
+
+    #[test]
+    fn test_beale_active_bound() {
+        // The unconstrained minimum `(3, 0.5)` lies outside of the box.
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+        let lower = vec![-4.5, -4.5];
+        let upper = vec![2.5, 4.5];
+        let solver = LBFGSB::new(linesearch, 7)
+            .with_bounds(lower.clone(), upper.clone())
+            .unwrap()
+            .with_tolerance_cost(0.0)
+            .unwrap();
+
+        let res = Executor::new(Beale {}, solver)
+            .configure(|state| state.param(vec![1.0, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.5, epsilon = 1e-10);
+        let grad = beale_derivative(&[param[0], param[1]]);
+        assert!(grad[0] < 0.0);
+        assert!(projected_gradient_norm(param, &grad, &lower, &upper) < 1e-6);
+    }
+
+    #[test]
+    fn test_beale_inactive_bounds() {
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+        let solver = LBFGSB::new(linesearch, 7)
+            .with_bounds(vec![0.0, 0.0], vec![4.5, 4.5])
+            .unwrap()
+            .with_tolerance_cost(0.0)
+            .unwrap();
+
+        let res = Executor::new(Beale {}, solver)
+            .configure(|state| state.param(vec![1.0, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 3.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_linesearch_error() {
+        // Cost function which fails beyond `x = 0.5`, the first trial step of the line search
+        struct Failing {}
+
+        impl CostFunction for Failing {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                if p[0] > 0.5 {
+                    return Err(argmin_error!(InvalidParameter, "`Failing`: x > 0.5"));
+                }
+                Ok((p[0] - 2.0).powi(2))
+            }
+        }
+
+        impl Gradient for Failing {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(vec![2.0 * (p[0] - 2.0)])
+            }
+        }
+
+        let solver = LBFGSB::new(MoreThuenteLineSearch::new(), 7)
+            .with_bounds(vec![-10.0], vec![10.0])
+            .unwrap();
+        let res = Executor::new(Failing {}, solver)
+            .configure(|state| state.param(vec![0.0]).max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Failing`: x > 0.5\""
+        );
+    }
+}
@@ -0,0 +1,787 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Robust loss functions for least squares problems
+//!
+//! Least squares solvers such as [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`),
+//! [`GaussNewtonLS`](`crate::solver::gaussnewton::GaussNewtonLS`) and
+//! [`LevenbergMarquardt`](`crate::solver::levenbergmarquardt::LevenbergMarquardt`) minimize
+//! `sum_i r_i^2`, which is very sensitive to outliers in the residuals `r_i`. Robust loss
+//! functions `rho` reduce the influence of large residuals by minimizing `sum_i rho(r_i^2)`
+//! instead.
+//!
+//! [`RobustLeastSquares`] wraps a problem implementing [`Operator`] and [`Jacobian`] together
+//! with a [`RobustLoss`] and rescales residuals and Jacobian accordingly, such that any least
+//! squares solver can be used unchanged.
+//!
+//! Available loss functions (`s = r^2`, `a` is a scale parameter beyond which residuals are
+//! considered outliers):
+//!
+//! * [`HuberLoss`]: `rho(s) = s` for `s <= a^2`, `2 a sqrt(s) - a^2` otherwise
+//! * [`SoftL1Loss`]: `rho(s) = 2 a^2 (sqrt(1 + s / a^2) - 1)`
+//! * [`CauchyLoss`]: `rho(s) = a^2 ln(1 + s / a^2)`
+//! * [`ArctanLoss`]: `rho(s) = a atan(s / a)`
+//! * [`TukeyLoss`]: `rho(s) = a^2 / 3 (1 - (1 - s / a^2)^3)` for `s <= a^2`, `a^2 / 3`
+//!   otherwise
+//!
+//! ## References
+//!
+//! Bill Triggs, Philip F. McLauchlan, Richard I. Hartley and Andrew W. Fitzgibbon (2000).
+//! Bundle Adjustment — A Modern Synthesis. In: Vision Algorithms: Theory and Practice,
+//! pp. 298-372. Springer.
+//!
+//! Sameer Agarwal, Keir Mierle and The Ceres Solver Team. Ceres Solver.
+//! <http://ceres-solver.org/nnls_modeling.html#lossfunction>
+
+use crate::core::{
+    count_evaluations, ArgminFloat, CostFunction, Error, Jacobian, Operator, SendAlias, SyncAlias,
+};
+use argmin_math::{ArgminL1Norm, ArgminMap, ArgminScaleRows};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+
+/// A robust loss function `rho(s)` of the squared residual `s = r^2`
+///
+/// Implementors must satisfy `rho(0) = 0`, `rho'(0) = 1` and `rho(s) >= 0`.
+pub trait RobustLoss {
+    /// Float type
+    type Float;
+
+    /// Returns `[rho(s), rho'(s), rho''(s)]` for a squared residual `s >= 0`.
+    fn evaluate(&self, s: Self::Float) -> [Self::Float; 3];
+}
+
+/// Checks that the scale parameter of a robust loss is positive.
+fn check_scale<F: ArgminFloat>(a: F, name: &'static str) -> Result<F, Error> {
+    if a <= float!(0.0) || !a.is_finite() {
+        return Err(argmin_error!(
+            InvalidParameter,
+            format!("`{name}`: scale parameter must be positive and finite.")
+        ));
+    }
+    Ok(a)
+}
+
+/// # Huber loss
+///
+/// `rho(s) = s` for `s <= a^2` and `rho(s) = 2 a sqrt(s) - a^2` otherwise. Quadratic for small
+/// residuals and linear for large ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HuberLoss<F> {
+    /// Scale parameter
+    a: F,
+}
+
+impl<F: ArgminFloat> HuberLoss<F> {
+    /// Constructs a new [`HuberLoss`] with scale parameter `a`, which must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::robustloss::HuberLoss;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let loss = HuberLoss::new(1.0f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(a: F) -> Result<Self, Error> {
+        Ok(HuberLoss {
+            a: check_scale(a, "HuberLoss")?,
+        })
+    }
+}
+
+impl<F: ArgminFloat> RobustLoss for HuberLoss<F> {
+    type Float = F;
+
+    fn evaluate(&self, s: F) -> [F; 3] {
+        let a = self.a;
+        if s <= a * a {
+            [s, float!(1.0), float!(0.0)]
+        } else {
+            let r = s.sqrt();
+            let rho1 = a / r;
+            [float!(2.0) * a * r - a * a, rho1, -rho1 / (float!(2.0) * s)]
+        }
+    }
+}
+
+/// # Soft L1 loss
+///
+/// `rho(s) = 2 a^2 (sqrt(1 + s / a^2) - 1)`. A smooth approximation of the [`HuberLoss`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SoftL1Loss<F> {
+    /// Scale parameter
+    a: F,
+}
+
+impl<F: ArgminFloat> SoftL1Loss<F> {
+    /// Constructs a new [`SoftL1Loss`] with scale parameter `a`, which must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::robustloss::SoftL1Loss;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let loss = SoftL1Loss::new(1.0f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(a: F) -> Result<Self, Error> {
+        Ok(SoftL1Loss {
+            a: check_scale(a, "SoftL1Loss")?,
+        })
+    }
+}
+
+impl<F: ArgminFloat> RobustLoss for SoftL1Loss<F> {
+    type Float = F;
+
+    fn evaluate(&self, s: F) -> [F; 3] {
+        let b = self.a * self.a;
+        let c = float!(1.0) / b;
+        let sum = float!(1.0) + s * c;
+        let tmp = sum.sqrt();
+        [
+            float!(2.0) * b * (tmp - float!(1.0)),
+            float!(1.0) / tmp,
+            -(c / float!(2.0)) / (sum * tmp),
+        ]
+    }
+}
+
+/// # Cauchy loss
+///
+/// `rho(s) = a^2 ln(1 + s / a^2)`. Grows only logarithmically for large residuals.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CauchyLoss<F> {
+    /// Scale parameter
+    a: F,
+}
+
+impl<F: ArgminFloat> CauchyLoss<F> {
+    /// Constructs a new [`CauchyLoss`] with scale parameter `a`, which must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::robustloss::CauchyLoss;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let loss = CauchyLoss::new(1.0f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(a: F) -> Result<Self, Error> {
+        Ok(CauchyLoss {
+            a: check_scale(a, "CauchyLoss")?,
+        })
+    }
+}
+
+impl<F: ArgminFloat> RobustLoss for CauchyLoss<F> {
+    type Float = F;
+
+    fn evaluate(&self, s: F) -> [F; 3] {
+        let b = self.a * self.a;
+        let c = float!(1.0) / b;
+        let sum = float!(1.0) + s * c;
+        let inv = float!(1.0) / sum;
+        [b * sum.ln(), inv, -c * inv * inv]
+    }
+}
+
+/// # Arctan loss
+///
+/// `rho(s) = a atan(s / a)`. Bounded by `a pi / 2` for large residuals.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ArctanLoss<F> {
+    /// Scale parameter
+    a: F,
+}
+
+impl<F: ArgminFloat> ArctanLoss<F> {
+    /// Constructs a new [`ArctanLoss`] with scale parameter `a`, which must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::robustloss::ArctanLoss;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let loss = ArctanLoss::new(1.0f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(a: F) -> Result<Self, Error> {
+        Ok(ArctanLoss {
+            a: check_scale(a, "ArctanLoss")?,
+        })
+    }
+}
+
+impl<F: ArgminFloat> RobustLoss for ArctanLoss<F> {
+    type Float = F;
+
+    fn evaluate(&self, s: F) -> [F; 3] {
+        let b = float!(1.0) / (self.a * self.a);
+        let inv = float!(1.0) / (float!(1.0) + s * s * b);
+        [
+            self.a * s.atan2(self.a),
+            inv,
+            float!(-2.0) * s * b * inv * inv,
+        ]
+    }
+}
+
+/// # Tukey biweight loss
+///
+/// `rho(s) = a^2 / 3 (1 - (1 - s / a^2)^3)` for `s <= a^2` and `rho(s) = a^2 / 3` otherwise.
+/// Residuals larger than `a` have no influence at all on the solution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct TukeyLoss<F> {
+    /// Scale parameter
+    a: F,
+}
+
+impl<F: ArgminFloat> TukeyLoss<F> {
+    /// Constructs a new [`TukeyLoss`] with scale parameter `a`, which must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::robustloss::TukeyLoss;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let loss = TukeyLoss::new(1.0f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(a: F) -> Result<Self, Error> {
+        Ok(TukeyLoss {
+            a: check_scale(a, "TukeyLoss")?,
+        })
+    }
+}
+
+impl<F: ArgminFloat> RobustLoss for TukeyLoss<F> {
+    type Float = F;
+
+    fn evaluate(&self, s: F) -> [F; 3] {
+        let b = self.a * self.a;
+        if s <= b {
+            let v = float!(1.0) - s / b;
+            [
+                b / float!(3.0) * (float!(1.0) - v * v * v),
+                v * v,
+                float!(-2.0) * v / b,
+            ]
+        } else {
+            [b / float!(3.0), float!(0.0), float!(0.0)]
+        }
+    }
+}
+
+/// # Robust least squares problem
+///
+/// Wraps a least squares problem implementing [`Operator`] (returning the residuals) and
+/// [`Jacobian`] and applies a [`RobustLoss`] to each residual. The wrapped problem can be solved
+/// with any least squares solver, such as
+/// [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`),
+/// [`GaussNewtonLS`](`crate::solver::gaussnewton::GaussNewtonLS`) or
+/// [`LevenbergMarquardt`](`crate::solver::levenbergmarquardt::LevenbergMarquardt`), which then
+/// minimize `sum_i rho(r_i^2)`.
+///
+/// Residuals and rows of the Jacobian are rescaled following Triggs et al. (2000) as done in Ceres
+/// Solver: with `s = r_i^2` and `alpha` the root of `alpha^2 / 2 - alpha - s rho''(s) / rho'(s)`,
+/// the residual is scaled by `sqrt(rho'(s)) / (1 - alpha)` and the corresponding row of the
+/// Jacobian by `sqrt(rho'(s)) (1 - alpha)`. If `rho''(s) <= 0` (which is the case for all losses
+/// in this module) `alpha` is zero and this reduces to iteratively reweighted least squares.
+///
+/// The residuals are always computed in [`Jacobian::jacobian`], therefore each Jacobian evaluation
+/// is accompanied by an operator evaluation which is reported as `operator_count`.
+///
+/// Note that the solvers report the norm of the rescaled residuals as cost. The actual robust cost
+/// `1/2 sum_i rho(r_i^2)` is available via the [`CostFunction`] implementation.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, Operator};
+/// # use argmin::solver::robustloss::{CauchyLoss, RobustLeastSquares};
+/// # fn main() -> Result<(), Error> {
+/// struct Residuals {}
+///
+/// impl Operator for Residuals {
+///     type Param = Vec<f64>;
+///     type Output = Vec<f64>;
+///
+///     fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(vec![p[0] - 1.0, p[0] - 100.0])
+///     }
+/// }
+///
+/// let problem = RobustLeastSquares::new(Residuals {}, CauchyLoss::new(1.0)?);
+/// let residuals = problem.apply(&vec![1.0])?;
+///
+/// // The outlier is strongly downweighted
+/// assert!(residuals[1].abs() < 1.0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RobustLeastSquares<O, L> {
+    /// Wrapped problem
+    problem: O,
+    /// Loss function
+    loss: L,
+}
+
+impl<O, L> RobustLeastSquares<O, L> {
+    /// Wraps `problem` and applies `loss` to its residuals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::robustloss::{HuberLoss, RobustLeastSquares};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # struct UserDefinedProblem {}
+    /// let problem = RobustLeastSquares::new(UserDefinedProblem {}, HuberLoss::new(0.5f64)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(problem: O, loss: L) -> Self {
+        RobustLeastSquares { problem, loss }
+    }
+
+    /// Returns a reference to the wrapped problem.
+    pub fn inner(&self) -> &O {
+        &self.problem
+    }
+}
+
+impl<O, L, F> RobustLeastSquares<O, L>
+where
+    L: RobustLoss<Float = F>,
+    F: ArgminFloat,
+{
+    /// Returns the scaling factors `(residual, Jacobian row)` for a residual `r`.
+    fn corrector(&self, r: F) -> (F, F) {
+        let s = r * r;
+        let [_, rho1, rho2] = self.loss.evaluate(s);
+        let sqrt_rho1 = rho1.max(float!(0.0)).sqrt();
+        if s == float!(0.0) || rho2 <= float!(0.0) || rho1 <= float!(0.0) {
+            return (sqrt_rho1, sqrt_rho1);
+        }
+        let alpha = float!(1.0) - (float!(1.0) + float!(2.0) * s * rho2 / rho1).sqrt();
+        (
+            sqrt_rho1 / (float!(1.0) - alpha),
+            sqrt_rho1 * (float!(1.0) - alpha),
+        )
+    }
+}
+
+impl<O, L, P, U, J, F> RobustLeastSquares<O, L>
+where
+    O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
+    U: ArgminMap<F>,
+    J: ArgminScaleRows<U>,
+    L: RobustLoss<Float = F>,
+    F: ArgminFloat,
+{
+    /// Computes the corrected Jacobian, which requires an evaluation of the residuals.
+    fn scaled_jacobian(&self, param: &P) -> Result<J, Error> {
+        let residuals = self.problem.apply(param)?;
+        let jacobian = self.problem.jacobian(param)?;
+        Ok(jacobian.scale_rows(&residuals.map(|r| self.corrector(r).1)))
+    }
+}
+
+impl<O, L, F> Operator for RobustLeastSquares<O, L>
+where
+    O: Operator,
+    O::Output: ArgminMap<F>,
+    L: RobustLoss<Float = F>,
+    F: ArgminFloat,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let residuals = self.problem.apply(param)?;
+        Ok(residuals.map(|r| self.corrector(r).0 * r))
+    }
+
+    fn parallelize(&self) -> bool {
+        self.problem.parallelize()
+    }
+}
+
+impl<O, L, P, U, J, F> Jacobian for RobustLeastSquares<O, L>
+where
+    O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
+    U: ArgminMap<F>,
+    J: ArgminScaleRows<U>,
+    L: RobustLoss<Float = F>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Jacobian = J;
+
+    fn jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        count_evaluations("operator_count", 1);
+        self.scaled_jacobian(param)
+    }
+
+    /// The operator evaluations are reported on the calling thread, because evaluations reported
+    /// on `rayon` worker threads are not visible to `Problem`.
+    fn bulk_jacobian<Q>(&self, params: &[Q]) -> Result<Vec<Self::Jacobian>, Error>
+    where
+        Q: Borrow<Self::Param> + SyncAlias,
+        Self::Jacobian: SendAlias,
+        Self: SyncAlias,
+    {
+        count_evaluations("operator_count", params.len() as u64);
+        #[cfg(feature = "rayon")]
+        {
+            if Jacobian::parallelize(self) {
+                params
+                    .par_iter()
+                    .map(|p| self.scaled_jacobian(p.borrow()))
+                    .collect()
+            } else {
+                params
+                    .iter()
+                    .map(|p| self.scaled_jacobian(p.borrow()))
+                    .collect()
+            }
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            params
+                .iter()
+                .map(|p| self.scaled_jacobian(p.borrow()))
+                .collect()
+        }
+    }
+
+    fn parallelize(&self) -> bool {
+        Jacobian::parallelize(&self.problem)
+    }
+}
+
+impl<O, L, F> CostFunction for RobustLeastSquares<O, L>
+where
+    O: Operator,
+    O::Output: ArgminMap<F> + ArgminL1Norm<F>,
+    L: RobustLoss<Float = F>,
+    F: ArgminFloat,
+{
+    type Param = O::Param;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let residuals = self.problem.apply(param)?;
+        count_evaluations("operator_count", 1);
+        let rho = residuals.map(|r| self.loss.evaluate(r * r)[0]);
+        Ok(float!(0.5) * rho.l1_norm())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Problem};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(huber_loss, HuberLoss<f64>);
+    test_trait_impl!(soft_l1_loss, SoftL1Loss<f64>);
+    test_trait_impl!(cauchy_loss, CauchyLoss<f64>);
+    test_trait_impl!(arctan_loss, ArctanLoss<f64>);
+    test_trait_impl!(tukey_loss, TukeyLoss<f64>);
+    test_trait_impl!(robust_least_squares, RobustLeastSquares<(), HuberLoss<f64>>);
+
+    fn check_loss<L: RobustLoss<Float = f64>>(loss: &L) {
+        let [rho0, rho1_0, _] = loss.evaluate(0.0);
+        assert_relative_eq!(rho0, 0.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(rho1_0, 1.0, epsilon = f64::EPSILON);
+
+        let h = 1e-6;
+        for s in [0.01, 0.3, 0.9, 1.5, 4.0, 25.0] {
+            let [rho, rho1, rho2] = loss.evaluate(s);
+            let [rho_p, rho1_p, _] = loss.evaluate(s + h);
+            let [rho_m, rho1_m, _] = loss.evaluate(s - h);
+            assert!(rho >= 0.0);
+            assert_relative_eq!(rho1, (rho_p - rho_m) / (2.0 * h), epsilon = 1e-6);
+            assert_relative_eq!(rho2, (rho1_p - rho1_m) / (2.0 * h), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_losses() {
+        check_loss(&HuberLoss::new(0.8).unwrap());
+        check_loss(&SoftL1Loss::new(0.8).unwrap());
+        check_loss(&CauchyLoss::new(0.8).unwrap());
+        check_loss(&ArctanLoss::new(0.8).unwrap());
+        check_loss(&TukeyLoss::new(0.8).unwrap());
+    }
+
+    #[test]
+    fn test_loss_values() {
+        assert_relative_eq!(HuberLoss::new(1.0).unwrap().evaluate(4.0)[0], 3.0);
+        assert_relative_eq!(
+            SoftL1Loss::new(1.0).unwrap().evaluate(3.0)[0],
+            2.0,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            CauchyLoss::new(1.0).unwrap().evaluate(1.0)[0],
+            2.0f64.ln(),
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            ArctanLoss::new(2.0).unwrap().evaluate(2.0)[0],
+            2.0 * std::f64::consts::FRAC_PI_4,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            TukeyLoss::new(1.0).unwrap().evaluate(9.0)[0],
+            1.0 / 3.0,
+            epsilon = f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_new_errors() {
+        for a in [0.0, -1.0, f64::INFINITY, f64::NAN] {
+            assert_error!(
+                HuberLoss::new(a),
+                ArgminError,
+                "Invalid parameter: \"`HuberLoss`: scale parameter must be positive and finite.\""
+            );
+            assert_error!(
+                SoftL1Loss::new(a),
+                ArgminError,
+                "Invalid parameter: \"`SoftL1Loss`: scale parameter must be positive and finite.\""
+            );
+            assert_error!(
+                CauchyLoss::new(a),
+                ArgminError,
+                "Invalid parameter: \"`CauchyLoss`: scale parameter must be positive and finite.\""
+            );
+            assert_error!(
+                ArctanLoss::new(a),
+                ArgminError,
+                "Invalid parameter: \"`ArctanLoss`: scale parameter must be positive and finite.\""
+            );
+            assert_error!(
+                TukeyLoss::new(a),
+                ArgminError,
+                "Invalid parameter: \"`TukeyLoss`: scale parameter must be positive and finite.\""
+            );
+        }
+    }
+
+    struct Residuals {}
+
+    impl Operator for Residuals {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0] - 1.0, 2.0 * p[1], p[0] * p[1] - 10.0])
+        }
+    }
+
+    impl Jacobian for Residuals {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![1.0, 0.0], vec![0.0, 2.0], vec![p[1], p[0]]])
+        }
+    }
+
+    #[test]
+    fn test_irls_scaling() {
+        let loss = CauchyLoss::new(1.0).unwrap();
+        let problem = RobustLeastSquares::new(Residuals {}, loss);
+        let param = vec![2.0, 1.0];
+
+        let r = Residuals {}.apply(&param).unwrap();
+        let j = Residuals {}.jacobian(&param).unwrap();
+        let r_robust = problem.apply(&param).unwrap();
+        let j_robust = problem.jacobian(&param).unwrap();
+
+        for i in 0..3 {
+            let w = loss.evaluate(r[i] * r[i])[1].sqrt();
+            assert_relative_eq!(r_robust[i], w * r[i], epsilon = f64::EPSILON);
+            for k in 0..2 {
+                assert_relative_eq!(j_robust[i][k], w * j[i][k], epsilon = f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_triggs_correction() {
+        // A loss with positive second derivative, rho(s) = s + s^2 / 2
+        struct Convex {}
+
+        impl RobustLoss for Convex {
+            type Float = f64;
+
+            fn evaluate(&self, s: f64) -> [f64; 3] {
+                [s + s * s / 2.0, 1.0 + s, 1.0]
+            }
+        }
+
+        let problem = RobustLeastSquares::new(Residuals {}, Convex {});
+        let param = vec![3.0, 1.0];
+        let r = Residuals {}.apply(&param).unwrap();
+        let j = Residuals {}.jacobian(&param).unwrap();
+        let r_robust = problem.apply(&param).unwrap();
+        let j_robust = problem.jacobian(&param).unwrap();
+
+        for i in 0..3 {
+            let s = r[i] * r[i];
+            let alpha = 1.0 - (1.0 + 2.0 * s / (1.0 + s)).sqrt();
+            let sqrt_rho1 = (1.0 + s).sqrt();
+            // alpha solves alpha^2 / 2 - alpha - s rho'' / rho' = 0
+            assert_relative_eq!(
+                alpha * alpha / 2.0 - alpha - s / (1.0 + s),
+                0.0,
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(
+                r_robust[i],
+                sqrt_rho1 / (1.0 - alpha) * r[i],
+                epsilon = 1e-12
+            );
+            for k in 0..2 {
+                assert_relative_eq!(
+                    j_robust[i][k],
+                    sqrt_rho1 * (1.0 - alpha) * j[i][k],
+                    epsilon = 1e-12
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cost_and_counts() {
+        let loss = HuberLoss::new(1.0).unwrap();
+        let mut problem = Problem::new(RobustLeastSquares::new(Residuals {}, loss));
+        let param = vec![2.0, 1.0];
+
+        // residuals: 1, 2, -8
+        let cost = problem.cost(&param).unwrap();
+        assert_relative_eq!(cost, 0.5 * (1.0 + 3.0 + 15.0), epsilon = f64::EPSILON);
+
+        problem.jacobian(&param).unwrap();
+        assert_eq!(problem.counts["cost_count"], 1);
+        assert_eq!(problem.counts["jacobian_count"], 1);
+        assert_eq!(problem.counts["operator_count"], 2);
+
+        let jacobians = problem
+            .bulk_jacobian(&[param.clone(), vec![1.0, 1.0], vec![0.0, 2.0]])
+            .unwrap();
+        assert_eq!(jacobians.len(), 3);
+        assert_eq!(jacobians[0], problem.jacobian(&param).unwrap());
+        assert_eq!(problem.counts["jacobian_count"], 5);
+        assert_eq!(problem.counts["operator_count"], 6);
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_line_fit_with_outliers() {
+        use crate::core::{Executor, State};
+        use crate::solver::gaussnewton::{GaussNewton, GaussNewtonLS};
+        use crate::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+        use ndarray::{Array1, Array2};
+
+        struct Line {
+            data: Vec<(f64, f64)>,
+        }
+
+        impl Operator for Line {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self.data.iter().map(|(x, y)| p[0] * x + p[1] - y).collect())
+            }
+        }
+
+        impl Jacobian for Line {
+            type Param = Array1<f64>;
+            type Jacobian = Array2<f64>;
+
+            fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(Array2::from_shape_fn((self.data.len(), 2), |(i, j)| {
+                    if j == 0 {
+                        self.data[i].0
+                    } else {
+                        1.0
+                    }
+                }))
+            }
+        }
+
+        // y = 2 x + 1 with small noise and two gross outliers
+        let noise = [
+            0.05, -0.03, 0.02, -0.04, 0.01, 0.03, -0.02, 0.04, -0.01, 0.0,
+        ];
+        let mut data: Vec<(f64, f64)> = noise
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (i as f64, 2.0 * i as f64 + 1.0 + n))
+            .collect();
+        data[3].1 += 30.0;
+        data[7].1 -= 40.0;
+
+        let init = Array1::from_vec(vec![0.0, 0.0]);
+
+        let plain = Executor::new(Line { data: data.clone() }, GaussNewton::new())
+            .configure(|state| state.param(init.clone()).max_iters(50))
+            .run()
+            .unwrap();
+        let plain_param = plain.state().get_best_param().unwrap().clone();
+        assert!((plain_param[0] - 2.0).abs() > 0.5);
+
+        let problem =
+            RobustLeastSquares::new(Line { data: data.clone() }, CauchyLoss::new(0.1).unwrap());
+        let res = Executor::new(problem, GaussNewton::new())
+            .configure(|state| state.param(init.clone()).max_iters(100))
+            .run()
+            .unwrap();
+        let param = res.state().get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.0, epsilon = 0.05);
+        assert_relative_eq!(param[1], 1.0, epsilon = 0.1);
+
+        let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(0.0001f64).unwrap());
+        let problem = RobustLeastSquares::new(Line { data }, HuberLoss::new(0.1).unwrap());
+        let res = Executor::new(problem, GaussNewtonLS::new(linesearch))
+            .configure(|state| state.param(init).max_iters(100))
+            .run()
+            .unwrap();
+        let param = res.state().get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.0, epsilon = 0.05);
+        assert_relative_eq!(param[1], 1.0, epsilon = 0.1);
+    }
+}
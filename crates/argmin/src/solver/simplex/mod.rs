@@ -0,0 +1,659 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Simplex method
+//!
+//! Two-phase revised simplex method for linear programs in standard form.
+//!
+//! For details see [`Simplex`].
+//!
+//! ## References
+//!
+//! \[0\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+//!
+//! \[1\] Robert G. Bland (1977). New finite pivoting rules for the simplex method.
+//! Mathematics of Operations Research 2(2), 103-107.
+//! DOI: <https://doi.org/10.1287/moor.2.2.103>
+
+use crate::core::{
+    ArgminFloat, Error, LinearProgram, LinearProgramState, Problem, Solver, State,
+    TerminationReason, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Phase of the two-phase simplex method
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+enum Phase {
+    /// Searching for a basic feasible solution by minimizing the sum of artificial variables
+    One,
+    /// Minimizing the actual objective function
+    Two,
+}
+
+/// # Simplex method
+///
+/// Two-phase revised simplex method for linear programs in standard form
+///
+/// ```text
+/// minimize    c^T x
+/// subject to  A x = b
+///             x >= 0
+/// ```
+///
+/// In Phase I, an artificial variable is added for each constraint and their sum is minimized in
+/// order to find a basic feasible solution. If no such solution exists, the solver terminates with
+/// [`TerminationReason::Infeasible`]. Phase II then minimizes the actual objective function
+/// starting from this basic feasible solution. If the objective is unbounded from below on the
+/// feasible set, the solver terminates with [`TerminationReason::Unbounded`]. If an optimal
+/// solution is found, the solver terminates with [`TerminationReason::SolverConverged`].
+///
+/// Each iteration corresponds to a single pivot. Entering and leaving variables are chosen with
+/// Bland's rule, which guarantees termination also for degenerate problems.
+///
+/// The basis inverse is stored as a dense matrix, therefore this solver is intended for small to
+/// medium sized problems.
+///
+/// During Phase I the cost is reported as `+inf` because the current parameter vector is not
+/// feasible.
+///
+/// No initial parameter vector is required.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`LinearProgram`].
+///
+/// ## References
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+///
+/// Robert G. Bland (1977). New finite pivoting rules for the simplex method.
+/// Mathematics of Operations Research 2(2), 103-107.
+/// DOI: <https://doi.org/10.1287/moor.2.2.103>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Simplex<F> {
+    /// Tolerance used for pivoting and feasibility decisions
+    tol: F,
+    /// Current phase
+    phase: Phase,
+    /// Constraint matrix (rows may be negated such that `b >= 0`)
+    a: Vec<Vec<F>>,
+    /// Right-hand side (non-negative)
+    b: Vec<F>,
+    /// Objective function coefficients
+    c: Vec<F>,
+    /// Indices of basic variables. Indices `>= n` refer to artificial variables.
+    basis: Vec<usize>,
+    /// Inverse of the basis matrix
+    b_inv: Vec<Vec<F>>,
+    /// Values of the basic variables
+    x_b: Vec<F>,
+}
+
+impl<F: ArgminFloat> Simplex<F> {
+    /// Construct a new instance of [`Simplex`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simplex::Simplex;
+    /// let simplex: Simplex<f64> = Simplex::new();
+    /// ```
+    pub fn new() -> Self {
+        Simplex {
+            tol: F::epsilon().sqrt(),
+            phase: Phase::One,
+            a: vec![],
+            b: vec![],
+            c: vec![],
+            basis: vec![],
+            b_inv: vec![],
+            x_b: vec![],
+        }
+    }
+
+    /// Set tolerance used for pivoting and for deciding feasibility.
+    ///
+    /// Must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simplex::Simplex;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let simplex = Simplex::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Simplex`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Number of variables of the linear program (without artificial variables)
+    fn num_vars(&self) -> usize {
+        self.c.len()
+    }
+
+    /// Element `i` of column `j` of `[A, I]`
+    fn column_entry(&self, i: usize, j: usize) -> F {
+        let n = self.num_vars();
+        if j < n {
+            self.a[i][j]
+        } else if j - n == i {
+            float!(1.0)
+        } else {
+            float!(0.0)
+        }
+    }
+
+    /// Cost coefficient of variable `j` in the current phase
+    fn phase_cost(&self, j: usize) -> F {
+        let n = self.num_vars();
+        match self.phase {
+            Phase::One if j >= n => float!(1.0),
+            Phase::One => float!(0.0),
+            Phase::Two if j < n => self.c[j],
+            Phase::Two => float!(0.0),
+        }
+    }
+
+    /// Computes `B^{-1} a_j`
+    fn transformed_column(&self, j: usize) -> Vec<F> {
+        let m = self.b.len();
+        let n = self.num_vars();
+        if j >= n {
+            return (0..m).map(|i| self.b_inv[i][j - n]).collect();
+        }
+        (0..m)
+            .map(|i| {
+                (0..m).fold(float!(0.0), |acc, k| {
+                    acc + self.b_inv[i][k] * self.column_entry(k, j)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the entering variable according to Bland's rule (smallest index with negative
+    /// reduced cost) or `None` if the current basis is optimal for the current phase.
+    fn entering_variable(&self) -> Option<usize> {
+        let m = self.b.len();
+        let n = self.num_vars();
+
+        // simplex multipliers y^T = c_B^T B^{-1}
+        let y: Vec<F> = (0..m)
+            .map(|k| {
+                (0..m).fold(float!(0.0), |acc, i| {
+                    acc + self.phase_cost(self.basis[i]) * self.b_inv[i][k]
+                })
+            })
+            .collect();
+
+        // Artificial variables are never allowed to (re-)enter the basis.
+        (0..n).filter(|j| !self.basis.contains(j)).find(|&j| {
+            let reduced_cost = (0..m).fold(self.phase_cost(j), |acc, i| {
+                acc - y[i] * self.column_entry(i, j)
+            });
+            reduced_cost < -self.tol
+        })
+    }
+
+    /// Returns the row of the leaving variable according to the minimum ratio test. Ties are
+    /// broken by choosing the basic variable with the smallest index (Bland's rule). Returns `None`
+    /// if the column is unbounded.
+    fn leaving_row(&self, column: &[F]) -> Option<usize> {
+        let mut leaving: Option<(usize, F)> = None;
+        for (i, &u) in column.iter().enumerate() {
+            if u > self.tol {
+                let ratio = self.x_b[i] / u;
+                leaving = match leaving {
+                    None => Some((i, ratio)),
+                    Some((r, best)) => {
+                        if ratio < best - self.tol
+                            || ((ratio - best).abs() <= self.tol && self.basis[i] < self.basis[r])
+                        {
+                            Some((i, ratio))
+                        } else {
+                            Some((r, best))
+                        }
+                    }
+                };
+            }
+        }
+        leaving.map(|(r, _)| r)
+    }
+
+    /// Pivots variable `entering` into the basis at row `row`, where `column` is `B^{-1} a_j`.
+    fn pivot(&mut self, entering: usize, row: usize, column: &[F]) {
+        let m = self.b.len();
+        let pivot = column[row];
+        for k in 0..m {
+            self.b_inv[row][k] = self.b_inv[row][k] / pivot;
+        }
+        self.x_b[row] = self.x_b[row] / pivot;
+        for i in (0..m).filter(|&i| i != row) {
+            let factor = column[i];
+            if factor.abs() > float!(0.0) {
+                for k in 0..m {
+                    self.b_inv[i][k] = self.b_inv[i][k] - factor * self.b_inv[row][k];
+                }
+                self.x_b[i] = self.x_b[i] - factor * self.x_b[row];
+            }
+        }
+        self.basis[row] = entering;
+    }
+
+    /// Removes artificial variables from the basis after Phase I where possible. Artificial
+    /// variables which cannot be pivoted out correspond to redundant constraints and remain in
+    /// the basis at level zero.
+    fn drive_out_artificials(&mut self) {
+        let n = self.num_vars();
+        for row in 0..self.b.len() {
+            if self.basis[row] < n {
+                continue;
+            }
+            let candidate = (0..n)
+                .filter(|j| !self.basis.contains(j))
+                .map(|j| (j, self.transformed_column(j)))
+                .find(|(_, column)| column[row].abs() > self.tol);
+            if let Some((j, column)) = candidate {
+                self.pivot(j, row, &column);
+            }
+        }
+    }
+
+    /// Current primal solution of the linear program (without artificial variables)
+    fn primal_solution(&self) -> Vec<F> {
+        let n = self.num_vars();
+        let mut x = vec![float!(0.0); n];
+        for (&j, &v) in self.basis.iter().zip(self.x_b.iter()) {
+            if j < n {
+                x[j] = v;
+            }
+        }
+        x
+    }
+
+    /// Objective function value of the current primal solution. This is `+inf` during Phase I.
+    fn objective(&self, x: &[F]) -> F {
+        match self.phase {
+            Phase::One => F::infinity(),
+            Phase::Two => self
+                .c
+                .iter()
+                .zip(x.iter())
+                .fold(float!(0.0), |acc, (&c, &x)| acc + c * x),
+        }
+    }
+
+    /// Sum of all artificial variables in the basis
+    fn infeasibility(&self) -> F {
+        let n = self.num_vars();
+        self.basis
+            .iter()
+            .zip(self.x_b.iter())
+            .filter(|(&j, _)| j >= n)
+            .fold(float!(0.0), |acc, (_, &v)| acc + v)
+    }
+}
+
+impl<F: ArgminFloat> Default for Simplex<F> {
+    fn default() -> Simplex<F> {
+        Simplex::new()
+    }
+}
+
+impl<O, F> Solver<O, LinearProgramState<Vec<F>, F>> for Simplex<F>
+where
+    O: LinearProgram<Param = Vec<F>, Float = F>,
+    F: ArgminFloat,
+{
+    fn name(&self) -> &str {
+        "Simplex"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: LinearProgramState<Vec<F>, F>,
+    ) -> Result<(LinearProgramState<Vec<F>, F>, Option<KV>), Error> {
+        let c = problem.c()?;
+        let mut b = problem.b()?;
+        let mut a = problem.A()?;
+
+        let n = c.len();
+        let m = b.len();
+
+        if a.len() != m {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Simplex`: Number of rows of `A` ({}) does not match length of `b` ({}).",
+                    a.len(),
+                    m
+                )
+            ));
+        }
+        if let Some(row) = a.iter().find(|row| row.len() != n) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Simplex`: Number of columns of `A` ({}) does not match length of `c` ({}).",
+                    row.len(),
+                    n
+                )
+            ));
+        }
+
+        // Ensure b >= 0 such that the artificial variables form a feasible initial basis.
+        for (row, bi) in a.iter_mut().zip(b.iter_mut()) {
+            if *bi < float!(0.0) {
+                *bi = -*bi;
+                row.iter_mut().for_each(|aij| *aij = -*aij);
+            }
+        }
+
+        self.phase = Phase::One;
+        self.basis = (n..n + m).collect();
+        self.b_inv = (0..m)
+            .map(|i| {
+                (0..m)
+                    .map(|k| if i == k { float!(1.0) } else { float!(0.0) })
+                    .collect()
+            })
+            .collect();
+        self.x_b = b.clone();
+        self.a = a;
+        self.b = b;
+        self.c = c;
+
+        let x = self.primal_solution();
+        let cost = self.objective(&x);
+        Ok((
+            state.param(x).cost(cost),
+            Some(kv!("phase" => 1; "infeasibility" => self.infeasibility();)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: LinearProgramState<Vec<F>, F>,
+    ) -> Result<(LinearProgramState<Vec<F>, F>, Option<KV>), Error> {
+        let phase = self.phase;
+        let Some(entering) = self.entering_variable() else {
+            // Current basis is optimal for the current phase.
+            if phase == Phase::Two {
+                let x = self.primal_solution();
+                let cost = self.objective(&x);
+                return Ok((
+                    state
+                        .param(x)
+                        .cost(cost)
+                        .terminate_with(TerminationReason::SolverConverged),
+                    Some(kv!("phase" => 2;)),
+                ));
+            }
+
+            let b_norm = self
+                .b
+                .iter()
+                .fold(float!(1.0), |acc: F, &bi| acc.max(bi.abs()));
+            let infeasibility = self.infeasibility();
+            if infeasibility > self.tol * b_norm {
+                let x = self.primal_solution();
+                return Ok((
+                    state
+                        .param(x)
+                        .cost(F::infinity())
+                        .terminate_with(TerminationReason::Infeasible),
+                    Some(kv!("phase" => 1; "infeasibility" => infeasibility;)),
+                ));
+            }
+
+            self.drive_out_artificials();
+            self.phase = Phase::Two;
+            let x = self.primal_solution();
+            let cost = self.objective(&x);
+            return Ok((state.param(x).cost(cost), Some(kv!("phase" => 2;))));
+        };
+
+        let column = self.transformed_column(entering);
+        let Some(row) = self.leaving_row(&column) else {
+            // Phase I is bounded from below by zero, therefore this can only happen in Phase II.
+            let x = self.primal_solution();
+            let cost = self.objective(&x);
+            return Ok((
+                state
+                    .param(x)
+                    .cost(cost)
+                    .terminate_with(TerminationReason::Unbounded),
+                Some(kv!("phase" => 2; "entering" => entering as u64;)),
+            ));
+        };
+
+        let leaving = self.basis[row];
+        self.pivot(entering, row, &column);
+
+        let x = self.primal_solution();
+        let cost = self.objective(&x);
+        let kv = match phase {
+            Phase::One => kv!(
+                "phase" => 1;
+                "entering" => entering as u64;
+                "leaving" => leaving as u64;
+                "infeasibility" => self.infeasibility();
+            ),
+            Phase::Two => kv!(
+                "phase" => 2;
+                "entering" => entering as u64;
+                "leaving" => leaving as u64;
+            ),
+        };
+        Ok((state.param(x).cost(cost), Some(kv)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(simplex, Simplex<f64>);
+
+    struct LP {
+        c: Vec<f64>,
+        b: Vec<f64>,
+        a: Vec<Vec<f64>>,
+    }
+
+    impl LinearProgram for LP {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn c(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.c.clone())
+        }
+
+        fn b(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.b.clone())
+        }
+
+        fn A(&self) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(self.a.clone())
+        }
+    }
+
+    fn solve(problem: LP) -> LinearProgramState<Vec<f64>, f64> {
+        Executor::new(problem, Simplex::new())
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap()
+            .state
+    }
+
+    #[test]
+    fn test_new() {
+        let Simplex { tol, phase, .. } = Simplex::<f64>::new();
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(phase, Phase::One);
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let Simplex { tol, .. } = Simplex::new().with_tolerance(1e-4f64).unwrap();
+        assert_eq!(tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            assert_error!(
+                Simplex::new().with_tolerance(tol),
+                ArgminError,
+                "Invalid parameter: \"`Simplex`: tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_dimension_mismatch() {
+        let problem = LP {
+            c: vec![1.0, 1.0],
+            b: vec![1.0, 2.0],
+            a: vec![vec![1.0, 1.0]],
+        };
+        let res = Simplex::new().init(&mut Problem::new(problem), LinearProgramState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`Simplex`: Number of rows of `A` (1) ",
+                "does not match length of `b` (2).\""
+            )
+        );
+
+        let problem = LP {
+            c: vec![1.0, 1.0],
+            b: vec![1.0],
+            a: vec![vec![1.0, 1.0, 1.0]],
+        };
+        let res = Simplex::new().init(&mut Problem::new(problem), LinearProgramState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`Simplex`: Number of columns of `A` (3) ",
+                "does not match length of `c` (2).\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        // maximize 3x + 5y s.t. x <= 4, 2y <= 12, 3x + 2y <= 18 (with slack variables)
+        let problem = LP {
+            c: vec![-3.0, -5.0, 0.0, 0.0, 0.0],
+            b: vec![4.0, 12.0, 18.0],
+            a: vec![
+                vec![1.0, 0.0, 1.0, 0.0, 0.0],
+                vec![0.0, 2.0, 0.0, 1.0, 0.0],
+                vec![3.0, 2.0, 0.0, 0.0, 1.0],
+            ],
+        };
+        let state = solve(problem);
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 6.0, epsilon = 1e-10);
+        assert_relative_eq!(state.get_best_cost(), -36.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_solve_negative_rhs_and_redundant_constraint() {
+        // x1 + x2 = 2 is given twice, once with negated signs
+        let problem = LP {
+            c: vec![1.0, 2.0],
+            b: vec![2.0, -2.0],
+            a: vec![vec![1.0, 1.0], vec![-1.0, -1.0]],
+        };
+        let state = solve(problem);
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 0.0, epsilon = 1e-10);
+        assert_relative_eq!(state.get_best_cost(), 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_degenerate_problem_does_not_cycle() {
+        // Beale's example, which cycles with Dantzig's rule.
+        let problem = LP {
+            c: vec![0.0, 0.0, 0.0, -0.75, 20.0, -0.5, 6.0],
+            b: vec![0.0, 0.0, 1.0],
+            a: vec![
+                vec![1.0, 0.0, 0.0, 0.25, -8.0, -1.0, 9.0],
+                vec![0.0, 1.0, 0.0, 0.5, -12.0, -0.5, 3.0],
+                vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        };
+        let state = solve(problem);
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(state.get_best_cost(), -1.25, epsilon = 1e-10);
+        let x = state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 0.75, epsilon = 1e-10);
+        assert_relative_eq!(x[3], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[5], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_infeasible() {
+        let problem = LP {
+            c: vec![1.0, 1.0],
+            b: vec![1.0, 2.0],
+            a: vec![vec![1.0, 1.0], vec![1.0, 1.0]],
+        };
+        let state = solve(problem);
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::Infeasible)
+        );
+        assert!(state.get_best_cost().is_infinite());
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let problem = LP {
+            c: vec![-1.0, 0.0],
+            b: vec![1.0],
+            a: vec![vec![1.0, -1.0]],
+        };
+        let state = solve(problem);
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::Unbounded)
+        );
+    }
+}
@@ -19,13 +19,14 @@
 //! DOI: 10.1126/science.220.4598.671
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, TerminationReason,
-    TerminationStatus, KV,
+    ArgminFloat, BoundsHandling, CostFunction, Error, IgnoreBounds, IterState, Problem,
+    ProjectOntoBounds, Solver, TerminationReason, TerminationStatus, KV,
 };
 use rand::prelude::*;
 use rand_xoshiro::Xoshiro256PlusPlus;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
 /// This trait handles the annealing of a parameter vector. Problems which are to be solved using
 /// [`SimulatedAnnealing`] must implement this trait.
@@ -135,6 +136,13 @@ pub enum SATempFunc<F> {
 ///
 /// The optimization problem is required to implement [`CostFunction`].
 ///
+/// ## Box constraints
+///
+/// With [`with_box_constraints`](`SimulatedAnnealing::with_box_constraints`), the problem is
+/// additionally required to implement [`BoxConstraint`](`crate::core::BoxConstraint`). The initial
+/// parameter vector as well as every annealed parameter vector is then projected onto the
+/// feasible box before the cost function is evaluated.
+///
 /// ## References
 ///
 /// [Wikipedia](https://en.wikipedia.org/wiki/Simulated_annealing)
@@ -144,7 +152,7 @@ pub enum SATempFunc<F> {
 /// DOI: 10.1126/science.220.4598.671
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct SimulatedAnnealing<F, R> {
+pub struct SimulatedAnnealing<F, R, B = IgnoreBounds> {
     /// Initial temperature
     init_temp: F,
     /// Temperature function used for decreasing the temperature
@@ -175,6 +183,8 @@ pub struct SimulatedAnnealing<F, R> {
     cur_temp: F,
     /// random number generator
     rng: R,
+    /// handling of box constraints
+    box_constraints: PhantomData<B>,
 }
 
 impl<F> SimulatedAnnealing<F, Xoshiro256PlusPlus>
@@ -203,7 +213,7 @@ where
     }
 }
 
-impl<F, R> SimulatedAnnealing<F, R>
+impl<F, R> SimulatedAnnealing<F, R, IgnoreBounds>
 where
     F: ArgminFloat,
 {
@@ -247,10 +257,52 @@ where
                 reanneal_iter_best: 0,
                 cur_temp: init_temp,
                 rng,
+                box_constraints: PhantomData,
             })
         }
     }
 
+    /// Respect the box constraints of the problem
+    ///
+    /// Requires the problem to implement [`BoxConstraint`](`crate::core::BoxConstraint`).
+    /// Annealed parameter vectors are projected onto the feasible box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simulatedannealing::SimulatedAnnealing;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let sa = SimulatedAnnealing::new(100.0f64)?.with_box_constraints();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_box_constraints(self) -> SimulatedAnnealing<F, R, ProjectOntoBounds> {
+        SimulatedAnnealing {
+            init_temp: self.init_temp,
+            temp_func: self.temp_func,
+            temp_iter: self.temp_iter,
+            stall_iter_accepted: self.stall_iter_accepted,
+            stall_iter_accepted_limit: self.stall_iter_accepted_limit,
+            stall_iter_best: self.stall_iter_best,
+            stall_iter_best_limit: self.stall_iter_best_limit,
+            reanneal_fixed: self.reanneal_fixed,
+            reanneal_iter_fixed: self.reanneal_iter_fixed,
+            reanneal_accepted: self.reanneal_accepted,
+            reanneal_iter_accepted: self.reanneal_iter_accepted,
+            reanneal_best: self.reanneal_best,
+            reanneal_iter_best: self.reanneal_iter_best,
+            cur_temp: self.cur_temp,
+            rng: self.rng,
+            box_constraints: PhantomData,
+        }
+    }
+}
+
+impl<F, R, B> SimulatedAnnealing<F, R, B>
+where
+    F: ArgminFloat,
+{
     /// Set temperature function
     ///
     /// The temperature function defines how the temperature is decreased over the course of the
@@ -435,12 +487,13 @@ where
     }
 }
 
-impl<O, P, F, R> Solver<O, IterState<P, (), (), (), (), F>> for SimulatedAnnealing<F, R>
+impl<O, P, F, R, B> Solver<O, IterState<P, (), (), (), (), F>> for SimulatedAnnealing<F, R, B>
 where
     O: CostFunction<Param = P, Output = F> + Anneal<Param = P, Output = P, Float = F>,
     P: Clone,
     F: ArgminFloat,
     R: Rng,
+    B: BoundsHandling<O, P>,
 {
     fn name(&self) -> &str {
         "Simulated Annealing"
@@ -450,7 +503,7 @@ where
         problem: &mut Problem<O>,
         mut state: IterState<P, (), (), (), (), F>,
     ) -> Result<(IterState<P, (), (), (), (), F>, Option<KV>), Error> {
-        let param = state.take_param().ok_or_else(argmin_error_closure!(
+        let mut param = state.take_param().ok_or_else(argmin_error_closure!(
             NotInitialized,
             concat!(
                 "`SimulatedAnnealing` requires an initial parameter vector. ",
@@ -458,7 +511,11 @@ where
             )
         ))?;
 
-        let cost = state.get_cost();
+        let mut cost = state.get_cost();
+        if let Some(projected) = B::project(problem, &param)? {
+            param = projected;
+            cost = F::infinity();
+        }
         let cost = if cost.is_infinite() {
             problem.cost(&param)?
         } else {
@@ -495,7 +552,10 @@ where
         let prev_cost = state.get_cost();
 
         // Make a move
-        let new_param = problem.anneal(&prev_param, self.cur_temp)?;
+        let mut new_param = problem.anneal(&prev_param, self.cur_temp)?;
+        if let Some(projected) = B::project(problem, &new_param)? {
+            new_param = projected;
+        }
 
         // Evaluate cost function with new parameter vector
         let new_cost = problem.cost(&new_param)?;
@@ -572,7 +632,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{test_utils::TestProblem, ArgminError, State};
+    use crate::core::test_utils::{TestBoxProblem, TestProblem};
+    use crate::core::{ArgminError, Executor, State};
     use approx::assert_relative_eq;
 
     test_trait_impl!(sa, SimulatedAnnealing<f64, StdRng>);
@@ -597,6 +658,7 @@ mod tests {
             reanneal_iter_best,
             cur_temp,
             rng: _rng,
+            box_constraints,
         } = sa;
 
         assert_eq!(init_temp.to_ne_bytes(), 100.0f64.to_ne_bytes());
@@ -613,6 +675,7 @@ mod tests {
         assert_eq!(reanneal_best, u64::MAX);
         assert_eq!(reanneal_iter_best, 0);
         assert_eq!(cur_temp.to_ne_bytes(), 100.0f64.to_ne_bytes());
+        assert_eq!(box_constraints, PhantomData::<IgnoreBounds>);
 
         for temp in [0.0, -1.0, -f64::EPSILON, -100.0] {
             let res = SimulatedAnnealing::new(temp);
@@ -647,6 +710,7 @@ mod tests {
             reanneal_iter_best,
             cur_temp,
             rng,
+            box_constraints,
         } = sa;
 
         assert_eq!(init_temp.to_ne_bytes(), 100.0f64.to_ne_bytes());
@@ -665,6 +729,7 @@ mod tests {
         assert_eq!(cur_temp.to_ne_bytes(), 100.0f64.to_ne_bytes());
         // important part
         assert_eq!(rng, MyRng {});
+        assert_eq!(box_constraints, PhantomData::<IgnoreBounds>);
 
         for temp in [0.0, -1.0, -f64::EPSILON, -100.0] {
             let res = SimulatedAnnealing::new_with_rng(temp, MyRng {});
@@ -880,4 +945,23 @@ mod tests {
 
         assert_eq!(state_out.get_cost().to_ne_bytes(), 1.0f64.to_ne_bytes())
     }
+
+    #[test]
+    fn test_box_constraints() {
+        let sa = SimulatedAnnealing::new(10.0f64)
+            .unwrap()
+            .with_box_constraints();
+        let res = Executor::new(TestBoxProblem::new(), sa)
+            .configure(|state| state.param(vec![5.0, 5.0]).max_iters(10))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert_eq!(param[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(param[1].to_ne_bytes(), (-1.0f64).to_ne_bytes());
+        assert_eq!(
+            res.state.get_best_cost().to_ne_bytes(),
+            2.0f64.to_ne_bytes()
+        );
+    }
 }
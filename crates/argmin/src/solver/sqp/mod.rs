@@ -0,0 +1,892 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Sequential quadratic programming (SQP)
+//!
+//! Line search SQP method for problems with nonlinear equality and inequality constraints.
+//! For details see [`SQP`].
+//!
+//! ## Reference
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+mod qp;
+
+use crate::core::{
+    ArgminFloat, CostFunction, EqualityConstraints, Error, Executor, Gradient,
+    InequalityConstraints, IterState, LineSearch, OptimizationResult, Problem, Solver,
+    TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminAdd, ArgminCholesky, ArgminDot, ArgminEye, ArgminL2Norm, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Sequential quadratic programming (SQP)
+///
+/// Minimizes `f(x)` subject to equality constraints `h(x) = 0` and inequality constraints
+/// `g(x) <= 0`. In each iteration, the quadratic subproblem
+///
+/// `min_d 1/2 d^T B d + ∇f(x)^T d   s.t.   ∇h(x) d + h(x) = 0,  ∇g(x) d + g(x) <= 0`
+///
+/// is solved with a dual active set method (Goldfarb-Idnani), where `B` is a damped BFGS
+/// approximation of the Hessian of the Lagrangian (Powell's damping keeps `B` positive definite).
+/// The step length along `d` is determined by a line search on the l1 merit function
+///
+/// `φ(x) = f(x) + ν (||h(x)||_1 + ||max(0, g(x))||_1)`,
+///
+/// where the penalty parameter `ν` is increased whenever it falls below the magnitude of the
+/// Lagrange multipliers. Any line search of [`crate::solver::linesearch`] can be used; a
+/// backtracking line search with the Armijo condition is the usual choice for the (nonsmooth)
+/// merit function.
+///
+/// The algorithm terminates with [`TerminationReason::SolverConverged`] once the KKT residual
+/// (the maximum of the norm of the gradient of the Lagrangian, the constraint violation and the
+/// complementarity violation) falls below the tolerance set via
+/// [`with_tolerance_kkt`](`SQP::with_tolerance_kkt`). The KKT residual, the penalty parameter and
+/// the Lagrange multipliers are reported via `KV`.
+///
+/// Requires an initial parameter vector. An initial Hessian approximation can be provided via the
+/// `hessian` of the state, otherwise the identity matrix is used.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`],
+/// [`EqualityConstraints`] and [`InequalityConstraints`], all operating on `Vec<F>` (Jacobians as
+/// `Vec<Vec<F>>`, one row per constraint). Problems without equality or without inequality
+/// constraints return empty vectors. The required math traits for `Vec<F>` are provided by the
+/// `vec` feature of `argmin-math`.
+///
+/// Unlike most other solvers, `SQP` is restricted to `Vec<F>` and cannot be used with the other
+/// backends of `argmin-math`: The active set method adds and removes individual constraints,
+/// which requires access to the rows of the constraint Jacobians and to the individual Lagrange
+/// multipliers. The linear systems of the QP subproblem are solved via [`ArgminCholesky`].
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SQP<L, F> {
+    /// line search
+    linesearch: L,
+    /// tolerance on the KKT residual
+    tol_kkt: F,
+    /// penalty parameter of the merit function
+    merit_penalty: F,
+    /// Lagrange multipliers of the equality constraints
+    eq_multipliers: Vec<F>,
+    /// Lagrange multipliers of the inequality constraints
+    ineq_multipliers: Vec<F>,
+    /// KKT residual of the current iterate
+    kkt_residual: F,
+}
+
+impl<L, F> SQP<L, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SQP`]
+    ///
+    /// Requires a line search, which is used on the merit function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::sqp::SQP;
+    /// # let linesearch = ();
+    /// let sqp: SQP<_, f64> = SQP::new(linesearch);
+    /// ```
+    pub fn new(linesearch: L) -> Self {
+        SQP {
+            linesearch,
+            tol_kkt: F::epsilon().sqrt(),
+            merit_penalty: float!(1.0),
+            eq_multipliers: vec![],
+            ineq_multipliers: vec![],
+            kkt_residual: F::infinity(),
+        }
+    }
+
+    /// The algorithm stops if the KKT residual is below `tol_kkt`.
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::sqp::SQP;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let sqp: SQP<_, f64> = SQP::new(linesearch).with_tolerance_kkt(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_kkt(mut self, tol_kkt: F) -> Result<Self, Error> {
+        if tol_kkt < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SQP`: KKT tolerance must be >= 0."
+            ));
+        }
+        self.tol_kkt = tol_kkt;
+        Ok(self)
+    }
+
+    /// Set initial penalty parameter of the merit function
+    ///
+    /// Must be larger than 0 and defaults to `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::sqp::SQP;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let sqp: SQP<_, f64> = SQP::new(linesearch).with_merit_penalty(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_merit_penalty(mut self, penalty: F) -> Result<Self, Error> {
+        if penalty <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SQP`: merit penalty must be > 0."
+            ));
+        }
+        self.merit_penalty = penalty;
+        Ok(self)
+    }
+
+    /// Returns the current Lagrange multipliers of the equality constraints
+    pub fn eq_multipliers(&self) -> &[F] {
+        &self.eq_multipliers
+    }
+
+    /// Returns the current Lagrange multipliers of the inequality constraints
+    pub fn ineq_multipliers(&self) -> &[F] {
+        &self.ineq_multipliers
+    }
+
+    /// Returns the KKT residual of the current iterate
+    pub fn kkt_residual(&self) -> F {
+        self.kkt_residual
+    }
+}
+
+impl<O, L, F> Solver<O, IterState<Vec<F>, Vec<F>, (), Vec<Vec<F>>, (), F>> for SQP<L, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F>
+        + Gradient<Param = Vec<F>, Gradient = Vec<F>>
+        + EqualityConstraints<Param = Vec<F>, Output = Vec<F>, Jacobian = Vec<Vec<F>>>
+        + InequalityConstraints<Param = Vec<F>, Output = Vec<F>, Jacobian = Vec<Vec<F>>>,
+    L: Clone
+        + LineSearch<Vec<F>, F>
+        + Solver<MeritProblem<O, F>, IterState<Vec<F>, Vec<F>, (), (), (), F>>,
+    Vec<F>: ArgminDot<Vec<F>, F>
+        + ArgminL2Norm<F>
+        + ArgminAdd<Vec<F>, Vec<F>>
+        + ArgminSub<Vec<F>, Vec<F>>,
+    Vec<Vec<F>>: ArgminEye + ArgminDot<Vec<F>, Vec<F>> + ArgminCholesky<Vec<F>, Vec<F>>,
+    F: ArgminFloat,
+{
+    fn name(&self) -> &str {
+        "SQP"
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, Vec<F>, (), Vec<Vec<F>>, (), F>,
+    ) -> Result<
+        (
+            IterState<Vec<F>, Vec<F>, (), Vec<Vec<F>>, (), F>,
+            Option<KV>,
+        ),
+        Error,
+    > {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`SQP` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let n = param.len();
+
+        let hessian = state.take_hessian().unwrap_or_else(|| Vec::eye(n));
+        if hessian.len() != n || hessian.iter().any(|row| row.len() != n) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SQP`: Initial Hessian approximation must be a square matrix of size n x n."
+            ));
+        }
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+        let grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&param))?;
+
+        self.eq_multipliers = vec![float!(0.0); problem.equality_constraints(&param)?.len()];
+        self.ineq_multipliers = vec![float!(0.0); problem.inequality_constraints(&param)?.len()];
+        self.kkt_residual = F::infinity();
+
+        Ok((
+            state
+                .param(param)
+                .cost(cost)
+                .gradient(grad)
+                .hessian(hessian),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, Vec<F>, (), Vec<Vec<F>>, (), F>,
+    ) -> Result<
+        (
+            IterState<Vec<F>, Vec<F>, (), Vec<Vec<F>>, (), F>,
+            Option<KV>,
+        ),
+        Error,
+    > {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SQP`: Parameter vector in state not set."
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SQP`: Gradient in state not set."
+        ))?;
+        let hessian = state.take_hessian().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SQP`: Hessian approximation in state not set."
+        ))?;
+        let cost = state.get_cost();
+
+        let h = problem.equality_constraints(&param)?;
+        let jac_h = problem.equality_jacobian(&param)?;
+        let g = problem.inequality_constraints(&param)?;
+        let jac_g = problem.inequality_jacobian(&param)?;
+
+        // QP subproblem: `jac_h d = -h` and `-jac_g d >= g`
+        let neg_h: Vec<F> = h.iter().map(|&hi| -hi).collect();
+        let neg_jac_g: Vec<Vec<F>> = jac_g
+            .iter()
+            .map(|row| row.iter().map(|&v| -v).collect())
+            .collect();
+        let qp::QpSolution {
+            x: direction,
+            eq_multipliers,
+            ineq_multipliers,
+        } = qp::solve_qp(&hessian, &grad, &jac_h, &neg_h, &neg_jac_g, &g)?;
+        // Convert to the sign convention `∇f + ∇h^T λ + ∇g^T μ = 0`
+        let eq_multipliers: Vec<F> = eq_multipliers.iter().map(|&u| -u).collect();
+
+        // Update penalty parameter of merit function
+        let max_multiplier = eq_multipliers
+            .iter()
+            .chain(ineq_multipliers.iter())
+            .fold(float!(0.0), |acc: F, m| acc.max(m.abs()));
+        if self.merit_penalty <= max_multiplier {
+            self.merit_penalty = float!(1.5) * max_multiplier;
+        }
+
+        // Directional derivative of the merit function along `direction`
+        let violation_l1 = l1_violation(&h, &g);
+        let directional_derivative = grad.dot(&direction) - self.merit_penalty * violation_l1;
+
+        let (param_new, step_length) = if directional_derivative < float!(0.0) {
+            self.linesearch.search_direction(direction.clone());
+
+            let merit_problem = MeritProblem {
+                problem: problem.take_problem().ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`SQP`: Failed to take `problem` for line search"
+                ))?,
+                penalty: self.merit_penalty,
+            };
+            let merit_cost = cost + self.merit_penalty * violation_l1;
+            let merit_grad = merit_subgradient(&grad, &h, &jac_h, &g, &jac_g, self.merit_penalty);
+
+            let OptimizationResult {
+                problem: mut line_problem,
+                state: mut line_state,
+                ..
+            } = Executor::new(merit_problem, self.linesearch.clone())
+                .configure(|config| {
+                    config
+                        .param(param.clone())
+                        .gradient(merit_grad)
+                        .cost(merit_cost)
+                })
+                .inherit_func_eval_budget(problem)
+                .ctrlc(false)
+                .run()?;
+
+            // Get back problem and function evaluation counts
+            let MeritProblem {
+                problem: user_problem,
+                ..
+            } = line_problem
+                .take_problem()
+                .ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`SQP`: Failed to get back `problem` from line search"
+                ))?;
+            let cost_count = line_problem.counts.get("cost_count").copied().unwrap_or(0);
+            let gradient_count = line_problem
+                .counts
+                .get("gradient_count")
+                .copied()
+                .unwrap_or(0);
+            problem.problem = Some(user_problem);
+            problem.consume_func_counts(line_problem);
+            for (key, count) in [
+                ("equality_constraints_count", cost_count + gradient_count),
+                ("inequality_constraints_count", cost_count + gradient_count),
+                ("equality_jacobian_count", gradient_count),
+                ("inequality_jacobian_count", gradient_count),
+            ] {
+                *problem.counts.entry(key).or_insert(0) += count;
+            }
+
+            let param_new = line_state.take_param().ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`SQP`: No `param` returned by line search"
+            ))?;
+            let step_length = param_new.sub(&param).l2_norm() / direction.l2_norm();
+            (param_new, step_length)
+        } else {
+            // Not a descent direction for the merit function (typically because `direction` is
+            // numerically zero): take the full step.
+            (param.add(&direction), float!(1.0))
+        };
+
+        // Evaluate problem at new parameter vector
+        let cost_new = problem.cost(&param_new)?;
+        let grad_new = problem.gradient(&param_new)?;
+        let h_new = problem.equality_constraints(&param_new)?;
+        let jac_h_new = problem.equality_jacobian(&param_new)?;
+        let g_new = problem.inequality_constraints(&param_new)?;
+        let jac_g_new = problem.inequality_jacobian(&param_new)?;
+
+        // Damped BFGS update of the Hessian of the Lagrangian
+        let lagrangian_grad =
+            lagrangian_gradient(&grad, &jac_h, &jac_g, &eq_multipliers, &ineq_multipliers);
+        let lagrangian_grad_new = lagrangian_gradient(
+            &grad_new,
+            &jac_h_new,
+            &jac_g_new,
+            &eq_multipliers,
+            &ineq_multipliers,
+        );
+        let s = param_new.sub(&param);
+        let y = lagrangian_grad_new.sub(&lagrangian_grad);
+        let hessian = damped_bfgs_update(hessian, &s, &y);
+
+        // KKT residual
+        let stationarity = lagrangian_grad_new
+            .iter()
+            .fold(float!(0.0), |acc: F, v| acc.max(v.abs()));
+        let feasibility = h_new
+            .iter()
+            .map(|hi| hi.abs())
+            .chain(g_new.iter().map(|&gi| gi.max(float!(0.0))))
+            .fold(float!(0.0), |acc: F, v| acc.max(v));
+        let complementarity = ineq_multipliers
+            .iter()
+            .zip(g_new.iter())
+            .fold(float!(0.0), |acc: F, (&mi, &gi)| acc.max((mi * gi).abs()));
+        self.kkt_residual = stationarity.max(feasibility).max(complementarity);
+        self.eq_multipliers = eq_multipliers;
+        self.ineq_multipliers = ineq_multipliers;
+
+        Ok((
+            state
+                .param(param_new)
+                .cost(cost_new)
+                .gradient(grad_new)
+                .hessian(hessian),
+            Some(kv!(
+                "kkt_residual" => self.kkt_residual;
+                "merit_penalty" => self.merit_penalty;
+                "step_length" => step_length;
+                "eq_multipliers" => format!("{:?}", self.eq_multipliers);
+                "ineq_multipliers" => format!("{:?}", self.ineq_multipliers);
+            )),
+        ))
+    }
+
+    fn terminate(
+        &mut self,
+        _state: &IterState<Vec<F>, Vec<F>, (), Vec<Vec<F>>, (), F>,
+    ) -> TerminationStatus {
+        if self.kkt_residual <= self.tol_kkt {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+/// l1 merit function `f(x) + ν (||h(x)||_1 + ||max(0, g(x))||_1)` used by the line search of
+/// [`SQP`].
+///
+/// The gradient is a subgradient of the merit function.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct MeritProblem<O, F> {
+    /// user defined problem
+    problem: O,
+    /// penalty parameter
+    penalty: F,
+}
+
+impl<O, F> CostFunction for MeritProblem<O, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F>
+        + EqualityConstraints<Param = Vec<F>, Output = Vec<F>>
+        + InequalityConstraints<Param = Vec<F>, Output = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let h = self.problem.equality_constraints(param)?;
+        let g = self.problem.inequality_constraints(param)?;
+        Ok(self.problem.cost(param)? + self.penalty * l1_violation(&h, &g))
+    }
+}
+
+impl<O, F> Gradient for MeritProblem<O, F>
+where
+    O: Gradient<Param = Vec<F>, Gradient = Vec<F>>
+        + EqualityConstraints<Param = Vec<F>, Output = Vec<F>, Jacobian = Vec<Vec<F>>>
+        + InequalityConstraints<Param = Vec<F>, Output = Vec<F>, Jacobian = Vec<Vec<F>>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(merit_subgradient(
+            &self.problem.gradient(param)?,
+            &self.problem.equality_constraints(param)?,
+            &self.problem.equality_jacobian(param)?,
+            &self.problem.inequality_constraints(param)?,
+            &self.problem.inequality_jacobian(param)?,
+            self.penalty,
+        ))
+    }
+}
+
+/// Computes `||h||_1 + ||max(0, g)||_1`
+fn l1_violation<F: ArgminFloat>(h: &[F], g: &[F]) -> F {
+    h.iter()
+        .map(|hi| hi.abs())
+        .chain(g.iter().map(|&gi| gi.max(float!(0.0))))
+        .fold(float!(0.0), |acc, v| acc + v)
+}
+
+/// Computes a subgradient of the l1 merit function
+fn merit_subgradient<F: ArgminFloat>(
+    grad: &[F],
+    h: &[F],
+    jac_h: &[Vec<F>],
+    g: &[F],
+    jac_g: &[Vec<F>],
+    penalty: F,
+) -> Vec<F> {
+    let signs_h: Vec<F> = h
+        .iter()
+        .map(|&hi| {
+            if hi > float!(0.0) {
+                penalty
+            } else if hi < float!(0.0) {
+                -penalty
+            } else {
+                float!(0.0)
+            }
+        })
+        .collect();
+    let signs_g: Vec<F> = g
+        .iter()
+        .map(|&gi| {
+            if gi > float!(0.0) {
+                penalty
+            } else {
+                float!(0.0)
+            }
+        })
+        .collect();
+    lagrangian_gradient(grad, jac_h, jac_g, &signs_h, &signs_g)
+}
+
+/// Computes `grad + jac_h^T lambda + jac_g^T mu`
+fn lagrangian_gradient<F: ArgminFloat>(
+    grad: &[F],
+    jac_h: &[Vec<F>],
+    jac_g: &[Vec<F>],
+    lambda: &[F],
+    mu: &[F],
+) -> Vec<F> {
+    jac_h
+        .iter()
+        .zip(lambda.iter())
+        .chain(jac_g.iter().zip(mu.iter()))
+        .fold(grad.to_vec(), |acc, (row, &m)| {
+            acc.iter()
+                .zip(row.iter())
+                .map(|(&a, &r)| a + m * r)
+                .collect()
+        })
+}
+
+/// Damped BFGS update (Powell) which keeps the Hessian approximation positive definite
+fn damped_bfgs_update<F>(b: Vec<Vec<F>>, s: &Vec<F>, y: &Vec<F>) -> Vec<Vec<F>>
+where
+    Vec<F>: ArgminDot<Vec<F>, F>,
+    Vec<Vec<F>>: ArgminDot<Vec<F>, Vec<F>>,
+    F: ArgminFloat,
+{
+    let bs: Vec<F> = b.dot(s);
+    let sbs = s.dot(&bs);
+    let sy = s.dot(y);
+    if sbs <= F::min_positive_value() {
+        return b;
+    }
+    let theta = if sy >= float!(0.2) * sbs {
+        float!(1.0)
+    } else {
+        float!(0.8) * sbs / (sbs - sy)
+    };
+    let r: Vec<F> = y
+        .iter()
+        .zip(bs.iter())
+        .map(|(&yi, &bsi)| theta * yi + (float!(1.0) - theta) * bsi)
+        .collect();
+    let sr = s.dot(&r);
+    b.iter()
+        .zip(bs.iter().zip(r.iter()))
+        .map(|(row, (&bsi, &ri))| {
+            row.iter()
+                .zip(bs.iter().zip(r.iter()))
+                .map(|(&bij, (&bsj, &rj))| bij - bsi * bsj / sbs + ri * rj / sr)
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, State};
+    use crate::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+    use approx::assert_relative_eq;
+
+    type Backtracking = BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64>;
+
+    test_trait_impl!(sqp, SQP<Backtracking, f64>);
+
+    fn linesearch() -> Backtracking {
+        BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap())
+    }
+
+    /// Bracken and McCormick: min (x - 2)^2 + (y - 1)^2
+    /// s.t. x - 2y + 1 = 0 and x^2/4 + y^2 - 1 <= 0
+    #[derive(Clone)]
+    struct Bracken {}
+
+    impl CostFunction for Bracken {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Bracken {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl EqualityConstraints for Bracken {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0] - 2.0 * p[1] + 1.0])
+        }
+
+        fn equality_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![1.0, -2.0]])
+        }
+    }
+
+    impl InequalityConstraints for Bracken {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0].powi(2) / 4.0 + p[1].powi(2) - 1.0])
+        }
+
+        fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![p[0] / 2.0, 2.0 * p[1]]])
+        }
+    }
+
+    /// min (x - 2)^2 + (y - 1)^2 s.t. x^2 + y^2 - 1 <= 0
+    #[derive(Clone)]
+    struct Disk {}
+
+    impl CostFunction for Disk {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Disk {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl EqualityConstraints for Disk {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraints(&self, _p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![])
+        }
+
+        fn equality_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![])
+        }
+    }
+
+    impl InequalityConstraints for Disk {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+        }
+
+        fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let SQP {
+            linesearch: _,
+            tol_kkt,
+            merit_penalty,
+            eq_multipliers,
+            ineq_multipliers,
+            kkt_residual,
+        } = SQP::<_, f64>::new(linesearch());
+
+        assert_eq!(tol_kkt.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(merit_penalty.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(eq_multipliers.is_empty());
+        assert!(ineq_multipliers.is_empty());
+        assert_eq!(kkt_residual.to_ne_bytes(), f64::INFINITY.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_tolerance_kkt() {
+        for tol in [0.0, 1e-6, 1.0] {
+            let sqp = SQP::<_, f64>::new(()).with_tolerance_kkt(tol);
+            assert!(sqp.is_ok());
+            assert_eq!(sqp.unwrap().tol_kkt.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        let sqp = SQP::<_, f64>::new(()).with_tolerance_kkt(-1.0);
+        assert_error!(
+            sqp,
+            ArgminError,
+            "Invalid parameter: \"`SQP`: KKT tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_with_merit_penalty() {
+        for penalty in [f64::EPSILON, 1.0, 100.0] {
+            let sqp = SQP::<_, f64>::new(()).with_merit_penalty(penalty);
+            assert!(sqp.is_ok());
+            assert_eq!(
+                sqp.unwrap().merit_penalty.to_ne_bytes(),
+                penalty.to_ne_bytes()
+            );
+        }
+
+        for penalty in [0.0, -1.0] {
+            let sqp = SQP::<_, f64>::new(()).with_merit_penalty(penalty);
+            assert_error!(
+                sqp,
+                ArgminError,
+                "Invalid parameter: \"`SQP`: merit penalty must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut sqp = SQP::<_, f64>::new(linesearch());
+        let res = sqp.init(&mut Problem::new(Bracken {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`SQP` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init_wrong_hessian() {
+        let mut sqp = SQP::<_, f64>::new(linesearch());
+        let res = sqp.init(
+            &mut Problem::new(Bracken {}),
+            IterState::new()
+                .param(vec![0.0, 0.0])
+                .hessian(vec![vec![1.0]]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`SQP`: Initial Hessian approximation must be a square ",
+                "matrix of size n x n.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut sqp = SQP::<_, f64>::new(linesearch());
+        let (state, kv) = sqp
+            .init(
+                &mut Problem::new(Bracken {}),
+                IterState::new().param(vec![2.0, 2.0]),
+            )
+            .unwrap();
+        assert!(kv.is_none());
+        assert_eq!(state.get_cost().to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(state.get_gradient().unwrap(), &vec![0.0, 2.0]);
+        assert_eq!(
+            state.get_hessian().unwrap(),
+            &vec![vec![1.0, 0.0], vec![0.0, 1.0]]
+        );
+        assert_eq!(sqp.eq_multipliers, vec![0.0]);
+        assert_eq!(sqp.ineq_multipliers, vec![0.0]);
+    }
+
+    #[test]
+    fn test_solve() {
+        let res = Executor::new(Bracken {}, SQP::new(linesearch()))
+            .configure(|state| state.param(vec![2.0, 2.0]).max_iters(100).counting(true))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        let x = (7.0f64.sqrt() - 1.0) / 2.0;
+        assert_relative_eq!(param[0], x, epsilon = 1e-6);
+        assert_relative_eq!(param[1], (x + 1.0) / 2.0, epsilon = 1e-6);
+        assert!(res.solver.kkt_residual() <= f64::EPSILON.sqrt());
+        assert!(res.solver.ineq_multipliers()[0] > 0.0);
+        assert!(res.state.get_func_counts()["equality_jacobian_count"] > 0);
+        assert!(res.state.get_func_counts()["inequality_constraints_count"] > 0);
+    }
+
+    #[test]
+    fn test_solve_inequality_only() {
+        let res = Executor::new(Disk {}, SQP::new(linesearch()))
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 2.0 / 5.0f64.sqrt(), epsilon = 1e-6);
+        assert_relative_eq!(param[1], 1.0 / 5.0f64.sqrt(), epsilon = 1e-6);
+        assert!(res.solver.eq_multipliers().is_empty());
+        assert_relative_eq!(
+            res.solver.ineq_multipliers()[0],
+            5.0f64.sqrt() - 1.0,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_next_iter_kv() {
+        let mut sqp = SQP::<_, f64>::new(linesearch());
+        let mut problem = Problem::new(Bracken {});
+        let (state, _) = sqp
+            .init(&mut problem, IterState::new().param(vec![2.0, 2.0]))
+            .unwrap();
+        let (state, kv) = sqp.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+        assert_relative_eq!(
+            kv.get("kkt_residual").unwrap().get_float().unwrap(),
+            sqp.kkt_residual()
+        );
+        assert!(kv.get("merit_penalty").is_some());
+        assert!(kv.get("step_length").is_some());
+        assert!(kv.get("eq_multipliers").is_some());
+        assert!(kv.get("ineq_multipliers").is_some());
+        assert!(state.get_param().is_some());
+        assert!(problem.problem.is_some());
+    }
+
+    #[test]
+    fn test_damped_bfgs_update_stays_positive_definite() {
+        let b = Vec::<Vec<f64>>::eye(2);
+        // Negative curvature: `s^T y < 0`
+        let b = damped_bfgs_update(b, &vec![1.0, 0.0], &vec![-1.0, 0.0]);
+        assert!(b[0][0] > 0.0);
+        assert!(b[0][0] * b[1][1] - b[0][1] * b[1][0] > 0.0);
+    }
+}
@@ -0,0 +1,385 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Dense solver for strictly convex quadratic programs
+//!
+//! Implements the dual active set method of Goldfarb and Idnani, which does not require a
+//! feasible starting point.
+//!
+//! ## Reference
+//!
+//! D. Goldfarb and A. Idnani (1983). A numerically stable dual method for solving strictly convex
+//! quadratic programs. Mathematical Programming 27, 1-33.
+
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminCholesky, ArgminDot, ArgminL2Norm};
+
+/// Solution of a quadratic program
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct QpSolution<F> {
+    /// Minimizer
+    pub x: Vec<F>,
+    /// Multipliers of the equality constraints
+    pub eq_multipliers: Vec<F>,
+    /// Multipliers of the inequality constraints (non-negative)
+    pub ineq_multipliers: Vec<F>,
+}
+
+/// Constraint in the active set
+struct Active<F> {
+    /// Normal of the constraint (possibly with flipped sign)
+    normal: Vec<F>,
+    /// `G^-1` times the normal
+    g_inv_normal: Vec<F>,
+    /// Right hand side (possibly with flipped sign)
+    rhs: F,
+    /// Index of the constraint and whether it is an equality constraint
+    index: usize,
+    equality: bool,
+    /// `-1` if the sign of an equality constraint was flipped, `1` otherwise
+    sign: F,
+}
+
+/// Solves `min 1/2 x^T G x + a^T x` subject to `C_eq x = b_eq` and `C_in x >= b_in`.
+///
+/// `G` must be symmetric positive definite. The rows of `C_eq` and `C_in` are the constraint
+/// normals. The returned multipliers `u` satisfy `G x + a = C_eq^T u_eq + C_in^T u_in` with
+/// `u_in >= 0`.
+pub(super) fn solve_qp<F>(
+    g: &Vec<Vec<F>>,
+    a: &Vec<F>,
+    c_eq: &[Vec<F>],
+    b_eq: &[F],
+    c_in: &[Vec<F>],
+    b_in: &[F],
+) -> Result<QpSolution<F>, Error>
+where
+    F: ArgminFloat,
+    Vec<F>: ArgminDot<Vec<F>, F> + ArgminL2Norm<F>,
+    Vec<Vec<F>>: ArgminCholesky<Vec<F>, Vec<F>>,
+{
+    let n = a.len();
+
+    // Unconstrained minimum. The Cholesky decomposition fails if `G` is not positive definite.
+    let mut x: Vec<F> = g
+        .cholesky_solve(a)
+        .ok()
+        .ok_or_else(argmin_error_closure!(
+            InvalidParameter,
+            "`SQP`: Hessian approximation of QP subproblem is not positive definite."
+        ))?
+        .into_iter()
+        .map(|v| -v)
+        .collect();
+    let mut active: Vec<Active<F>> = Vec::new();
+    let mut u: Vec<F> = Vec::new();
+    let mut eq_done = vec![false; c_eq.len()];
+    let mut in_active = vec![false; c_in.len()];
+
+    let tol = F::epsilon() * float!(1e3);
+    let max_iter = 10 * (n + c_eq.len() + c_in.len()) + 100;
+
+    for _ in 0..max_iter {
+        // Step 1: Choose a violated constraint; equality constraints first
+        let next = if let Some(i) = eq_done.iter().position(|done| !done) {
+            let s = c_eq[i].dot(&x) - b_eq[i];
+            let sign = if s > float!(0.0) {
+                float!(-1.0)
+            } else {
+                float!(1.0)
+            };
+            let normal: Vec<F> = c_eq[i].iter().map(|&c| c * sign).collect();
+            Some(Active {
+                g_inv_normal: g.cholesky_solve(&normal)?,
+                normal,
+                rhs: b_eq[i] * sign,
+                index: i,
+                equality: true,
+                sign,
+            })
+        } else {
+            c_in.iter()
+                .zip(b_in.iter())
+                .enumerate()
+                .filter(|(i, _)| !in_active[*i])
+                .map(|(i, (c, &b))| (i, c.dot(&x) - b, b))
+                .filter(|&(_, s, b)| s < -tol * (float!(1.0) + b.abs()))
+                .min_by(|(_, s1, _), (_, s2, _)| {
+                    s1.partial_cmp(s2).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(i, _, _)| -> Result<_, Error> {
+                    Ok(Active {
+                        normal: c_in[i].clone(),
+                        g_inv_normal: g.cholesky_solve(&c_in[i])?,
+                        rhs: b_in[i],
+                        index: i,
+                        equality: false,
+                        sign: float!(1.0),
+                    })
+                })
+                .transpose()?
+        };
+
+        let Some(p) = next else {
+            // All constraints satisfied: optimal
+            let mut eq_multipliers = vec![float!(0.0); c_eq.len()];
+            let mut ineq_multipliers = vec![float!(0.0); c_in.len()];
+            for (c, &ui) in active.iter().zip(u.iter()) {
+                if c.equality {
+                    eq_multipliers[c.index] = ui * c.sign;
+                } else {
+                    ineq_multipliers[c.index] = ui;
+                }
+            }
+            return Ok(QpSolution {
+                x,
+                eq_multipliers,
+                ineq_multipliers,
+            });
+        };
+
+        // Step 2: Determine step direction and step length
+        let mut u_plus = u.clone();
+        u_plus.push(float!(0.0));
+        loop {
+            let (z, r) = step_directions(&active, &p)?;
+            let s = p.normal.dot(&x) - p.rhs;
+
+            // Partial step length (dual feasibility)
+            let (t1, drop) = active
+                .iter()
+                .zip(r.iter())
+                .enumerate()
+                .filter(|(_, (c, &rj))| !c.equality && rj > float!(0.0))
+                .map(|(j, (_, &rj))| (u_plus[j] / rj, Some(j)))
+                .fold(
+                    (F::infinity(), None),
+                    |acc, v| if v.0 < acc.0 { v } else { acc },
+                );
+
+            // Full step length (primal feasibility)
+            let zn = z.dot(&p.normal);
+            let t2 = if zn <= tol * p.normal.l2_norm().powi(2) {
+                F::infinity()
+            } else {
+                -s / zn
+            };
+
+            if t1.is_infinite() && t2.is_infinite() {
+                if p.equality && s.abs() <= tol * (float!(1.0) + p.rhs.abs()) {
+                    // Linearly dependent, but consistent equality constraint
+                    eq_done[p.index] = true;
+                    break;
+                }
+                return Err(argmin_error!(
+                    ConditionViolated,
+                    "`SQP`: QP subproblem is infeasible."
+                ));
+            }
+
+            let t = t1.min(t2);
+            if t2.is_finite() {
+                x.iter_mut()
+                    .zip(z.iter())
+                    .for_each(|(xi, &zi)| *xi = *xi + t * zi);
+            }
+            u_plus
+                .iter_mut()
+                .zip(r.iter())
+                .for_each(|(ui, &ri)| *ui = *ui - t * ri);
+            *u_plus.last_mut().unwrap() = *u_plus.last().unwrap() + t;
+
+            if t2 <= t1 {
+                // Full step: add constraint to active set
+                if p.equality {
+                    eq_done[p.index] = true;
+                } else {
+                    in_active[p.index] = true;
+                }
+                active.push(p);
+                u = u_plus;
+                break;
+            }
+
+            // Partial step: drop constraint from active set
+            let drop = drop.unwrap();
+            let removed = active.remove(drop);
+            in_active[removed.index] = false;
+            u_plus.remove(drop);
+        }
+    }
+
+    Err(argmin_error!(
+        ConditionViolated,
+        "`SQP`: Maximum number of iterations of QP subproblem solver reached."
+    ))
+}
+
+/// Computes the primal step direction `z = H n` and the dual step direction `r = N* n` for the
+/// constraint `p`.
+fn step_directions<F>(active: &[Active<F>], p: &Active<F>) -> Result<(Vec<F>, Vec<F>), Error>
+where
+    F: ArgminFloat,
+    Vec<F>: ArgminDot<Vec<F>, F>,
+    Vec<Vec<F>>: ArgminCholesky<Vec<F>, Vec<F>>,
+{
+    let w = p.g_inv_normal.clone();
+    if active.is_empty() {
+        return Ok((w, vec![]));
+    }
+    let m: Vec<Vec<F>> = active
+        .iter()
+        .map(|ci| {
+            active
+                .iter()
+                .map(|cj| ci.normal.dot(&cj.g_inv_normal))
+                .collect()
+        })
+        .collect();
+    let rhs: Vec<F> = active.iter().map(|c| c.normal.dot(&w)).collect();
+    let r = m
+        .cholesky_solve(&rhs)
+        .ok()
+        .ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SQP`: Active constraints of QP subproblem are linearly dependent."
+        ))?;
+    let z = active.iter().zip(r.iter()).fold(w, |z, (cj, &rj)| {
+        z.iter()
+            .zip(cj.g_inv_normal.iter())
+            .map(|(&zi, &gi)| zi - rj * gi)
+            .collect()
+    });
+    Ok((z, r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_unconstrained() {
+        let g = vec![vec![2.0f64, 0.0], vec![0.0, 2.0]];
+        let res = solve_qp(&g, &vec![-4.0, -2.0], &[], &[], &[], &[]).unwrap();
+        assert_relative_eq!(res.x[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(res.x[1], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_equality() {
+        // min (x-2)^2 + (y-1)^2 s.t. x + y = 1
+        let g = vec![vec![2.0f64, 0.0], vec![0.0, 2.0]];
+        let res = solve_qp(&g, &vec![-4.0, -2.0], &[vec![1.0, 1.0]], &[1.0], &[], &[]).unwrap();
+        assert_relative_eq!(res.x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.x[1], 0.0, epsilon = 1e-12);
+        // G x + a = u * c
+        assert_relative_eq!(res.eq_multipliers[0], -2.0, epsilon = 1e-12);
+
+        // Sign of the constraint must not matter
+        let res = solve_qp(
+            &g,
+            &vec![-4.0, -2.0],
+            &[vec![-1.0, -1.0]],
+            &[-1.0],
+            &[],
+            &[],
+        )
+        .unwrap();
+        assert_relative_eq!(res.x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.x[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(res.eq_multipliers[0], 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_dependent_equality() {
+        let g = vec![vec![2.0f64, 0.0], vec![0.0, 2.0]];
+        let c_eq = vec![vec![1.0, 1.0], vec![2.0, 2.0]];
+        let res = solve_qp(&g, &vec![-4.0, -2.0], &c_eq, &[1.0, 2.0], &[], &[]).unwrap();
+        assert_relative_eq!(res.x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.x[1], 0.0, epsilon = 1e-12);
+
+        let res = solve_qp(&g, &vec![-4.0, -2.0], &c_eq, &[1.0, 3.0], &[], &[]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`SQP`: QP subproblem is infeasible.\""
+        );
+    }
+
+    #[test]
+    fn test_inequality() {
+        // min (x-2)^2 + (y-1)^2 s.t. x <= 1, y <= 2 (inactive), x + y >= 0 (inactive)
+        let g = vec![vec![2.0f64, 0.0], vec![0.0, 2.0]];
+        let c_in = vec![vec![-1.0, 0.0], vec![0.0, -1.0], vec![1.0, 1.0]];
+        let b_in = vec![-1.0, -2.0, 0.0];
+        let res = solve_qp(&g, &vec![-4.0, -2.0], &[], &[], &c_in, &b_in).unwrap();
+        assert_relative_eq!(res.x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.x[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.ineq_multipliers[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(res.ineq_multipliers[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(res.ineq_multipliers[2], 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_mixed() {
+        // min x^2 + y^2 + z^2 s.t. x + y + z = 3, x >= 2
+        let g = vec![
+            vec![2.0f64, 0.0, 0.0],
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 2.0],
+        ];
+        let res = solve_qp(
+            &g,
+            &vec![0.0, 0.0, 0.0],
+            &[vec![1.0, 1.0, 1.0]],
+            &[3.0],
+            &[vec![1.0, 0.0, 0.0]],
+            &[2.0],
+        )
+        .unwrap();
+        assert_relative_eq!(res.x[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(res.x[1], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(res.x[2], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(res.eq_multipliers[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.ineq_multipliers[0], 3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_infeasible() {
+        // x >= 1 and x <= 0
+        let g = vec![vec![1.0f64]];
+        let res = solve_qp(
+            &g,
+            &vec![0.0],
+            &[],
+            &[],
+            &[vec![1.0], vec![-1.0]],
+            &[1.0, 0.0],
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`SQP`: QP subproblem is infeasible.\""
+        );
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let g = vec![vec![-1.0f64]];
+        let res = solve_qp(&g, &vec![0.0], &[], &[], &[], &[]);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`SQP`: Hessian approximation of QP subproblem is not ",
+                "positive definite.\""
+            )
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::core::{
     TerminationStatus, TrustRegionRadius, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminWeightedDot,
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSolve, ArgminSub, ArgminWeightedDot,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -62,7 +62,7 @@ where
         + ArgminDot<P, F>
         + ArgminAdd<P, P>
         + ArgminSub<P, P>,
-    H: ArgminInv<H> + ArgminDot<P, P>,
+    H: ArgminSolve<P, P> + ArgminDot<P, P>,
     F: ArgminFloat,
 {
     fn name(&self) -> &str {
@@ -95,7 +95,7 @@ where
         let pstar;
 
         // pb = -H^-1g
-        let pb = (h.inv()?).dot(&g).mul(&float!(-1.0));
+        let pb = h.solve(&g)?.mul(&float!(-1.0));
 
         if pb.l2_norm() <= self.radius {
             pstar = pb;
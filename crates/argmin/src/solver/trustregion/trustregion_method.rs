@@ -245,6 +245,7 @@ where
                     .gradient(grad.clone())
                     .hessian(hessian.clone())
             })
+            .inherit_func_eval_budget(problem)
             .ctrlc(false)
             .run()?;
 
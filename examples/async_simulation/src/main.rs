@@ -0,0 +1,63 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{observers::ObserverMode, AsyncCostFunction, AsyncProblem, Error, Executor},
+    solver::particleswarm::ParticleSwarm,
+};
+use argmin_observer_slog::SlogLogger;
+use argmin_testfunctions::himmelblau;
+use std::time::Duration;
+
+/// Stand-in for a simulation service which takes a while to respond
+struct Simulation {}
+
+impl AsyncCostFunction for Simulation {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    async fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok(himmelblau(&[param[0], param[1]]))
+    }
+}
+
+async fn run() -> Result<(), Error> {
+    let problem = AsyncProblem::new(Simulation {});
+
+    // All particles of an iteration are evaluated concurrently
+    let solver = ParticleSwarm::new((vec![-4.0, -4.0], vec![4.0, 4.0]), 40);
+
+    // Meanwhile, the runtime is free to work on other tasks
+    let heartbeat = tokio::spawn(async {
+        loop {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            println!("Runtime is still responsive");
+        }
+    });
+
+    let res = Executor::new(problem, solver)
+        .configure(|state| state.max_iters(50))
+        .add_observer(SlogLogger::term(), ObserverMode::Every(10))
+        .timer(true)
+        .run_async()
+        .await?;
+
+    heartbeat.abort();
+
+    // Print Result
+    println!("{res}");
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(ref e) = run().await {
+        println!("{e}");
+    }
+}
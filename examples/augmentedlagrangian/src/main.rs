@@ -0,0 +1,83 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{CostFunction, Error, Executor, Gradient, InequalityConstraints, State},
+    solver::{
+        augmentedlagrangian::AugmentedLagrangian, linesearch::MoreThuenteLineSearch,
+        quasinewton::LBFGS,
+    },
+};
+use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+
+/// Rosenbrock function restricted to the unit disk
+struct ConstrainedRosenbrock {}
+
+impl CostFunction for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rosenbrock(p))
+    }
+}
+
+impl Gradient for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rosenbrock_derivative(p))
+    }
+}
+
+impl InequalityConstraints for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+    type Jacobian = Vec<Vec<f64>>;
+
+    /// x^2 + y^2 - 1 <= 0
+    fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+    }
+
+    fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+        Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let cost = ConstrainedRosenbrock {};
+
+    // The unconstrained subproblems are solved with L-BFGS
+    let linesearch = MoreThuenteLineSearch::new();
+    let inner = LBFGS::new(linesearch, 7);
+
+    let solver = AugmentedLagrangian::new(inner)
+        .with_inequality_constraints()
+        .with_constraint_tolerance(1e-8)?;
+
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(50))
+        .run()?;
+
+    // Print Result
+    println!("{res}");
+    println!("Solution: {:?}", res.state.get_param().unwrap());
+    println!(
+        "Multipliers: {:?}",
+        res.solver().inequality_multipliers().unwrap()
+    );
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
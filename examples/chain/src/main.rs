@@ -0,0 +1,63 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{observers::ObserverMode, CostFunction, Error, Executor, Gradient},
+    solver::{
+        chain::Chain, linesearch::MoreThuenteLineSearch, particleswarm::ParticleSwarm,
+        quasinewton::LBFGS,
+    },
+};
+use argmin_observer_slog::SlogLogger;
+use argmin_testfunctions::{himmelblau, himmelblau_derivative};
+
+struct Himmelblau {}
+
+impl CostFunction for Himmelblau {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(himmelblau(&[p[0], p[1]]))
+    }
+}
+
+impl Gradient for Himmelblau {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(himmelblau_derivative(&[p[0], p[1]]).to_vec())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Explore the parameter space with a particle swarm ...
+    let pso = ParticleSwarm::new((vec![-4.0, -4.0], vec![4.0, 4.0]), 40);
+
+    // ... and polish the best particle with L-BFGS
+    let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+
+    let solver = Chain::new(pso, lbfgs).with_first_max_iters(20);
+
+    // Run solver
+    let res = Executor::new(Himmelblau {}, solver)
+        .configure(|state| state.max_iters(100).counting(true))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
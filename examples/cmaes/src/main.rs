@@ -0,0 +1,42 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::{CostFunction, Error, Executor};
+use argmin::solver::cmaes::{RestartStrategy, CMAES};
+use argmin_testfunctions::rastrigin;
+
+struct Rastrigin {}
+
+impl CostFunction for Rastrigin {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rastrigin(param))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let cost_function = Rastrigin {};
+
+    let solver = CMAES::new(vec![3.0, -2.0, 4.0, 1.0], 2.0).with_restarts(RestartStrategy::IPOP, 9);
+
+    let res = Executor::new(cost_function, solver)
+        .configure(|state| state.max_iters(5000).target_cost(1e-10))
+        .run()?;
+
+    // Print Result
+    println!("{res}");
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
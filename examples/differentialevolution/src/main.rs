@@ -0,0 +1,56 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{checkpointing::CheckpointingFrequency, CostFunction, Error, Executor},
+    solver::differentialevolution::{DifferentialEvolution, Strategy},
+};
+use argmin_checkpointing_file::FileCheckpoint;
+use argmin_testfunctions::rastrigin;
+
+struct Rastrigin {}
+
+impl CostFunction for Rastrigin {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rastrigin(param))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let cost_function = Rastrigin {};
+
+    let solver = DifferentialEvolution::new((vec![-5.12; 4], vec![5.12; 4]), 40)
+        .with_strategy(Strategy::JADE)
+        .with_mutation_factor(0.5)?
+        .with_crossover_probability(0.5)?;
+
+    // Configure checkpointing; rerunning the example resumes from the last checkpoint.
+    let checkpoint = FileCheckpoint::new(
+        ".checkpoints",
+        "differentialevolution",
+        CheckpointingFrequency::Every(20),
+    );
+
+    let res = Executor::new(cost_function, solver)
+        .configure(|state| state.max_iters(500).target_cost(1e-10))
+        .checkpointing(checkpoint)
+        .run()?;
+
+    // Print Result
+    println!("{res}");
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{observers::ObserverMode, Error, Executor, Jacobian, Operator},
+    solver::gaussnewton::GaussNewton,
+};
+use argmin_observer_slog::SlogLogger;
+use ndarray::Array1;
+use sprs::{CsMat, TriMat};
+
+// Smoothing of noisy measurements `y_i`: The sought parameters `x_i` should be close to the
+// measurements while neighbouring parameters should be similar. This leads to the residuals
+// `x_i - y_i` and `lambda * (x_{i+1} - x_i)`, whose Jacobian is very sparse: each row contains
+// at most two nonzero entries.
+struct Problem {
+    data: Array1<f64>,
+    lambda: f64,
+}
+
+impl Operator for Problem {
+    type Param = Array1<f64>;
+    type Output = Array1<f64>;
+
+    fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        let n = self.data.len();
+        Ok(Array1::from_shape_fn(2 * n - 1, |i| {
+            if i < n {
+                p[i] - self.data[i]
+            } else {
+                let i = i - n;
+                self.lambda * (p[i + 1] - p[i])
+            }
+        }))
+    }
+}
+
+impl Jacobian for Problem {
+    type Param = Array1<f64>;
+    type Jacobian = CsMat<f64>;
+
+    fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+        let n = self.data.len();
+        let mut jacobian = TriMat::new((2 * n - 1, n));
+        for i in 0..n {
+            jacobian.add_triplet(i, i, 1.0);
+        }
+        for i in 0..(n - 1) {
+            jacobian.add_triplet(n + i, i, -self.lambda);
+            jacobian.add_triplet(n + i, i + 1, self.lambda);
+        }
+        Ok(jacobian.to_csr())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Noisy samples of a sine wave
+    let n = 50;
+    let data = Array1::from_shape_fn(n, |i| {
+        let x = i as f64 / n as f64 * std::f64::consts::TAU;
+        x.sin() + 0.1 * (13.0 * x).sin()
+    });
+
+    let problem = Problem { data, lambda: 2.0 };
+
+    // Define initial parameter vector
+    let init_param: Array1<f64> = Array1::zeros(n);
+
+    // Set up solver
+    let solver: GaussNewton<f64> = GaussNewton::new();
+
+    // Run solver
+    let res = Executor::new(problem, solver)
+        .configure(|state| state.param(init_param).max_iters(10))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{observers::ObserverMode, CostFunction, Error, Executor, Gradient},
+    solver::{
+        linesearch::MoreThuenteLineSearch,
+        multistart::{BasinHopping, MultiStart, Sampling},
+        quasinewton::LBFGS,
+    },
+};
+use argmin_observer_slog::SlogLogger;
+use argmin_testfunctions::{rastrigin, rastrigin_derivative};
+
+#[derive(Clone)]
+struct Rastrigin {}
+
+impl CostFunction for Rastrigin {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rastrigin(p))
+    }
+}
+
+impl Gradient for Rastrigin {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rastrigin_derivative(p))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let bounds = (vec![-5.12; 3], vec![5.12; 3]);
+    let lbfgs = LBFGS::new(MoreThuenteLineSearch::new(), 7);
+
+    // Run L-BFGS from 64 points of a Latin hypercube sample, 16 at a time
+    let solver = MultiStart::new(lbfgs.clone(), bounds.clone(), 64)
+        .with_sampling(Sampling::LatinHypercube)
+        .with_batch_size(16)?;
+
+    let res = Executor::new(Rastrigin {}, solver)
+        .configure(|state| state.max_iters(100).counting(true))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    println!("{res}");
+    println!(
+        "Multi-start found {} distinct local minima",
+        res.solver().local_minima().len()
+    );
+
+    // Basin-hopping starting from a local minimum
+    let solver = BasinHopping::new(lbfgs)
+        .with_bounds(bounds)?
+        .with_step_size(1.0)?
+        .with_trials(4)?;
+
+    let res = Executor::new(Rastrigin {}, solver)
+        .configure(|state| state.param(vec![3.0, -2.0, 4.0]).max_iters(50))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    println!("{res}");
+    println!(
+        "Basin-hopping found {} distinct local minima",
+        res.solver().local_minima().len()
+    );
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{observers::ObserverMode, BatchExecutor, CostFunction, Error, Gradient, IterState},
+    solver::{linesearch::MoreThuenteLineSearch, quasinewton::LBFGS},
+};
+use argmin_observer_spectator::SpectatorBuilder;
+use argmin_testfunctions::{rosenbrock_ab, rosenbrock_ab_derivative};
+
+/// Rosenbrock function with parameters `a` and `b`
+struct Rosenbrock {
+    a: f64,
+    b: f64,
+}
+
+impl CostFunction for Rosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        // Artificially slow down computation of cost function
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        Ok(rosenbrock_ab(p, self.a, self.b))
+    }
+}
+
+impl Gradient for Rosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rosenbrock_ab_derivative(p, self.a, self.b))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // One independent optimization problem per value of `a`
+    let runs = (0..16).map(|i| {
+        let a = 1.0 + 0.25 * i as f64;
+        (
+            Rosenbrock { a, b: 100.0 },
+            LBFGS::new(MoreThuenteLineSearch::new(), 7),
+            |state: IterState<_, _, _, _, _, _>| state.param(vec![-1.2, 1.0]).max_iters(1000),
+        )
+    });
+
+    let results = BatchExecutor::new(runs)
+        // Run at most four optimizations at the same time
+        .num_threads(4)
+        // Each run reports to Spectator under its own name
+        .with_setup(|index, executor| {
+            let observer = SpectatorBuilder::new()
+                .with_name(format!("rosenbrock_{index}"))
+                .build();
+            executor.add_observer(observer, ObserverMode::Always)
+        })
+        .run()?;
+
+    // Print results
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(res) => println!("rosenbrock_{index}:\n{res}"),
+            Err(e) => println!("rosenbrock_{index} failed: {e}"),
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::{
+    core::{
+        observers::ObserverMode, CostFunction, EqualityConstraints, Error, Executor, Gradient,
+        InequalityConstraints, State,
+    },
+    solver::{
+        linesearch::{condition::ArmijoCondition, BacktrackingLineSearch},
+        sqp::SQP,
+    },
+};
+use argmin_observer_slog::SlogLogger;
+use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
+
+/// Rosenbrock function restricted to the unit disk and to the line `x = 2y`
+struct ConstrainedRosenbrock {}
+
+impl CostFunction for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rosenbrock(p))
+    }
+}
+
+impl Gradient for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rosenbrock_derivative(p))
+    }
+}
+
+impl EqualityConstraints for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+    type Jacobian = Vec<Vec<f64>>;
+
+    /// x - 2y = 0
+    fn equality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(vec![p[0] - 2.0 * p[1]])
+    }
+
+    fn equality_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+        Ok(vec![vec![1.0, -2.0]])
+    }
+}
+
+impl InequalityConstraints for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+    type Jacobian = Vec<Vec<f64>>;
+
+    /// x^2 + y^2 - 1 <= 0
+    fn inequality_constraints(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+    }
+
+    fn inequality_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+        Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let cost = ConstrainedRosenbrock {};
+
+    // The merit function is nonsmooth, therefore a backtracking line search is used
+    let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4)?);
+
+    let solver = SQP::new(linesearch).with_tolerance_kkt(1e-8)?;
+
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Print Result
+    println!("{res}");
+    println!("Solution: {:?}", res.state.get_param().unwrap());
+    println!("Equality multipliers: {:?}", res.solver().eq_multipliers());
+    println!(
+        "Inequality multipliers: {:?}",
+        res.solver().ineq_multipliers()
+    );
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
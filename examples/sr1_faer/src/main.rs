@@ -0,0 +1,77 @@
+// Copyright 2018-2024 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[allow(unused_imports)]
+use argmin::{
+    core::{observers::ObserverMode, CostFunction, Error, Executor, Gradient},
+    solver::{
+        linesearch::{HagerZhangLineSearch, MoreThuenteLineSearch},
+        quasinewton::SR1,
+    },
+};
+use argmin_observer_slog::SlogLogger;
+use argmin_testfunctions::{styblinski_tang, styblinski_tang_derivative};
+use faer::{Col, Mat};
+
+struct StyblinskiTang {}
+
+impl CostFunction for StyblinskiTang {
+    type Param = Col<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(styblinski_tang(p.as_slice()))
+    }
+}
+impl Gradient for StyblinskiTang {
+    type Param = Col<f64>;
+    type Gradient = Col<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        let grad = styblinski_tang_derivative(p.as_slice());
+        Ok(Col::from_fn(grad.len(), |i| grad[i]))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Define cost function
+    let cost = StyblinskiTang {};
+
+    // Define initial parameter vector
+    // let init_param: Col<f64> = faer::col![-1.2, 1.0, -5.0, 2.0, 3.0, 2.0, 4.0, 5.0];
+    let init_param: Col<f64> = faer::col![5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0];
+    let init_hessian: Mat<f64> = Mat::identity(8, 8);
+
+    // set up a line search
+    let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9)?;
+    // let linesearch = HagerZhangLineSearch::new();
+
+    // Set up solver
+    let solver = SR1::new(linesearch);
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| {
+            state
+                .param(init_param)
+                .inv_hessian(init_hessian)
+                .max_iters(1000)
+        })
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}